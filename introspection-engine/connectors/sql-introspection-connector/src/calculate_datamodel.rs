@@ -59,7 +59,7 @@ mod tests {
     use super::*;
     use datamodel::{
         dml, Datamodel, DefaultValue as DMLDefault, Field, FieldArity, FieldType, Model, NativeTypeInstance,
-        OnDeleteStrategy, RelationField, RelationInfo, ScalarField, ScalarType, ValueGenerator,
+        RelationField, RelationInfo, ScalarField, ScalarType, ValueGenerator,
     };
     use native_types::{NativeType, PostgresType};
     use pretty_assertions::assert_eq;
@@ -73,6 +73,10 @@ mod tests {
                 name: "Table1".to_string(),
                 documentation: None,
                 is_embedded: false,
+                is_view: false,
+                view_definition: None,
+                schema: None,
+                check_constraints: vec![],
                 is_commented_out: false,
                 is_ignored: false,
                 fields: vec![
@@ -94,6 +98,7 @@ mod tests {
                         is_updated_at: false,
                         is_commented_out: false,
                         is_ignored: false,
+                        custom_attributes: vec![],
                     }),
                     Field::ScalarField(ScalarField::new(
                         "list",
@@ -104,8 +109,10 @@ mod tests {
                 is_generated: false,
                 indices: vec![],
                 id_fields: vec![],
+                custom_attributes: vec![],
             }],
             enums: vec![],
+            composite_types: vec![],
         };
 
         let schema = SqlSchema {
@@ -139,6 +146,8 @@ mod tests {
                     constraint_name: None,
                 }),
                 foreign_keys: vec![],
+                schema: None,
+                check_constraints: vec![],
             }],
             enums: vec![],
             sequences: vec![],
@@ -160,6 +169,10 @@ mod tests {
                     name: "Table1".to_string(),
                     documentation: None,
                     is_embedded: false,
+                    is_view: false,
+                    view_definition: None,
+                    schema: None,
+                    check_constraints: vec![],
                     is_commented_out: false,
                     is_ignored: false,
                     fields: vec![Field::ScalarField(ScalarField {
@@ -182,10 +195,12 @@ mod tests {
                         is_updated_at: false,
                         is_commented_out: false,
                         is_ignored: false,
+                        custom_attributes: vec![],
                     })],
                     is_generated: false,
                     indices: vec![],
                     id_fields: vec![],
+                    custom_attributes: vec![],
                 },
                 // Model with non-auto-incrementing primary key
                 Model {
@@ -193,6 +208,10 @@ mod tests {
                     name: "Table2".to_string(),
                     documentation: None,
                     is_embedded: false,
+                    is_view: false,
+                    view_definition: None,
+                    schema: None,
+                    check_constraints: vec![],
                     is_commented_out: false,
                     is_ignored: false,
                     fields: vec![Field::ScalarField(ScalarField {
@@ -215,10 +234,12 @@ mod tests {
                         is_updated_at: false,
                         is_commented_out: false,
                         is_ignored: false,
+                        custom_attributes: vec![],
                     })],
                     is_generated: false,
                     indices: vec![],
                     id_fields: vec![],
+                    custom_attributes: vec![],
                 },
                 // Model with primary key seeded by sequence
                 Model {
@@ -226,6 +247,10 @@ mod tests {
                     name: "Table3".to_string(),
                     documentation: None,
                     is_embedded: false,
+                    is_view: false,
+                    view_definition: None,
+                    schema: None,
+                    check_constraints: vec![],
                     is_commented_out: false,
                     is_ignored: false,
                     fields: vec![Field::ScalarField(ScalarField {
@@ -248,13 +273,16 @@ mod tests {
                         is_updated_at: false,
                         is_commented_out: false,
                         is_ignored: false,
+                        custom_attributes: vec![],
                     })],
                     is_generated: false,
                     indices: vec![],
                     id_fields: vec![],
+                    custom_attributes: vec![],
                 },
             ],
             enums: vec![],
+            composite_types: vec![],
         };
 
         let schema = SqlSchema {
@@ -280,6 +308,8 @@ mod tests {
                         constraint_name: None,
                     }),
                     foreign_keys: vec![],
+                    schema: None,
+                    check_constraints: vec![],
                 },
                 Table {
                     name: "Table2".to_string(),
@@ -301,6 +331,8 @@ mod tests {
                         constraint_name: None,
                     }),
                     foreign_keys: vec![],
+                    schema: None,
+                    check_constraints: vec![],
                 },
                 Table {
                     name: "Table3".to_string(),
@@ -324,6 +356,8 @@ mod tests {
                         constraint_name: None,
                     }),
                     foreign_keys: vec![],
+                    schema: None,
+                    check_constraints: vec![],
                 },
             ],
             enums: vec![],
@@ -344,6 +378,10 @@ mod tests {
                 name: "Table1".to_string(),
                 documentation: None,
                 is_embedded: false,
+                is_view: false,
+                view_definition: None,
+                schema: None,
+                check_constraints: vec![],
                 is_commented_out: false,
                 is_ignored: false,
                 fields: vec![
@@ -365,13 +403,16 @@ mod tests {
                         is_updated_at: false,
                         is_commented_out: false,
                         is_ignored: false,
+                        custom_attributes: vec![],
                     }),
                 ],
                 is_generated: false,
                 indices: vec![],
                 id_fields: vec![],
+                custom_attributes: vec![],
             }],
             enums: vec![],
+            composite_types: vec![],
         };
 
         let schema = SqlSchema {
@@ -400,6 +441,8 @@ mod tests {
                 }],
                 primary_key: None,
                 foreign_keys: vec![],
+                schema: None,
+                check_constraints: vec![],
             }],
             enums: vec![],
             sequences: vec![],
@@ -419,6 +462,10 @@ mod tests {
                     name: "City".to_string(),
                     documentation: None,
                     is_embedded: false,
+                    is_view: false,
+                    view_definition: None,
+                    schema: None,
+                    check_constraints: vec![],
                     is_commented_out: false,
                     is_ignored: false,
                     fields: vec![
@@ -442,6 +489,7 @@ mod tests {
                             is_updated_at: false,
                             is_commented_out: false,
                             is_ignored: false,
+                            custom_attributes: vec![],
                         }),
                         Field::ScalarField(ScalarField::new(
                             "name",
@@ -463,19 +511,25 @@ mod tests {
                                 fields: vec![],
                                 references: vec![],
                                 name: "CityToUser".to_string(),
-                                on_delete: OnDeleteStrategy::None,
+                                on_delete: None,
+                                on_update: None,
                             },
                         )),
                     ],
                     is_generated: false,
                     indices: vec![],
                     id_fields: vec![],
+                    custom_attributes: vec![],
                 },
                 Model {
                     database_name: None,
                     name: "User".to_string(),
                     documentation: None,
                     is_embedded: false,
+                    is_view: false,
+                    view_definition: None,
+                    schema: None,
+                    check_constraints: vec![],
                     is_commented_out: false,
                     is_ignored: false,
                     fields: vec![
@@ -499,6 +553,7 @@ mod tests {
                             is_updated_at: false,
                             is_commented_out: false,
                             is_ignored: false,
+                            custom_attributes: vec![],
                         }),
                         Field::ScalarField(ScalarField {
                             name: "city_id".to_string(),
@@ -520,6 +575,7 @@ mod tests {
                             is_updated_at: false,
                             is_commented_out: false,
                             is_ignored: false,
+                            custom_attributes: vec![],
                         }),
                         Field::ScalarField(ScalarField {
                             name: "city_name".to_string(),
@@ -541,6 +597,7 @@ mod tests {
                             is_updated_at: false,
                             is_commented_out: false,
                             is_ignored: false,
+                            custom_attributes: vec![],
                         }),
                         Field::RelationField(RelationField::new(
                             "City",
@@ -550,16 +607,19 @@ mod tests {
                                 to: "City".to_string(),
                                 fields: vec!["city_id".to_string(), "city_name".to_string()],
                                 references: vec!["id".to_string(), "name".to_string()],
-                                on_delete: OnDeleteStrategy::None,
+                                on_delete: Some(dml::ReferentialAction::NoAction),
+                                on_update: Some(dml::ReferentialAction::NoAction),
                             },
                         )),
                     ],
                     is_generated: false,
                     indices: vec![],
                     id_fields: vec![],
+                    custom_attributes: vec![],
                 },
             ],
             enums: vec![],
+            composite_types: vec![],
         };
 
         let schema = SqlSchema {
@@ -599,6 +659,8 @@ mod tests {
                         constraint_name: None,
                     }),
                     foreign_keys: vec![],
+                    schema: None,
+                    check_constraints: vec![],
                 },
                 Table {
                     name: "User".to_string(),
@@ -648,10 +710,13 @@ mod tests {
                         constraint_name: None,
                         columns: vec!["city-id".to_string(), "city-name".to_string()],
                         referenced_table: "City".to_string(),
+                        referenced_schema: None,
                         on_delete_action: ForeignKeyAction::NoAction,
                         on_update_action: ForeignKeyAction::NoAction,
                         referenced_columns: vec!["id".to_string(), "name".to_string()],
                     }],
+                    schema: None,
+                    check_constraints: vec![],
                 },
             ],
             enums: vec![],
@@ -671,6 +736,10 @@ mod tests {
                 name: "User".to_string(),
                 documentation: None,
                 is_embedded: false,
+                is_view: false,
+                view_definition: None,
+                schema: None,
+                check_constraints: vec![],
                 is_commented_out: false,
                 is_ignored: false,
                 fields: vec![
@@ -694,6 +763,7 @@ mod tests {
                         is_updated_at: false,
                         is_commented_out: false,
                         is_ignored: false,
+                        custom_attributes: vec![],
                     }),
                     Field::ScalarField(ScalarField::new(
                         "name",
@@ -727,8 +797,10 @@ mod tests {
                     tpe: datamodel::dml::IndexType::Unique,
                 }],
                 id_fields: vec![],
+                custom_attributes: vec![],
             }],
             enums: vec![],
+            composite_types: vec![],
         };
 
         let schema = SqlSchema {
@@ -782,6 +854,8 @@ mod tests {
                     constraint_name: None,
                 }),
                 foreign_keys: vec![],
+                schema: None,
+                check_constraints: vec![],
             }],
             enums: vec![],
             sequences: vec![],
@@ -801,6 +875,10 @@ mod tests {
                     name: "City".to_string(),
                     documentation: None,
                     is_embedded: false,
+                    is_view: false,
+                    view_definition: None,
+                    schema: None,
+                    check_constraints: vec![],
                     is_commented_out: false,
                     is_ignored: false,
                     fields: vec![
@@ -824,6 +902,7 @@ mod tests {
                             is_updated_at: false,
                             is_commented_out: false,
                             is_ignored: false,
+                            custom_attributes: vec![],
                         }),
                         Field::ScalarField(ScalarField::new(
                             "name",
@@ -845,19 +924,25 @@ mod tests {
                                 fields: vec![],
                                 references: vec![],
                                 name: "CityToUser".to_string(),
-                                on_delete: OnDeleteStrategy::None,
+                                on_delete: None,
+                                on_update: None,
                             },
                         )),
                     ],
                     is_generated: false,
                     indices: vec![],
                     id_fields: vec![],
+                    custom_attributes: vec![],
                 },
                 Model {
                     database_name: None,
                     name: "User".to_string(),
                     documentation: None,
                     is_embedded: false,
+                    is_view: false,
+                    view_definition: None,
+                    schema: None,
+                    check_constraints: vec![],
                     is_commented_out: false,
                     is_ignored: false,
                     fields: vec![
@@ -881,6 +966,7 @@ mod tests {
                             is_updated_at: false,
                             is_commented_out: false,
                             is_ignored: false,
+                            custom_attributes: vec![],
                         }),
                         Field::ScalarField(ScalarField::new(
                             "city_id",
@@ -902,16 +988,19 @@ mod tests {
                                 to: "City".to_string(),
                                 fields: vec!["city_id".to_string()],
                                 references: vec!["id".to_string()],
-                                on_delete: OnDeleteStrategy::None,
+                                on_delete: Some(dml::ReferentialAction::NoAction),
+                                on_update: Some(dml::ReferentialAction::NoAction),
                             },
                         )),
                     ],
                     is_generated: false,
                     indices: vec![],
                     id_fields: vec![],
+                    custom_attributes: vec![],
                 },
             ],
             enums: vec![],
+            composite_types: vec![],
         };
 
         let schema = SqlSchema {
@@ -951,6 +1040,8 @@ mod tests {
                         constraint_name: None,
                     }),
                     foreign_keys: vec![],
+                    schema: None,
+                    check_constraints: vec![],
                 },
                 Table {
                     name: "User".to_string(),
@@ -988,10 +1079,13 @@ mod tests {
                         constraint_name: None,
                         columns: vec!["city_id".to_string()],
                         referenced_table: "City".to_string(),
+                        referenced_schema: None,
                         on_delete_action: ForeignKeyAction::NoAction,
                         on_update_action: ForeignKeyAction::NoAction,
                         referenced_columns: vec!["id".to_string()],
                     }],
+                    schema: None,
+                    check_constraints: vec![],
                 },
             ],
             enums: vec![],
@@ -1018,15 +1112,19 @@ mod tests {
                         documentation: None,
                         database_name: None,
                         commented_out: false,
+                        custom_attributes: vec![],
                     },
                     datamodel::dml::EnumValue {
                         name: "b".to_string(),
                         documentation: None,
                         database_name: None,
                         commented_out: false,
+                        custom_attributes: vec![],
                     },
                 ],
+                custom_attributes: vec![],
             }],
+            composite_types: vec![],
         };
 
         let enum_values = vec!["a".to_string(), "b".to_string()];
@@ -28,6 +28,15 @@ pub fn introspect(
     {
         debug!("Calculating model: {}", table.name);
         let mut model = Model::new(table.name.clone(), None);
+        model.schema = table.schema.clone();
+        model.check_constraints = table
+            .check_constraints
+            .iter()
+            .map(|check| dml::CheckConstraintDefinition {
+                name: check.name.clone(),
+                expression: check.expression.clone(),
+            })
+            .collect();
 
         for column in &table.columns {
             version_check.check_column_for_type_and_default_value(&column);
@@ -63,6 +72,31 @@ pub fn introspect(
         data_model.add_model(model);
     }
 
+    for view in schema.views.iter() {
+        debug!("Calculating view: {}", view.name);
+        let mut model = Model::new(view.name.clone(), None);
+        model.is_view = true;
+        model.view_definition = Some(view.definition.clone());
+
+        // Views have no keys of their own, the columns are introspected like those of a keyless table.
+        let table = Table {
+            name: view.name.clone(),
+            columns: view.columns.clone(),
+            indices: Vec::new(),
+            primary_key: None,
+            foreign_keys: Vec::new(),
+            schema: None,
+            check_constraints: Vec::new(),
+        };
+
+        for column in &table.columns {
+            let field = calculate_scalar_field(&table, &column, &sql_family);
+            model.add_field(Field::ScalarField(field));
+        }
+
+        data_model.add_model(model);
+    }
+
     for e in schema.enums.iter() {
         let values = e.values.iter().map(|v| dml::EnumValue::new(v)).collect();
         data_model.add_enum(dml::Enum::new(&e.name, values));
@@ -2,13 +2,15 @@ use crate::Dedup;
 use crate::SqlError;
 use datamodel::{
     common::RelationNames, Datamodel, DefaultValue as DMLDef, FieldArity, FieldType, IndexDefinition, Model,
-    OnDeleteStrategy, RelationField, RelationInfo, ScalarField, ScalarType, ValueGenerator as VG,
+    ReferentialAction, RelationField, RelationInfo, ScalarField, ScalarType, ValueGenerator as VG,
 };
 use datamodel_connector::Connector;
 use quaint::connector::SqlFamily;
 use sql_datamodel_connector::SqlDatamodelConnectors;
 use sql_schema_describer::DefaultKind;
-use sql_schema_describer::{Column, ColumnArity, ColumnTypeFamily, ForeignKey, Index, IndexType, SqlSchema, Table};
+use sql_schema_describer::{
+    Column, ColumnArity, ColumnTypeFamily, ForeignKey, ForeignKeyAction, Index, IndexType, SqlSchema, Table,
+};
 use tracing::debug;
 
 //checks
@@ -107,7 +109,8 @@ pub fn calculate_many_to_many_field(
         fields: vec![],
         to: opposite_foreign_key.referenced_table.clone(),
         references: opposite_foreign_key.referenced_columns.clone(),
-        on_delete: OnDeleteStrategy::None,
+        on_delete: None,
+        on_update: None,
     };
 
     let basename = opposite_foreign_key.referenced_table.clone();
@@ -125,6 +128,7 @@ pub(crate) fn calculate_index(index: &Index) -> IndexDefinition {
     let tpe = match index.tpe {
         IndexType::Unique => datamodel::dml::IndexType::Unique,
         IndexType::Normal => datamodel::dml::IndexType::Normal,
+        IndexType::Fulltext => datamodel::dml::IndexType::Fulltext,
     };
 
     IndexDefinition {
@@ -164,6 +168,7 @@ pub(crate) fn calculate_scalar_field(table: &Table, column: &Column, family: &Sq
         is_updated_at: false,
         is_commented_out: false,
         is_ignored: false,
+        custom_attributes: vec![],
     }
 }
 
@@ -179,7 +184,8 @@ pub(crate) fn calculate_relation_field(
         fields: foreign_key.columns.clone(),
         to: foreign_key.referenced_table.clone(),
         references: foreign_key.referenced_columns.clone(),
-        on_delete: OnDeleteStrategy::None,
+        on_delete: None,
+        on_update: None,
     };
 
     let columns: Vec<&Column> = foreign_key
@@ -193,7 +199,31 @@ pub(crate) fn calculate_relation_field(
         false => FieldArity::Required,
     };
 
-    Ok(RelationField::new(&foreign_key.referenced_table, arity, relation_info))
+    let mut relation_field = RelationField::new(&foreign_key.referenced_table, arity, relation_info);
+
+    // Only render the referential actions that differ from the defaults.
+    let on_delete = calculate_referential_action(&foreign_key.on_delete_action);
+    let on_update = calculate_referential_action(&foreign_key.on_update_action);
+
+    if on_delete != relation_field.default_on_delete_action() {
+        relation_field.relation_info.on_delete = Some(on_delete);
+    }
+
+    if on_update != relation_field.default_on_update_action() {
+        relation_field.relation_info.on_update = Some(on_update);
+    }
+
+    Ok(relation_field)
+}
+
+fn calculate_referential_action(action: &ForeignKeyAction) -> ReferentialAction {
+    match action {
+        ForeignKeyAction::Cascade => ReferentialAction::Cascade,
+        ForeignKeyAction::Restrict => ReferentialAction::Restrict,
+        ForeignKeyAction::NoAction => ReferentialAction::NoAction,
+        ForeignKeyAction::SetNull => ReferentialAction::SetNull,
+        ForeignKeyAction::SetDefault => ReferentialAction::SetDefault,
+    }
 }
 
 pub(crate) fn calculate_backrelation_field(
@@ -213,7 +243,8 @@ pub(crate) fn calculate_backrelation_field(
                 to: model.name.clone(),
                 fields: vec![],
                 references: vec![],
-                on_delete: OnDeleteStrategy::None,
+                on_delete: None,
+                on_update: None,
             };
 
             // unique or id
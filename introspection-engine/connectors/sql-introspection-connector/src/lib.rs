@@ -25,6 +25,9 @@ pub type SqlIntrospectionResult<T> = core::result::Result<T, SqlError>;
 pub struct SqlIntrospectionConnector {
     connection_info: ConnectionInfo,
     describer: Box<dyn SqlSchemaDescriberBackend>,
+    /// The database schemas to introspect, from the `schemas` datasource property. Only the
+    /// connection's schema is introspected when empty.
+    schemas: Vec<String>,
 }
 
 impl fmt::Debug for SqlIntrospectionConnector {
@@ -52,9 +55,16 @@ impl SqlIntrospectionConnector {
         Ok(SqlIntrospectionConnector {
             describer,
             connection_info,
+            schemas: Vec::new(),
         })
     }
 
+    /// Introspect the given database schemas instead of only the one from the connection string.
+    pub fn with_schemas(mut self, schemas: Vec<String>) -> Self {
+        self.schemas = schemas;
+        self
+    }
+
     async fn catch<O>(&self, fut: impl Future<Output = Result<O, SqlError>>) -> ConnectorResult<O> {
         fut.await
             .map_err(|sql_introspection_error| sql_introspection_error.into_connector_error(&self.connection_info))
@@ -74,7 +84,13 @@ impl SqlIntrospectionConnector {
     }
 
     async fn describe(&self) -> SqlIntrospectionResult<SqlSchema> {
-        Ok(self.describer.describe(self.connection_info.schema_name()).await?)
+        if self.schemas.is_empty() {
+            return Ok(self.describer.describe(self.connection_info.schema_name()).await?);
+        }
+
+        let schemas: Vec<&str> = self.schemas.iter().map(String::as_str).collect();
+
+        Ok(self.describer.describe_schemas(&schemas).await?)
     }
 
     async fn version(&self) -> SqlIntrospectionResult<String> {
@@ -1,6 +1,6 @@
 use crate::introspection_helpers::replace_field_names;
 use crate::warnings::*;
-use datamodel::{Datamodel, DefaultValue, FieldType, Ignorable, ValueGenerator};
+use datamodel::{Datamodel, DefaultValue, FieldType, Ignorable, ValueGenerator, WithCustomAttributes};
 use introspection_connector::Warning;
 use prisma_value::PrismaValue;
 use quaint::connector::SqlFamily;
@@ -432,6 +432,80 @@ pub fn enrich(old_data_model: &Datamodel, new_data_model: &mut Datamodel, family
         }
     }
 
+    // custom attributes of generators - we do NOT generate warnings for custom attributes
+    {
+        let mut re_introspected_model_custom_attributes = vec![];
+        let mut re_introspected_field_custom_attributes = vec![];
+        {
+            for model in new_data_model.models() {
+                if let Some(old_model) = old_data_model.find_model(&model.name) {
+                    if !old_model.custom_attributes.is_empty() {
+                        re_introspected_model_custom_attributes
+                            .push((Model::new(&model.name), old_model.custom_attributes.clone()))
+                    }
+
+                    for field in &model.fields {
+                        if let Some(old_field) = old_model.find_field(&field.name()) {
+                            if !old_field.custom_attributes().is_empty() {
+                                re_introspected_field_custom_attributes.push((
+                                    ModelAndField::new(&model.name, &field.name()),
+                                    old_field.custom_attributes().to_vec(),
+                                ))
+                            }
+                        }
+                    }
+                }
+            }
+
+            for (model, custom_attributes) in re_introspected_model_custom_attributes {
+                new_data_model.find_model_mut(&model.model).custom_attributes = custom_attributes;
+            }
+
+            for (model_and_field, custom_attributes) in re_introspected_field_custom_attributes {
+                let field = new_data_model.find_field_mut(&model_and_field.model, &model_and_field.field);
+
+                for custom_attribute in custom_attributes {
+                    field.add_custom_attribute(custom_attribute);
+                }
+            }
+        }
+
+        let mut re_introspected_enum_custom_attributes = vec![];
+        let mut re_introspected_enum_value_custom_attributes = vec![];
+        {
+            for enm in new_data_model.enums() {
+                if let Some(old_enum) = old_data_model.find_enum(&enm.name) {
+                    if !old_enum.custom_attributes.is_empty() {
+                        re_introspected_enum_custom_attributes
+                            .push((Enum::new(&enm.name), old_enum.custom_attributes.clone()))
+                    }
+
+                    for value in &enm.values {
+                        if let Some(old_value) = old_enum.find_value(&value.name) {
+                            if !old_value.custom_attributes.is_empty() {
+                                re_introspected_enum_value_custom_attributes.push((
+                                    EnumAndValue::new(&enm.name, &value.name),
+                                    old_value.custom_attributes.clone(),
+                                ))
+                            }
+                        }
+                    }
+                }
+            }
+
+            for (enm, custom_attributes) in re_introspected_enum_custom_attributes {
+                new_data_model.find_enum_mut(&enm.enm).custom_attributes = custom_attributes;
+            }
+
+            for (enum_and_value, custom_attributes) in re_introspected_enum_value_custom_attributes {
+                new_data_model
+                    .find_enum_mut(&enum_and_value.enm)
+                    .find_value_mut(&enum_and_value.value)
+                    .custom_attributes = custom_attributes;
+            }
+        }
+    }
+
     // restore old model order
     new_data_model.models.sort_by(|model_a, model_b| {
         let model_a_idx = old_data_model.models().position(|model| model.name == model_a.name);
@@ -68,20 +68,18 @@ impl RpcImpl {
     async fn load_connector(schema: &str) -> Result<(Configuration, String, Box<dyn IntrospectionConnector>), Error> {
         let config = datamodel::parse_configuration(&schema)?;
 
-        let url = config
+        let datasource = config
             .subject
             .datasources
             .first()
-            .ok_or_else(|| CommandError::Generic(anyhow::anyhow!("There is no datasource in the schema.")))?
-            .url()
-            .to_owned()
-            .value;
-
-        Ok((
-            config.subject,
-            url.clone(),
-            Box::new(SqlIntrospectionConnector::new(&url).await?),
-        ))
+            .ok_or_else(|| CommandError::Generic(anyhow::anyhow!("There is no datasource in the schema.")))?;
+
+        let url = datasource.url().to_owned().value;
+        let connector = SqlIntrospectionConnector::new(&url)
+            .await?
+            .with_schemas(datasource.schemas.clone());
+
+        Ok((config.subject, url, Box::new(connector)))
     }
 
     pub async fn catch<O>(fut: impl std::future::Future<Output = ConnectorResult<O>>) -> RpcResult<O> {
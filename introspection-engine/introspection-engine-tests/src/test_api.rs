@@ -122,7 +122,7 @@ impl TestApi {
     #[tracing::instrument(skip(self, data_model_string))]
     pub async fn re_introspect(&self, data_model_string: &str) -> Result<String> {
         let config = self.configuration();
-        let data_model = parse_datamodel(data_model_string).context("parsing datamodel")?;
+        let data_model = parse_datamodel(&self.dm_with_sources(data_model_string)).context("parsing datamodel")?;
 
         let introspection_result = self
             .api
@@ -138,7 +138,7 @@ impl TestApi {
     }
 
     pub async fn re_introspect_warnings(&self, data_model_string: &str) -> Result<String> {
-        let data_model = parse_datamodel(data_model_string)?;
+        let data_model = parse_datamodel(&self.dm_with_sources(data_model_string))?;
         let introspection_result = self.api.introspect(&data_model).await?;
 
         Ok(serde_json::to_string(&introspection_result.warnings)?)
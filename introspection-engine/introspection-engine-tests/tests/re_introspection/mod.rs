@@ -135,7 +135,7 @@ async fn mapped_model_and_field_name(api: &TestApi) -> crate::TestResult {
         model Post {{
             id               Int         @id @default(autoincrement())
             c_user_id        Int         @map("user_id")
-            Custom_User      Custom_User @relation(fields: [c_user_id], references: [c_id])
+            Custom_User      Custom_User @relation(fields: [c_user_id], references: [c_id], onDelete: NoAction, onUpdate: NoAction)
             {}
         }}
 
@@ -154,7 +154,7 @@ async fn mapped_model_and_field_name(api: &TestApi) -> crate::TestResult {
         model Post {{
             id               Int         @id @default(autoincrement())
             c_user_id        Int         @map("user_id")
-            Custom_User      Custom_User @relation(fields: [c_user_id], references: [c_id])
+            Custom_User      Custom_User @relation(fields: [c_user_id], references: [c_id], onDelete: NoAction, onUpdate: NoAction)
             {}
         }}
 
@@ -236,7 +236,7 @@ async fn manually_mapped_model_and_field_name(api: &TestApi) -> crate::TestResul
         model Post {{
             id               Int         @id @default(autoincrement())
             c_user_id        Int         @map("user_id")
-            Custom_User      Custom_User @relation(fields: [c_user_id], references: [c_id])
+            Custom_User      Custom_User @relation(fields: [c_user_id], references: [c_id], onDelete: NoAction, onUpdate: NoAction)
             {}
         }}
 
@@ -255,7 +255,7 @@ async fn manually_mapped_model_and_field_name(api: &TestApi) -> crate::TestResul
         model Post {{
             id               Int         @id @default(autoincrement())
             c_user_id        Int         @map("user_id")
-            Custom_User      Custom_User @relation(fields: [c_user_id], references: [c_id])
+            Custom_User      Custom_User @relation(fields: [c_user_id], references: [c_id], onDelete: NoAction, onUpdate: NoAction)
             {}
         }}
 
@@ -818,8 +818,8 @@ async fn multiple_changed_relation_names(api: &TestApi) -> crate::TestResult {
             id                                            Int         @id @default(autoincrement())
             morningEmployeeId                             Int
             eveningEmployeeId                             Int
-            Employee_EmployeeToSchedule_eveningEmployeeId Employee    @relation("EmployeeToSchedule_eveningEmployeeId", fields: [eveningEmployeeId], references: [id])
-            Employee_EmployeeToSchedule_morningEmployeeId Employee    @relation("EmployeeToSchedule_morningEmployeeId", fields: [morningEmployeeId], references: [id])
+            Employee_EmployeeToSchedule_eveningEmployeeId Employee    @relation("EmployeeToSchedule_eveningEmployeeId", fields: [eveningEmployeeId], references: [id], onDelete: NoAction, onUpdate: NoAction)
+            Employee_EmployeeToSchedule_morningEmployeeId Employee    @relation("EmployeeToSchedule_morningEmployeeId", fields: [morningEmployeeId], references: [id], onDelete: NoAction, onUpdate: NoAction)
             {}
             {}
         }}
@@ -839,8 +839,8 @@ async fn multiple_changed_relation_names(api: &TestApi) -> crate::TestResult {
             id                                            Int         @id @default(autoincrement())
             morningEmployeeId                             Int
             eveningEmployeeId                             Int
-            Employee_EmployeeToSchedule_eveningEmployeeId Employee    @relation("EmployeeToSchedule_eveningEmployeeId", fields: [eveningEmployeeId], references: [id])
-            Employee_EmployeeToSchedule_morningEmployeeId Employee    @relation("EmployeeToSchedule_morningEmployeeId", fields: [morningEmployeeId], references: [id])
+            Employee_EmployeeToSchedule_eveningEmployeeId Employee    @relation("EmployeeToSchedule_eveningEmployeeId", fields: [eveningEmployeeId], references: [id], onDelete: NoAction, onUpdate: NoAction)
+            Employee_EmployeeToSchedule_morningEmployeeId Employee    @relation("EmployeeToSchedule_morningEmployeeId", fields: [morningEmployeeId], references: [id], onDelete: NoAction, onUpdate: NoAction)
             {}
             {}
         }}
@@ -881,7 +881,7 @@ async fn custom_virtual_relation_field_names(api: &TestApi) -> crate::TestResult
         model Post {
             id               Int @id @default(autoincrement())
             user_id          Int  @unique
-            custom_User      User @relation(fields: [user_id], references: [id])
+            custom_User      User @relation(fields: [user_id], references: [id], onDelete: NoAction, onUpdate: NoAction)
         }
 
         model User {
@@ -894,7 +894,7 @@ async fn custom_virtual_relation_field_names(api: &TestApi) -> crate::TestResult
         model Post {
             id               Int @id @default(autoincrement())
             user_id          Int  @unique
-            custom_User      User @relation(fields: [user_id], references: [id])
+            custom_User      User @relation(fields: [user_id], references: [id], onDelete: NoAction, onUpdate: NoAction)
         }
 
         model User {
@@ -1122,8 +1122,8 @@ async fn multiple_changed_relation_names_due_to_mapped_models(api: &TestApi) ->
             id               Int @id @default(autoincrement())
             user_id          Int  @unique
             user_id2         Int  @unique
-            custom_User      Custom_User @relation("CustomRelationName", fields: [user_id], references: [id])
-            custom_User2     Custom_User @relation("AnotherCustomRelationName", fields: [user_id2], references: [id])
+            custom_User      Custom_User @relation("CustomRelationName", fields: [user_id], references: [id], onDelete: NoAction, onUpdate: NoAction)
+            custom_User2     Custom_User @relation("AnotherCustomRelationName", fields: [user_id2], references: [id], onDelete: NoAction, onUpdate: NoAction)
         }
 
         model Custom_User {
@@ -1140,8 +1140,8 @@ async fn multiple_changed_relation_names_due_to_mapped_models(api: &TestApi) ->
             id               Int @id @default(autoincrement())
             user_id          Int  @unique
             user_id2         Int  @unique
-            custom_User      Custom_User @relation("CustomRelationName", fields: [user_id], references: [id])
-            custom_User2     Custom_User @relation("AnotherCustomRelationName", fields: [user_id2], references: [id])
+            custom_User      Custom_User @relation("CustomRelationName", fields: [user_id], references: [id], onDelete: NoAction, onUpdate: NoAction)
+            custom_User2     Custom_User @relation("AnotherCustomRelationName", fields: [user_id2], references: [id], onDelete: NoAction, onUpdate: NoAction)
         }
 
         model Custom_User {
@@ -1212,6 +1212,75 @@ async fn virtual_cuid_default(api: &TestApi) -> crate::TestResult {
     Ok(())
 }
 
+#[test_each_connector(tags("postgres"))]
+async fn custom_attributes_should_be_kept(api: &TestApi) -> crate::TestResult {
+    let sql = "CREATE Type a as ENUM (\'A\')".to_string();
+    api.database().execute_raw(&sql, &[]).await?;
+
+    api.barrel()
+        .execute(|migration| {
+            migration.create_table("User", |t| {
+                t.add_column("id", types::primary());
+                t.add_column("name", types::text());
+            });
+
+            migration.create_table("Unrelated", |t| {
+                t.add_column("id", types::primary());
+            });
+        })
+        .await?;
+
+    let generator = indoc! {r#"
+        generator client {
+            provider         = "prisma-client-js"
+            customAttributes = ["zod", "graphql"]
+        }
+    "#};
+
+    let input_dm = formatdoc! {r#"
+        {generator}
+
+        model User {{
+            id   Int    @id @default(autoincrement())
+            name String @zod.min(3) @zod.regex("^[a-z]+$", message: "lowercase only")
+
+            @@graphql.hide
+        }}
+
+        enum a {{
+            A @graphql.name("a")
+
+            @@graphql.deprecated
+        }}
+    "#, generator = generator};
+
+    let final_dm = formatdoc! {r#"
+        {generator}
+
+        model User {{
+            id   Int    @id @default(autoincrement())
+            name String @zod.min(3) @zod.regex("^[a-z]+$", message: "lowercase only")
+
+            @@graphql.hide
+        }}
+
+        model Unrelated {{
+            id               Int @id @default(autoincrement())
+        }}
+
+        enum a {{
+            A @graphql.name("a")
+
+            @@graphql.deprecated
+        }}
+    "#, generator = generator};
+
+    let result = format!("{}{}", generator, api.re_introspect(&input_dm).await?);
+    api.assert_eq_datamodels(&final_dm, &result);
+
+    Ok(())
+}
+
 #[test_each_connector(tags("postgres"))]
 async fn comments_should_be_kept(api: &TestApi) -> crate::TestResult {
     let sql = "CREATE Type a as ENUM (\'A\')".to_string();
@@ -38,7 +38,7 @@ async fn compound_foreign_keys_for_one_to_one_relations(api: &TestApi) -> crate:
             id       Int   @id @default(autoincrement())
             user_id  Int?
             user_age Int?
-            User     User? @relation(fields: [user_id, user_age], references: [id, age])
+            User     User? @relation(fields: [user_id, user_age], references: [id, age], onDelete: NoAction, onUpdate: NoAction)
 
             @@unique([user_id, user_age], name: "{}")
         }}
@@ -94,7 +94,7 @@ async fn compound_foreign_keys_for_required_one_to_one_relations(api: &TestApi)
             id       Int  @id @default(autoincrement())
             user_id  Int
             user_age Int
-            User     User @relation(fields: [user_id, user_age], references: [id, age])
+            User     User @relation(fields: [user_id, user_age], references: [id, age], onDelete: NoAction, onUpdate: NoAction)
 
             @@unique([user_id, user_age], name: "{}")
         }}
@@ -148,7 +148,7 @@ async fn compound_foreign_keys_for_one_to_many_relations(api: &TestApi) -> crate
             id       Int   @id @default(autoincrement())
             user_id  Int?
             user_age Int?
-            User     User? @relation(fields: [user_id, user_age], references: [id, age])
+            User     User? @relation(fields: [user_id, user_age], references: [id, age], onDelete: NoAction, onUpdate: NoAction)
             {}
         }}
 
@@ -201,7 +201,7 @@ async fn compound_foreign_keys_for_required_one_to_many_relations(api: &TestApi)
             id       Int  @id @default(autoincrement())
             user_id  Int
             user_age Int
-            User     User @relation(fields: [user_id, user_age], references: [id, age])
+            User     User @relation(fields: [user_id, user_age], references: [id, age], onDelete: NoAction, onUpdate: NoAction)
             {}
         }}
 
@@ -256,7 +256,7 @@ async fn compound_foreign_keys_for_required_self_relations(api: &TestApi) -> cra
             age          Int
             partner_id   Int
             partner_age  Int
-            Person       Person   @relation("PersonToPerson_partner_id_partner_age", fields: [partner_id, partner_age], references: [id, age])
+            Person       Person   @relation("PersonToPerson_partner_id_partner_age", fields: [partner_id, partner_age], references: [id, age], onDelete: NoAction, onUpdate: NoAction)
             other_Person Person[] @relation("PersonToPerson_partner_id_partner_age")
 
             @@unique([id, age], name: "{}")
@@ -306,7 +306,7 @@ async fn compound_foreign_keys_for_self_relations(api: &TestApi) -> crate::TestR
             age          Int
             partner_id   Int?
             partner_age  Int?
-            Person       Person?  @relation("PersonToPerson_partner_id_partner_age", fields: [partner_id, partner_age], references: [id, age])
+            Person       Person?  @relation("PersonToPerson_partner_id_partner_age", fields: [partner_id, partner_age], references: [id, age], onDelete: NoAction, onUpdate: NoAction)
             other_Person Person[] @relation("PersonToPerson_partner_id_partner_age")
 
             @@unique([id, age], name: "{}")
@@ -356,7 +356,7 @@ async fn compound_foreign_keys_with_defaults(api: &TestApi) -> crate::TestResult
             age          Int
             partner_id   Int      @default(0)
             partner_age  Int      @default(0)
-            Person       Person   @relation("PersonToPerson_partner_id_partner_age", fields: [partner_id, partner_age], references: [id, age])
+            Person       Person   @relation("PersonToPerson_partner_id_partner_age", fields: [partner_id, partner_age], references: [id, age], onDelete: NoAction, onUpdate: NoAction)
             other_Person Person[] @relation("PersonToPerson_partner_id_partner_age")
 
             @@unique([id, age], name: "{}")
@@ -410,7 +410,7 @@ async fn compound_foreign_keys_for_one_to_many_relations_with_non_unique_index(a
             id       Int  @id @default(autoincrement())
             user_id  Int
             user_age Int
-            User     User @relation(fields: [user_id, user_age], references: [id, age])
+            User     User @relation(fields: [user_id, user_age], references: [id, age], onDelete: NoAction, onUpdate: NoAction)
             {}
         }}
 
@@ -470,7 +470,7 @@ async fn repro_matt_references_on_wrong_side(api: &TestApi) -> crate::TestResult
             one Int
             two Int
 
-            a   a   @relation(fields: [one, two], references: [one, two])
+            a   a   @relation(fields: [one, two], references: [one, two], onDelete: NoAction, onUpdate: NoAction)
             {}
         }}
     "#,
@@ -523,7 +523,7 @@ async fn a_compound_fk_pk_with_overlapping_primary_key(api: &TestApi) -> crate::
             dummy Int
             one   Int
             two   Int
-            a     a   @relation(fields: [one, two], references: [one, two])
+            a     a   @relation(fields: [one, two], references: [one, two], onDelete: NoAction, onUpdate: NoAction)
 
             @@id([dummy, one, two])
             {}
@@ -584,8 +584,8 @@ async fn compound_foreign_keys_for_duplicate_one_to_many_relations(api: &TestApi
             user_age                                         Int?
             other_user_id                                    Int?
             other_user_age                                   Int?
-            User_Post_other_user_id_other_user_ageToUser     User? @relation("Post_other_user_id_other_user_ageToUser", fields: [other_user_id, other_user_age], references: [id, age])
-            User_Post_user_id_user_ageToUser                 User? @relation("Post_user_id_user_ageToUser", fields: [user_id, user_age], references: [id, age])
+            User_Post_other_user_id_other_user_ageToUser     User? @relation("Post_other_user_id_other_user_ageToUser", fields: [other_user_id, other_user_age], references: [id, age], onDelete: NoAction, onUpdate: NoAction)
+            User_Post_user_id_user_ageToUser                 User? @relation("Post_user_id_user_ageToUser", fields: [user_id, user_age], references: [id, age], onDelete: NoAction, onUpdate: NoAction)
             {}
         }}
 
@@ -67,6 +67,8 @@ async fn database_description_for_mysql_should_work(api: &TestApi) -> crate::Tes
                 },
             ),
             foreign_keys: [],
+                schema: None,
+                check_constraints: [],
         },
     ],
     enums: [],
@@ -132,6 +134,8 @@ async fn database_description_for_mysql_8_should_work(api: &TestApi) -> crate::T
                 },
             ),
             foreign_keys: [],
+                schema: None,
+                check_constraints: [],
         },
     ],
     enums: [],
@@ -210,6 +214,8 @@ async fn database_description_for_postgres_should_work(api: &TestApi) -> crate::
                 },
             ),
             foreign_keys: [],
+                schema: None,
+                check_constraints: [],
         },
     ],
     enums: [],
@@ -271,6 +277,8 @@ async fn database_description_for_sqlite_should_work(api: &TestApi) -> crate::Te
                 },
             ),
             foreign_keys: [],
+                schema: None,
+                check_constraints: [],
         },
     ],
     enums: [],
@@ -0,0 +1,38 @@
+use indoc::indoc;
+use introspection_engine_tests::test_api::*;
+use test_macros::test_each_connector;
+
+#[test_each_connector(tags("sqlite"))]
+async fn views_are_introspected_with_their_definition(api: &TestApi) -> crate::TestResult {
+    api.barrel()
+        .execute(|migration| {
+            migration.create_table("cats", |t| {
+                t.inject_custom("id INTEGER PRIMARY KEY");
+                t.inject_custom("name TEXT");
+            });
+
+            migration.inject_custom("CREATE VIEW cat_names AS SELECT name FROM cats");
+        })
+        .await?;
+
+    let dm = indoc! {r#"
+        model cats {
+          id   Int     @id @default(autoincrement())
+          name String?
+        }
+
+        /// The underlying table does not contain a valid unique identifier and can therefore currently not be handled by the Prisma Client.
+        view cat_names {
+          name String?
+
+          @@ignore
+          @@definition("CREATE VIEW cat_names AS SELECT name FROM cats")
+        }
+    "#};
+
+    let result = api.introspect().await?;
+
+    api.assert_eq_datamodels(dm, &result);
+
+    Ok(())
+}
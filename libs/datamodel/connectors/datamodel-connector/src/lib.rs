@@ -8,7 +8,7 @@ pub use combined_connector::CombinedConnector;
 use crate::connector_error::{ConnectorError, ConnectorErrorFactory, ErrorKind};
 use dml::{
     field::Field, model::Model, native_type_constructor::NativeTypeConstructor,
-    native_type_instance::NativeTypeInstance, scalars::ScalarType,
+    native_type_instance::NativeTypeInstance, relation_info::ReferentialAction, scalars::ScalarType,
 };
 
 pub trait Connector: Send + Sync {
@@ -20,6 +20,16 @@ pub trait Connector: Send + Sync {
         self.capabilities().contains(&capability)
     }
 
+    /// The referential actions that can be used in `onDelete` and `onUpdate`
+    /// on this connector.
+    fn referential_actions(&self) -> &'static [ReferentialAction] {
+        ReferentialAction::ALL
+    }
+
+    fn supports_referential_action(&self, action: ReferentialAction) -> bool {
+        self.referential_actions().contains(&action)
+    }
+
     fn validate_field(&self, field: &Field) -> Result<(), ConnectorError>;
 
     fn validate_model(&self, model: &Model) -> Result<(), ConnectorError>;
@@ -128,11 +138,24 @@ pub enum ConnectorCapability {
     AutoIncrementMultipleAllowed,
     AutoIncrementNonIndexedAllowed,
     RelationFieldsInArbitraryOrder,
+    FullTextIndex,
+    MultiSchema,
     // start of Query Engine Capabilities
     InsensitiveFilters,
     CreateMany,
+    // Json path filters take the path as a JSON path string (MySQL) or as a list of keys (Postgres). SQLite and
+    // SQL Server have no Json type, so they have no Json filters. SQLite support needs the Json type first.
+    JsonFilteringJsonPath,
+    JsonFilteringArrayPath,
+    FullTextSearchWithIndex,
+    FullTextSearchWithoutIndex,
     WritableAutoincField,
     CreateSkipDuplicates,
+    NativeDistinct,
+    RelationJoins,
+    CreateManyReturning,
+    UpsertMany,
+    RowLocking,
 }
 
 /// Contains all capabilities that the connector is able to serve.
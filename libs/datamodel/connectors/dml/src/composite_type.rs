@@ -0,0 +1,39 @@
+use crate::field::ScalarField;
+
+/// Represents a composite type in the datamodel: a named group of fields that is embedded in the fields of models
+/// instead of being stored in a table of its own.
+#[derive(Debug, PartialEq, Clone)]
+pub struct CompositeType {
+    /// Name of the composite type.
+    pub name: String,
+    /// Fields of the composite type. These can be scalars, enums or other composite types.
+    pub fields: Vec<ScalarField>,
+    /// Comments for this composite type.
+    pub documentation: Option<String>,
+}
+
+impl CompositeType {
+    /// Creates a new composite type with the given name and no fields.
+    pub fn new(name: &str) -> CompositeType {
+        CompositeType {
+            name: String::from(name),
+            fields: vec![],
+            documentation: None,
+        }
+    }
+
+    /// Adds a field to this composite type.
+    pub fn add_field(&mut self, field: ScalarField) {
+        self.fields.push(field)
+    }
+
+    /// Gets an iterator over all fields.
+    pub fn fields(&self) -> std::slice::Iter<ScalarField> {
+        self.fields.iter()
+    }
+
+    /// Finds a field by name.
+    pub fn find_field(&self, name: &str) -> Option<&ScalarField> {
+        self.fields().find(|f| f.name == name)
+    }
+}
@@ -0,0 +1,33 @@
+/// Represents an attribute of a namespace declared by a generator, e.g. `@zod.min(3)` or `@@graphql.hide`.
+/// The datamodel does not interpret custom attributes, they are passed on to generators as written.
+#[derive(Debug, PartialEq, Clone)]
+pub struct CustomAttribute {
+    /// Full name of the attribute, including the namespace, e.g. `zod.min`.
+    pub name: String,
+    /// Arguments of the attribute, in the order they were written.
+    pub arguments: Vec<CustomAttributeArgument>,
+}
+
+/// Represents an argument of a custom attribute.
+#[derive(Debug, PartialEq, Clone)]
+pub struct CustomAttributeArgument {
+    /// Name of the argument, if it was given as a named argument.
+    pub name: Option<String>,
+    /// The value of the argument as written in the schema.
+    pub value: String,
+}
+
+impl CustomAttribute {
+    /// Creates a new custom attribute with the given full name and arguments.
+    pub fn new(name: &str, arguments: Vec<CustomAttributeArgument>) -> CustomAttribute {
+        CustomAttribute {
+            name: String::from(name),
+            arguments,
+        }
+    }
+
+    /// The namespace of the attribute, e.g. `zod` for `@zod.min(3)`.
+    pub fn namespace(&self) -> &str {
+        self.name.split('.').next().unwrap_or_default()
+    }
+}
@@ -1,3 +1,4 @@
+use crate::composite_type::CompositeType;
 use crate::field::{Field, FieldType, RelationField, ScalarField};
 use crate::model::Model;
 use crate::r#enum::Enum;
@@ -13,6 +14,7 @@ use crate::relation_info::RelationInfo;
 pub struct Datamodel {
     pub enums: Vec<Enum>,
     pub models: Vec<Model>,
+    pub composite_types: Vec<CompositeType>,
 }
 
 impl Datamodel {
@@ -20,9 +22,9 @@ impl Datamodel {
         Datamodel { ..Default::default() }
     }
 
-    /// Checks if a datamodel contains neither enums nor models.
+    /// Checks if a datamodel contains neither enums, models nor composite types.
     pub fn is_empty(&self) -> bool {
-        self.enums.is_empty() && self.models.is_empty()
+        self.enums.is_empty() && self.models.is_empty() && self.composite_types.is_empty()
     }
 
     /// Adds an enum to this datamodel.
@@ -35,6 +37,11 @@ impl Datamodel {
         self.models.push(model);
     }
 
+    /// Adds a composite type to this datamodel.
+    pub fn add_composite_type(&mut self, composite_type: CompositeType) {
+        self.composite_types.push(composite_type);
+    }
+
     /// Gets an iterator over all models.
     pub fn models(&self) -> std::slice::Iter<Model> {
         self.models.iter()
@@ -45,6 +52,11 @@ impl Datamodel {
         self.enums.iter()
     }
 
+    /// Gets an iterator over all composite types.
+    pub fn composite_types(&self) -> std::slice::Iter<CompositeType> {
+        self.composite_types.iter()
+    }
+
     /// Gets a mutable iterator over all models.
     pub fn models_mut(&mut self) -> std::slice::IterMut<Model> {
         self.models.iter_mut()
@@ -93,6 +105,11 @@ impl Datamodel {
         self.enums().find(|m| m.name == *name)
     }
 
+    /// Finds a composite type by name.
+    pub fn find_composite_type(&self, name: &str) -> Option<&CompositeType> {
+        self.composite_types().find(|ct| ct.name == name)
+    }
+
     /// Finds an enum by database name.
     pub fn find_enum_db_name(&self, db_name: &str) -> Option<&Enum> {
         self.enums().find(|e| e.database_name == Some(db_name.to_owned()))
@@ -1,4 +1,5 @@
-use crate::traits::{WithDatabaseName, WithName};
+use crate::custom_attribute::CustomAttribute;
+use crate::traits::{WithCustomAttributes, WithDatabaseName, WithName};
 
 /// Represents an enum in the datamodel.
 #[derive(Debug, PartialEq, Clone)]
@@ -13,6 +14,8 @@ pub struct Enum {
     pub database_name: Option<String>,
     /// Has to be commented out.
     pub commented_out: bool,
+    /// Block attributes of namespaces declared by generators.
+    pub custom_attributes: Vec<CustomAttribute>,
 }
 
 impl Enum {
@@ -24,6 +27,7 @@ impl Enum {
             documentation: None,
             database_name: None,
             commented_out: false,
+            custom_attributes: vec![],
         }
     }
 
@@ -94,6 +98,8 @@ pub struct EnumValue {
     pub documentation: Option<String>,
     /// Has to be commented out.
     pub commented_out: bool,
+    /// Attributes of namespaces declared by generators.
+    pub custom_attributes: Vec<CustomAttribute>,
 }
 
 impl EnumValue {
@@ -104,6 +110,7 @@ impl EnumValue {
             database_name: None,
             documentation: None,
             commented_out: false,
+            custom_attributes: vec![],
         }
     }
 
@@ -132,3 +139,23 @@ impl WithDatabaseName for EnumValue {
         self.database_name = database_name;
     }
 }
+
+impl WithCustomAttributes for Enum {
+    fn custom_attributes(&self) -> &[CustomAttribute] {
+        &self.custom_attributes
+    }
+
+    fn add_custom_attribute(&mut self, attribute: CustomAttribute) {
+        self.custom_attributes.push(attribute)
+    }
+}
+
+impl WithCustomAttributes for EnumValue {
+    fn custom_attributes(&self) -> &[CustomAttribute] {
+        &self.custom_attributes
+    }
+
+    fn add_custom_attribute(&mut self, attribute: CustomAttribute) {
+        self.custom_attributes.push(attribute)
+    }
+}
@@ -1,8 +1,10 @@
 use super::*;
+use crate::custom_attribute::CustomAttribute;
 use crate::default_value::{DefaultValue, ValueGenerator};
 use crate::native_type_instance::NativeTypeInstance;
+use crate::relation_info::ReferentialAction;
 use crate::scalars::ScalarType;
-use crate::traits::{Ignorable, WithDatabaseName, WithName};
+use crate::traits::{Ignorable, WithCustomAttributes, WithDatabaseName, WithName};
 use std::hash::Hash;
 
 /// Arity of a Field in a Model.
@@ -42,6 +44,8 @@ pub enum FieldType {
     Unsupported(String),
     /// The option is Some(x) if the scalar type is based upon a type alias.
     Base(ScalarType, Option<String>),
+    /// This is a field embedding the composite type of the given name.
+    CompositeType(String),
 }
 
 impl FieldType {
@@ -52,6 +56,13 @@ impl FieldType {
         }
     }
 
+    pub fn as_composite_type(&self) -> Option<&str> {
+        match self {
+            FieldType::CompositeType(name) => Some(name),
+            _ => None,
+        }
+    }
+
     pub fn as_native_type(&self) -> Option<(&ScalarType, &NativeTypeInstance)> {
         match self {
             FieldType::NativeType(a, b) => Some((a, b)),
@@ -252,6 +263,9 @@ pub struct RelationField {
 
     /// Indicates if this field has to be ignored by the Client.
     pub is_ignored: bool,
+
+    /// Attributes of namespaces declared by generators.
+    pub custom_attributes: Vec<CustomAttribute>,
 }
 
 impl RelationField {
@@ -265,6 +279,7 @@ impl RelationField {
             is_generated: false,
             is_commented_out: false,
             is_ignored: false,
+            custom_attributes: vec![],
         }
     }
     /// Creates a new field with the given name and type, marked as generated and optional.
@@ -294,6 +309,37 @@ impl RelationField {
     pub fn is_optional(&self) -> bool {
         self.arity.is_optional()
     }
+
+    /// The `onDelete` action of the foreign key when the relation does not
+    /// define one: deleting the referenced record deletes required relations
+    /// and unlinks optional ones.
+    pub fn default_on_delete_action(&self) -> ReferentialAction {
+        if self.is_required() {
+            ReferentialAction::Cascade
+        } else {
+            ReferentialAction::SetNull
+        }
+    }
+
+    /// The `onUpdate` action of the foreign key when the relation does not
+    /// define one.
+    pub fn default_on_update_action(&self) -> ReferentialAction {
+        ReferentialAction::Cascade
+    }
+
+    /// The effective `onDelete` action of the relation.
+    pub fn on_delete_action(&self) -> ReferentialAction {
+        self.relation_info
+            .on_delete
+            .unwrap_or_else(|| self.default_on_delete_action())
+    }
+
+    /// The effective `onUpdate` action of the relation.
+    pub fn on_update_action(&self) -> ReferentialAction {
+        self.relation_info
+            .on_update
+            .unwrap_or_else(|| self.default_on_update_action())
+    }
 }
 
 /// Represents a scalar field in a model.
@@ -335,6 +381,9 @@ pub struct ScalarField {
 
     /// Indicates if this field is ignored by the Client.
     pub is_ignored: bool,
+
+    /// Attributes of namespaces declared by generators, e.g. `@zod.min(3)`.
+    pub custom_attributes: Vec<CustomAttribute>,
 }
 
 impl ScalarField {
@@ -353,6 +402,7 @@ impl ScalarField {
             is_updated_at: false,
             is_commented_out: false,
             is_ignored: false,
+            custom_attributes: vec![],
         }
     }
     /// Creates a new field with the given name and type, marked as generated and optional.
@@ -416,6 +466,22 @@ impl WithDatabaseName for ScalarField {
     }
 }
 
+impl WithCustomAttributes for Field {
+    fn custom_attributes(&self) -> &[CustomAttribute] {
+        match self {
+            Field::ScalarField(sf) => &sf.custom_attributes,
+            Field::RelationField(rf) => &rf.custom_attributes,
+        }
+    }
+
+    fn add_custom_attribute(&mut self, attribute: CustomAttribute) {
+        match self {
+            Field::ScalarField(sf) => sf.custom_attributes.push(attribute),
+            Field::RelationField(rf) => rf.custom_attributes.push(attribute),
+        }
+    }
+}
+
 impl Ignorable for Field {
     fn is_ignored(&self) -> bool {
         match self {
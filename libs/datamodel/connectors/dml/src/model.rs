@@ -1,5 +1,6 @@
+use crate::custom_attribute::CustomAttribute;
 use crate::field::{Field, FieldType, RelationField, ScalarField};
-use crate::traits::{Ignorable, WithDatabaseName, WithName};
+use crate::traits::{Ignorable, WithCustomAttributes, WithDatabaseName, WithName};
 
 /// Represents a model in a prisma schema.
 #[derive(Debug, PartialEq, Clone)]
@@ -24,9 +25,19 @@ pub struct Model {
     pub is_commented_out: bool,
     /// Indicates if this model has to be ignored by the Client.
     pub is_ignored: bool,
+    /// Indicates if this model is a database view, declared with `view` instead of `model`.
+    pub is_view: bool,
+    /// The query defining the view, from `@@definition`. Only views have one.
+    pub view_definition: Option<String>,
+    /// The database schema (namespace) of the model, from `@@schema`.
+    pub schema: Option<String>,
+    /// Describes check constraints, from `@@check`.
+    pub check_constraints: Vec<CheckConstraintDefinition>,
+    /// Block attributes of namespaces declared by generators, e.g. `@@graphql.hide`.
+    pub custom_attributes: Vec<CustomAttribute>,
 }
 
-/// Represents an index defined via `@@index` or `@@unique`.
+/// Represents an index defined via `@@index`, `@@unique` or `@@fulltext`.
 #[derive(Debug, PartialEq, Clone)]
 pub struct IndexDefinition {
     pub name: Option<String>,
@@ -38,12 +49,26 @@ impl IndexDefinition {
     pub fn is_unique(&self) -> bool {
         matches!(self.tpe, IndexType::Unique)
     }
+
+    pub fn is_fulltext(&self) -> bool {
+        matches!(self.tpe, IndexType::Fulltext)
+    }
+}
+
+/// Represents a check constraint defined via `@@check`.
+#[derive(Debug, PartialEq, Clone)]
+pub struct CheckConstraintDefinition {
+    pub name: Option<String>,
+    /// The SQL expression, passed to the database as-is.
+    pub expression: String,
 }
 
 #[derive(Debug, PartialEq, Clone, Copy)]
 pub enum IndexType {
     Unique,
     Normal,
+    /// An index for full-text search queries.
+    Fulltext,
 }
 
 /// A unique criteria is a set of fields through which a record can be uniquely identified.
@@ -72,6 +97,11 @@ impl Model {
             is_generated: false,
             is_commented_out: false,
             is_ignored: false,
+            is_view: false,
+            view_definition: None,
+            schema: None,
+            check_constraints: vec![],
+            custom_attributes: vec![],
         }
     }
 
@@ -336,6 +366,16 @@ impl WithDatabaseName for Model {
     }
 }
 
+impl WithCustomAttributes for Model {
+    fn custom_attributes(&self) -> &[CustomAttribute] {
+        &self.custom_attributes
+    }
+
+    fn add_custom_attribute(&mut self, attribute: CustomAttribute) {
+        self.custom_attributes.push(attribute)
+    }
+}
+
 impl Ignorable for Model {
     fn is_ignored(&self) -> bool {
         self.is_ignored
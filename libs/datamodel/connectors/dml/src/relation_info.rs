@@ -1,3 +1,5 @@
+use std::{fmt, str::FromStr};
+
 /// Holds information about a relation field.
 #[derive(Debug, Clone)]
 pub struct RelationInfo {
@@ -11,16 +13,16 @@ pub struct RelationInfo {
     pub name: String,
     /// A strategy indicating what happens when
     /// a related node is deleted.
-    pub on_delete: OnDeleteStrategy,
+    pub on_delete: Option<ReferentialAction>,
+    /// A strategy indicating what happens when
+    /// a related node is updated.
+    pub on_update: Option<ReferentialAction>,
 }
 
 impl PartialEq for RelationInfo {
-    //ignores the relation name for reintrospection
+    //ignores the relation name and the referential actions for reintrospection
     fn eq(&self, other: &Self) -> bool {
-        self.to == other.to
-            && self.fields == other.fields
-            && self.references == other.references
-            && self.on_delete == other.on_delete
+        self.to == other.to && self.fields == other.fields && self.references == other.references
     }
 }
 
@@ -33,23 +35,61 @@ impl RelationInfo {
             fields: Vec::new(),
             references: Vec::new(),
             name: String::new(),
-            on_delete: OnDeleteStrategy::None,
+            on_delete: None,
+            on_update: None,
         }
     }
 }
 
-/// Describes what happens when related nodes are deleted.
-#[derive(Debug, Copy, PartialEq, Clone)]
-pub enum OnDeleteStrategy {
+/// Describes what happens when related nodes are deleted or updated.
+#[derive(Debug, Copy, PartialEq, Eq, Hash, Clone)]
+pub enum ReferentialAction {
+    /// Deletes or updates the referencing records together with the referenced one.
     Cascade,
-    None,
+    /// Prevents the referenced record from being deleted or updated while it is referenced.
+    Restrict,
+    /// Like `Restrict`, but the check may be deferred to the end of the transaction.
+    NoAction,
+    /// Sets the referencing fields to `NULL`.
+    SetNull,
+    /// Sets the referencing fields to their default values.
+    SetDefault,
+}
+
+impl ReferentialAction {
+    /// All referential actions known to Prisma.
+    pub const ALL: &'static [ReferentialAction] = &[
+        ReferentialAction::Cascade,
+        ReferentialAction::Restrict,
+        ReferentialAction::NoAction,
+        ReferentialAction::SetNull,
+        ReferentialAction::SetDefault,
+    ];
+}
+
+impl FromStr for ReferentialAction {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Cascade" => Ok(ReferentialAction::Cascade),
+            "Restrict" => Ok(ReferentialAction::Restrict),
+            "NoAction" => Ok(ReferentialAction::NoAction),
+            "SetNull" => Ok(ReferentialAction::SetNull),
+            "SetDefault" => Ok(ReferentialAction::SetDefault),
+            _ => Err(format!("{} is not a known referential action.", s)),
+        }
+    }
 }
 
-impl ToString for OnDeleteStrategy {
-    fn to_string(&self) -> String {
+impl fmt::Display for ReferentialAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            OnDeleteStrategy::Cascade => String::from("CASCADE"),
-            OnDeleteStrategy::None => String::from("NONE"),
+            ReferentialAction::Cascade => f.write_str("Cascade"),
+            ReferentialAction::Restrict => f.write_str("Restrict"),
+            ReferentialAction::NoAction => f.write_str("NoAction"),
+            ReferentialAction::SetNull => f.write_str("SetNull"),
+            ReferentialAction::SetDefault => f.write_str("SetDefault"),
         }
     }
 }
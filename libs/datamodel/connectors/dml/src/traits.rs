@@ -1,3 +1,5 @@
+use crate::custom_attribute::CustomAttribute;
+
 pub trait WithName {
     fn name(&self) -> &String;
 
@@ -18,3 +20,9 @@ pub trait Ignorable {
     fn is_ignored(&self) -> bool;
     fn ignore(&mut self);
 }
+
+pub trait WithCustomAttributes {
+    fn custom_attributes(&self) -> &[CustomAttribute];
+
+    fn add_custom_attribute(&mut self, attribute: CustomAttribute);
+}
@@ -5,6 +5,7 @@ use dml::field::{Field, FieldType};
 use dml::model::{IndexType, Model};
 use dml::native_type_constructor::NativeTypeConstructor;
 use dml::native_type_instance::NativeTypeInstance;
+use dml::relation_info::ReferentialAction;
 use dml::scalars::ScalarType;
 use native_types::{MsSqlType, MsSqlTypeParameter};
 use once_cell::sync::Lazy;
@@ -52,6 +53,11 @@ impl MsSqlDatamodelConnector {
             ConnectorCapability::AutoIncrementMultipleAllowed,
             ConnectorCapability::AutoIncrementNonIndexedAllowed,
             ConnectorCapability::CreateMany,
+            ConnectorCapability::MultiSchema,
+            ConnectorCapability::NativeDistinct,
+            ConnectorCapability::CreateManyReturning,
+            ConnectorCapability::UpsertMany,
+            ConnectorCapability::RowLocking,
         ];
 
         let constructors: Vec<NativeTypeConstructor> = vec![
@@ -110,6 +116,14 @@ const SCALAR_TYPE_DEFAULTS: &[(ScalarType, MsSqlType)] = &[
     ),
 ];
 
+// SQL Server has no `RESTRICT`, `NO ACTION` is the equivalent.
+const REFERENTIAL_ACTIONS: &[ReferentialAction] = &[
+    ReferentialAction::Cascade,
+    ReferentialAction::NoAction,
+    ReferentialAction::SetNull,
+    ReferentialAction::SetDefault,
+];
+
 impl Connector for MsSqlDatamodelConnector {
     fn name(&self) -> String {
         "SQL Server".to_string()
@@ -119,6 +133,10 @@ impl Connector for MsSqlDatamodelConnector {
         &self.capabilities
     }
 
+    fn referential_actions(&self) -> &'static [ReferentialAction] {
+        REFERENTIAL_ACTIONS
+    }
+
     fn default_native_type_for_scalar_type(&self, scalar_type: &ScalarType) -> serde_json::Value {
         let native_type = SCALAR_TYPE_DEFAULTS
             .iter()
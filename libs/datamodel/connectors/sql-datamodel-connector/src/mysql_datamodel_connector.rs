@@ -5,6 +5,7 @@ use dml::field::{Field, FieldType};
 use dml::model::{IndexType, Model};
 use dml::native_type_constructor::NativeTypeConstructor;
 use dml::native_type_instance::NativeTypeInstance;
+use dml::relation_info::ReferentialAction;
 use dml::scalars::ScalarType;
 use native_types::MySqlType;
 use native_types::MySqlType::*;
@@ -70,6 +71,13 @@ impl MySqlDatamodelConnector {
             ConnectorCapability::CreateMany,
             ConnectorCapability::WritableAutoincField,
             ConnectorCapability::CreateSkipDuplicates,
+            ConnectorCapability::JsonFilteringJsonPath,
+            ConnectorCapability::FullTextIndex,
+            ConnectorCapability::FullTextSearchWithIndex,
+            ConnectorCapability::NativeDistinct,
+            ConnectorCapability::RelationJoins,
+            ConnectorCapability::UpsertMany,
+            ConnectorCapability::RowLocking,
         ];
 
         let int = NativeTypeConstructor::without_args(INT_TYPE_NAME, vec![ScalarType::Int]);
@@ -163,6 +171,14 @@ const SCALAR_TYPE_DEFAULTS: &[(ScalarType, MySqlType)] = &[
     (ScalarType::Json, MySqlType::Json),
 ];
 
+// `SET DEFAULT` is accepted by the MySQL parser, but rejected by InnoDB.
+const REFERENTIAL_ACTIONS: &[ReferentialAction] = &[
+    ReferentialAction::Cascade,
+    ReferentialAction::Restrict,
+    ReferentialAction::NoAction,
+    ReferentialAction::SetNull,
+];
+
 impl Connector for MySqlDatamodelConnector {
     fn name(&self) -> String {
         "MySQL".to_string()
@@ -172,6 +188,10 @@ impl Connector for MySqlDatamodelConnector {
         &self.capabilities
     }
 
+    fn referential_actions(&self) -> &'static [ReferentialAction] {
+        REFERENTIAL_ACTIONS
+    }
+
     fn default_native_type_for_scalar_type(&self, scalar_type: &ScalarType) -> serde_json::Value {
         let native_type = SCALAR_TYPE_DEFAULTS
             .iter()
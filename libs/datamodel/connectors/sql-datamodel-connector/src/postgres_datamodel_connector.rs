@@ -56,6 +56,14 @@ impl PostgresDatamodelConnector {
             ConnectorCapability::CreateMany,
             ConnectorCapability::WritableAutoincField,
             ConnectorCapability::CreateSkipDuplicates,
+            ConnectorCapability::JsonFilteringArrayPath,
+            ConnectorCapability::FullTextSearchWithoutIndex,
+            ConnectorCapability::MultiSchema,
+            ConnectorCapability::NativeDistinct,
+            ConnectorCapability::RelationJoins,
+            ConnectorCapability::CreateManyReturning,
+            ConnectorCapability::UpsertMany,
+            ConnectorCapability::RowLocking,
         ];
 
         let small_int = NativeTypeConstructor::without_args(SMALL_INT_TYPE_NAME, vec![ScalarType::Int]);
@@ -11,7 +11,10 @@ pub struct SqliteDatamodelConnector {
 
 impl SqliteDatamodelConnector {
     pub fn new() -> SqliteDatamodelConnector {
-        let capabilities = vec![ConnectorCapability::RelationFieldsInArbitraryOrder];
+        let capabilities = vec![
+            ConnectorCapability::RelationFieldsInArbitraryOrder,
+            ConnectorCapability::NativeDistinct,
+        ];
         let constructors: Vec<NativeTypeConstructor> = vec![];
 
         SqliteDatamodelConnector {
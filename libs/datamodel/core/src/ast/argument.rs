@@ -69,4 +69,12 @@ impl Argument {
     pub fn is_unnamed(&self) -> bool {
         self.name.name.is_empty()
     }
+
+    pub fn with_lifted_span(&self, offset: usize) -> Argument {
+        Argument {
+            name: self.name.with_lifted_span(offset),
+            value: self.value.with_lifted_span(offset),
+            span: self.span.lift_span(offset),
+        }
+    }
 }
@@ -19,6 +19,14 @@ impl Attribute {
     pub fn is_index(&self) -> bool {
         matches!(self.name.name.as_str(), "index" | "unique")
     }
+
+    pub fn with_lifted_span(&self, offset: usize) -> Attribute {
+        Attribute {
+            name: self.name.with_lifted_span(offset),
+            arguments: self.arguments.iter().map(|arg| arg.with_lifted_span(offset)).collect(),
+            span: self.span.lift_span(offset),
+        }
+    }
 }
 
 impl WithIdentifier for Attribute {
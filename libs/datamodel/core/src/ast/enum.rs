@@ -15,6 +15,22 @@ pub struct Enum {
     pub span: Span,
 }
 
+impl Enum {
+    pub fn with_lifted_span(&self, offset: usize) -> Enum {
+        Enum {
+            name: self.name.with_lifted_span(offset),
+            values: self.values.iter().map(|value| value.with_lifted_span(offset)).collect(),
+            attributes: self
+                .attributes
+                .iter()
+                .map(|attr| attr.with_lifted_span(offset))
+                .collect(),
+            documentation: self.documentation.clone(),
+            span: self.span.lift_span(offset),
+        }
+    }
+}
+
 impl WithIdentifier for Enum {
     fn identifier(&self) -> &Identifier {
         &self.name
@@ -56,6 +72,22 @@ pub struct EnumValue {
     pub commented_out: bool,
 }
 
+impl EnumValue {
+    pub fn with_lifted_span(&self, offset: usize) -> EnumValue {
+        EnumValue {
+            name: self.name.with_lifted_span(offset),
+            attributes: self
+                .attributes
+                .iter()
+                .map(|attr| attr.with_lifted_span(offset))
+                .collect(),
+            documentation: self.documentation.clone(),
+            span: self.span.lift_span(offset),
+            commented_out: self.commented_out,
+        }
+    }
+}
+
 impl WithIdentifier for EnumValue {
     fn identifier(&self) -> &Identifier {
         &self.name
@@ -18,6 +18,24 @@ pub struct Field {
     pub is_commented_out: bool,
 }
 
+impl Field {
+    pub fn with_lifted_span(&self, offset: usize) -> Field {
+        Field {
+            field_type: self.field_type.with_lifted_span(offset),
+            name: self.name.with_lifted_span(offset),
+            arity: self.arity,
+            attributes: self
+                .attributes
+                .iter()
+                .map(|attr| attr.with_lifted_span(offset))
+                .collect(),
+            documentation: self.documentation.clone(),
+            span: self.span.lift_span(offset),
+            is_commented_out: self.is_commented_out,
+        }
+    }
+}
+
 impl WithIdentifier for Field {
     fn identifier(&self) -> &Identifier {
         &self.name
@@ -13,6 +13,21 @@ pub struct GeneratorConfig {
     pub span: Span,
 }
 
+impl GeneratorConfig {
+    pub fn with_lifted_span(&self, offset: usize) -> GeneratorConfig {
+        GeneratorConfig {
+            name: self.name.with_lifted_span(offset),
+            properties: self
+                .properties
+                .iter()
+                .map(|prop| prop.with_lifted_span(offset))
+                .collect(),
+            documentation: self.documentation.clone(),
+            span: self.span.lift_span(offset),
+        }
+    }
+}
+
 impl WithIdentifier for GeneratorConfig {
     fn identifier(&self) -> &Identifier {
         &self.name
@@ -15,6 +15,13 @@ impl Identifier {
         }
     }
 
+    pub fn with_lifted_span(&self, offset: usize) -> Identifier {
+        Identifier {
+            name: self.name.clone(),
+            span: self.span.lift_span(offset),
+        }
+    }
+
     pub fn validate(&self, schema_item: &str) -> Result<(), DatamodelError> {
         if self.name.is_empty() {
             Err(DatamodelError::new_validation_error(
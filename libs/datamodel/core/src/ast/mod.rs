@@ -16,6 +16,7 @@ mod model;
 mod parser;
 mod renderer;
 mod source_config;
+mod source_files;
 mod span;
 mod top;
 mod traits;
@@ -32,6 +33,7 @@ pub use identifier::Identifier;
 pub use model::Model;
 pub use r#enum::{Enum, EnumValue};
 pub use source_config::SourceConfig;
+pub use source_files::{SourceFile, SourceFiles};
 pub use span::Span;
 pub use top::Top;
 pub use traits::{ArgumentContainer, WithAttributes, WithDocumentation, WithIdentifier, WithName, WithSpan};
@@ -77,11 +79,15 @@ impl SchemaAst {
 
     pub fn find_model_mut(&mut self, model_name: &str) -> Option<&mut Model> {
         self.tops.iter_mut().find_map(|top| match top {
-            Top::Model(model) if model.name.name == model_name => Some(model),
+            Top::Model(model) if !model.is_composite_type && model.name.name == model_name => Some(model),
             _ => None,
         })
     }
 
+    pub fn find_composite_type(&self, type_name: &str) -> Option<&Model> {
+        self.composite_types().into_iter().find(|t| t.name.name == type_name)
+    }
+
     pub fn find_type_alias(&self, type_name: &str) -> Option<&Field> {
         self.types().into_iter().find(|t| t.name.name == type_name)
     }
@@ -141,7 +147,17 @@ impl SchemaAst {
         self.tops
             .iter()
             .filter_map(|top| match top {
-                Top::Model(x) => Some(x),
+                Top::Model(x) if !x.is_composite_type => Some(x),
+                _ => None,
+            })
+            .collect()
+    }
+
+    pub fn composite_types(&self) -> Vec<&Model> {
+        self.tops
+            .iter()
+            .filter_map(|top| match top {
+                Top::Model(x) if x.is_composite_type => Some(x),
                 _ => None,
             })
             .collect()
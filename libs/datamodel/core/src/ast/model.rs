@@ -15,6 +15,10 @@ pub struct Model {
     pub span: Span,
     /// Should this be commented out.
     pub commented_out: bool,
+    /// Whether this was declared with the `view` keyword instead of `model`.
+    pub is_view: bool,
+    /// Whether this was declared with the `type` keyword, i.e. is a composite type embedded in the fields of models.
+    pub is_composite_type: bool,
 }
 
 impl Model {
@@ -24,6 +28,23 @@ impl Model {
             .find(|ast_field| ast_field.name.name == name)
             .unwrap()
     }
+
+    pub fn with_lifted_span(&self, offset: usize) -> Model {
+        Model {
+            name: self.name.with_lifted_span(offset),
+            fields: self.fields.iter().map(|field| field.with_lifted_span(offset)).collect(),
+            attributes: self
+                .attributes
+                .iter()
+                .map(|attr| attr.with_lifted_span(offset))
+                .collect(),
+            documentation: self.documentation.clone(),
+            span: self.span.lift_span(offset),
+            commented_out: self.commented_out,
+            is_view: self.is_view,
+            is_composite_type: self.is_composite_type,
+        }
+    }
 }
 
 impl WithIdentifier for Model {
@@ -14,13 +14,13 @@ pub fn parse_model(token: &Token) -> Result<Model, Diagnostics> {
     let mut attributes: Vec<Attribute> = vec![];
     let mut fields: Vec<Field> = vec![];
     let mut comment: Option<Comment> = None;
+    let mut is_view = false;
+    let mut is_composite_type = false;
 
     for current in token.relevant_children() {
         match current.as_rule() {
-            Rule::TYPE_KEYWORD => errors.push_error(DatamodelError::new_legacy_parser_error(
-                "Model declarations have to be indicated with the `model` keyword.",
-                Span::from_pest(current.as_span()),
-            )),
+            Rule::TYPE_KEYWORD => is_composite_type = true,
+            Rule::VIEW_KEYWORD => is_view = true,
             Rule::non_empty_identifier => name = Some(current.to_id()),
             Rule::block_level_attribute => attributes.push(parse_attribute(&current)),
             Rule::field_declaration => match parse_field(&name.as_ref().unwrap().name, &current) {
@@ -46,6 +46,8 @@ pub fn parse_model(token: &Token) -> Result<Model, Diagnostics> {
             documentation: comment,
             span: Span::from_pest(token.as_span()),
             commented_out: false,
+            is_view,
+            is_composite_type,
         }),
         _ => panic!(
             "Encountered impossible model declaration during parsing: {:?}",
@@ -133,6 +133,7 @@ fn rule_to_string(rule: Rule) -> &'static str {
         Rule::BLOCK_CLOSE => "End of block (\"}\")",
         Rule::MODEL_KEYWORD => "\"model\" keyword",
         Rule::TYPE_KEYWORD => "\"type\" keyword",
+        Rule::VIEW_KEYWORD => "\"view\" keyword",
         Rule::ENUM_KEYWORD => "\"enum\" keyword",
         Rule::GENERATOR_KEYWORD => "\"generator\" keyword",
         Rule::DATASOURCE_KEYWORD => "\"datasource\" keyword",
@@ -15,10 +15,27 @@ pub struct Reformatter<'a> {
 
 impl<'a> Reformatter<'a> {
     pub fn new(input: &'a str) -> Self {
-        //todo don't run parsing and validating of the string in every step
-        let missing_fields = Self::find_all_missing_fields(&input);
-        let missing_field_attributes = Self::find_all_missing_attributes(&input);
-        let missing_relation_attribute_args = Self::find_all_missing_relation_attribute_args(&input);
+        Self::with_schema(input, crate::parse_schema_ast(input))
+    }
+
+    /// Creates a reformatter for one file of a schema split across multiple files. The fields missing from the models
+    /// of the file are found in the whole schema, so back relation fields to models of other files are added as well.
+    pub fn new_for_file(files: &'a ast::SourceFiles, file_name: &str) -> Option<Self> {
+        let input = files.file_text(file_name)?;
+
+        Some(Self::with_schema(input, files.parse()))
+    }
+
+    fn with_schema(input: &'a str, schema_ast: Result<ast::SchemaAst, crate::diagnostics::Diagnostics>) -> Self {
+        //todo don't run validating of the schema in every step
+        let (missing_fields, missing_field_attributes, missing_relation_attribute_args) = match schema_ast {
+            Ok(schema_ast) => (
+                Self::find_missing_fields(&schema_ast),
+                Self::find_all_missing_attributes(&schema_ast),
+                Self::find_all_missing_relation_attribute_args(&schema_ast),
+            ),
+            Err(err) => (Err(err.clone()), Err(err.clone()), Err(err)),
+        };
 
         Reformatter {
             input,
@@ -29,10 +46,17 @@ impl<'a> Reformatter<'a> {
     }
 
     // this finds all auto generated fields, that are added during auto generation AND are missing from the original input.
-    fn find_all_missing_fields(schema_string: &str) -> Result<ValidatedMissingFields, crate::diagnostics::Diagnostics> {
+    pub fn find_all_missing_fields(
+        schema_string: &str,
+    ) -> Result<ValidatedMissingFields, crate::diagnostics::Diagnostics> {
+        Self::find_missing_fields(&crate::parse_schema_ast(&schema_string)?)
+    }
+
+    fn find_missing_fields(
+        schema_ast: &ast::SchemaAst,
+    ) -> Result<ValidatedMissingFields, crate::diagnostics::Diagnostics> {
         let mut diagnostics = crate::diagnostics::Diagnostics::new();
-        let schema_ast = crate::parse_schema_ast(&schema_string)?;
-        let validated_datamodel = crate::parse_datamodel_and_ignore_datasource_urls_for_formatter(&schema_string)?;
+        let validated_datamodel = crate::validate_schema_ast(schema_ast, true, true)?;
 
         let lowerer = crate::transform::dml_to_ast::LowerDmlToAst::new(None);
         let mut result = Vec::new();
@@ -61,11 +85,10 @@ impl<'a> Reformatter<'a> {
     }
 
     fn find_all_missing_attributes(
-        schema_string: &str,
+        schema_ast: &ast::SchemaAst,
     ) -> Result<Vec<MissingFieldAttribute>, crate::diagnostics::Diagnostics> {
         let mut diagnostics = crate::diagnostics::Diagnostics::new();
-        let schema_ast = crate::parse_schema_ast(&schema_string)?;
-        let validated_datamodel = crate::parse_datamodel_and_ignore_datasource_urls_for_formatter(&schema_string)?;
+        let validated_datamodel = crate::validate_schema_ast(schema_ast, true, true)?;
 
         diagnostics.append_warning_vec(validated_datamodel.warnings);
         let lowerer = crate::transform::dml_to_ast::LowerDmlToAst::new(None);
@@ -98,11 +121,10 @@ impl<'a> Reformatter<'a> {
     }
 
     fn find_all_missing_relation_attribute_args(
-        schema_string: &str,
+        schema_ast: &ast::SchemaAst,
     ) -> Result<Vec<MissingRelationAttributeArg>, crate::diagnostics::Diagnostics> {
         let mut diagnostics = crate::diagnostics::Diagnostics::new();
-        let schema_ast = crate::parse_schema_ast(&schema_string)?;
-        let validated_datamodel = crate::parse_datamodel_and_ignore_datasource_urls_for_formatter(&schema_string)?;
+        let validated_datamodel = crate::validate_schema_ast(schema_ast, true, true)?;
 
         diagnostics.append_warning_vec(validated_datamodel.warnings);
         let lowerer = crate::transform::dml_to_ast::LowerDmlToAst::new(None);
@@ -279,8 +301,18 @@ impl<'a> Reformatter<'a> {
     }
 
     fn reformat_model(&self, target: &mut Renderer, token: &Token) {
+        let has_keyword = |keyword: Rule| token.clone().into_inner().any(|current| current.as_rule() == keyword);
+
+        let block_type = if has_keyword(Rule::VIEW_KEYWORD) {
+            "view"
+        } else if has_keyword(Rule::TYPE_KEYWORD) {
+            "type"
+        } else {
+            "model"
+        };
+
         self.reformat_block_element_internal(
-            "model",
+            block_type,
             target,
             &token,
             Box::new(|table, renderer, token, model_name| {
@@ -291,6 +323,7 @@ impl<'a> Reformatter<'a> {
                         Self::reformat_attribute(renderer, &token, "@@", vec![]);
                     }
                     Rule::field_declaration => self.reformat_field(table, &token, model_name),
+                    Rule::VIEW_KEYWORD | Rule::TYPE_KEYWORD => {}
                     _ => Self::reformat_generic_token(table, &token),
                 }
             }),
@@ -870,6 +903,20 @@ pub struct MissingField {
     pub field: crate::ast::Field,
 }
 
+impl MissingField {
+    /// Renders the field the way the formatter adds it to the model, without indentation, e.g. `posts Post[]`.
+    pub fn render(&self) -> String {
+        let mut table = TableFormat::new();
+        Renderer::render_field(&mut table, &self.field, false);
+
+        let mut target_string = WritableString::new();
+        table.render(&mut Renderer::new(&mut target_string, 0));
+
+        let result: String = target_string.into();
+        result.trim_end().to_owned()
+    }
+}
+
 #[derive(Debug)]
 pub struct MissingFieldAttribute {
     pub model: String,
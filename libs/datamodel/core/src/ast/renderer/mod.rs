@@ -176,7 +176,15 @@ impl<'a> Renderer<'a> {
 
         Self::render_documentation(self, model);
 
-        self.write(format!("{}model ", comment_out).as_ref());
+        let keyword = if model.is_view {
+            "view"
+        } else if model.is_composite_type {
+            "type"
+        } else {
+            "model"
+        };
+
+        self.write(format!("{}{} ", comment_out, keyword).as_ref());
         self.write(&model.name.name);
         self.write(" {");
         self.end_line();
@@ -0,0 +1,102 @@
+use super::{parse_schema, SchemaAst, Span};
+use crate::diagnostics::Diagnostics;
+
+/// A schema split across multiple named source files.
+///
+/// Each file is parsed on its own, so a block left unclosed in one file can't swallow the next one. The spans of the
+/// parsed AST and of its diagnostics are offsets into the merged text of all files. Use `locate` to map a span back to
+/// the file it was declared in.
+#[derive(Debug, Clone)]
+pub struct SourceFiles {
+    text: String,
+    files: Vec<SourceFile>,
+}
+
+/// A file of a schema split across multiple files.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SourceFile {
+    /// The name of the file, e.g. its path.
+    pub name: String,
+    /// The location of the file in the merged schema text.
+    pub span: Span,
+}
+
+impl SourceFiles {
+    /// Merges the files, given as pairs of name and content, in the given order.
+    pub fn new<N, C>(files: impl IntoIterator<Item = (N, C)>) -> SourceFiles
+    where
+        N: Into<String>,
+        C: AsRef<str>,
+    {
+        let mut text = String::new();
+        let mut source_files = Vec::new();
+
+        for (name, content) in files {
+            let start = text.len();
+            text.push_str(content.as_ref());
+
+            source_files.push(SourceFile {
+                name: name.into(),
+                span: Span::new(start, text.len()),
+            });
+
+            // Separates the files, so the end of a file is never the start of the next one.
+            text.push('\n');
+        }
+
+        SourceFiles {
+            text,
+            files: source_files,
+        }
+    }
+
+    /// Parses every file separately and merges their declarations, in the order of the files.
+    pub fn parse(&self) -> Result<SchemaAst, Diagnostics> {
+        let mut tops = Vec::new();
+        let mut errors = Diagnostics::new();
+
+        for file in &self.files {
+            let offset = file.span.start;
+
+            match parse_schema(&self.text[file.span.start..file.span.end]) {
+                Ok(ast) => tops.extend(ast.tops.iter().map(|top| top.with_lifted_span(offset))),
+                Err(err) => {
+                    for error in err.errors {
+                        errors.push_error(error.with_lifted_span(offset));
+                    }
+                }
+            }
+        }
+
+        errors.to_result()?;
+
+        Ok(SchemaAst { tops })
+    }
+
+    /// The merged schema text.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn files(&self) -> &[SourceFile] {
+        &self.files
+    }
+
+    /// The content of the file with the name.
+    pub fn file_text(&self, name: &str) -> Option<&str> {
+        self.files
+            .iter()
+            .find(|file| file.name == name)
+            .map(|file| &self.text[file.span.start..file.span.end])
+    }
+
+    /// The file a span of the merged schema text starts in, and the span relative to the start of that file.
+    pub fn locate(&self, span: Span) -> Option<(&SourceFile, Span)> {
+        let file = self.files.iter().rev().find(|file| file.span.start <= span.start)?;
+
+        let start = span.start - file.span.start;
+        let end = span.end.min(file.span.end).max(span.start) - file.span.start;
+
+        Some((file, Span::new(start, end)))
+    }
+}
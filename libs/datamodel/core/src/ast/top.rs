@@ -38,6 +38,8 @@ impl Top {
     pub fn get_type(&self) -> &str {
         match self {
             Top::Enum(_) => "enum",
+            Top::Model(model) if model.is_view => "view",
+            Top::Model(model) if model.is_composite_type => "composite type",
             Top::Model(_) => "model",
             Top::Source(_) => "source",
             Top::Generator(_) => "generator",
@@ -82,4 +84,14 @@ impl Top {
             _ => None,
         }
     }
+
+    pub fn with_lifted_span(&self, offset: usize) -> Top {
+        match self {
+            Top::Enum(x) => Top::Enum(x.with_lifted_span(offset)),
+            Top::Model(x) => Top::Model(x.with_lifted_span(offset)),
+            Top::Source(x) => Top::Source(x.with_lifted_span(offset)),
+            Top::Generator(x) => Top::Generator(x.with_lifted_span(offset)),
+            Top::Type(x) => Top::Type(x.with_lifted_span(offset)),
+        }
+    }
 }
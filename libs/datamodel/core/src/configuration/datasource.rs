@@ -1,5 +1,6 @@
 use datamodel_connector::{Connector, ConnectorCapabilities};
 use serde::Serialize;
+use std::{fmt, str::FromStr};
 
 /// a `datasource` from the prisma schema.
 pub struct Datasource {
@@ -16,6 +17,10 @@ pub struct Datasource {
     pub active_connector: Box<dyn Connector>,
     /// An optional user-defined shadow database URL.
     pub shadow_database_url: Option<StringFromEnvVar>,
+    /// How the referential integrity of relations is enforced.
+    pub referential_integrity: ReferentialIntegrity,
+    /// The database schemas (namespaces) the models can be placed in with `@@schema`.
+    pub schemas: Vec<String>,
 }
 
 impl std::fmt::Debug for Datasource {
@@ -26,6 +31,8 @@ impl std::fmt::Debug for Datasource {
             .field("active_provider", &self.active_provider)
             .field("url", &self.url)
             .field("documentation", &self.documentation)
+            .field("referential_integrity", &self.referential_integrity)
+            .field("schemas", &self.schemas)
             .field("active_connector", &&"...")
             .finish()
     }
@@ -40,6 +47,52 @@ impl Datasource {
         let capabilities = self.active_connector.capabilities().clone();
         ConnectorCapabilities::new(capabilities)
     }
+
+    /// True if the referential actions of relations have to be emulated by Prisma, because the
+    /// database does not have foreign keys.
+    pub fn emulates_referential_actions(&self) -> bool {
+        self.referential_integrity == ReferentialIntegrity::Prisma
+    }
+}
+
+/// Defines who enforces the referential integrity of relations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReferentialIntegrity {
+    /// Foreign keys are created in the database, and the database enforces the referential actions.
+    ForeignKeys,
+    /// No foreign keys are created. Prisma checks the relations and emulates the referential actions.
+    Prisma,
+}
+
+impl ReferentialIntegrity {
+    pub const ALL: &'static [ReferentialIntegrity] = &[ReferentialIntegrity::ForeignKeys, ReferentialIntegrity::Prisma];
+}
+
+impl Default for ReferentialIntegrity {
+    fn default() -> Self {
+        ReferentialIntegrity::ForeignKeys
+    }
+}
+
+impl FromStr for ReferentialIntegrity {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "foreignKeys" => Ok(ReferentialIntegrity::ForeignKeys),
+            "prisma" => Ok(ReferentialIntegrity::Prisma),
+            _ => Err(format!("{} is not a known referential integrity mode.", s)),
+        }
+    }
+}
+
+impl fmt::Display for ReferentialIntegrity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReferentialIntegrity::ForeignKeys => write!(f, "foreignKeys"),
+            ReferentialIntegrity::Prisma => write!(f, "prisma"),
+        }
+    }
 }
 
 #[serde(rename_all = "camelCase")]
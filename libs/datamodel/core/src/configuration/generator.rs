@@ -12,6 +12,10 @@ pub struct Generator {
     pub binary_targets: Vec<String>,
     #[serde(default = "Vec::new")]
     pub preview_features: Vec<String>,
+    /// Custom attributes the generator understands, either a whole namespace, e.g. `zod` for
+    /// `@zod.min(3)`, or single attributes of a namespace, e.g. `zod.min`.
+    #[serde(default = "Vec::new", skip_serializing_if = "Vec::is_empty")]
+    pub custom_attributes: Vec<String>,
     pub config: HashMap<String, String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub documentation: Option<String>,
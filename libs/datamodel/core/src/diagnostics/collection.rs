@@ -1,4 +1,5 @@
-use super::DatamodelError;
+use super::{helper::pretty_print, DatamodelError};
+use crate::ast::SourceFiles;
 use crate::diagnostics::warning::DatamodelWarning;
 
 /// Represents a list of validation or parser errors and warnings.
@@ -91,6 +92,23 @@ impl Diagnostics {
 
         String::from_utf8_lossy(&message).into_owned()
     }
+
+    /// Pretty prints the errors of a schema split across multiple files, each against the file it occurred in.
+    pub fn to_pretty_string_for_files(&self, files: &SourceFiles) -> String {
+        let mut message: Vec<u8> = Vec::new();
+
+        for err in self.to_error_iter() {
+            let (file_name, text, span) = match files.locate(err.span()) {
+                Some((file, span)) => (file.name.as_str(), &files.text()[file.span.start..file.span.end], span),
+                None => ("", files.text(), err.span()),
+            };
+
+            pretty_print(&mut message, file_name, text, span, err.description().as_str())
+                .expect("printing datamodel error");
+        }
+
+        String::from_utf8_lossy(&message).into_owned()
+    }
 }
 
 impl std::fmt::Display for Diagnostics {
@@ -65,6 +65,12 @@ pub enum DatamodelError {
   #[error("Attribute not known: \"@{}\".", attribute_name)]
   AttributeNotKnownError { attribute_name: String, span: Span },
 
+  #[error("Attribute not known: \"@{}\". The namespace \"{}\" is neither the datasource nor declared in the `customAttributes` of a generator.", attribute_name, namespace)]
+  AttributeNamespaceNotKnownError { namespace: String, attribute_name: String, span: Span },
+
+  #[error("Attribute not known: \"@{}\". The custom attributes declared by the generators in the namespace \"{}\" are: {}.", attribute_name, namespace, declared_attributes)]
+  CustomAttributeNotKnownError { namespace: String, attribute_name: String, declared_attributes: String, span: Span },
+
   #[error("Function not known: \"{}\".", function_name)]
   FunctionNotKnownError { function_name: String, span: Span },
 
@@ -121,6 +127,12 @@ pub enum DatamodelError {
 
   #[error("Error validating: {}", message)]
   ValidationError { message: String, span: Span },
+
+  #[error("Error validating field `{}` in model `{}`: The relation field `{}` on Model `{}` is missing an opposite relation field on the model `{}`. Either run `prisma format` or add it manually.", field, model, field, model, related_model)]
+  MissingOppositeRelationFieldError { model: String, field: String, related_model: String, span: Span },
+
+  #[error("Error validating: The argument `references` must refer to a unique criteria in the related model `{}`. But it is referencing the following fields that are not a unique criteria: {}", related_model, references)]
+  ReferencesNotUniqueError { related_model: String, references: String, span: Span },
 }
 
 #[rustfmt::skip]
@@ -302,6 +314,23 @@ impl DatamodelError {
     }
   }
 
+  pub fn new_missing_opposite_relation_field_error(model: &str, field: &str, related_model: &str, span: Span) -> DatamodelError {
+    DatamodelError::MissingOppositeRelationFieldError {
+      model: String::from(model),
+      field: String::from(field),
+      related_model: String::from(related_model),
+      span,
+    }
+  }
+
+  pub fn new_references_not_unique_error(related_model: &str, references: &[String], span: Span) -> DatamodelError {
+    DatamodelError::ReferencesNotUniqueError {
+      related_model: String::from(related_model),
+      references: references.join(", "),
+      span,
+    }
+  }
+
   pub fn new_legacy_parser_error(message: &str, span: Span) -> DatamodelError {
     DatamodelError::LegacyParserError {
       message: String::from(message),
@@ -340,6 +369,28 @@ impl DatamodelError {
     DatamodelError::AttributeNotKnownError { attribute_name: String::from(attribute_name), span }
   }
 
+  pub fn new_attribute_namespace_not_known_error(namespace: &str, attribute_name: &str, span: Span) -> DatamodelError {
+    DatamodelError::AttributeNamespaceNotKnownError {
+      namespace: String::from(namespace),
+      attribute_name: String::from(attribute_name),
+      span,
+    }
+  }
+
+  pub fn new_custom_attribute_not_known_error(
+    namespace: &str,
+    attribute_name: &str,
+    declared_attributes: &[&str],
+    span: Span,
+  ) -> DatamodelError {
+    DatamodelError::CustomAttributeNotKnownError {
+      namespace: String::from(namespace),
+      attribute_name: String::from(attribute_name),
+      declared_attributes: declared_attributes.iter().map(|name| format!("@{}", name)).collect::<Vec<_>>().join(", "),
+      span,
+    }
+  }
+
   pub fn new_function_not_known_error(function_name: &str, span: Span) -> DatamodelError {
     DatamodelError::FunctionNotKnownError { function_name: String::from(function_name), span }
   }
@@ -383,6 +434,8 @@ impl DatamodelError {
       DatamodelError::GeneratorArgumentNotFound { span, .. } => *span,
       DatamodelError::AttributeValidationError { span, .. } => *span,
       DatamodelError::AttributeNotKnownError { span, .. } => *span,
+      DatamodelError::AttributeNamespaceNotKnownError { span, .. } => *span,
+      DatamodelError::CustomAttributeNotKnownError { span, .. } => *span,
       DatamodelError::ReservedScalarTypeError { span, .. } => *span,
       DatamodelError::FunctionNotKnownError { span, .. } => *span,
       DatamodelError::DatasourceProviderNotKnownError { span, .. } => *span,
@@ -395,6 +448,8 @@ impl DatamodelError {
       DatamodelError::TypeMismatchError { span, .. } => *span,
       DatamodelError::ValueParserError { span, .. } => *span,
       DatamodelError::ValidationError { span, .. } => *span,
+      DatamodelError::MissingOppositeRelationFieldError { span, .. } => *span,
+      DatamodelError::ReferencesNotUniqueError { span, .. } => *span,
       DatamodelError::LegacyParserError { span, .. } => *span,
       DatamodelError::ModelValidationError { span, .. } => *span,
       DatamodelError::DuplicateAttributeError { span, .. } => *span,
@@ -415,6 +470,59 @@ impl DatamodelError {
       DatamodelError::ShadowDatabaseUrlIsSameAsMainUrl { span, .. } => *span,
     }
   }
+
+  /// The error with its span moved by the offset, e.g. to locate an error of a single file in a schema split across files.
+  pub fn with_lifted_span(mut self, offset: usize) -> DatamodelError {
+    let span = self.span_mut();
+    *span = span.lift_span(offset);
+    self
+  }
+
+  fn span_mut(&mut self) -> &mut Span {
+    match self {
+      DatamodelError::ArgumentNotFound { span, .. } => span,
+      DatamodelError::AttributeArgumentNotFound { span, .. } => span,
+      DatamodelError::ArgumentCountMissmatch { span, .. } => span,
+      DatamodelError::SourceArgumentNotFound { span, .. } => span,
+      DatamodelError::GeneratorArgumentNotFound { span, .. } => span,
+      DatamodelError::AttributeValidationError { span, .. } => span,
+      DatamodelError::AttributeNotKnownError { span, .. } => span,
+      DatamodelError::AttributeNamespaceNotKnownError { span, .. } => span,
+      DatamodelError::CustomAttributeNotKnownError { span, .. } => span,
+      DatamodelError::ReservedScalarTypeError { span, .. } => span,
+      DatamodelError::FunctionNotKnownError { span, .. } => span,
+      DatamodelError::DatasourceProviderNotKnownError { span, .. } => span,
+      DatamodelError::LiteralParseError { span, .. } => span,
+      DatamodelError::TypeNotFoundError { span, .. } => span,
+      DatamodelError::ScalarTypeNotFoundError { span, .. } => span,
+      DatamodelError::ParserError { span, .. } => span,
+      DatamodelError::FunctionalEvaluationError { span, .. } => span,
+      DatamodelError::EnvironmentFunctionalEvaluationError { span, .. } => span,
+      DatamodelError::TypeMismatchError { span, .. } => span,
+      DatamodelError::ValueParserError { span, .. } => span,
+      DatamodelError::ValidationError { span, .. } => span,
+      DatamodelError::MissingOppositeRelationFieldError { span, .. } => span,
+      DatamodelError::ReferencesNotUniqueError { span, .. } => span,
+      DatamodelError::LegacyParserError { span, .. } => span,
+      DatamodelError::ModelValidationError { span, .. } => span,
+      DatamodelError::DuplicateAttributeError { span, .. } => span,
+      DatamodelError::DuplicateConfigKeyError { span, .. } => span,
+      DatamodelError::DuplicateTopError { span, .. } => span,
+      DatamodelError::DuplicateFieldError { span, .. } => span,
+      DatamodelError::DuplicateEnumValueError { span, .. } => span,
+      DatamodelError::DuplicateArgumentError { span, .. } => span,
+      DatamodelError::DuplicateDefaultArgumentError { span, .. } => span,
+      DatamodelError::UnusedArgumentError { span, .. } => span,
+      DatamodelError::ScalarListFieldsAreNotSupported {span, ..} => span,
+      DatamodelError::MultipleIndexesWithSameNameAreNotSupported {span, ..} => span,
+      DatamodelError::FieldValidationError {span , ..} => span,
+      DatamodelError::SourceValidationError {span, ..} => span,
+      DatamodelError::EnumValidationError {span, ..} => span,
+      DatamodelError::ConnectorError { span, .. } => span,
+      DatamodelError::PreviewFeatureNotKnownError {span, ..} => span,
+      DatamodelError::ShadowDatabaseUrlIsSameAsMainUrl { span, .. } => span,
+    }
+  }
   pub fn description(&self) -> String {
     format!("{}", self)
   }
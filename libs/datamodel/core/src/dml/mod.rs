@@ -1,3 +1,5 @@
+pub use dml::composite_type::*;
+pub use dml::custom_attribute::*;
 pub use dml::datamodel::*;
 pub use dml::default_value::*;
 pub use dml::field::*;
@@ -28,11 +28,15 @@ pub struct Field {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub relation_on_delete: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub relation_on_update: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub is_generated: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub is_updated_at: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub documentation: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub custom_attributes: Vec<CustomAttribute>,
 }
 
 #[serde(rename_all = "camelCase")]
@@ -52,10 +56,16 @@ pub struct Model {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub is_generated: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_view: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schema: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub documentation: Option<String>,
     pub id_fields: Vec<String>,
     pub unique_fields: Vec<Vec<String>>,
     pub unique_indexes: Vec<UniqueIndex>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub custom_attributes: Vec<CustomAttribute>,
 }
 
 #[serde(rename_all = "camelCase")]
@@ -73,6 +83,8 @@ pub struct Enum {
     pub db_name: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub documentation: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub custom_attributes: Vec<CustomAttribute>,
 }
 
 #[serde(rename_all = "camelCase")]
@@ -80,10 +92,39 @@ pub struct Enum {
 pub struct EnumValue {
     pub name: String,
     pub db_name: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub custom_attributes: Vec<CustomAttribute>,
+}
+
+/// An attribute of a namespace declared by a generator, with its arguments as written in the schema.
+#[serde(rename_all = "camelCase")]
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct CustomAttribute {
+    pub name: String,
+    pub args: Vec<CustomAttributeArgument>,
+}
+
+#[serde(rename_all = "camelCase")]
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct CustomAttributeArgument {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    pub value: String,
+}
+
+#[serde(rename_all = "camelCase")]
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct CompositeType {
+    pub name: String,
+    pub fields: Vec<Field>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub documentation: Option<String>,
 }
 
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
 pub struct Datamodel {
     pub enums: Vec<Enum>,
     pub models: Vec<Model>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub types: Vec<CompositeType>,
 }
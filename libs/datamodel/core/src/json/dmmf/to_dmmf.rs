@@ -1,5 +1,8 @@
-use super::{Datamodel, Enum, EnumValue, Field, Function, Model, UniqueIndex};
-use crate::{dml, FieldType, Ignorable, IndexType, ScalarType};
+use super::{
+    CompositeType, CustomAttribute, CustomAttributeArgument, Datamodel, Enum, EnumValue, Field, Function, Model,
+    UniqueIndex,
+};
+use crate::{dml, FieldType, Ignorable, IndexType, ScalarType, WithCustomAttributes};
 use bigdecimal::ToPrimitive;
 use prisma_value::PrismaValue;
 
@@ -17,6 +20,7 @@ fn schema_to_dmmf(schema: &dml::Datamodel) -> Datamodel {
     let mut datamodel = Datamodel {
         models: vec![],
         enums: vec![],
+        types: vec![],
     };
 
     for enum_model in schema.enums() {
@@ -27,6 +31,10 @@ fn schema_to_dmmf(schema: &dml::Datamodel) -> Datamodel {
         datamodel.models.push(model_to_dmmf(&model));
     }
 
+    for composite_type in schema.composite_types() {
+        datamodel.types.push(composite_type_to_dmmf(&composite_type));
+    }
+
     datamodel
 }
 
@@ -36,6 +44,7 @@ fn enum_to_dmmf(en: &dml::Enum) -> Enum {
         values: vec![],
         db_name: en.database_name.clone(),
         documentation: en.documentation.clone(),
+        custom_attributes: custom_attributes_to_dmmf(en),
     };
 
     for enum_value in en.values() {
@@ -49,6 +58,7 @@ fn enum_value_to_dmmf(en: &dml::EnumValue) -> EnumValue {
     EnumValue {
         name: en.name.clone(),
         db_name: en.database_name.clone(),
+        custom_attributes: custom_attributes_to_dmmf(en),
     }
 }
 
@@ -63,6 +73,8 @@ fn model_to_dmmf(model: &dml::Model) -> Model {
             .map(|f| field_to_dmmf(model, f))
             .collect(),
         is_generated: Some(model.is_generated),
+        is_view: if model.is_view { Some(true) } else { None },
+        schema: model.schema.clone(),
         documentation: model.documentation.clone(),
         id_fields: model.id_fields.clone(),
         unique_fields: model
@@ -90,6 +102,44 @@ fn model_to_dmmf(model: &dml::Model) -> Model {
                 }
             })
             .collect(),
+        custom_attributes: custom_attributes_to_dmmf(model),
+    }
+}
+
+fn composite_type_to_dmmf(composite_type: &dml::CompositeType) -> CompositeType {
+    CompositeType {
+        name: composite_type.name.clone(),
+        fields: composite_type
+            .fields()
+            .map(|f| composite_type_field_to_dmmf(f))
+            .collect(),
+        documentation: composite_type.documentation.clone(),
+    }
+}
+
+fn composite_type_field_to_dmmf(field: &dml::ScalarField) -> Field {
+    let field = dml::Field::ScalarField(field.clone());
+
+    Field {
+        name: field.name().to_string(),
+        kind: get_field_kind(&field),
+        is_required: *field.arity() == dml::FieldArity::Required || *field.arity() == dml::FieldArity::List,
+        is_list: *field.arity() == dml::FieldArity::List,
+        is_id: false,
+        is_read_only: false,
+        has_default_value: false,
+        default: None,
+        is_unique: false,
+        relation_name: None,
+        relation_from_fields: None,
+        relation_to_fields: None,
+        relation_on_delete: None,
+        relation_on_update: None,
+        field_type: get_field_type(&field),
+        is_generated: None,
+        is_updated_at: None,
+        documentation: field.documentation().map(|v| v.to_owned()),
+        custom_attributes: vec![],
     }
 }
 
@@ -112,16 +162,37 @@ fn field_to_dmmf(model: &dml::Model, field: &dml::Field) -> Field {
         relation_from_fields: get_relation_from_fields(field),
         relation_to_fields: get_relation_to_fields(field),
         relation_on_delete: get_relation_delete_strategy(field),
+        relation_on_update: get_relation_update_strategy(field),
         field_type: get_field_type(field),
         is_generated: Some(field.is_generated()),
         is_updated_at: Some(field.is_updated_at()),
         documentation: field.documentation().map(|v| v.to_owned()),
+        custom_attributes: custom_attributes_to_dmmf(field),
     }
 }
 
+/// Custom attributes are emitted as written, generators interpret them on their own.
+fn custom_attributes_to_dmmf(item: &dyn WithCustomAttributes) -> Vec<CustomAttribute> {
+    item.custom_attributes()
+        .iter()
+        .map(|attribute| CustomAttribute {
+            name: attribute.name.clone(),
+            args: attribute
+                .arguments
+                .iter()
+                .map(|arg| CustomAttributeArgument {
+                    name: arg.name.clone(),
+                    value: arg.value.clone(),
+                })
+                .collect(),
+        })
+        .collect()
+}
+
 fn get_field_kind(field: &dml::Field) -> String {
     match field.field_type() {
         dml::FieldType::Relation(_) => String::from("object"),
+        dml::FieldType::CompositeType(_) => String::from("object"),
         dml::FieldType::Enum(_) => String::from("enum"),
         dml::FieldType::Base(_, _) => String::from("scalar"),
         dml::FieldType::NativeType(_, _) => String::from("scalar"),
@@ -171,6 +242,7 @@ fn get_field_type(field: &dml::Field) -> String {
     match &field.field_type() {
         dml::FieldType::Relation(relation_info) => relation_info.to.clone(),
         dml::FieldType::Enum(t) => t.clone(),
+        dml::FieldType::CompositeType(t) => t.clone(),
         dml::FieldType::Unsupported(t) => t.clone(),
         dml::FieldType::Base(t, _) => type_to_string(t),
         dml::FieldType::NativeType(t, _) => type_to_string(t),
@@ -204,7 +276,19 @@ fn get_relation_to_fields(field: &dml::Field) -> Option<Vec<String>> {
 
 fn get_relation_delete_strategy(field: &dml::Field) -> Option<String> {
     match &field {
-        dml::Field::RelationField(rf) => Some(rf.relation_info.on_delete.to_string()),
+        dml::Field::RelationField(rf) => Some(
+            rf.relation_info
+                .on_delete
+                .map(|action| action.to_string())
+                .unwrap_or_else(|| String::from("NONE")),
+        ),
+        _ => None,
+    }
+}
+
+fn get_relation_update_strategy(field: &dml::Field) -> Option<String> {
+    match &field {
+        dml::Field::RelationField(rf) => rf.relation_info.on_update.map(|action| action.to_string()),
         _ => None,
     }
 }
@@ -9,6 +9,8 @@ pub struct SourceConfig {
     pub url: StringFromEnvVar,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub documentation: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub schemas: Vec<String>,
 }
 
 pub fn render_sources_to_json_value(sources: &[configuration::Datasource]) -> serde_json::Value {
@@ -38,5 +40,6 @@ fn source_to_json_struct(source: &configuration::Datasource) -> SourceConfig {
         active_provider: source.active_provider.to_string(),
         url: source.url().clone(),
         documentation: source.documentation.clone(),
+        schemas: source.schemas.clone(),
     }
 }
@@ -85,7 +85,7 @@ pub mod walkers;
 pub use crate::dml::*;
 pub use configuration::*;
 
-use crate::ast::SchemaAst;
+use crate::ast::{SchemaAst, SourceFiles};
 use crate::diagnostics::{ValidatedConfiguration, ValidatedDatamodel, ValidatedDatasources};
 use transform::{
     ast_to_dml::{DatasourceLoader, GeneratorLoader, ValidationPipeline},
@@ -116,22 +116,43 @@ pub fn parse_datamodel_or_pretty_error(datamodel_string: &str, file_name: &str)
         .map_err(|err| err.to_pretty_string(file_name, datamodel_string))
 }
 
+/// Parses and validates a schema split across multiple files, using core attributes only.
+/// The spans of the AST and of the diagnostics can be mapped back to the files with `SourceFiles::locate`.
+pub fn parse_datamodel_from_files(files: &SourceFiles) -> Result<ValidatedDatamodel, diagnostics::Diagnostics> {
+    validate_schema_ast(&files.parse()?, false, false)
+}
+
+pub fn parse_datamodel_from_files_and_ignore_datasource_urls(
+    files: &SourceFiles,
+) -> Result<ValidatedDatamodel, diagnostics::Diagnostics> {
+    validate_schema_ast(&files.parse()?, true, false)
+}
+
 fn parse_datamodel_internal(
     datamodel_string: &str,
     ignore_datasource_urls: bool,
     transform: bool,
 ) -> Result<ValidatedDatamodel, diagnostics::Diagnostics> {
-    let mut diagnostics = diagnostics::Diagnostics::new();
     let ast = ast::parse_schema(datamodel_string)?;
 
-    let sources = load_sources(&ast, ignore_datasource_urls, vec![])?;
-    let generators = GeneratorLoader::load_generators_from_ast(&ast)?;
-    let validator = ValidationPipeline::new(&sources.subject);
+    validate_schema_ast(&ast, ignore_datasource_urls, transform)
+}
+
+pub(crate) fn validate_schema_ast(
+    ast: &SchemaAst,
+    ignore_datasource_urls: bool,
+    transform: bool,
+) -> Result<ValidatedDatamodel, diagnostics::Diagnostics> {
+    let mut diagnostics = diagnostics::Diagnostics::new();
+
+    let sources = load_sources(ast, ignore_datasource_urls, vec![])?;
+    let generators = GeneratorLoader::load_generators_from_ast(ast)?;
+    let validator = ValidationPipeline::new(&sources.subject, &generators.subject);
 
     diagnostics.append_warning_vec(sources.warnings);
     diagnostics.append_warning_vec(generators.warnings);
 
-    match validator.validate(&ast, transform) {
+    match validator.validate(ast, transform) {
         Ok(mut src) => {
             src.warnings.append(&mut diagnostics.warnings);
             Ok(src)
@@ -166,6 +187,25 @@ pub fn parse_configuration(datamodel_string: &str) -> Result<ValidatedConfigurat
     })
 }
 
+/// Loads all configuration blocks from a schema split across multiple files.
+pub fn parse_configuration_from_files(files: &SourceFiles) -> Result<ValidatedConfiguration, diagnostics::Diagnostics> {
+    let mut warnings = Vec::new();
+    let ast = files.parse()?;
+    let mut validated_sources = load_sources(&ast, false, vec![])?;
+    let mut validated_generators = GeneratorLoader::load_generators_from_ast(&ast)?;
+
+    warnings.append(&mut validated_generators.warnings);
+    warnings.append(&mut validated_sources.warnings);
+
+    Ok(ValidatedConfiguration {
+        subject: Configuration {
+            datasources: validated_sources.subject,
+            generators: validated_generators.subject,
+        },
+        warnings,
+    })
+}
+
 /// - `datasource_url_overrides`: the tuples consist of datasource name and url
 pub fn parse_configuration_with_url_overrides(
     schema: &str,
@@ -5,12 +5,14 @@ use super::{
     datasource_provider::DatasourceProvider,
 };
 use crate::ast::Span;
-use crate::configuration::StringFromEnvVar;
+use crate::configuration::{ReferentialIntegrity, StringFromEnvVar};
 use crate::diagnostics::{DatamodelError, DatamodelWarning, Diagnostics, ValidatedDatasource, ValidatedDatasources};
 use crate::{ast, Datasource};
-use datamodel_connector::{CombinedConnector, Connector};
+use datamodel_connector::{CombinedConnector, Connector, ConnectorCapability};
 
 const PREVIEW_FEATURES_KEY: &str = "previewFeatures";
+const REFERENTIAL_INTEGRITY_KEY: &str = "referentialIntegrity";
+const SCHEMAS_KEY: &str = "schemas";
 const SHADOW_DATABASE_URL_KEY: &str = "shadowDatabaseUrl";
 const URL_KEY: &str = "url";
 
@@ -206,6 +208,17 @@ impl DatasourceLoader {
                 None
             };
 
+        let referential_integrity = match args.optional_arg(REFERENTIAL_INTEGRITY_KEY) {
+            Some(arg) => parse_referential_integrity(&arg, source_name)?,
+            None => ReferentialIntegrity::default(),
+        };
+
+        let schemas_arg = args.optional_arg(SCHEMAS_KEY);
+        let schemas = match schemas_arg.as_ref() {
+            Some(arg) => arg.as_array().to_str_vec()?,
+            None => Vec::new(),
+        };
+
         preview_features_guardrail(&mut args)?;
 
         let documentation = ast_source.documentation.as_ref().map(|comment| comment.text.clone());
@@ -247,6 +260,17 @@ impl DatasourceLoader {
                         })?;
                 }
 
+                // Validate the schemas
+                if let Some(schemas_arg) = schemas_arg.as_ref() {
+                    if !provider.connector().has_capability(ConnectorCapability::MultiSchema) {
+                        return Err(DatamodelError::new_source_validation_error(
+                            "The `schemas` property is not supported on the current connector.",
+                            source_name,
+                            schemas_arg.span(),
+                        ));
+                    }
+                }
+
                 Ok(provider)
             })
             .collect();
@@ -273,6 +297,8 @@ impl DatasourceLoader {
                     combined_connector,
                     active_connector: first_successful_provider.connector(),
                     shadow_database_url,
+                    referential_integrity,
+                    schemas,
                 },
                 warnings: diagnostics.warnings,
             })
@@ -315,6 +341,30 @@ fn preview_features_guardrail(args: &mut Arguments) -> Result<(), DatamodelError
     ))
 }
 
+fn parse_referential_integrity(
+    arg: &ValueValidator,
+    source_name: &str,
+) -> Result<ReferentialIntegrity, DatamodelError> {
+    let value = arg.as_str()?;
+
+    value.parse().map_err(|_| {
+        let allowed_values = ReferentialIntegrity::ALL
+            .iter()
+            .map(|mode| format!("`{}`", mode))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        DatamodelError::new_source_validation_error(
+            &format!(
+                "Invalid referential integrity mode: `{}`. Allowed values: ({})",
+                value, allowed_values
+            ),
+            source_name,
+            arg.span(),
+        )
+    })
+}
+
 /// Validate that the `url` argument in the datasource block is not empty.
 fn validate_datasource_url(
     url: &StringFromEnvVar,
@@ -10,12 +10,14 @@ const OUTPUT_KEY: &str = "output";
 const BINARY_TARGETS_KEY: &str = "binaryTargets";
 const EXPERIMENTAL_FEATURES_KEY: &str = "experimentalFeatures";
 const PREVIEW_FEATURES_KEY: &str = "previewFeatures";
+const CUSTOM_ATTRIBUTES_KEY: &str = "customAttributes";
 const FIRST_CLASS_PROPERTIES: &[&str] = &[
     PROVIDER_KEY,
     OUTPUT_KEY,
     BINARY_TARGETS_KEY,
     EXPERIMENTAL_FEATURES_KEY,
     PREVIEW_FEATURES_KEY,
+    CUSTOM_ATTRIBUTES_KEY,
 ];
 
 /// Is responsible for loading and validating Generators defined in an AST.
@@ -103,6 +105,31 @@ impl GeneratorLoader {
             }
         }
 
+        let custom_attributes = match args.arg(CUSTOM_ATTRIBUTES_KEY).ok() {
+            Some(x) => {
+                let custom_attributes = x.as_array().to_str_vec()?;
+
+                // Either a namespace or the name of a single attribute in a namespace.
+                let is_valid = |declared: &String| {
+                    let segments: Vec<&str> = declared.split('.').collect();
+                    segments.len() <= 2 && segments.iter().all(|segment| !segment.is_empty())
+                };
+
+                if let Some(invalid) = custom_attributes.iter().find(|declared| !is_valid(declared)) {
+                    return Err(diagnostics.merge_error(DatamodelError::new_validation_error(
+                        &format!(
+                            "The custom attribute \"{}\" is not valid. Declare either a namespace, e.g. \"zod\", or an attribute of a namespace, e.g. \"zod.min\".",
+                            invalid
+                        ),
+                        x.span(),
+                    )));
+                }
+
+                custom_attributes
+            }
+            None => Vec::new(),
+        };
+
         for prop in &ast_generator.properties {
             let is_first_class_prop = FIRST_CLASS_PROPERTIES.iter().any(|k| *k == prop.name.name);
             if is_first_class_prop {
@@ -119,6 +146,7 @@ impl GeneratorLoader {
                 output,
                 binary_targets,
                 preview_features,
+                custom_attributes,
                 config: properties,
                 documentation: ast_generator.documentation.clone().map(|comment| comment.text),
             },
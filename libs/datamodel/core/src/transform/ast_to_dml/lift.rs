@@ -17,6 +17,7 @@ use regex::Regex;
 pub struct LiftAstToDml<'a> {
     attributes: AllAttributes,
     source: Option<&'a configuration::Datasource>,
+    custom_attributes: Vec<String>,
 }
 
 impl<'a> LiftAstToDml<'a> {
@@ -24,10 +25,21 @@ impl<'a> LiftAstToDml<'a> {
     /// the attributes defined by the given sources registered.
     ///
     /// The attributes defined by the given sources will be namespaced.
-    pub fn new(source: Option<&'a configuration::Datasource>) -> LiftAstToDml<'a> {
+    /// The custom attributes declared by the given generators are kept as written.
+    pub fn new(
+        source: Option<&'a configuration::Datasource>,
+        generators: &[configuration::Generator],
+    ) -> LiftAstToDml<'a> {
+        let custom_attributes: Vec<String> = generators
+            .iter()
+            .flat_map(|generator| generator.custom_attributes.iter().cloned())
+            .collect();
+
         LiftAstToDml {
-            attributes: AllAttributes::new(),
+            attributes: AllAttributes::new()
+                .with_namespaces(source.map(|source| source.name.as_str()), &custom_attributes),
             source,
+            custom_attributes,
         }
     }
 
@@ -41,6 +53,10 @@ impl<'a> LiftAstToDml<'a> {
                     Ok(en) => schema.add_enum(en),
                     Err(mut err) => errors.append(&mut err),
                 },
+                ast::Top::Model(ty) if ty.is_composite_type => match self.lift_composite_type(&ty, ast_schema) {
+                    Ok(ct) => schema.add_composite_type(ct),
+                    Err(mut err) => errors.append(&mut err),
+                },
                 ast::Top::Model(ty) => match self.lift_model(&ty, ast_schema) {
                     Ok(md) => schema.add_model(md),
                     Err(mut err) => errors.append(&mut err),
@@ -63,6 +79,7 @@ impl<'a> LiftAstToDml<'a> {
     fn lift_model(&self, ast_model: &ast::Model, ast_schema: &ast::SchemaAst) -> Result<dml::Model, Diagnostics> {
         let mut model = dml::Model::new(ast_model.name.name.clone(), None);
         model.documentation = ast_model.documentation.clone().map(|comment| comment.text);
+        model.is_view = ast_model.is_view;
 
         let mut errors = Diagnostics::new();
 
@@ -84,6 +101,55 @@ impl<'a> LiftAstToDml<'a> {
         Ok(model)
     }
 
+    /// Internal: Validates a composite type AST node and lifts it to a DML composite type.
+    fn lift_composite_type(
+        &self,
+        ast_composite_type: &ast::Model,
+        ast_schema: &ast::SchemaAst,
+    ) -> Result<dml::CompositeType, Diagnostics> {
+        let mut composite_type = dml::CompositeType::new(&ast_composite_type.name.name);
+        composite_type.documentation = ast_composite_type.documentation.clone().map(|comment| comment.text);
+
+        let mut errors = Diagnostics::new();
+
+        for attribute in &ast_composite_type.attributes {
+            errors.push_error(DatamodelError::new_attribute_validation_error(
+                "Composite types do not support block attributes.",
+                &attribute.name.name,
+                attribute.span,
+            ));
+        }
+
+        for ast_field in &ast_composite_type.fields {
+            if let Some(attribute) = ast_field.attributes.first() {
+                errors.push_error(DatamodelError::new_attribute_validation_error(
+                    "Fields of composite types do not support attributes.",
+                    &attribute.name.name,
+                    attribute.span,
+                ));
+
+                continue;
+            }
+
+            match self.lift_field(ast_field, ast_schema) {
+                Ok(Field::ScalarField(field)) => composite_type.add_field(field),
+                Ok(Field::RelationField(_)) => errors.push_error(DatamodelError::new_field_validation_error(
+                    "Composite types cannot have relation fields.",
+                    &ast_composite_type.name.name,
+                    &ast_field.name.name,
+                    ast_field.span,
+                )),
+                Err(mut err) => errors.append(&mut err),
+            }
+        }
+
+        if errors.has_errors() {
+            return Err(errors);
+        }
+
+        Ok(composite_type)
+    }
+
     /// Internal: Validates an enum AST node.
     fn lift_enum(&self, ast_enum: &ast::Enum) -> Result<dml::Enum, Diagnostics> {
         let mut errors = Diagnostics::new();
@@ -179,6 +245,15 @@ impl<'a> LiftAstToDml<'a> {
         }
     }
 
+    /// Internal: Whether the attribute belongs to a namespace of custom attributes declared by a generator.
+    /// Unknown attributes of such a namespace are reported when the attributes are validated.
+    fn is_custom_attribute(&self, attribute: &ast::Attribute) -> bool {
+        let namespace = attribute.name.name.split('.').next().unwrap();
+        self.custom_attributes
+            .iter()
+            .any(|declared| declared.split('.').next() == Some(namespace))
+    }
+
     /// Internal: Lift a field's arity.
     fn lift_field_arity(&self, ast_field: &ast::FieldArity) -> dml::FieldArity {
         match ast_field {
@@ -223,6 +298,7 @@ impl<'a> LiftAstToDml<'a> {
                     .attributes
                     .iter()
                     .filter(|dir| dir.name.name.contains('.') && !dir.name.name.starts_with(&prefix))
+                    .filter(|dir| !self.is_custom_attribute(dir))
                     .collect_vec();
 
                 if !type_specifications_with_invalid_datasource_name.is_empty() {
@@ -335,6 +411,8 @@ impl<'a> LiftAstToDml<'a> {
             Ok((dml::FieldType::Relation(dml::RelationInfo::new(type_name)), vec![]))
         } else if ast_schema.find_enum(type_name).is_some() {
             Ok((dml::FieldType::Enum(type_name.clone()), vec![]))
+        } else if ast_schema.find_composite_type(type_name).is_some() {
+            Ok((dml::FieldType::CompositeType(type_name.clone()), vec![]))
         } else {
             self.resolve_custom_type(ast_field, ast_schema, checked_types)
         }
@@ -368,7 +446,7 @@ impl<'a> LiftAstToDml<'a> {
             let (field_type, mut attrs) =
                 self.lift_field_type(custom_type, Some(type_name.to_owned()), ast_schema, checked_types)?;
 
-            if let dml::FieldType::Relation(_) = field_type {
+            if let dml::FieldType::Relation(_) | dml::FieldType::CompositeType(_) = field_type {
                 return Err(DatamodelError::new_validation_error(
                     "Only scalar types can be used for defining custom types.",
                     custom_type.field_type.span,
@@ -4,6 +4,7 @@ use crate::{
     diagnostics::{DatamodelError, Diagnostics},
     dml, DefaultValue, FieldType,
 };
+use datamodel_connector::ConnectorCapability;
 use prisma_value::PrismaValue;
 use std::collections::HashSet;
 
@@ -129,6 +130,29 @@ impl<'a> Validator<'a> {
                 errors_for_model.append(the_errors);
             }
 
+            if let Err(ref mut the_errors) =
+                self.validate_referential_actions(ast_schema.find_model(&model.name).expect(STATE_ERROR), model)
+            {
+                errors_for_model.append(the_errors);
+            }
+
+            if let Err(ref mut the_errors) =
+                self.validate_fulltext_indexes(ast_schema.find_model(&model.name).expect(STATE_ERROR), model)
+            {
+                errors_for_model.append(the_errors);
+            }
+
+            if let Err(ref mut the_errors) =
+                self.validate_views_in_relations(schema, ast_schema.find_model(&model.name).expect(STATE_ERROR), model)
+            {
+                errors_for_model.append(the_errors);
+            }
+
+            if let Err(err) = self.validate_model_schema(ast_schema.find_model(&model.name).expect(STATE_ERROR), model)
+            {
+                errors_for_model.push_error(err);
+            }
+
             all_errors.append(&mut errors_for_model);
         }
 
@@ -145,6 +169,17 @@ impl<'a> Validator<'a> {
             all_errors.append(&mut errors_for_enum);
         }
 
+        // Composite type level validations.
+        for composite_type in schema.composite_types() {
+            if let Err(err) = self.validate_composite_type_is_not_recursive(
+                schema,
+                ast_schema.find_composite_type(&composite_type.name).expect(STATE_ERROR),
+                composite_type,
+            ) {
+                all_errors.push_error(err);
+            }
+        }
+
         if all_errors.has_errors() {
             Err(all_errors)
         } else {
@@ -196,6 +231,14 @@ impl<'a> Validator<'a> {
             }
         }
 
+        for composite_type in ast_schema.composite_types() {
+            errors.push_opt_error(composite_type.name.validate("Composite type").err());
+
+            for field in composite_type.fields.iter() {
+                errors.push_opt_error(field.name.validate("Field").err());
+            }
+        }
+
         for enum_decl in ast_schema.enums() {
             errors.push_opt_error(enum_decl.name.validate("Enum").err());
             errors.append(&mut enum_decl.validate_attributes());
@@ -256,7 +299,11 @@ impl<'a> Validator<'a> {
             None => false,
         };
 
-        for field in model.scalar_fields() {
+        // Composite type fields are stored as Json, lists of them don't need scalar list support.
+        for field in model
+            .scalar_fields()
+            .filter(|f| f.field_type.as_composite_type().is_none())
+        {
             if field.is_list() && !scalar_lists_are_supported {
                 errors.push_error(DatamodelError::new_scalar_list_fields_are_not_supported(
                     &model.name,
@@ -276,13 +323,30 @@ impl<'a> Validator<'a> {
     fn validate_field_types(&self, ast_model: &ast::Model, model: &dml::Model) -> Result<(), Diagnostics> {
         let mut errors = Diagnostics::new();
 
+        let supports_json_type = self.source.map_or(false, |source| source.combined_connector.supports_json());
+
         for field in model.scalar_fields() {
+            if let Some(composite_type) = field.field_type.as_composite_type() {
+                if !supports_json_type {
+                    errors.push_error(DatamodelError::new_field_validation_error(
+                        &format!("Field `{}` in model `{}` can't be of composite type `{}`. Composite types are stored as Json, which the current connector does not support.", &field.name, &model.name, composite_type),
+                        &model.name,
+                        &field.name,
+                        ast_model.find_field(&field.name).span,
+                    ));
+                }
+
+                if field.is_id || field.is_unique {
+                    errors.push_error(DatamodelError::new_field_validation_error(
+                        "Fields of a composite type cannot be marked as id or unique.",
+                        &model.name,
+                        &field.name,
+                        ast_model.find_field(&field.name).span,
+                    ));
+                }
+            }
+
             if let Some(dml::ScalarType::Json) = field.field_type.scalar_type() {
-                // TODO: this is really ugly
-                let supports_json_type = match self.source {
-                    Some(source) => source.combined_connector.supports_json(),
-                    None => false,
-                };
                 if !supports_json_type {
                     errors.push_error(DatamodelError::new_field_validation_error(
                         &format!("Field `{}` in model `{}` can't be of type Json. The current connector does not support the Json type.", &field.name, &model.name),
@@ -746,12 +810,11 @@ impl<'a> Validator<'a> {
                 };
 
                 if !references_unique_criteria && must_reference_unique_criteria {
-                    errors.push_error(DatamodelError::new_validation_error(
-                            &format!("The argument `references` must refer to a unique criteria in the related model `{}`. But it is referencing the following fields that are not a unique criteria: {}",
-                                     &related_model.name,
-                                     rel_info.references.join(", ")),
-                            ast_field.span)
-                        );
+                    errors.push_error(DatamodelError::new_references_not_unique_error(
+                        &related_model.name,
+                        &rel_info.references,
+                        ast_field.span,
+                    ));
                 } else if !reference_order_correct {
                     errors.push_error(DatamodelError::new_validation_error(
                         &format!("The argument `references` must refer to a unique criteria in the related model `{}` using the same order of fields. Please check the ordering in the following fields: `{}`.",
@@ -799,6 +862,213 @@ impl<'a> Validator<'a> {
         }
     }
 
+    fn validate_referential_actions(&self, ast_model: &ast::Model, model: &dml::Model) -> Result<(), Diagnostics> {
+        let mut errors = Diagnostics::new();
+
+        for field in model.relation_fields() {
+            let rel_info = &field.relation_info;
+            let actions = [("onDelete", rel_info.on_delete), ("onUpdate", rel_info.on_update)];
+
+            if actions.iter().all(|(_, action)| action.is_none()) {
+                continue;
+            }
+
+            let span = ast_model
+                .find_field(&field.name)
+                .attributes
+                .iter()
+                .find(|attr| attr.name.name == RELATION_ATTRIBUTE_NAME)
+                .map(|attr| attr.span)
+                .unwrap_or_else(ast::Span::empty);
+
+            if rel_info.fields.is_empty() {
+                errors.push_error(DatamodelError::new_attribute_validation_error(
+                    &format!(
+                        "The relation field `{}` does not define `fields`. Referential actions can only be defined on the side of the relation that holds the foreign key.",
+                        &field.name
+                    ),
+                    RELATION_ATTRIBUTE_NAME,
+                    span,
+                ));
+
+                continue;
+            }
+
+            for (argument, action) in actions.iter() {
+                let action = match action {
+                    Some(action) => *action,
+                    None => continue,
+                };
+
+                if let Some(source) = self.source {
+                    let connector = &source.active_connector;
+
+                    if !connector.supports_referential_action(action) {
+                        let allowed_values = connector
+                            .referential_actions()
+                            .iter()
+                            .map(|action| format!("`{}`", action))
+                            .collect::<Vec<_>>()
+                            .join(", ");
+
+                        errors.push_error(DatamodelError::new_attribute_validation_error(
+                            &format!(
+                                "Invalid referential action: `{}` is not supported by the {} connector. Allowed values for `{}`: ({})",
+                                action,
+                                connector.name(),
+                                argument,
+                                allowed_values
+                            ),
+                            RELATION_ATTRIBUTE_NAME,
+                            span,
+                        ));
+                    }
+                }
+
+                let has_required_underlying_field = rel_info
+                    .fields
+                    .iter()
+                    .filter_map(|base_field| model.find_scalar_field(&base_field))
+                    .any(|f| f.is_required());
+
+                if action == dml::ReferentialAction::SetNull && has_required_underlying_field {
+                    errors.push_error(DatamodelError::new_attribute_validation_error(
+                        &format!(
+                            "The referential action `SetNull` in `{}` cannot be used on the relation field `{}`, because it uses required fields: {}.",
+                            argument,
+                            &field.name,
+                            rel_info.fields.join(", ")
+                        ),
+                        RELATION_ATTRIBUTE_NAME,
+                        span,
+                    ));
+                }
+            }
+        }
+
+        if errors.has_errors() {
+            Err(errors)
+        } else {
+            Ok(())
+        }
+    }
+
+    fn validate_fulltext_indexes(&self, ast_model: &ast::Model, model: &dml::Model) -> Result<(), Diagnostics> {
+        let mut errors = Diagnostics::new();
+
+        if !model.indices.iter().any(|index| index.is_fulltext()) {
+            return Ok(());
+        }
+
+        let supports_fulltext_indexes = match self.source {
+            Some(source) => source
+                .active_connector
+                .has_capability(ConnectorCapability::FullTextIndex),
+            None => false,
+        };
+
+        if !supports_fulltext_indexes {
+            for attribute in ast_model.attributes.iter().filter(|attr| attr.name.name == "fulltext") {
+                errors.push_error(DatamodelError::new_attribute_validation_error(
+                    "You must specify a datasource that supports fulltext indexes to use them. Fulltext indexes are currently only supported on MySQL.",
+                    "fulltext",
+                    attribute.span,
+                ));
+            }
+        }
+
+        if errors.has_errors() {
+            Err(errors)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// The schema of a model must be one of the schemas declared on the datasource.
+    fn validate_model_schema(&self, ast_model: &ast::Model, model: &dml::Model) -> Result<(), DatamodelError> {
+        let schema = match &model.schema {
+            Some(schema) => schema,
+            None => return Ok(()),
+        };
+
+        let span = ast_model
+            .attributes
+            .iter()
+            .find(|attr| attr.name.name == "schema")
+            .map(|attr| attr.span)
+            .unwrap_or(ast_model.span);
+
+        let declared_schemas = self.source.map(|source| source.schemas.as_slice()).unwrap_or(&[]);
+
+        if declared_schemas.is_empty() {
+            return Err(DatamodelError::new_attribute_validation_error(
+                "The `@@schema` attribute can only be used if the datasource defines its `schemas`.",
+                "schema",
+                span,
+            ));
+        }
+
+        if !declared_schemas.contains(schema) {
+            return Err(DatamodelError::new_attribute_validation_error(
+                &format!(
+                    "The schema `{}` is not defined in the `schemas` property of the datasource.",
+                    schema
+                ),
+                "schema",
+                span,
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Views are read-only, so they can neither have relation fields nor be the target of one.
+    fn validate_views_in_relations(
+        &self,
+        datamodel: &dml::Datamodel,
+        ast_model: &ast::Model,
+        model: &dml::Model,
+    ) -> Result<(), Diagnostics> {
+        let mut errors = Diagnostics::new();
+
+        for field in model.relation_fields() {
+            let ast_field = ast_model.find_field(&field.name);
+
+            if model.is_view {
+                errors.push_error(DatamodelError::new_field_validation_error(
+                    "Views cannot have relation fields.",
+                    &model.name,
+                    &field.name,
+                    ast_field.span,
+                ));
+
+                continue;
+            }
+
+            if datamodel
+                .find_model(&field.relation_info.to)
+                .map(|related_model| related_model.is_view)
+                .unwrap_or(false)
+            {
+                errors.push_error(DatamodelError::new_field_validation_error(
+                    &format!(
+                        "The relation field points to the view `{}`. Views cannot be part of relations.",
+                        field.relation_info.to
+                    ),
+                    &model.name,
+                    &field.name,
+                    ast_field.span,
+                ));
+            }
+        }
+
+        if errors.has_errors() {
+            Err(errors)
+        } else {
+            Ok(())
+        }
+    }
+
     fn validate_relation_arguments_bla(
         &self,
         datamodel: &dml::Datamodel,
@@ -967,15 +1237,10 @@ impl<'a> Validator<'a> {
                         ));
                 }
             } else {
-                errors.push_error(DatamodelError::new_field_validation_error(
-                    &format!(
-                        "The relation field `{}` on Model `{}` is missing an opposite relation field on the model `{}`. Either run `prisma format` or add it manually.",
-                        &field.name,
-                        &model.name,
-                        &related_model.name,
-                    ),
+                errors.push_error(DatamodelError::new_missing_opposite_relation_field_error(
                     &model.name,
                     &field.name,
+                    &related_model.name,
                     field_span,
                 ));
             }
@@ -1102,4 +1367,62 @@ impl<'a> Validator<'a> {
 
         Ok(())
     }
+
+    /// A composite type embedding itself through required fields could never be written.
+    fn validate_composite_type_is_not_recursive(
+        &self,
+        datamodel: &dml::Datamodel,
+        ast_composite_type: &ast::Model,
+        composite_type: &dml::CompositeType,
+    ) -> Result<(), DatamodelError> {
+        let mut path = vec![composite_type.name.as_str()];
+
+        if find_required_composite_type_cycle(datamodel, composite_type, &mut path) {
+            return Err(DatamodelError::new_validation_error(
+                &format!(
+                    "Composite types cannot embed themselves through required fields. Recursive path was: {}.",
+                    path.join(" -> ")
+                ),
+                ast_composite_type.name.span,
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+/// Follows the required composite type fields, extending `path` until it leads back to its first composite type.
+fn find_required_composite_type_cycle<'a>(
+    datamodel: &'a dml::Datamodel,
+    composite_type: &'a dml::CompositeType,
+    path: &mut Vec<&'a str>,
+) -> bool {
+    for field in composite_type.fields().filter(|field| field.is_required()) {
+        let field_type = match field.field_type.as_composite_type() {
+            Some(field_type) => field_type,
+            None => continue,
+        };
+
+        if field_type == path[0] {
+            path.push(field_type);
+            return true;
+        }
+
+        // Cycles not passing through the first composite type are reported for the types on them.
+        if path.contains(&field_type) {
+            continue;
+        }
+
+        if let Some(embedded) = datamodel.find_composite_type(field_type) {
+            path.push(field_type);
+
+            if find_required_composite_type_cycle(datamodel, embedded, path) {
+                return true;
+            }
+
+            path.pop();
+        }
+    }
+
+    false
 }
@@ -12,10 +12,13 @@ pub struct ValidationPipeline<'a> {
 }
 
 impl<'a, 'b> ValidationPipeline<'a> {
-    pub fn new(sources: &'a [configuration::Datasource]) -> ValidationPipeline<'a> {
+    pub fn new(
+        sources: &'a [configuration::Datasource],
+        generators: &[configuration::Generator],
+    ) -> ValidationPipeline<'a> {
         let source = sources.first();
         ValidationPipeline {
-            lifter: LiftAstToDml::new(source),
+            lifter: LiftAstToDml::new(source, generators),
             validator: Validator::new(source),
             standardiser_for_formatting: StandardiserForFormatting::new(),
             standardiser_for_parsing: StandardiserForParsing::new(),
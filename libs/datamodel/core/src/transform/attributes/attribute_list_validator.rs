@@ -1,7 +1,7 @@
 use super::{super::helpers::*, AttributeValidator};
 use crate::ast;
 use crate::diagnostics::{DatamodelError, Diagnostics};
-use crate::dml;
+use crate::dml::{self, CustomAttribute, CustomAttributeArgument, WithCustomAttributes};
 
 // BTreeMap has a strictly defined order.
 // That's important since rendering depends on that order.
@@ -11,15 +11,27 @@ use std::collections::{BTreeMap, HashMap};
 /// picks the right one for each attribute in the given object.
 pub struct AttributeListValidator<T> {
     known_attributes: BTreeMap<String, Box<dyn AttributeValidator<T>>>,
+    native_types_namespace: Option<String>,
+    custom_attributes: Vec<String>,
 }
 
-impl<T: 'static> AttributeListValidator<T> {
+impl<T: WithCustomAttributes + 'static> AttributeListValidator<T> {
     pub fn new() -> Self {
         AttributeListValidator {
             known_attributes: BTreeMap::new(),
+            native_types_namespace: None,
+            custom_attributes: Vec::new(),
         }
     }
 
+    /// Sets the namespaces of attributes which are not validated here: the native type attributes
+    /// of the datasource and the custom attributes declared by generators, either by namespace
+    /// (`zod`) or by name (`zod.min`).
+    pub fn set_namespaces(&mut self, native_types_namespace: Option<&str>, custom_attributes: &[String]) {
+        self.native_types_namespace = native_types_namespace.map(String::from);
+        self.custom_attributes = custom_attributes.to_vec();
+    }
+
     /// Adds an attribute validator.
     pub fn add(&mut self, validator: Box<dyn AttributeValidator<T>>) {
         let name = validator.attribute_name();
@@ -90,8 +102,42 @@ impl<T: 'static> AttributeListValidator<T> {
                         }
                     }
                 }
+                None if attribute.name.name.contains('.') => {
+                    let namespace = attribute.name.name.split('.').next().unwrap();
+
+                    let is_native_type = self.native_types_namespace.as_deref() == Some(namespace);
+                    let is_custom_attribute = self
+                        .custom_attributes
+                        .iter()
+                        .any(|declared| declared == namespace || *declared == attribute.name.name);
+
+                    let declared_attributes: Vec<&str> = self
+                        .custom_attributes
+                        .iter()
+                        .filter(|declared| declared.split('.').next() == Some(namespace) && declared.contains('.'))
+                        .map(String::as_str)
+                        .collect();
+
+                    // Native type attributes are validated together with the field type.
+                    if is_custom_attribute && !is_native_type {
+                        t.add_custom_attribute(lift_custom_attribute(attribute));
+                    } else if !is_native_type && !declared_attributes.is_empty() {
+                        errors.push_error(DatamodelError::new_custom_attribute_not_known_error(
+                            namespace,
+                            &attribute.name.name,
+                            &declared_attributes,
+                            attribute.name.span,
+                        ))
+                    } else if !is_native_type {
+                        errors.push_error(DatamodelError::new_attribute_namespace_not_known_error(
+                            namespace,
+                            &attribute.name.name,
+                            attribute.name.span,
+                        ))
+                    }
+                }
                 None => {
-                    if !attribute.name.name.is_empty() && !attribute.name.name.contains('.') {
+                    if !attribute.name.name.is_empty() {
                         errors.push_error(DatamodelError::new_attribute_not_known_error(
                             &attribute.name.name,
                             attribute.name.span,
@@ -107,10 +153,37 @@ impl<T: 'static> AttributeListValidator<T> {
     }
 
     pub fn serialize(&self, t: &T, datamodel: &dml::Datamodel) -> Vec<ast::Attribute> {
+        let custom_attributes = t.custom_attributes().iter().map(lower_custom_attribute);
+
         self.known_attributes
             .values()
             .map(|attribute| attribute.serialize(t, datamodel))
             .flatten()
+            .chain(custom_attributes)
             .collect()
     }
 }
+
+/// Custom attributes are not interpreted, the arguments are kept as written.
+fn lift_custom_attribute(attribute: &ast::Attribute) -> CustomAttribute {
+    let arguments = attribute
+        .arguments
+        .iter()
+        .map(|arg| CustomAttributeArgument {
+            name: Some(arg.name.name.clone()).filter(|name| !name.is_empty()),
+            value: arg.value.render_to_string(),
+        })
+        .collect();
+
+    CustomAttribute::new(&attribute.name.name, arguments)
+}
+
+fn lower_custom_attribute(attribute: &CustomAttribute) -> ast::Attribute {
+    let arguments = attribute
+        .arguments
+        .iter()
+        .map(|arg| ast::Argument::new_constant(arg.name.as_deref().unwrap_or(""), &arg.value))
+        .collect();
+
+    ast::Attribute::new(&attribute.name, arguments)
+}
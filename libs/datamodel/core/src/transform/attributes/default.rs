@@ -61,6 +61,11 @@ impl AttributeValidator<dml::Field> for DefaultAttributeValidator {
                             .new_attribute_validation_error("Invalid default value on Unsupported type.", args.span())
                     }
                 }
+            } else if let dml::FieldType::CompositeType(_) = sf.field_type {
+                return self.new_attribute_validation_error(
+                    "Fields of a composite type cannot have a default value.",
+                    args.span(),
+                );
             }
         }
         Ok(())
@@ -0,0 +1,45 @@
+use super::{super::helpers::*, AttributeValidator};
+use crate::ast::Span;
+use crate::diagnostics::DatamodelError;
+use crate::{ast, dml, Datamodel};
+
+/// Prismas builtin `@@definition` attribute, holding the query of a view.
+pub struct DefinitionAttributeValidator {}
+
+const ATTRIBUTE_NAME: &str = "definition";
+
+impl AttributeValidator<dml::Model> for DefinitionAttributeValidator {
+    fn attribute_name(&self) -> &str {
+        ATTRIBUTE_NAME
+    }
+
+    fn validate_and_apply(&self, args: &mut Arguments, obj: &mut dml::Model) -> Result<(), DatamodelError> {
+        if !obj.is_view {
+            return self.new_attribute_validation_error(
+                "The `@@definition` attribute can only be used on views.",
+                args.span(),
+            );
+        }
+
+        let definition = args.default_arg("sql")?.as_str().map_err(|err| {
+            DatamodelError::new_attribute_validation_error(&format!("{}", err), ATTRIBUTE_NAME, err.span())
+        })?;
+
+        obj.view_definition = Some(definition);
+
+        Ok(())
+    }
+
+    fn serialize(&self, obj: &dml::Model, _datamodel: &Datamodel) -> Vec<ast::Attribute> {
+        match &obj.view_definition {
+            Some(definition) => vec![ast::Attribute::new(
+                ATTRIBUTE_NAME,
+                vec![ast::Argument::new_unnamed(ast::Expression::StringValue(
+                    definition.clone(),
+                    Span::empty(),
+                ))],
+            )],
+            None => vec![],
+        }
+    }
+}
@@ -1,10 +1,13 @@
 mod attribute_list_validator;
 mod attribute_validator;
+mod check;
 mod default;
+mod definition;
 mod id;
 mod ignore;
 mod map;
 mod relation;
+mod schema;
 mod unique_and_index;
 mod updated_at;
 
@@ -29,6 +32,17 @@ impl AllAttributes {
             enm_value: new_builtin_enum_value_attributes(),
         }
     }
+
+    /// Registers the namespace of the native type attributes of the datasource and the
+    /// custom attributes declared by generators, which are passed on to them without validation.
+    pub fn with_namespaces(mut self, native_types_namespace: Option<&str>, custom_attributes: &[String]) -> Self {
+        self.field.set_namespaces(native_types_namespace, custom_attributes);
+        self.model.set_namespaces(native_types_namespace, custom_attributes);
+        self.enm.set_namespaces(native_types_namespace, custom_attributes);
+        self.enm_value.set_namespaces(native_types_namespace, custom_attributes);
+
+        self
+    }
 }
 
 fn new_builtin_field_attributes() -> AttributeListValidator<dml::Field> {
@@ -53,8 +67,12 @@ fn new_builtin_model_attributes() -> AttributeListValidator<dml::Model> {
     validator.add(Box::new(id::ModelLevelIdAttributeValidator {}));
     validator.add(Box::new(unique_and_index::ModelLevelUniqueAttributeValidator {}));
     validator.add(Box::new(unique_and_index::ModelLevelIndexAttributeValidator {}));
+    validator.add(Box::new(unique_and_index::ModelLevelFulltextAttributeValidator {}));
+    validator.add(Box::new(check::CheckAttributeValidator {}));
     validator.add(Box::new(map::MapAttributeValidator {}));
+    validator.add(Box::new(schema::SchemaAttributeValidator {}));
     validator.add(Box::new(ignore::IgnoreAttributeValidator {}));
+    validator.add(Box::new(definition::DefinitionAttributeValidator {}));
 
     validator
 }
@@ -112,7 +112,55 @@ impl AttributeValidator<dml::Model> for ModelLevelIndexAttributeValidator {
     }
 }
 
-/// common logic for `@@unique` and `@@index`
+/// Prismas builtin `@@fulltext` attribute.
+pub struct ModelLevelFulltextAttributeValidator {}
+
+impl IndexAttributeBase<dml::Model> for ModelLevelFulltextAttributeValidator {}
+impl AttributeValidator<dml::Model> for ModelLevelFulltextAttributeValidator {
+    fn attribute_name(&self) -> &str {
+        "fulltext"
+    }
+
+    fn is_duplicate_definition_allowed(&self) -> bool {
+        true
+    }
+
+    fn validate_and_apply(&self, args: &mut Arguments, obj: &mut dml::Model) -> Result<(), DatamodelError> {
+        let index_def = self.validate_index(args, obj, IndexType::Fulltext)?;
+
+        let non_string_fields: Vec<&str> = index_def
+            .fields
+            .iter()
+            .filter(|field| {
+                obj.find_scalar_field(field)
+                    .map(|sf| !sf.field_type.is_string())
+                    .unwrap_or(false)
+            })
+            .map(|field| field.as_str())
+            .collect();
+
+        if !non_string_fields.is_empty() {
+            return Err(DatamodelError::new_model_validation_error(
+                &format!(
+                    "The fulltext index definition refers to the non-String fields {}. Fulltext indexes can only be defined on String fields.",
+                    non_string_fields.join(", ")
+                ),
+                &obj.name,
+                args.span(),
+            ));
+        }
+
+        obj.indices.push(index_def);
+
+        Ok(())
+    }
+
+    fn serialize(&self, model: &dml::Model, _datamodel: &dml::Datamodel) -> Vec<ast::Attribute> {
+        self.serialize_index_definitions(&model, IndexType::Fulltext)
+    }
+}
+
+/// common logic for `@@unique`, `@@index` and `@@fulltext`
 trait IndexAttributeBase<T>: AttributeValidator<T> {
     fn validate_index(
         &self,
@@ -155,7 +203,7 @@ trait IndexAttributeBase<T>: AttributeValidator<T> {
             return Err(DatamodelError::new_model_validation_error(
                 &format!(
                     "The {}index definition refers to the fields {} multiple times.",
-                    if index_type == IndexType::Unique { "unique " } else { "" },
+                    index_kind_prefix(index_type),
                     duplicated_fields.join(", ")
                 ),
                 &obj.name,
@@ -186,7 +234,7 @@ trait IndexAttributeBase<T>: AttributeValidator<T> {
             return Err(DatamodelError::new_model_validation_error(
                 &format!(
                     "The {}index definition refers to the unknown fields {}.",
-                    if index_type == IndexType::Unique { "unique " } else { "" },
+                    index_kind_prefix(index_type),
                     undefined_fields.join(", ")
                 ),
                 &obj.name,
@@ -224,7 +272,7 @@ trait IndexAttributeBase<T>: AttributeValidator<T> {
             return Err(DatamodelError::new_model_validation_error(
                 &format!(
                     "The {prefix}index definition refers to the relation fields {the_fields}. Index definitions must reference only scalar fields.{suggestion}",
-                    prefix = if index_type == IndexType::Unique { "unique " } else { "" },
+                    prefix = index_kind_prefix(index_type),
                     the_fields = referenced_relation_fields.join(", "),
                     suggestion = suggestion
                 ),
@@ -265,10 +313,18 @@ trait IndexAttributeBase<T>: AttributeValidator<T> {
 }
 
 fn attribute_name(index_type: dml::IndexType) -> &'static str {
-    if index_type == dml::IndexType::Unique {
-        "unique"
-    } else {
-        "index"
+    match index_type {
+        dml::IndexType::Unique => "unique",
+        dml::IndexType::Normal => "index",
+        dml::IndexType::Fulltext => "fulltext",
+    }
+}
+
+fn index_kind_prefix(index_type: dml::IndexType) -> &'static str {
+    match index_type {
+        dml::IndexType::Unique => "unique ",
+        dml::IndexType::Normal => "",
+        dml::IndexType::Fulltext => "fulltext ",
     }
 }
 
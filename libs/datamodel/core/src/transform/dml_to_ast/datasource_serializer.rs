@@ -1,5 +1,5 @@
 use crate::ast;
-use crate::configuration::Datasource;
+use crate::configuration::{Datasource, ReferentialIntegrity};
 
 pub struct DatasourceSerializer {}
 
@@ -32,6 +32,23 @@ impl DatasourceSerializer {
             }
         }
 
+        if source.referential_integrity != ReferentialIntegrity::default() {
+            arguments.push(ast::Argument::new_string(
+                "referentialIntegrity",
+                &source.referential_integrity.to_string(),
+            ));
+        }
+
+        if !source.schemas.is_empty() {
+            let schemas = source
+                .schemas
+                .iter()
+                .map(|schema| ast::Expression::StringValue(schema.clone(), ast::Span::empty()))
+                .collect();
+
+            arguments.push(ast::Argument::new_array("schemas", schemas));
+        }
+
         ast::SourceConfig {
             name: ast::Identifier::new(&source.name),
             properties: arguments,
@@ -44,6 +44,16 @@ impl GeneratorSerializer {
             arguments.push(ast::Argument::new_array("binaryTargets", platform_values));
         }
 
+        if !generator.custom_attributes.is_empty() {
+            let namespaces: Vec<ast::Expression> = generator
+                .custom_attributes
+                .iter()
+                .map(|ns| ast::Expression::StringValue(ns.to_owned(), ast::Span::empty()))
+                .collect();
+
+            arguments.push(ast::Argument::new_array("customAttributes", namespaces));
+        }
+
         for (key, value) in &generator.config {
             arguments.push(ast::Argument::new_string(&key, &value));
         }
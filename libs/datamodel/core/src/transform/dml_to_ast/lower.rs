@@ -31,6 +31,10 @@ impl<'a> LowerDmlToAst<'a> {
             tops.push(ast::Top::Enum(self.lower_enum(enm, datamodel)))
         }
 
+        for composite_type in datamodel.composite_types() {
+            tops.push(ast::Top::Model(self.lower_composite_type(composite_type, datamodel)))
+        }
+
         ast::SchemaAst { tops }
     }
 
@@ -48,6 +52,26 @@ impl<'a> LowerDmlToAst<'a> {
             documentation: model.documentation.clone().map(|text| ast::Comment { text }),
             span: ast::Span::empty(),
             commented_out: model.is_commented_out,
+            is_view: model.is_view,
+            is_composite_type: false,
+        }
+    }
+
+    fn lower_composite_type(&self, composite_type: &dml::CompositeType, datamodel: &dml::Datamodel) -> ast::Model {
+        let fields = composite_type
+            .fields()
+            .map(|field| self.lower_field(&dml::Field::ScalarField(field.clone()), datamodel))
+            .collect();
+
+        ast::Model {
+            name: ast::Identifier::new(&composite_type.name),
+            fields,
+            attributes: vec![],
+            documentation: composite_type.documentation.clone().map(|text| ast::Comment { text }),
+            span: ast::Span::empty(),
+            commented_out: false,
+            is_view: false,
+            is_composite_type: true,
         }
     }
 
@@ -106,6 +130,7 @@ impl<'a> LowerDmlToAst<'a> {
                 ast::Identifier::new(&custom_type_name.as_ref().unwrap_or(&tpe.to_string()))
             }
             dml::FieldType::Enum(tpe) => ast::Identifier::new(&tpe),
+            dml::FieldType::CompositeType(tpe) => ast::Identifier::new(&tpe),
             dml::FieldType::Unsupported(tpe) => ast::Identifier::new(&format!("Unsupported(\"{}\")", tpe)),
             dml::FieldType::Relation(rel) => ast::Identifier::new(&rel.to),
             dml::FieldType::NativeType(prisma_tpe, _native_tpe) => ast::Identifier::new(&prisma_tpe.to_string()),
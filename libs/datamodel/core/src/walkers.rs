@@ -2,10 +2,10 @@
 //! The most prominent functionality is the pain free navigation of relations.
 use crate::{
     dml::{
-        Datamodel, DefaultValue, Enum, EnumValue, FieldArity, FieldType, IndexDefinition, Model, ScalarField,
-        WithDatabaseName,
+        CheckConstraintDefinition, Datamodel, DefaultValue, Enum, EnumValue, FieldArity, FieldType, IndexDefinition,
+        Model, ScalarField, WithDatabaseName,
     },
-    NativeTypeInstance, RelationField,
+    NativeTypeInstance, ReferentialAction, RelationField,
 };
 use dml::scalars::ScalarType;
 use itertools::Itertools;
@@ -114,6 +114,25 @@ impl<'a> ModelWalker<'a> {
         &self.get().name
     }
 
+    /// Whether the model was declared with the `view` keyword.
+    pub fn is_view(&self) -> bool {
+        self.get().is_view
+    }
+
+    /// The database schema of the model, from its `@@schema` attribute.
+    pub fn schema(&self) -> Option<&'a str> {
+        self.get().schema.as_deref()
+    }
+
+    /// The SQL definition of the view, from its `@@definition` attribute.
+    pub fn view_definition(&self) -> Option<&'a str> {
+        self.get().view_definition.as_deref()
+    }
+
+    pub fn check_constraints(&self) -> &'a [CheckConstraintDefinition] {
+        &self.get().check_constraints
+    }
+
     pub fn id_fields(&self) -> impl Iterator<Item = ScalarFieldWalker<'a>> + 'a {
         let walker = *self;
         let model_idx = self.model_idx;
@@ -157,8 +176,12 @@ pub struct ScalarFieldWalker<'a> {
 }
 
 impl<'a> ScalarFieldWalker<'a> {
+    /// The arity of the field. Lists of composite types are stored as a single, nullable Json array.
     pub fn arity(&self) -> FieldArity {
-        self.get().arity
+        match &self.get().field_type {
+            FieldType::CompositeType(_) if self.get().arity.is_list() => FieldArity::Optional,
+            _ => self.get().arity,
+        }
     }
 
     pub fn db_name(&self) -> &'a str {
@@ -178,6 +201,8 @@ impl<'a> ScalarFieldWalker<'a> {
             FieldType::Base(scalar_type, _) => TypeWalker::Base(*scalar_type),
             FieldType::NativeType(scalar_type, native_type) => TypeWalker::NativeType(*scalar_type, native_type),
             FieldType::Unsupported(description) => TypeWalker::Unsupported(description.clone()),
+            // Composite types are stored as Json.
+            FieldType::CompositeType(_) => TypeWalker::Base(ScalarType::Json),
             FieldType::Relation(_) => unreachable!("FieldType::Relation in ScalarFieldWalker"),
         }
     }
@@ -270,6 +295,16 @@ impl<'a> RelationFieldWalker<'a> {
         }
     }
 
+    /// The `onDelete` action of the relation, or the default for the field's arity.
+    pub fn on_delete_action(&self) -> ReferentialAction {
+        self.get().on_delete_action()
+    }
+
+    /// The `onUpdate` action of the relation, or the default.
+    pub fn on_update_action(&self) -> ReferentialAction {
+        self.get().on_update_action()
+    }
+
     pub fn opposite_side(&self) -> RelationFieldWalker<'a> {
         RelationFieldWalker {
             datamodel: self.datamodel,
@@ -0,0 +1,53 @@
+use datamodel::{render_datamodel_to_string, CheckConstraintDefinition};
+
+use crate::common::*;
+
+#[test]
+fn check_constraints_must_work() {
+    let dml = r#"
+    model Product {
+        id       Int @id
+        price    Int
+        discount Int
+
+        @@check("price > 0")
+        @@check("discount <= price", name: "discount_below_price")
+    }
+    "#;
+
+    let schema = parse(dml);
+    let product = schema.assert_has_model("Product");
+
+    assert_eq!(
+        product.check_constraints,
+        &[
+            CheckConstraintDefinition {
+                name: None,
+                expression: "price > 0".to_owned(),
+            },
+            CheckConstraintDefinition {
+                name: Some("discount_below_price".to_owned()),
+                expression: "discount <= price".to_owned(),
+            },
+        ]
+    );
+
+    let rendered = render_datamodel_to_string(&schema);
+    assert!(rendered.contains(r#"@@check("price > 0")"#));
+    assert!(rendered.contains(r#"@@check("discount <= price", name: "discount_below_price")"#));
+}
+
+#[test]
+fn check_constraints_must_not_be_empty() {
+    let dml = r#"
+    model Product {
+        id    Int @id
+        price Int
+
+        @@check("  ")
+    }
+    "#;
+
+    parse_error(dml)
+        .assert_is_message("Error parsing attribute \"@check\": The check constraint expression cannot be empty.");
+}
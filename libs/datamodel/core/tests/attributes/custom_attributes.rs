@@ -0,0 +1,292 @@
+use crate::common::*;
+use datamodel::{
+    render_datamodel_and_config_to_string, render_datamodel_to_string, CustomAttribute, CustomAttributeArgument,
+};
+
+#[test]
+fn custom_attributes_of_declared_namespaces_must_be_preserved() {
+    let dml = r#"
+    datasource db {
+        provider = "postgres"
+        url      = "postgresql://localhost:5432"
+    }
+
+    generator client {
+        provider         = "prisma-client-js"
+        customAttributes = ["zod", "graphql"]
+    }
+
+    model User {
+        id    Int    @id
+        name  String @zod.min(3) @zod.regex("^[a-z]+$", message: "lowercase only") @db.VarChar(20)
+        role  Role   @graphql.hide
+        posts Post[] @graphql.hide
+
+        @@graphql.hide
+    }
+
+    model Post {
+        id       Int  @id
+        authorId Int
+        author   User @relation(fields: [authorId], references: [id])
+    }
+
+    enum Role {
+        USER  @graphql.name("user")
+        ADMIN
+
+        @@graphql.deprecated
+    }
+    "#;
+
+    let schema = parse(dml);
+    let user = schema.assert_has_model("User");
+
+    assert_eq!(user.custom_attributes, &[CustomAttribute::new("graphql.hide", vec![])]);
+    assert_eq!(
+        user.assert_has_scalar_field("name").custom_attributes,
+        &[
+            CustomAttribute::new(
+                "zod.min",
+                vec![CustomAttributeArgument {
+                    name: None,
+                    value: "3".to_owned(),
+                }]
+            ),
+            CustomAttribute::new(
+                "zod.regex",
+                vec![
+                    CustomAttributeArgument {
+                        name: None,
+                        value: r#""^[a-z]+$""#.to_owned(),
+                    },
+                    CustomAttributeArgument {
+                        name: Some("message".to_owned()),
+                        value: r#""lowercase only""#.to_owned(),
+                    },
+                ]
+            ),
+        ]
+    );
+    assert_eq!(user.assert_has_scalar_field("role").custom_attributes.len(), 1);
+    assert_eq!(user.assert_has_relation_field("posts").custom_attributes.len(), 1);
+
+    let role = schema.assert_has_enum("Role");
+    assert_eq!(
+        role.custom_attributes,
+        &[CustomAttribute::new("graphql.deprecated", vec![])]
+    );
+    assert_eq!(
+        role.assert_has_value("USER").custom_attributes,
+        &[CustomAttribute::new(
+            "graphql.name",
+            vec![CustomAttributeArgument {
+                name: None,
+                value: r#""user""#.to_owned(),
+            }]
+        )]
+    );
+
+    let rendered = render_datamodel_to_string(&schema);
+    assert!(rendered.contains(r#"@zod.min(3) @zod.regex("^[a-z]+$", message: "lowercase only")"#));
+    assert!(rendered.contains("@@graphql.hide"));
+    assert!(rendered.contains(r#"@graphql.name("user")"#));
+    assert!(rendered.contains("@@graphql.deprecated"));
+}
+
+#[test]
+fn custom_attributes_must_be_rendered_to_dmmf() {
+    let dml = r#"
+    generator client {
+        provider         = "prisma-client-js"
+        customAttributes = ["zod"]
+    }
+
+    model User {
+        id   Int    @id
+        name String @zod.max(20, message: "too long")
+    }
+    "#;
+
+    let schema = parse(dml);
+    let dmmf = datamodel::json::dmmf::render_to_dmmf_value(&schema);
+
+    let name_field = &dmmf["models"][0]["fields"][1];
+    assert_eq!(name_field["name"], "name");
+    assert_eq!(
+        name_field["customAttributes"],
+        serde_json::json!([
+            {
+                "name": "zod.max",
+                "args": [
+                    { "value": "20" },
+                    { "name": "message", "value": "\"too long\"" }
+                ]
+            }
+        ])
+    );
+    assert!(dmmf["models"][0]["fields"][0].get("customAttributes").is_none());
+}
+
+#[test]
+fn custom_attributes_of_undeclared_namespaces_must_error() {
+    let dml = r#"
+    datasource db {
+        provider = "postgres"
+        url      = "postgresql://localhost:5432"
+    }
+
+    generator client {
+        provider         = "prisma-client-js"
+        customAttributes = ["zod"]
+    }
+
+    model User {
+        id Int @id
+
+        @@zdo.strict
+    }
+    "#;
+
+    parse_error(dml).assert_is_message(
+        "Attribute not known: \"@zdo.strict\". The namespace \"zdo\" is neither the datasource nor declared in the `customAttributes` of a generator.",
+    );
+}
+
+#[test]
+fn custom_attributes_of_undeclared_namespaces_must_error_without_a_datasource() {
+    let dml = r#"
+    generator client {
+        provider         = "prisma-client-js"
+        customAttributes = ["zod"]
+    }
+
+    model User {
+        id   Int    @id
+        name String @zdo.min(3)
+    }
+    "#;
+
+    parse_error(dml).assert_is_message(
+        "Attribute not known: \"@zdo.min\". The namespace \"zdo\" is neither the datasource nor declared in the `customAttributes` of a generator.",
+    );
+}
+
+#[test]
+fn custom_attributes_declared_by_name_must_be_preserved() {
+    let dml = r#"
+    datasource db {
+        provider = "postgres"
+        url      = "postgresql://localhost:5432"
+    }
+
+    generator client {
+        provider         = "prisma-client-js"
+        customAttributes = ["zod.min", "zod.max"]
+    }
+
+    model User {
+        id   Int    @id
+        name String @zod.min(3) @zod.max(20)
+    }
+    "#;
+
+    let schema = parse(dml);
+    let name_field = schema.assert_has_model("User").assert_has_scalar_field("name");
+
+    let names: Vec<&str> = name_field
+        .custom_attributes
+        .iter()
+        .map(|attribute| attribute.name.as_str())
+        .collect();
+    assert_eq!(names, &["zod.min", "zod.max"]);
+}
+
+#[test]
+fn custom_attributes_not_declared_by_name_must_error() {
+    let dml = r#"
+    datasource db {
+        provider = "postgres"
+        url      = "postgresql://localhost:5432"
+    }
+
+    generator client {
+        provider         = "prisma-client-js"
+        customAttributes = ["zod.min", "zod.max"]
+    }
+
+    model User {
+        id   Int    @id
+        name String @zod.mni(3)
+    }
+    "#;
+
+    parse_error(dml).assert_is_message(
+        "Attribute not known: \"@zod.mni\". The custom attributes declared by the generators in the namespace \"zod\" are: @zod.min, @zod.max.",
+    );
+}
+
+#[test]
+fn custom_attributes_must_survive_a_round_trip() {
+    let dml = r#"
+    generator client {
+        provider         = "prisma-client-js"
+        customAttributes = ["zod", "graphql.hide", "graphql.name"]
+    }
+
+    model User {
+        id   Int    @id
+        name String @zod.min(3) @zod.regex("^[a-z]+$", message: "lowercase only")
+        role Role   @graphql.hide
+
+        @@graphql.hide
+    }
+
+    enum Role {
+        USER  @graphql.name("user")
+        ADMIN
+    }
+    "#;
+
+    let schema = parse(dml);
+    let config = parse_configuration(dml);
+    let rendered = render_datamodel_and_config_to_string(&schema, &config);
+    let reparsed = parse(&rendered);
+
+    let user = schema.assert_has_model("User");
+    let reparsed_user = reparsed.assert_has_model("User");
+    assert_eq!(reparsed_user.assert_has_scalar_field("name").custom_attributes.len(), 2);
+    assert_eq!(user.custom_attributes, reparsed_user.custom_attributes);
+    assert_eq!(
+        user.assert_has_scalar_field("name").custom_attributes,
+        reparsed_user.assert_has_scalar_field("name").custom_attributes
+    );
+    assert_eq!(
+        user.assert_has_scalar_field("role").custom_attributes,
+        reparsed_user.assert_has_scalar_field("role").custom_attributes
+    );
+    assert_eq!(
+        schema
+            .assert_has_enum("Role")
+            .assert_has_value("USER")
+            .custom_attributes,
+        reparsed
+            .assert_has_enum("Role")
+            .assert_has_value("USER")
+            .custom_attributes
+    );
+}
+
+#[test]
+fn custom_attribute_declarations_must_be_namespaces_or_attribute_names() {
+    let dml = r#"
+    generator client {
+        provider         = "prisma-client-js"
+        customAttributes = ["zod.min.length"]
+    }
+    "#;
+
+    parse_error(dml).assert_is_message(
+        "Error validating: The custom attribute \"zod.min.length\" is not valid. Declare either a namespace, e.g. \"zod\", or an attribute of a namespace, e.g. \"zod.min\".",
+    );
+}
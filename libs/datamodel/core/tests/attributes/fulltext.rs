@@ -0,0 +1,122 @@
+use datamodel::{render_datamodel_to_string, IndexDefinition, IndexType};
+
+use crate::common::*;
+
+#[test]
+fn fulltext_index_must_work_on_mysql() {
+    let dml = r#"
+    datasource db {
+        provider = "mysql"
+        url = "mysql://"
+    }
+
+    model Post {
+        id      Int    @id
+        title   String
+        content String
+
+        @@fulltext([title, content])
+    }
+    "#;
+
+    let schema = parse(dml);
+    let post_model = schema.assert_has_model("Post");
+    post_model.assert_has_index(IndexDefinition {
+        name: None,
+        fields: vec!["title".to_string(), "content".to_string()],
+        tpe: IndexType::Fulltext,
+    });
+}
+
+#[test]
+fn the_name_argument_must_work_for_fulltext_indexes() {
+    let dml = r#"
+    datasource db {
+        provider = "mysql"
+        url = "mysql://"
+    }
+
+    model Post {
+        id      Int    @id
+        title   String
+        content String
+
+        @@fulltext([title, content], name: "post_search")
+    }
+    "#;
+
+    let schema = parse(dml);
+    let post_model = schema.assert_has_model("Post");
+    post_model.assert_has_index(IndexDefinition {
+        name: Some("post_search".to_string()),
+        fields: vec!["title".to_string(), "content".to_string()],
+        tpe: IndexType::Fulltext,
+    });
+}
+
+#[test]
+fn fulltext_indexes_are_not_supported_on_postgres() {
+    let dml = r#"
+    datasource db {
+        provider = "postgres"
+        url = "postgres://"
+    }
+
+    model Post {
+        id      Int    @id
+        title   String
+        content String
+
+        @@fulltext([title, content])
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is_message("Error parsing attribute \"@fulltext\": You must specify a datasource that supports fulltext indexes to use them. Fulltext indexes are currently only supported on MySQL.");
+}
+
+#[test]
+fn fulltext_indexes_on_non_string_fields_must_error() {
+    let dml = r#"
+    datasource db {
+        provider = "mysql"
+        url = "mysql://"
+    }
+
+    model Post {
+        id    Int    @id
+        title String
+        views Int
+
+        @@fulltext([title, views])
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is_message("Error validating model \"Post\": The fulltext index definition refers to the non-String fields views. Fulltext indexes can only be defined on String fields.");
+}
+
+#[test]
+fn fulltext_index_attributes_must_serialize_to_valid_dml() {
+    let dml = r#"
+    datasource db {
+        provider = "mysql"
+        url = "mysql://"
+    }
+
+    model Post {
+        id      Int    @id
+        title   String
+        content String
+
+        @@fulltext([title, content], name: "post_search")
+    }
+    "#;
+
+    let schema = parse(dml);
+    let rendered = render_datamodel_to_string(&schema);
+
+    assert!(rendered.contains(r#"@@fulltext([title, content], name: "post_search")"#));
+}
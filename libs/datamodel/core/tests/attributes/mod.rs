@@ -1,11 +1,15 @@
 pub mod builtin_attributes;
+pub mod check;
+pub mod custom_attributes;
 pub mod default_negative;
 pub mod default_positive;
+pub mod fulltext;
 pub mod id_negative;
 pub mod id_positive;
 pub mod index;
 pub mod map;
 pub mod relations;
+pub mod schema;
 pub mod unique;
 pub mod unique_criteria;
 pub mod updated_at_negative;
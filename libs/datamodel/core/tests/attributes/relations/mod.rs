@@ -1,3 +1,4 @@
+pub mod referential_actions;
 pub mod relations_negative;
 pub mod relations_new;
 pub mod relations_positive;
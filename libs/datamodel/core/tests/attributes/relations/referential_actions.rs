@@ -0,0 +1,184 @@
+use crate::common::*;
+use datamodel::dml;
+
+#[test]
+fn on_delete_and_on_update_are_parsed() {
+    let dml = r#"
+    model User {
+        id    Int    @id
+        posts Post[]
+    }
+
+    model Post {
+        id       Int  @id
+        authorId Int
+        author   User @relation(fields: [authorId], references: [id], onDelete: Restrict, onUpdate: SetDefault)
+    }
+    "#;
+
+    let schema = parse(dml);
+
+    schema
+        .assert_has_model("Post")
+        .assert_has_relation_field("author")
+        .assert_relation_delete_strategy(dml::ReferentialAction::Restrict)
+        .assert_relation_update_strategy(dml::ReferentialAction::SetDefault);
+}
+
+#[test]
+fn referential_actions_default_to_none() {
+    let dml = r#"
+    model User {
+        id    Int    @id
+        posts Post[]
+    }
+
+    model Post {
+        id       Int  @id
+        authorId Int
+        author   User @relation(fields: [authorId], references: [id])
+    }
+    "#;
+
+    let schema = parse(dml);
+    let author = schema.assert_has_model("Post").assert_has_relation_field("author");
+
+    assert_eq!(author.relation_info.on_delete, None);
+    assert_eq!(author.relation_info.on_update, None);
+    assert_eq!(author.on_delete_action(), dml::ReferentialAction::Cascade);
+    assert_eq!(author.on_update_action(), dml::ReferentialAction::Cascade);
+}
+
+#[test]
+fn set_null_is_allowed_on_optional_relations() {
+    let dml = r#"
+    model User {
+        id    Int    @id
+        posts Post[]
+    }
+
+    model Post {
+        id       Int   @id
+        authorId Int?
+        author   User? @relation(fields: [authorId], references: [id], onDelete: SetNull, onUpdate: Cascade)
+    }
+    "#;
+
+    let schema = parse(dml);
+
+    schema
+        .assert_has_model("Post")
+        .assert_has_relation_field("author")
+        .assert_relation_delete_strategy(dml::ReferentialAction::SetNull)
+        .assert_relation_update_strategy(dml::ReferentialAction::Cascade);
+}
+
+#[test]
+fn unknown_referential_actions_are_rejected() {
+    let dml = r#"
+    model User {
+        id    Int    @id
+        posts Post[]
+    }
+
+    model Post {
+        id       Int  @id
+        authorId Int
+        author   User @relation(fields: [authorId], references: [id], onDelete: Explode)
+    }
+    "#;
+
+    parse_error(dml).assert_is_message(
+        "Error parsing attribute \"@relation\": Invalid referential action: `Explode`. Allowed values: (`Cascade`, `Restrict`, `NoAction`, `SetNull`, `SetDefault`)",
+    );
+}
+
+#[test]
+fn set_null_is_rejected_on_required_relations() {
+    let dml = r#"
+    model User {
+        id    Int    @id
+        posts Post[]
+    }
+
+    model Post {
+        id       Int  @id
+        authorId Int
+        author   User @relation(fields: [authorId], references: [id], onDelete: SetNull)
+    }
+    "#;
+
+    parse_error(dml).assert_is_message(
+        "Error parsing attribute \"@relation\": The referential action `SetNull` in `onDelete` cannot be used on the relation field `author`, because it uses required fields: authorId.",
+    );
+}
+
+#[test]
+fn referential_actions_are_rejected_on_the_side_without_fields() {
+    let dml = r#"
+    model User {
+        id    Int    @id
+        posts Post[] @relation(onDelete: Cascade)
+    }
+
+    model Post {
+        id       Int  @id
+        authorId Int
+        author   User @relation(fields: [authorId], references: [id])
+    }
+    "#;
+
+    parse_error(dml).assert_is_message(
+        "Error parsing attribute \"@relation\": The relation field `posts` does not define `fields`. Referential actions can only be defined on the side of the relation that holds the foreign key.",
+    );
+}
+
+#[test]
+fn restrict_is_rejected_on_sql_server() {
+    let dml = r#"
+    datasource db {
+        provider = "sqlserver"
+        url = "sqlserver://localhost:1433"
+    }
+
+    model User {
+        id    Int    @id
+        posts Post[]
+    }
+
+    model Post {
+        id       Int  @id
+        authorId Int
+        author   User @relation(fields: [authorId], references: [id], onDelete: Restrict)
+    }
+    "#;
+
+    parse_error(dml).assert_is_message(
+        "Error parsing attribute \"@relation\": Invalid referential action: `Restrict` is not supported by the SQL Server connector. Allowed values for `onDelete`: (`Cascade`, `NoAction`, `SetNull`, `SetDefault`)",
+    );
+}
+
+#[test]
+fn set_default_is_rejected_on_mysql() {
+    let dml = r#"
+    datasource db {
+        provider = "mysql"
+        url = "mysql://localhost:3306"
+    }
+
+    model User {
+        id    Int    @id
+        posts Post[]
+    }
+
+    model Post {
+        id       Int  @id
+        authorId Int
+        author   User @relation(fields: [authorId], references: [id], onUpdate: SetDefault)
+    }
+    "#;
+
+    parse_error(dml).assert_is_message(
+        "Error parsing attribute \"@relation\": Invalid referential action: `SetDefault` is not supported by the MySQL connector. Allowed values for `onUpdate`: (`Cascade`, `Restrict`, `NoAction`, `SetNull`)",
+    );
+}
@@ -270,10 +270,10 @@ fn should_fail_on_conflicting_back_relation_field_name() {
 
     let errors = parse_error(dml);
 
-    errors.assert_are(&[DatamodelError::new_field_validation_error(
-        "The relation field `more_posts` on Model `User` is missing an opposite relation field on the model `Post`. Either run `prisma format` or add it manually.",
+    errors.assert_are(&[DatamodelError::new_missing_opposite_relation_field_error(
         "User",
         "more_posts",
+        "Post",
         Span::new(90, 108),
     ),
         DatamodelError::new_attribute_validation_error(
@@ -349,10 +349,10 @@ fn should_fail_on_conflicting_generated_back_relation_fields() {
 
     let errors = parse_error(dml);
 
-    errors.assert_is_at(0, DatamodelError::new_field_validation_error(
-        "The relation field `author` on Model `Todo` is missing an opposite relation field on the model `Owner`. Either run `prisma format` or add it manually.",
+    errors.assert_is_at(0, DatamodelError::new_missing_opposite_relation_field_error(
         "Todo",
         "author",
+        "Owner",
         Span::new(45, 88),
     ));
 }
@@ -377,7 +377,7 @@ fn must_generate_forward_relation_fields_for_named_relation_fields() {
     let errors = parse_error(dml);
 
     errors.assert_is(
-        DatamodelError::new_field_validation_error("The relation field `assignees` on Model `Todo` is missing an opposite relation field on the model `User`. Either run `prisma format` or add it manually.", "Todo", "assignees",Span::new(45, 95)),
+        DatamodelError::new_missing_opposite_relation_field_error("Todo", "assignees", "User", Span::new(45, 95)),
     );
 }
 
@@ -400,7 +400,7 @@ fn issue4850() {
     let errors = parse_error(dml);
 
     errors.assert_is(
-        DatamodelError::new_field_validation_error("The relation field `postableEntities` on Model `Post` is missing an opposite relation field on the model `PostableEntity`. Either run `prisma format` or add it manually.", "Post", "postableEntities",Span::new(138, 172)),
+        DatamodelError::new_missing_opposite_relation_field_error("Post", "postableEntities", "PostableEntity", Span::new(138, 172)),
     );
 }
 
@@ -424,8 +424,8 @@ fn issue4822() {
 
     let errors = parse_error(dml);
     errors.assert_are(
-        &[DatamodelError::new_field_validation_error("The relation field `custom_User` on Model `Post` is missing an opposite relation field on the model `User`. Either run `prisma format` or add it manually.", "Post", "custom_User",Span::new(106, 186)),
-            DatamodelError::new_field_validation_error("The relation field `custom_Post` on Model `User` is missing an opposite relation field on the model `Post`. Either run `prisma format` or add it manually.", "User", "custom_Post",Span::new(265, 283))
+        &[DatamodelError::new_missing_opposite_relation_field_error("Post", "custom_User", "User", Span::new(106, 186)),
+            DatamodelError::new_missing_opposite_relation_field_error("User", "custom_Post", "Post", Span::new(265, 283))
         ],
     );
 }
@@ -484,7 +484,7 @@ fn issue5069() {
     let errors = parse_error(dml);
 
     errors.assert_is(
-        DatamodelError::new_field_validation_error("The relation field `createdBy` on Model `Code` is missing an opposite relation field on the model `User`. Either run `prisma format` or add it manually.", "Code", "createdBy",Span::new(103, 121)),
+        DatamodelError::new_missing_opposite_relation_field_error("Code", "createdBy", "User", Span::new(103, 121)),
     );
 }
 
@@ -276,7 +276,7 @@ fn relation_must_error_when_referenced_fields_are_not_a_unique_criteria() {
     "#;
 
     let errors = parse_error(dml);
-    errors.assert_is(DatamodelError::new_validation_error("The argument `references` must refer to a unique criteria in the related model `User`. But it is referencing the following fields that are not a unique criteria: firstName", Span::new(205, 276)));
+    errors.assert_is(DatamodelError::new_references_not_unique_error("User", &["firstName".to_owned()], Span::new(205, 276)));
 }
 
 #[allow(non_snake_case)]
@@ -327,7 +327,7 @@ fn relation_must_error_when_referenced_fields_are_multiple_uniques() {
     "#;
 
     let errors = parse_error(dml);
-    errors.assert_is(DatamodelError::new_validation_error("The argument `references` must refer to a unique criteria in the related model `User`. But it is referencing the following fields that are not a unique criteria: id, firstName", Span::new(290, 367)));
+    errors.assert_is(DatamodelError::new_references_not_unique_error("User", &["id".to_owned(), "firstName".to_owned()], Span::new(290, 367)));
 }
 
 #[test]
@@ -47,8 +47,6 @@ fn settings_must_be_deteced() {
         .assert_relation_to("Todo")
         .assert_relation_referenced_fields(&["id"])
         .assert_arity(&dml::FieldArity::Optional);
-    // TODO: bring `onDelete` back once `prisma migrate` is a thing
-    //        .assert_relation_delete_strategy(dml::OnDeleteStrategy::Cascade);
 }
 
 #[test]
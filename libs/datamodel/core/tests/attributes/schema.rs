@@ -0,0 +1,73 @@
+use datamodel::render_datamodel_and_config_to_string;
+
+use crate::common::*;
+
+#[test]
+fn the_schema_attribute_must_work() {
+    let dml = r#"
+    datasource db {
+        provider = "postgresql"
+        url = "postgresql://"
+        schemas = ["public", "billing"]
+    }
+
+    model Invoice {
+        id Int @id
+
+        @@schema("billing")
+    }
+
+    model User {
+        id Int @id
+    }
+    "#;
+
+    let schema = parse(dml);
+
+    assert_eq!(schema.assert_has_model("Invoice").schema.as_deref(), Some("billing"));
+    assert_eq!(schema.assert_has_model("User").schema, None);
+
+    let rendered = render_datamodel_and_config_to_string(&schema, &parse_configuration(dml));
+    assert!(rendered.contains(r#"@@schema("billing")"#));
+}
+
+#[test]
+fn the_schema_attribute_must_reference_a_datasource_schema() {
+    let dml = r#"
+    datasource db {
+        provider = "postgresql"
+        url = "postgresql://"
+        schemas = ["public", "billing"]
+    }
+
+    model Invoice {
+        id Int @id
+
+        @@schema("accounting")
+    }
+    "#;
+
+    parse_error(dml).assert_is_message(
+        "Error parsing attribute \"@schema\": The schema `accounting` is not defined in the `schemas` property of the datasource.",
+    );
+}
+
+#[test]
+fn the_schema_attribute_requires_datasource_schemas() {
+    let dml = r#"
+    datasource db {
+        provider = "postgresql"
+        url = "postgresql://"
+    }
+
+    model Invoice {
+        id Int @id
+
+        @@schema("billing")
+    }
+    "#;
+
+    parse_error(dml).assert_is_message(
+        "Error parsing attribute \"@schema\": The `@@schema` attribute can only be used if the datasource defines its `schemas`.",
+    );
+}
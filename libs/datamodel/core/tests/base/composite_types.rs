@@ -0,0 +1,195 @@
+use crate::common::*;
+use datamodel::{dml, render_datamodel_to_string};
+use indoc::indoc;
+
+#[test]
+fn composite_types_must_be_usable_as_field_types() {
+    let dml = indoc! {r#"
+        datasource db {
+          provider = "postgres"
+          url      = "postgresql://localhost:5432"
+        }
+
+        model User {
+          id        Int       @id
+          address   Address
+          addresses Address[]
+        }
+
+        /// A postal address.
+        type Address {
+          street String
+          zip    String?
+          tags   String[]
+          geo    Geo?
+        }
+
+        type Geo {
+          lat Float
+          lng Float
+        }
+    "#};
+
+    let schema = parse(dml);
+
+    let user = schema.assert_has_model("User");
+    user.assert_has_scalar_field("address")
+        .assert_composite_type("Address")
+        .assert_arity(&dml::FieldArity::Required);
+    user.assert_has_scalar_field("addresses")
+        .assert_composite_type("Address")
+        .assert_arity(&dml::FieldArity::List);
+
+    let address = schema.find_composite_type("Address").unwrap();
+    assert_eq!(address.documentation.as_deref(), Some("A postal address."));
+    assert_eq!(
+        address.fields().map(|f| f.name.as_str()).collect::<Vec<_>>(),
+        &["street", "zip", "tags", "geo"]
+    );
+    assert_eq!(
+        address.find_field("geo").unwrap().field_type,
+        dml::FieldType::CompositeType("Geo".to_owned())
+    );
+
+    assert!(schema.find_model("Address").is_none());
+}
+
+#[test]
+fn composite_types_require_a_connector_supporting_json() {
+    let dml = r#"
+    datasource db {
+        provider = "sqlite"
+        url      = "file:dev.db"
+    }
+
+    model User {
+        id      Int     @id
+        address Address
+    }
+
+    type Address {
+        street String
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is_message(
+        "Error validating field `address` in model `User`: Field `address` in model `User` can't be of composite type `Address`. Composite types are stored as Json, which the current connector does not support.",
+    );
+}
+
+#[test]
+fn fields_of_composite_types_must_not_have_attributes() {
+    let dml = r#"
+    datasource db {
+        provider = "postgres"
+        url      = "postgresql://localhost:5432"
+    }
+
+    type Address {
+        street String @unique
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors
+        .assert_is_message("Error parsing attribute \"@unique\": Fields of composite types do not support attributes.");
+}
+
+#[test]
+fn composite_types_must_not_have_relation_fields() {
+    let dml = r#"
+    datasource db {
+        provider = "postgres"
+        url      = "postgresql://localhost:5432"
+    }
+
+    model User {
+        id Int @id
+    }
+
+    type Address {
+        owner User
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is_message(
+        "Error validating field `owner` in model `Address`: Composite types cannot have relation fields.",
+    );
+}
+
+#[test]
+fn composite_types_must_not_embed_themselves_through_required_fields() {
+    let dml = r#"
+    datasource db {
+        provider = "postgres"
+        url      = "postgresql://localhost:5432"
+    }
+
+    type Node {
+        value Int
+        inner Inner
+    }
+
+    type Inner {
+        node Node
+        next Node?
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is_message_at(
+        0,
+        "Error validating: Composite types cannot embed themselves through required fields. Recursive path was: Node -> Inner -> Node.",
+    );
+}
+
+#[test]
+fn composite_type_fields_cannot_have_a_default_value() {
+    let dml = r#"
+    datasource db {
+        provider = "postgres"
+        url      = "postgresql://localhost:5432"
+    }
+
+    model User {
+        id      Int     @id
+        address Address @default("{}")
+    }
+
+    type Address {
+        street String
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is_message(
+        "Error parsing attribute \"@default\": Fields of a composite type cannot have a default value.",
+    );
+}
+
+#[test]
+fn composite_types_must_render_with_the_type_keyword() {
+    let dml = indoc! {r#"
+        model User {
+          id      Int      @id
+          address Address?
+        }
+
+        type Address {
+          street String
+          tags   String[]
+        }
+    "#};
+
+    let schema = parse(&format!("{}\n{}", POSTGRES_SOURCE, dml));
+    let rendered = render_datamodel_to_string(&schema);
+
+    assert_eq!(rendered, dml);
+}
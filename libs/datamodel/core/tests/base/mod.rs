@@ -2,6 +2,9 @@ pub mod array_sugar;
 pub mod base_types;
 pub mod basic;
 pub mod comments;
+pub mod composite_types;
 pub mod duplicates;
+pub mod multi_file;
 pub mod preview_features;
 mod unsupported_type;
+pub mod views;
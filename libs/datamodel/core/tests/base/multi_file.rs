@@ -0,0 +1,124 @@
+use crate::common::*;
+use datamodel::{
+    ast::{reformat::Reformatter, SourceFiles, Span},
+    diagnostics::DatamodelError,
+};
+use pretty_assertions::assert_eq;
+
+const USERS: &str = r#"model User {
+  id    Int    @id
+  posts Post[]
+}
+"#;
+
+const POSTS: &str = r#"model Post {
+  id       Int  @id
+  authorId Int
+  author   User @relation(fields: [authorId], references: [id])
+}
+"#;
+
+#[test]
+fn relations_must_resolve_across_files() {
+    let files = SourceFiles::new(vec![("users.prisma", USERS), ("posts.prisma", POSTS)]);
+    let datamodel = datamodel::parse_datamodel_from_files(&files).unwrap().subject;
+
+    datamodel
+        .assert_has_model("Post")
+        .assert_has_relation_field("author")
+        .assert_relation_to("User");
+
+    datamodel
+        .assert_has_model("User")
+        .assert_has_relation_field("posts")
+        .assert_relation_to("Post");
+}
+
+#[test]
+fn errors_must_be_located_in_their_file() {
+    let duplicate = r#"model User {
+  id Int @id
+}
+"#;
+
+    let files = SourceFiles::new(vec![("users.prisma", USERS), ("duplicate.prisma", duplicate)]);
+    let errors = datamodel::parse_datamodel_from_files(&files).unwrap_err();
+
+    let start = USERS.len() + 1 + 6;
+    errors.assert_is(DatamodelError::new_duplicate_top_error(
+        "User",
+        "model",
+        "model",
+        Span::new(start, start + 4),
+    ));
+
+    let (file, span) = files.locate(errors.errors[0].span()).unwrap();
+
+    assert_eq!(file.name, "duplicate.prisma");
+    assert_eq!(span, Span::new(6, 10));
+    assert!(errors.to_pretty_string_for_files(&files).contains("duplicate.prisma:1"));
+}
+
+#[test]
+fn an_unclosed_block_must_not_swallow_the_next_file() {
+    let broken = r#"model User {
+  id Int @id
+"#;
+
+    let files = SourceFiles::new(vec![("users.prisma", broken), ("posts.prisma", POSTS)]);
+    let errors = datamodel::parse_datamodel_from_files(&files).unwrap_err();
+
+    // Both lines of the unclosed block are invalid, and `Post` is parsed on its own.
+    assert_eq!(errors.errors.len(), 2);
+
+    for error in errors.to_error_iter() {
+        let (file, _) = files.locate(error.span()).unwrap();
+
+        assert_eq!(file.name, "users.prisma");
+    }
+}
+
+#[test]
+fn files_without_trailing_newline_must_be_merged() {
+    let files = SourceFiles::new(vec![("users.prisma", USERS.trim_end()), ("posts.prisma", POSTS)]);
+
+    assert_eq!(files.file_text("users.prisma"), Some(USERS.trim_end()));
+    assert_eq!(files.file_text("posts.prisma"), Some(POSTS));
+
+    parse(files.text()).assert_has_model("Post");
+}
+
+#[test]
+fn reformatting_a_file_must_add_back_relation_fields_to_models_of_other_files() {
+    let posts = r#"model Post {
+  id       Int  @id
+  authorId Int
+  author   User @relation(fields: [authorId], references: [id])
+}
+"#;
+
+    let users = r#"model User {
+  id Int @id
+}
+"#;
+
+    let files = SourceFiles::new(vec![("posts.prisma", posts), ("users.prisma", users)]);
+
+    let expected = r#"model User {
+  id   Int    @id
+  Post Post[]
+}
+"#;
+
+    let result = Reformatter::new_for_file(&files, "users.prisma")
+        .unwrap()
+        .reformat_to_string();
+
+    assert_eq!(result, expected);
+    assert_eq!(
+        Reformatter::new_for_file(&files, "posts.prisma")
+            .unwrap()
+            .reformat_to_string(),
+        posts
+    );
+}
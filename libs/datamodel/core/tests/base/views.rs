@@ -0,0 +1,85 @@
+use crate::common::*;
+use datamodel::render_datamodel_to_string;
+use indoc::indoc;
+
+#[test]
+fn views_must_be_parsed_as_read_only_models() {
+    let dml = r#"
+    model User {
+        id   Int    @id
+        name String
+    }
+
+    view UserName {
+        id   Int    @id
+        name String
+
+        @@definition("SELECT id, name FROM User")
+    }
+    "#;
+
+    let schema = parse(dml);
+
+    let user = schema.assert_has_model("User");
+    assert!(!user.is_view);
+    assert_eq!(user.view_definition, None);
+
+    let user_name = schema.assert_has_model("UserName");
+    assert!(user_name.is_view);
+    assert_eq!(user_name.view_definition.as_deref(), Some("SELECT id, name FROM User"));
+}
+
+#[test]
+fn the_definition_attribute_is_not_allowed_on_models() {
+    let dml = r#"
+    model User {
+        id Int @id
+
+        @@definition("SELECT 1 AS id")
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is_message(
+        "Error parsing attribute \"@definition\": The `@@definition` attribute can only be used on views.",
+    );
+}
+
+#[test]
+fn views_cannot_be_the_target_of_relations() {
+    let dml = r#"
+    model Post {
+        id     Int  @id
+        userId Int
+        user   User @relation(fields: [userId], references: [id])
+    }
+
+    view User {
+        id Int @id
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is_message(
+        "Error validating field `user` in model `Post`: The relation field points to the view `User`. Views cannot be part of relations.",
+    );
+}
+
+#[test]
+fn views_must_render_with_the_view_keyword() {
+    let dml = indoc! {r#"
+        view UserName {
+          id   Int    @id
+          name String
+
+          @@definition("SELECT id,\n  name FROM \"User\"")
+        }
+    "#};
+
+    let schema = parse(dml);
+    let rendered = render_datamodel_to_string(&schema);
+
+    assert_eq!(rendered, dml);
+}
@@ -20,6 +20,7 @@ pub trait ScalarFieldAsserts {
     fn assert_base_type(&self, t: &ScalarType) -> &Self;
     fn assert_unsupported_type(&self, t: &str) -> &Self;
     fn assert_enum_type(&self, en: &str) -> &Self;
+    fn assert_composite_type(&self, composite_type: &str) -> &Self;
     fn assert_native_type(&self) -> &NativeTypeInstance;
     fn assert_with_db_name(&self, t: &str) -> &Self;
     fn assert_default_value(&self, t: dml::DefaultValue) -> &Self;
@@ -32,7 +33,8 @@ pub trait ScalarFieldAsserts {
 pub trait RelationFieldAsserts {
     fn assert_relation_name(&self, t: &str) -> &Self;
     fn assert_relation_to(&self, t: &str) -> &Self;
-    fn assert_relation_delete_strategy(&self, t: dml::OnDeleteStrategy) -> &Self;
+    fn assert_relation_delete_strategy(&self, t: dml::ReferentialAction) -> &Self;
+    fn assert_relation_update_strategy(&self, t: dml::ReferentialAction) -> &Self;
     fn assert_relation_referenced_fields(&self, t: &[&str]) -> &Self;
     fn assert_relation_base_fields(&self, t: &[&str]) -> &Self;
     fn assert_ignored(&self, state: bool) -> &Self;
@@ -135,6 +137,15 @@ impl ScalarFieldAsserts for dml::ScalarField {
         self
     }
 
+    fn assert_composite_type(&self, composite_type: &str) -> &Self {
+        if let dml::FieldType::CompositeType(name) = &self.field_type {
+            assert_eq!(name, composite_type);
+        } else {
+            panic!("Composite type expected, but found {:?}", self.field_type);
+        }
+        self
+    }
+
     fn assert_native_type(&self) -> &NativeTypeInstance {
         if let dml::FieldType::NativeType(_, t) = &self.field_type {
             &t
@@ -202,8 +213,13 @@ impl RelationFieldAsserts for dml::RelationField {
         self
     }
 
-    fn assert_relation_delete_strategy(&self, t: dml::OnDeleteStrategy) -> &Self {
-        assert_eq!(self.relation_info.on_delete, t);
+    fn assert_relation_delete_strategy(&self, t: dml::ReferentialAction) -> &Self {
+        assert_eq!(self.relation_info.on_delete, Some(t));
+        self
+    }
+
+    fn assert_relation_update_strategy(&self, t: dml::ReferentialAction) -> &Self {
+        assert_eq!(self.relation_info.on_update, Some(t));
         self
     }
 
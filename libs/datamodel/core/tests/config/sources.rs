@@ -1,5 +1,5 @@
 use crate::common::*;
-use datamodel::{ast::Span, diagnostics::DatamodelError, StringFromEnvVar};
+use datamodel::{ast::Span, diagnostics::DatamodelError, ReferentialIntegrity, StringFromEnvVar};
 use pretty_assertions::assert_eq;
 use serial_test::serial;
 
@@ -472,3 +472,97 @@ fn assert_eq_json(a: &str, b: &str) {
 //         Span::new(134, 153),
 //     ));
 // }
+
+#[test]
+fn referential_integrity_defaults_to_foreign_keys() {
+    let schema = r#"
+        datasource myds {
+            provider = "mysql"
+            url = "mysql://"
+        }
+    "#;
+
+    let config = parse_configuration(schema);
+
+    assert_eq!(
+        config.datasources[0].referential_integrity,
+        ReferentialIntegrity::ForeignKeys
+    );
+    assert!(!config.datasources[0].emulates_referential_actions());
+}
+
+#[test]
+fn referential_integrity_can_be_emulated_by_prisma() {
+    let schema = r#"
+        datasource myds {
+            provider = "mysql"
+            url = "mysql://"
+            referentialIntegrity = "prisma"
+        }
+    "#;
+
+    let config = parse_configuration(schema);
+
+    assert_eq!(
+        config.datasources[0].referential_integrity,
+        ReferentialIntegrity::Prisma
+    );
+    assert!(config.datasources[0].emulates_referential_actions());
+
+    let rendered = datamodel::render_datamodel_and_config_to_string(&datamodel::Datamodel::new(), &config);
+    assert!(rendered.contains(r#"referentialIntegrity = "prisma""#));
+}
+
+#[test]
+fn must_error_for_unknown_referential_integrity_modes() {
+    let schema = r#"
+        datasource myds {
+            provider = "mysql"
+            url = "mysql://"
+            referentialIntegrity = "database"
+        }
+    "#;
+
+    let config = datamodel::parse_configuration(schema);
+    let diagnostics = config.err().expect("This must error");
+
+    diagnostics.assert_is_message(
+        "Error validating datasource `myds`: Invalid referential integrity mode: `database`. Allowed values: (`foreignKeys`, `prisma`)",
+    );
+}
+
+#[test]
+fn schemas_can_be_defined_on_postgres() {
+    let schema = r#"
+        datasource myds {
+            provider = "postgresql"
+            url = "postgresql://"
+            schemas = ["public", "billing"]
+        }
+    "#;
+
+    let config = parse_configuration(schema);
+
+    assert_eq!(config.datasources[0].schemas, vec!["public", "billing"]);
+
+    let rendered = datamodel::render_datamodel_and_config_to_string(&datamodel::Datamodel::new(), &config);
+    assert!(rendered.contains(r#"["public", "billing"]"#));
+}
+
+#[test]
+fn must_error_for_schemas_on_mysql() {
+    let schema = r#"
+        datasource myds {
+            provider = "mysql"
+            url = "mysql://"
+            schemas = ["public", "billing"]
+        }
+    "#;
+
+    let config = datamodel::parse_configuration(schema);
+    let diagnostics = config.err().expect("This must error");
+
+    diagnostics.assert_is_message(
+        "Error validating datasource `myds`: The `schemas` property is not supported on the current connector.",
+    );
+}
@@ -997,6 +997,39 @@ fn must_handle_conflicts_with_existing_fields_if_types_are_incompatible_and_name
     assert_eq!(input, result);
 }
 
+#[test]
+fn missing_back_relation_fields_must_be_rendered_like_the_formatter_adds_them() {
+    let input = r#"model Blog {
+  id    Int     @id
+  posts Post[]
+}
+
+model Post {
+  id Int @id
+}
+"#;
+
+    let missing_fields = datamodel::ast::reformat::Reformatter::find_all_missing_fields(&input)
+        .unwrap()
+        .subject;
+
+    let rendered: Vec<(String, String)> = missing_fields
+        .iter()
+        .map(|missing_field| (missing_field.model.clone(), missing_field.render()))
+        .collect();
+
+    assert_eq!(
+        rendered,
+        vec![
+            (
+                "Post".to_owned(),
+                "Blog Blog? @relation(fields: [blogId], references: [id])".to_owned()
+            ),
+            ("Post".to_owned(), "blogId Int?".to_owned()),
+        ]
+    );
+}
+
 fn assert_reformat(schema: &str, expected_result: &str) {
     let result = datamodel::ast::reformat::Reformatter::new(&schema).reformat_to_string();
     parse_datamodel(&result).unwrap();
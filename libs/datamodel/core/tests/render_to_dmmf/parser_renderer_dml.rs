@@ -5,7 +5,6 @@ use pretty_assertions::assert_eq;
 
 #[test]
 fn test_parser_renderer_via_dml() {
-    // TODO: test `onDelete` back once `prisma migrate` is a thing
     let input = r#"model User {
   id        Int      @id
   createdAt DateTime
@@ -23,7 +22,7 @@ model Profile {
   id     Int    @id
   bio    String
   userId Int
-  user   User   @relation(fields: [userId], references: [id])
+  user   User   @relation(fields: [userId], references: [id], onDelete: Restrict, onUpdate: NoAction)
 
   @@map("profile")
 }
@@ -12,6 +12,7 @@ fn test_dmmf_rendering() {
         "source_with_generator",
         "without_relation_name",
         "ignore",
+        "composite_types",
     ];
 
     for test_case in test_cases {
@@ -55,8 +55,15 @@ macro_rules! flags {
 // `microsoftSqlServer`: Support for Microsoft SQL Server databases
 // `groupBy`: Group-By aggregations in the QE.
 // `createMany`: Create many (bulk insert) API operation.
-// `orderByRelation`: Allows ordering by to-one relation in the QE API.
-flags!(microsoftSqlServer, groupBy, createMany, orderByRelation, napi);
+// `orderByRelation`: Allows ordering by to-one relations and by aggregates of to-many relations in the QE API.
+flags!(
+    microsoftSqlServer,
+    groupBy,
+    createMany,
+    orderByRelation,
+    napi,
+    fullTextSearch
+);
 
 /// Initializes the feature flags with given flags.
 /// Noop if already initialized.
@@ -29,7 +29,9 @@ impl<'a> DatamodelConverter<'a> {
             models: self.convert_models(),
             relations: self.convert_relations(),
             enums: self.convert_enums(),
+            composite_types: self.convert_composite_types(),
             version: Some("v2".to_string()),
+            referential_integrity: Default::default(),
         }
     }
 
@@ -52,6 +54,25 @@ impl<'a> DatamodelConverter<'a> {
             .collect()
     }
 
+    fn convert_composite_types(&self) -> Vec<InternalCompositeType> {
+        self.datamodel
+            .composite_types()
+            .map(|ct| InternalCompositeType {
+                name: ct.name.clone(),
+                fields: ct
+                    .fields()
+                    .map(|field| InternalCompositeTypeField {
+                        name: field.name.clone(),
+                        type_identifier: field.type_identifier(),
+                        is_required: field.is_required(),
+                        is_list: field.is_list(),
+                        composite_type: field.field_type.as_composite_type().map(ToOwned::to_owned),
+                    })
+                    .collect(),
+            })
+            .collect()
+    }
+
     fn convert_models(&self) -> Vec<ModelTemplate> {
         self.datamodel
             .models()
@@ -60,6 +81,7 @@ impl<'a> DatamodelConverter<'a> {
             .map(|model| ModelTemplate {
                 name: model.name.clone(),
                 is_embedded: model.is_embedded,
+                is_view: model.is_view,
                 fields: self.convert_fields(&model),
                 manifestation: model.database_name().map(|s| s.to_owned()),
                 id_field_names: model.id_fields.clone(),
@@ -105,19 +127,28 @@ impl<'a> DatamodelConverter<'a> {
                         return None;
                     }
 
+                    let composite_type = sf.composite_type();
+
+                    // Lists of composite values are stored as a single, nullable Json array. Null reads as an empty list.
+                    let (is_required, is_list, arity) = match &composite_type {
+                        Some(ct) if ct.is_list => (false, false, dml::FieldArity::Optional),
+                        _ => (sf.is_required(), sf.is_list(), sf.arity),
+                    };
+
                     Some(FieldTemplate::Scalar(ScalarFieldTemplate {
                         name: sf.name.clone(),
                         type_identifier: sf.type_identifier(),
-                        is_required: sf.is_required(),
-                        is_list: sf.is_list(),
+                        is_required,
+                        is_list,
                         is_unique: sf.is_unique(&model),
                         is_id: sf.is_id(&model),
                         is_auto_generated_int_id: sf.is_auto_generated_int_id(),
                         is_autoincrement: sf.is_auto_increment(),
                         behaviour: sf.behaviour(),
                         internal_enum: sf.internal_enum(self.datamodel),
+                        composite_type,
                         db_name: sf.database_name.clone(),
-                        arity: sf.arity,
+                        arity,
                         default_value: sf.default_value.clone(),
                     }))
                 }
@@ -151,6 +182,7 @@ impl<'a> DatamodelConverter<'a> {
                 typ: match i.tpe {
                     dml::IndexType::Unique => IndexType::Unique,
                     dml::IndexType::Normal => IndexType::Normal,
+                    dml::IndexType::Fulltext => IndexType::Fulltext,
                 },
             })
             .collect()
@@ -433,6 +465,7 @@ trait DatamodelFieldExtensions {
     fn behaviour(&self) -> Option<FieldBehaviour>;
     fn internal_enum(&self, datamodel: &dml::Datamodel) -> Option<InternalEnum>;
     fn internal_enum_value(&self, enum_value: &dml::EnumValue) -> InternalEnumValue;
+    fn composite_type(&self) -> Option<CompositeTypeReference>;
 }
 
 impl DatamodelFieldExtensions for dml::ScalarField {
@@ -440,6 +473,7 @@ impl DatamodelFieldExtensions for dml::ScalarField {
         match &self.field_type {
             dml::FieldType::Enum(x) => TypeIdentifier::Enum(x.clone()),
             dml::FieldType::Relation(_) => TypeIdentifier::String, // Todo: Unused
+            dml::FieldType::CompositeType(_) => TypeIdentifier::Json,
             dml::FieldType::Base(scalar, _) => match scalar {
                 dml::ScalarType::Boolean => TypeIdentifier::Boolean,
                 dml::ScalarType::DateTime => TypeIdentifier::DateTime,
@@ -508,4 +542,11 @@ impl DatamodelFieldExtensions for dml::ScalarField {
             database_name: enum_value.database_name.clone(),
         }
     }
+
+    fn composite_type(&self) -> Option<CompositeTypeReference> {
+        self.field_type.as_composite_type().map(|name| CompositeTypeReference {
+            name: name.to_owned(),
+            is_list: self.is_list(),
+        })
+    }
 }
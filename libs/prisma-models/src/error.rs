@@ -27,6 +27,9 @@ pub enum DomainError {
     #[error("Enum `{}` not found", name)]
     EnumNotFound { name: String },
 
+    #[error("Composite type `{}` not found", name)]
+    CompositeTypeNotFound { name: String },
+
     #[error("Conversion from `{}` to `{}` failed.", _0, _1)]
     ConversionFailure(String, String),
 }
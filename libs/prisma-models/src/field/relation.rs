@@ -1,5 +1,5 @@
 use crate::prelude::*;
-use datamodel::{FieldArity, RelationInfo};
+use datamodel::{FieldArity, ReferentialAction, RelationInfo};
 use once_cell::sync::OnceCell;
 use std::{
     fmt::Debug,
@@ -160,6 +160,21 @@ impl RelationField {
         !self.is_required
     }
 
+    /// The action to take on this side of the relation when the referenced record is deleted.
+    /// Falls back to the same defaults the migration engine uses for the foreign keys.
+    pub fn on_delete_action(&self) -> ReferentialAction {
+        self.relation_info.on_delete.unwrap_or(if self.is_required {
+            ReferentialAction::Cascade
+        } else {
+            ReferentialAction::SetNull
+        })
+    }
+
+    /// The action to take on this side of the relation when the referenced fields are updated.
+    pub fn on_update_action(&self) -> ReferentialAction {
+        self.relation_info.on_update.unwrap_or(ReferentialAction::Cascade)
+    }
+
     pub fn model(&self) -> ModelRef {
         self.model
             .upgrade()
@@ -27,6 +27,7 @@ pub struct ScalarFieldTemplate {
     pub is_autoincrement: bool,
     pub behaviour: Option<FieldBehaviour>,
     pub internal_enum: Option<InternalEnum>,
+    pub composite_type: Option<CompositeTypeReference>,
     pub arity: FieldArity,
     pub db_name: Option<String>,
     pub default_value: Option<DefaultValue>,
@@ -41,6 +42,7 @@ pub struct ScalarField {
     pub is_auto_generated_int_id: bool,
     pub is_autoincrement: bool,
     pub internal_enum: Option<InternalEnum>,
+    pub composite_type: Option<CompositeTypeReference>,
     pub behaviour: Option<FieldBehaviour>,
     pub arity: FieldArity,
     pub db_name: Option<String>,
@@ -62,6 +64,7 @@ impl Debug for ScalarField {
             .field("is_auto_generated_int_id", &self.is_auto_generated_int_id)
             .field("is_autoincrement", &self.is_autoincrement)
             .field("internal_enum", &self.internal_enum)
+            .field("composite_type", &self.composite_type)
             .field("behaviour", &self.behaviour)
             .field("arity", &self.arity)
             .field("db_name", &self.db_name)
@@ -84,6 +87,7 @@ impl Hash for ScalarField {
         self.is_id.hash(state);
         self.is_auto_generated_int_id.hash(state);
         self.internal_enum.hash(state);
+        self.composite_type.hash(state);
         self.behaviour.hash(state);
         self.is_unique.hash(state);
         self.model().hash(state);
@@ -101,6 +105,7 @@ impl PartialEq for ScalarField {
             && self.is_id == other.is_id
             && self.is_auto_generated_int_id == other.is_auto_generated_int_id
             && self.internal_enum == other.internal_enum
+            && self.composite_type == other.composite_type
             && self.behaviour == other.behaviour
             && self.default_value == other.default_value
             && self.is_unique == other.is_unique
@@ -136,6 +141,7 @@ impl ScalarFieldTemplate {
             read_only: OnceCell::new(),
             is_unique: self.is_unique,
             internal_enum: self.internal_enum,
+            composite_type: self.composite_type,
             behaviour: self.behaviour,
             arity: self.arity,
             db_name: self.db_name,
@@ -203,4 +209,9 @@ impl ScalarField {
     pub fn is_numeric(&self) -> bool {
         self.type_identifier.is_numeric()
     }
+
+    /// Whether the field embeds a composite type, stored as Json.
+    pub fn is_composite(&self) -> bool {
+        self.composite_type.is_some()
+    }
 }
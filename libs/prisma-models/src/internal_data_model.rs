@@ -1,22 +1,27 @@
 use crate::prelude::*;
+use datamodel::ReferentialIntegrity;
 use once_cell::sync::OnceCell;
 use std::sync::{Arc, Weak};
 
 pub type InternalDataModelRef = Arc<InternalDataModel>;
 pub type InternalDataModelWeakRef = Weak<InternalDataModel>;
 pub type InternalEnumRef = Arc<InternalEnum>;
+pub type InternalCompositeTypeRef = Arc<InternalCompositeType>;
 
 #[derive(Debug, Default)]
 pub struct InternalDataModelTemplate {
     pub models: Vec<ModelTemplate>,
     pub relations: Vec<RelationTemplate>,
     pub enums: Vec<InternalEnum>,
+    pub composite_types: Vec<InternalCompositeType>,
     pub version: Option<String>,
+    pub referential_integrity: ReferentialIntegrity,
 }
 
 #[derive(Debug)]
 pub struct InternalDataModel {
     pub enums: Vec<InternalEnumRef>,
+    pub composite_types: Vec<InternalCompositeTypeRef>,
     version: Option<String>,
 
     /// Todo clarify / rename.
@@ -26,6 +31,9 @@ pub struct InternalDataModel {
     /// influence the `database` part instead.
     pub db_name: String,
 
+    /// Who enforces the referential actions of relations, the database or the query engine.
+    pub referential_integrity: ReferentialIntegrity,
+
     models: OnceCell<Vec<ModelRef>>,
     relations: OnceCell<Vec<RelationRef>>,
     relation_fields: OnceCell<Vec<RelationFieldRef>>,
@@ -75,14 +83,42 @@ impl InternalEnumValue {
     }
 }
 
+/// A composite type. Values of composite types are embedded in the records of models as Json.
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct InternalCompositeType {
+    pub name: String,
+    pub fields: Vec<InternalCompositeTypeField>,
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct InternalCompositeTypeField {
+    pub name: String,
+    /// `Json` for fields embedding another composite type.
+    pub type_identifier: TypeIdentifier,
+    pub is_required: bool,
+    pub is_list: bool,
+    /// The name of the embedded composite type, if any.
+    pub composite_type: Option<String>,
+}
+
+/// The composite type embedded in a scalar field of a model. The field is stored as a single Json value, which is an
+/// array of composite values if `is_list` is set. A null list is read as an empty list.
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct CompositeTypeReference {
+    pub name: String,
+    pub is_list: bool,
+}
+
 impl InternalDataModelTemplate {
     pub fn build(self, db_name: String) -> InternalDataModelRef {
         let internal_data_model = Arc::new(InternalDataModel {
             models: OnceCell::new(),
             relations: OnceCell::new(),
             enums: self.enums.into_iter().map(Arc::new).collect(),
+            composite_types: self.composite_types.into_iter().map(Arc::new).collect(),
             version: self.version,
             db_name,
+            referential_integrity: self.referential_integrity,
             relation_fields: OnceCell::new(),
         });
 
@@ -127,6 +163,14 @@ impl InternalDataModel {
             .ok_or_else(|| DomainError::EnumNotFound { name: name.to_string() })
     }
 
+    pub fn find_composite_type(&self, name: &str) -> crate::Result<InternalCompositeTypeRef> {
+        self.composite_types
+            .iter()
+            .find(|ct| ct.name == name)
+            .cloned()
+            .ok_or_else(|| DomainError::CompositeTypeNotFound { name: name.to_string() })
+    }
+
     pub fn find_model(&self, name: &str) -> crate::Result<ModelRef> {
         self.models
             .get()
@@ -156,6 +200,23 @@ impl InternalDataModel {
             .collect()
     }
 
+    /// All relation fields holding a foreign key to the given model (including self-relations).
+    /// Those are the fields whose referential actions apply when records of `model` are
+    /// deleted or updated.
+    pub fn fields_referencing_model(&self, model: &ModelRef) -> Vec<RelationFieldRef> {
+        self.relation_fields()
+            .iter()
+            .filter(|rf| &rf.related_model() == model)
+            .filter(|rf| !rf.relation().is_many_to_many() && rf.relation_is_inlined_in_parent())
+            .map(|f| Arc::clone(f))
+            .collect()
+    }
+
+    /// True if the referential actions of relations have to be emulated by the query engine.
+    pub fn emulates_referential_actions(&self) -> bool {
+        self.referential_integrity == ReferentialIntegrity::Prisma
+    }
+
     pub fn relation_fields(&self) -> &[RelationFieldRef] {
         self.relation_fields
             .get_or_init(|| {
@@ -13,6 +13,7 @@ pub type ModelWeakRef = Weak<Model>;
 pub struct ModelTemplate {
     pub name: String,
     pub is_embedded: bool,
+    pub is_view: bool,
     pub fields: Vec<FieldTemplate>,
     pub manifestation: Option<String>,
     pub id_field_names: Vec<String>,
@@ -25,6 +26,9 @@ pub struct Model {
     pub name: String,
     pub is_embedded: bool,
 
+    /// Views are read-only, no write operations are exposed for them.
+    pub is_view: bool,
+
     manifestation: Option<String>,
     fields: OnceCell<Fields>,
     indexes: OnceCell<Vec<Index>>,
@@ -40,6 +44,7 @@ impl Debug for Model {
         f.debug_struct("Model")
             .field("name", &self.name)
             .field("is_embedded", &self.is_embedded)
+            .field("is_view", &self.is_view)
             .field("manifestation", &self.manifestation)
             .field("fields", &self.fields)
             .field("indexes", &self.indexes)
@@ -55,6 +60,7 @@ impl ModelTemplate {
         let model = Arc::new(Model {
             name: self.name,
             is_embedded: self.is_embedded,
+            is_view: self.is_view,
             manifestation: self.manifestation,
             fields: OnceCell::new(),
             indexes: OnceCell::new(),
@@ -154,6 +160,14 @@ impl Model {
         self.manifestation.as_ref().map(|m| m.as_ref())
     }
 
+    /// The database schema of the model: the one from `@@schema`, or the schema of the connection.
+    pub fn schema_name(&self) -> String {
+        self.dml_model
+            .schema
+            .clone()
+            .unwrap_or_else(|| self.internal_data_model().db_name.clone())
+    }
+
     pub fn internal_data_model(&self) -> InternalDataModelRef {
         self.internal_data_model
             .upgrade()
@@ -2,22 +2,102 @@ use crate::{ModelRef, RelationFieldRef, ScalarFieldRef};
 use std::string::ToString;
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
-pub struct OrderBy {
-    pub field: ScalarFieldRef,
-    pub path: Vec<RelationFieldRef>,
-    pub sort_order: SortOrder,
+pub enum OrderBy {
+    /// Orders by a scalar field of the model or of a model reachable through to-one relations.
+    Scalar(OrderByScalar),
+    /// Orders by the full-text search relevance of a set of fields for a search query.
+    Relevance(OrderByRelevance),
+    /// Orders by an aggregate of the records of a to-many relation, e.g. the number of related records.
+    Aggregation(OrderByAggregation),
 }
 
 impl OrderBy {
-    pub fn new(field: ScalarFieldRef, path: Vec<RelationFieldRef>, sort_order: SortOrder) -> Self {
-        Self {
+    pub fn scalar(field: ScalarFieldRef, path: Vec<RelationFieldRef>, sort_order: SortOrder) -> Self {
+        Self::Scalar(OrderByScalar {
+            field,
+            path,
+            sort_order,
+        })
+    }
+
+    pub fn relevance(fields: Vec<ScalarFieldRef>, search: String, sort_order: SortOrder) -> Self {
+        Self::Relevance(OrderByRelevance {
+            fields,
+            search,
+            sort_order,
+        })
+    }
+
+    pub fn aggregation(
+        field: Option<ScalarFieldRef>,
+        path: Vec<RelationFieldRef>,
+        sort_order: SortOrder,
+        sort_aggregation: SortAggregation,
+    ) -> Self {
+        Self::Aggregation(OrderByAggregation {
             field,
             path,
             sort_order,
+            sort_aggregation,
+        })
+    }
+
+    pub fn sort_order(&self) -> SortOrder {
+        match self {
+            Self::Scalar(o) => o.sort_order,
+            Self::Relevance(o) => o.sort_order,
+            Self::Aggregation(o) => o.sort_order,
+        }
+    }
+
+    pub fn as_scalar(&self) -> Option<&OrderByScalar> {
+        match self {
+            Self::Scalar(o) => Some(o),
+            _ => None,
         }
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct OrderByScalar {
+    pub field: ScalarFieldRef,
+    pub path: Vec<RelationFieldRef>,
+    pub sort_order: SortOrder,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct OrderByRelevance {
+    pub fields: Vec<ScalarFieldRef>,
+    pub search: String,
+    pub sort_order: SortOrder,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct OrderByAggregation {
+    /// The aggregated field of the related model. `None` for counts.
+    pub field: Option<ScalarFieldRef>,
+    /// To-one relation hops, followed by the to-many relation whose records are aggregated.
+    pub path: Vec<RelationFieldRef>,
+    pub sort_order: SortOrder,
+    pub sort_aggregation: SortAggregation,
+}
+
+impl OrderByAggregation {
+    /// Counts are never null, all other aggregates are null if there are no related records.
+    pub fn is_nullable(&self) -> bool {
+        self.sort_aggregation != SortAggregation::Count
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Debug, Eq, Hash)]
+pub enum SortAggregation {
+    Count,
+    Avg,
+    Sum,
+    Min,
+    Max,
+}
+
 pub trait IntoOrderBy {
     fn into_order_by(self, model: ModelRef) -> OrderBy;
 }
@@ -39,10 +119,6 @@ impl ToString for SortOrder {
 
 impl From<ScalarFieldRef> for OrderBy {
     fn from(field: ScalarFieldRef) -> Self {
-        Self {
-            field,
-            path: vec![],
-            sort_order: SortOrder::Ascending,
-        }
+        Self::scalar(field, vec![], SortOrder::Ascending)
     }
 }
@@ -65,7 +65,14 @@ impl ManyRecords {
         }
     }
 
+    /// Orders the records in memory. Relevance orderings can only be computed by the database and are skipped.
     pub fn order_by(&mut self, order_bys: &[OrderBy]) {
+        let order_bys: Vec<_> = order_bys.iter().filter_map(|o| o.as_scalar()).collect();
+
+        if order_bys.is_empty() {
+            return;
+        }
+
         let field_indices: HashMap<&str, usize> = self
             .field_names
             .iter()
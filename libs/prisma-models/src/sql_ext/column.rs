@@ -151,8 +151,9 @@ where
 {
     fn as_column(&self) -> Column<'static> {
         let sf = self.as_ref();
-        let db = sf.internal_data_model().db_name.clone();
-        let table = sf.model().db_name().to_string();
+        let model = sf.model();
+        let db = model.schema_name();
+        let table = model.db_name().to_string();
         let col = sf.db_name().to_string();
 
         let type_family = match sf.type_identifier {
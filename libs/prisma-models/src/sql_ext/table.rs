@@ -8,7 +8,7 @@ pub trait AsTable {
 
 impl AsTable for Model {
     fn as_table(&self) -> Table<'static> {
-        let table: Table<'static> = (self.internal_data_model().db_name.clone(), self.db_name().to_string()).into();
+        let table: Table<'static> = (self.schema_name(), self.db_name().to_string()).into();
 
         // Todo: Check with Julius
         let id_cols: Vec<Column<'static>> = self
@@ -109,7 +109,7 @@ impl<'a> Display for ForeignKey<'a> {
 #[derive(Debug)]
 pub enum ForeignKeyAction {
     Cascade,
-    DoNothing,
+    NoAction,
     Restrict,
     SetDefault,
     SetNull,
@@ -120,7 +120,7 @@ impl Display for ForeignKeyAction {
         let s = match self {
             ForeignKeyAction::Cascade => "CASCADE",
             ForeignKeyAction::Restrict => "RESTRICT",
-            ForeignKeyAction::DoNothing => "DO NOTHING",
+            ForeignKeyAction::NoAction => "NO ACTION",
             ForeignKeyAction::SetNull => "SET NULL",
             ForeignKeyAction::SetDefault => "SET DEFAULT",
         };
@@ -129,9 +129,28 @@ impl Display for ForeignKeyAction {
     }
 }
 
+#[derive(Debug)]
+pub enum IndexType {
+    Normal,
+    Unique,
+    Fulltext,
+}
+
+impl Display for IndexType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            IndexType::Normal => "",
+            IndexType::Unique => "UNIQUE ",
+            IndexType::Fulltext => "FULLTEXT ",
+        };
+
+        f.write_str(s)
+    }
+}
+
 #[derive(Debug)]
 pub struct CreateIndex<'a> {
-    pub unique: bool,
+    pub index_type: IndexType,
     pub index_name: Cow<'a, str>,
     pub on: (Cow<'a, str>, Vec<Cow<'a, str>>),
 }
@@ -140,8 +159,8 @@ impl Display for CreateIndex<'_> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "CREATE {maybe_unique}INDEX `{index_name}` ON `{table_name}`(",
-            maybe_unique = if self.unique { "UNIQUE " } else { "" },
+            "CREATE {index_type}INDEX `{index_name}` ON `{table_name}`(",
+            index_type = self.index_type,
             index_name = self.index_name,
             table_name = self.on.0,
         )?;
@@ -186,14 +205,14 @@ mod tests {
             changes: vec![AlterTableClause::AddForeignKey(ForeignKey {
                 constrained_columns: vec!["bestFriendId".into()],
                 constraint_name: Some("myfk".into()),
-                on_delete: Some(ForeignKeyAction::DoNothing),
+                on_delete: Some(ForeignKeyAction::NoAction),
                 on_update: Some(ForeignKeyAction::SetNull),
                 referenced_columns: vec!["id".into()],
                 referenced_table: "Dog".into(),
             })],
         };
 
-        let expected = "ALTER TABLE `Cat` ADD CONSTRAINT `myfk` FOREIGN KEY (`bestFriendId`) REFERENCES `Dog`(`id`) ON DELETE DO NOTHING ON UPDATE SET NULL";
+        let expected = "ALTER TABLE `Cat` ADD CONSTRAINT `myfk` FOREIGN KEY (`bestFriendId`) REFERENCES `Dog`(`id`) ON DELETE NO ACTION ON UPDATE SET NULL";
 
         assert_eq!(alter_table.to_string(), expected);
     }
@@ -43,7 +43,7 @@ impl Display for AlterTableClause<'_> {
 pub struct ForeignKey<'a> {
     pub constraint_name: Option<Cow<'a, str>>,
     pub constrained_columns: Vec<Cow<'a, str>>,
-    pub referenced_table: Cow<'a, str>,
+    pub referenced_table: PostgresIdentifier<'a>,
     pub referenced_columns: Vec<Cow<'a, str>>,
     pub on_delete: Option<ForeignKeyAction>,
     pub on_update: Option<ForeignKeyAction>,
@@ -63,7 +63,7 @@ impl Display for ForeignKey<'_> {
 
         self.constrained_columns.iter().map(|s| Ident(s)).join(", ", f)?;
 
-        write!(f, ") REFERENCES \"{}\"(", self.referenced_table)?;
+        write!(f, ") REFERENCES {}(", self.referenced_table)?;
 
         self.referenced_columns.iter().map(|s| Ident(s)).join(", ", f)?;
 
@@ -86,7 +86,7 @@ impl Display for ForeignKey<'_> {
 #[derive(Debug)]
 pub enum ForeignKeyAction {
     Cascade,
-    DoNothing,
+    NoAction,
     Restrict,
     SetDefault,
     SetNull,
@@ -97,7 +97,7 @@ impl Display for ForeignKeyAction {
         let s = match self {
             ForeignKeyAction::Cascade => "CASCADE",
             ForeignKeyAction::Restrict => "RESTRICT",
-            ForeignKeyAction::DoNothing => "DO NOTHING",
+            ForeignKeyAction::NoAction => "NO ACTION",
             ForeignKeyAction::SetNull => "SET NULL",
             ForeignKeyAction::SetDefault => "SET DEFAULT",
         };
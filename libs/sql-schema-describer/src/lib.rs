@@ -32,6 +32,18 @@ pub trait SqlSchemaDescriberBackend: Send + Sync + 'static {
     /// Describe a database schema.
     async fn describe(&self, schema: &str) -> DescriberResult<SqlSchema>;
 
+    /// Describe several database schemas into one `SqlSchema`. Each table is qualified with the
+    /// schema it was found in.
+    async fn describe_schemas(&self, schemas: &[&str]) -> DescriberResult<SqlSchema> {
+        let mut sql_schema = SqlSchema::empty();
+
+        for schema in schemas {
+            sql_schema.merge_schema(schema, self.describe(schema).await?);
+        }
+
+        Ok(sql_schema)
+    }
+
     /// Get the database version.
     async fn version(&self, schema: &str) -> DescriberResult<Option<String>>;
 }
@@ -111,6 +123,34 @@ impl SqlSchema {
         SqlSchema::default()
     }
 
+    /// Add the contents of a described database schema, qualifying its tables with the schema name.
+    pub fn merge_schema(&mut self, schema_name: &str, described: SqlSchema) {
+        let SqlSchema {
+            tables,
+            enums,
+            sequences,
+            views,
+            procedures,
+        } = described;
+
+        self.tables.extend(tables.into_iter().map(|mut table| {
+            table.schema = Some(schema_name.to_owned());
+
+            // Foreign keys without a referenced schema point to a table of the same schema.
+            for foreign_key in table.foreign_keys.iter_mut() {
+                foreign_key
+                    .referenced_schema
+                    .get_or_insert_with(|| schema_name.to_owned());
+            }
+
+            table
+        }));
+        self.enums.extend(enums);
+        self.sequences.extend(sequences);
+        self.views.extend(views);
+        self.procedures.extend(procedures);
+    }
+
     pub fn table_walkers(&self) -> impl Iterator<Item = TableWalker<'_>> {
         (0..self.tables.len()).map(move |table_index| TableWalker::new(self, table_index))
     }
@@ -140,6 +180,10 @@ pub struct Table {
     pub primary_key: Option<PrimaryKey>,
     /// The table's foreign keys.
     pub foreign_keys: Vec<ForeignKey>,
+    /// The database schema the table lives in, when several schemas are described at once.
+    pub schema: Option<String>,
+    /// The table's check constraints.
+    pub check_constraints: Vec<CheckConstraint>,
 }
 
 impl Table {
@@ -203,12 +247,18 @@ pub enum IndexType {
     Unique,
     /// Normal type.
     Normal,
+    /// Fulltext type.
+    Fulltext,
 }
 
 impl IndexType {
     pub fn is_unique(&self) -> bool {
         matches!(self, IndexType::Unique)
     }
+
+    pub fn is_fulltext(&self) -> bool {
+        matches!(self, IndexType::Fulltext)
+    }
 }
 
 /// An index of a table.
@@ -414,6 +464,15 @@ pub enum ForeignKeyAction {
     SetDefault,
 }
 
+/// A check constraint.
+#[derive(PartialEq, Debug, Clone)]
+pub struct CheckConstraint {
+    /// The name of the constraint. SQLite constraints declared without a name have none.
+    pub name: Option<String>,
+    /// The boolean SQL expression the rows must satisfy, without the surrounding `CHECK (...)`.
+    pub expression: String,
+}
+
 /// A foreign key.
 #[derive(Debug, Clone)]
 pub struct ForeignKey {
@@ -423,6 +482,8 @@ pub struct ForeignKey {
     pub columns: Vec<String>,
     /// Referenced table.
     pub referenced_table: String,
+    /// The database schema of the referenced table, when several schemas are described at once.
+    pub referenced_schema: Option<String>,
     /// Referenced columns.
     pub referenced_columns: Vec<String>,
     /// Action on deletion.
@@ -435,6 +496,7 @@ impl PartialEq for ForeignKey {
     fn eq(&self, other: &Self) -> bool {
         self.columns == other.columns
             && self.referenced_table == other.referenced_table
+            && self.referenced_schema == other.referenced_schema
             && self.referenced_columns == other.referenced_columns
     }
 }
@@ -462,6 +524,8 @@ pub struct View {
     pub name: String,
     /// The SQL definition of the view.
     pub definition: String,
+    /// The columns of the view. Views have no keys, indexes or constraints of their own.
+    pub columns: Vec<Column>,
 }
 
 #[derive(PartialEq, Debug, Clone)]
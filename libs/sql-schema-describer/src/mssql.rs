@@ -1,7 +1,7 @@
 use crate::{
-    getters::Getter, parsers::Parser, Column, ColumnArity, ColumnType, ColumnTypeFamily, DefaultValue, DescriberError,
-    DescriberErrorKind, DescriberResult, ForeignKey, ForeignKeyAction, Index, IndexType, PrimaryKey, Procedure,
-    SQLMetadata, SqlSchema, Table, View,
+    getters::Getter, parsers::Parser, CheckConstraint, Column, ColumnArity, ColumnType, ColumnTypeFamily, DefaultValue,
+    DescriberError, DescriberErrorKind, DescriberResult, ForeignKey, ForeignKeyAction, Index, IndexType, PrimaryKey,
+    Procedure, SQLMetadata, SqlSchema, Table, View,
 };
 use indoc::indoc;
 use native_types::{MsSqlType, MsSqlTypeParameter, NativeType};
@@ -83,9 +83,39 @@ impl super::SqlSchemaDescriberBackend for SqlSchemaDescriber {
 
     #[tracing::instrument]
     async fn describe(&self, schema: &str) -> DescriberResult<SqlSchema> {
+        self.describe_schema(schema, &[schema]).await
+    }
+
+    #[tracing::instrument]
+    async fn describe_schemas(&self, schemas: &[&str]) -> DescriberResult<SqlSchema> {
+        let mut sql_schema = SqlSchema::empty();
+
+        for schema in schemas {
+            sql_schema.merge_schema(schema, self.describe_schema(schema, schemas).await?);
+        }
+
+        Ok(sql_schema)
+    }
+
+    #[tracing::instrument]
+    async fn version(&self, schema: &str) -> DescriberResult<Option<String>> {
+        Ok(self.conn.version().await?)
+    }
+}
+
+impl Parser for SqlSchemaDescriber {}
+
+impl SqlSchemaDescriber {
+    pub fn new(conn: Quaint) -> Self {
+        Self { conn }
+    }
+
+    /// Describe one schema. Foreign keys may reference tables in any of the
+    /// `referenceable_schemas`.
+    async fn describe_schema(&self, schema: &str, referenceable_schemas: &[&str]) -> DescriberResult<SqlSchema> {
         let mut columns = self.get_all_columns(schema).await?;
         let mut indexes = self.get_all_indices(schema).await?;
-        let mut foreign_keys = self.get_foreign_keys(schema).await?;
+        let mut foreign_keys = self.get_foreign_keys(schema, referenceable_schemas).await?;
 
         let table_names = self.get_table_names(schema).await?;
         let mut tables = Vec::with_capacity(table_names.len());
@@ -95,7 +125,18 @@ impl super::SqlSchemaDescriberBackend for SqlSchemaDescriber {
             tables.push(table);
         }
 
-        let views = self.get_views(schema).await?;
+        let mut check_constraints = self.get_check_constraints(schema).await?;
+
+        for table in tables.iter_mut() {
+            table.check_constraints = check_constraints.remove(&table.name).unwrap_or_default();
+        }
+
+        let mut views = self.get_views(schema).await?;
+
+        for view in views.iter_mut() {
+            view.columns = columns.remove(&view.name).unwrap_or_default();
+        }
+
         let procedures = self.get_procedures(schema).await?;
 
         Ok(SqlSchema {
@@ -107,19 +148,6 @@ impl super::SqlSchemaDescriberBackend for SqlSchemaDescriber {
         })
     }
 
-    #[tracing::instrument]
-    async fn version(&self, schema: &str) -> DescriberResult<Option<String>> {
-        Ok(self.conn.version().await?)
-    }
-}
-
-impl Parser for SqlSchemaDescriber {}
-
-impl SqlSchemaDescriber {
-    pub fn new(conn: Quaint) -> Self {
-        Self { conn }
-    }
-
     #[tracing::instrument]
     async fn get_databases(&self) -> DescriberResult<Vec<String>> {
         let sql = "SELECT name FROM sys.schemas";
@@ -227,6 +255,8 @@ impl SqlSchemaDescriber {
             foreign_keys,
             indices: indices.into_iter().map(|(_k, v)| v).collect(),
             primary_key,
+            schema: None,
+            check_constraints: Vec::new(),
         }
     }
 
@@ -247,8 +277,9 @@ impl SqlSchemaDescriber {
                     WHEN c.system_type_id IN (40, 41, 42, 43, 58, 61) THEN NULL
                     ELSE ODBCSCALE(c.system_type_id, c.scale) END) AS numeric_scale
             FROM sys.columns c
-                    INNER JOIN sys.tables t ON c.object_id = t.object_id
+                    INNER JOIN sys.objects t ON c.object_id = t.object_id
             WHERE OBJECT_SCHEMA_NAME(c.object_id) = @P1
+            AND t.type IN ('U', 'V')
             AND t.is_ms_shipped = 0
             ORDER BY COLUMNPROPERTY(c.object_id, c.name, 'ordinal');
         "#};
@@ -470,6 +501,34 @@ impl SqlSchemaDescriber {
         Ok(map)
     }
 
+    #[tracing::instrument]
+    async fn get_check_constraints(&self, schema: &str) -> DescriberResult<HashMap<String, Vec<CheckConstraint>>> {
+        let sql = indoc! {r#"
+            SELECT OBJECT_NAME(cc.parent_object_id) AS table_name, cc.name AS constraint_name,
+                cc.definition AS constraint_definition
+            FROM sys.check_constraints cc
+            WHERE SCHEMA_NAME(cc.schema_id) = @P1
+            ORDER BY cc.name
+        "#};
+
+        let rows = self.conn.query_raw(sql, &[schema.into()]).await?;
+        let mut check_constraints: HashMap<String, Vec<CheckConstraint>> = HashMap::new();
+
+        for row in rows.into_iter() {
+            check_constraints
+                .entry(row.get_expect_string("table_name"))
+                .or_default()
+                .push(CheckConstraint {
+                    name: Some(row.get_expect_string("constraint_name")),
+                    expression: row.get_expect_string("constraint_definition"),
+                });
+        }
+
+        trace!("Found check constraints: {:?}", check_constraints);
+
+        Ok(check_constraints)
+    }
+
     #[tracing::instrument]
     async fn get_views(&self, schema: &str) -> DescriberResult<Vec<View>> {
         let sql = indoc! {r#"
@@ -486,13 +545,18 @@ impl SqlSchemaDescriber {
             views.push(View {
                 name: row.get_expect_string("view_name"),
                 definition: row.get_expect_string("view_sql"),
+                columns: Vec::new(),
             })
         }
 
         Ok(views)
     }
 
-    async fn get_foreign_keys(&self, schema: &str) -> DescriberResult<HashMap<String, Vec<ForeignKey>>> {
+    async fn get_foreign_keys(
+        &self,
+        schema: &str,
+        referenceable_schemas: &[&str],
+    ) -> DescriberResult<HashMap<String, Vec<ForeignKey>>> {
         // Foreign keys covering multiple columns will return multiple rows, which we need to
         // merge.
         let mut map: HashMap<String, HashMap<String, ForeignKey>> = HashMap::new();
@@ -540,7 +604,7 @@ impl SqlSchemaDescriber {
             let referenced_column = row.get_expect_string("referenced_column_name");
             let ord_pos = row.get_expect_i64("ordinal_position");
 
-            if schema != referenced_schema_name {
+            if !referenceable_schemas.contains(&referenced_schema_name.as_str()) {
                 return Err(DescriberError::from(DescriberErrorKind::CrossSchemaReference {
                     from: format!("{}.{}", schema, table_name),
                     to: format!("{}.{}", referenced_schema_name, referenced_table),
@@ -587,6 +651,7 @@ impl SqlSchemaDescriber {
                         constraint_name: Some(constraint_name.clone()),
                         columns: vec![column],
                         referenced_table,
+                        referenced_schema: Some(referenced_schema_name).filter(|referenced| referenced != schema),
                         referenced_columns: vec![referenced_column],
                         on_delete_action,
                         on_update_action,
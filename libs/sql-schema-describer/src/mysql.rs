@@ -71,7 +71,21 @@ impl super::SqlSchemaDescriberBackend for SqlSchemaDescriber {
             enums.extend(enms.iter().cloned());
         }
 
-        let views = self.get_views(schema).await?;
+        let mut check_constraints = self.get_check_constraints(schema, &flavour).await?;
+
+        for table in tables.iter_mut() {
+            table.check_constraints = check_constraints.remove(&table.name).unwrap_or_default();
+        }
+
+        let mut views = self.get_views(schema).await?;
+
+        for view in views.iter_mut() {
+            view.columns = columns
+                .remove(&view.name)
+                .map(|(columns, _)| columns)
+                .unwrap_or_default();
+        }
+
         let procedures = self.get_procedures(schema).await?;
 
         Ok(SqlSchema {
@@ -111,6 +125,71 @@ impl SqlSchemaDescriber {
         Ok(names)
     }
 
+    /// Check constraints are only enforced, and listed in `CHECK_CONSTRAINTS`, on MySQL 8.0.16+
+    /// and MariaDB 10.2+.
+    async fn get_check_constraints(
+        &self,
+        schema: &str,
+        flavour: &Flavour,
+    ) -> DescriberResult<HashMap<String, Vec<CheckConstraint>>> {
+        let mut check_constraints: HashMap<String, Vec<CheckConstraint>> = HashMap::new();
+
+        let table_exists_sql = indoc! {r#"
+            SELECT COUNT(*) AS count
+            FROM INFORMATION_SCHEMA.TABLES
+            WHERE TABLE_SCHEMA = 'information_schema' AND TABLE_NAME = 'CHECK_CONSTRAINTS'
+        "#};
+
+        let table_exists = self
+            .conn
+            .query_raw(table_exists_sql, &[])
+            .await?
+            .into_iter()
+            .next()
+            .and_then(|row| row.get_i64("count"))
+            .unwrap_or(0)
+            > 0;
+
+        if !table_exists {
+            return Ok(check_constraints);
+        }
+
+        let sql = indoc! {r#"
+            SELECT tc.TABLE_NAME AS table_name, cc.CONSTRAINT_NAME AS constraint_name,
+                cc.CHECK_CLAUSE AS check_clause
+            FROM INFORMATION_SCHEMA.CHECK_CONSTRAINTS cc
+            JOIN INFORMATION_SCHEMA.TABLE_CONSTRAINTS tc
+                ON tc.CONSTRAINT_SCHEMA = cc.CONSTRAINT_SCHEMA
+                AND tc.CONSTRAINT_NAME = cc.CONSTRAINT_NAME
+            WHERE cc.CONSTRAINT_SCHEMA = ? AND tc.CONSTRAINT_TYPE = 'CHECK'
+            ORDER BY cc.CONSTRAINT_NAME
+        "#};
+
+        let rows = self.conn.query_raw(sql, &[schema.into()]).await?;
+
+        for row in rows.into_iter() {
+            // MySQL escapes the quotes of string literals in the check clause (`_utf8mb4\'a\'`).
+            let expression = row.get_expect_string("check_clause").replace("\\'", "'");
+
+            // MariaDB implements the JSON type as LONGTEXT with a `json_valid()` check.
+            if matches!(flavour, Flavour::MariaDb) && expression.starts_with("json_valid(") {
+                continue;
+            }
+
+            check_constraints
+                .entry(row.get_expect_string("table_name"))
+                .or_default()
+                .push(CheckConstraint {
+                    name: Some(row.get_expect_string("constraint_name")),
+                    expression,
+                });
+        }
+
+        trace!("Found check constraints: {:?}", check_constraints);
+
+        Ok(check_constraints)
+    }
+
     #[tracing::instrument(skip(self))]
     async fn get_views(&self, schema: &str) -> DescriberResult<Vec<View>> {
         let sql = indoc! {r#"
@@ -126,6 +205,7 @@ impl SqlSchemaDescriber {
             views.push(View {
                 name: row.get_expect_string("view_name"),
                 definition: row.get_expect_string("view_sql"),
+                columns: Vec::new(),
             })
         }
 
@@ -217,6 +297,8 @@ impl SqlSchemaDescriber {
                 foreign_keys,
                 indices: indices.into_iter().map(|(_k, v)| v).collect(),
                 primary_key,
+                schema: None,
+                check_constraints: Vec::new(),
             },
             enums,
         )
@@ -383,7 +465,8 @@ impl SqlSchemaDescriber {
                 column_name AS column_name,
                 seq_in_index AS seq_in_index,
                 table_name AS table_name,
-                sub_part AS partial
+                sub_part AS partial,
+                index_type AS index_type
             FROM INFORMATION_SCHEMA.STATISTICS
             WHERE table_schema = ?
             ORDER BY index_name, seq_in_index
@@ -402,6 +485,7 @@ impl SqlSchemaDescriber {
                     let seq_in_index = row.get_expect_i64("seq_in_index");
                     let pos = seq_in_index - 1;
                     let is_unique = !row.get_expect_bool("non_unique");
+                    let is_fulltext = row.get_expect_string("index_type") == "FULLTEXT";
 
                     // Multi-column indices will return more than one row (with different column_name values).
                     // We cannot assume that one row corresponds to one index.
@@ -443,9 +527,10 @@ impl SqlSchemaDescriber {
                             Index {
                                 name: index_name,
                                 columns: vec![column_name],
-                                tpe: match is_unique {
-                                    true => IndexType::Unique,
-                                    false => IndexType::Normal,
+                                tpe: match (is_unique, is_fulltext) {
+                                    (true, _) => IndexType::Unique,
+                                    (false, true) => IndexType::Fulltext,
+                                    (false, false) => IndexType::Normal,
                                 },
                             },
                         );
@@ -555,6 +640,7 @@ impl SqlSchemaDescriber {
                         constraint_name: Some(constraint_name.clone()),
                         columns: vec![column],
                         referenced_table,
+                        referenced_schema: None,
                         referenced_columns: vec![referenced_column],
                         on_delete_action,
                         on_update_action,
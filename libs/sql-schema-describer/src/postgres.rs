@@ -33,29 +33,18 @@ impl super::SqlSchemaDescriberBackend for SqlSchemaDescriber {
 
     #[tracing::instrument]
     async fn describe(&self, schema: &str) -> DescriberResult<SqlSchema> {
-        let sequences = self.get_sequences(schema).await?;
-        let enums = self.get_enums(schema).await?;
-        let mut columns = self.get_columns(schema, &enums, &sequences).await?;
-        let mut foreign_keys = self.get_foreign_keys(schema).await?;
-        let mut indexes = self.get_indices(schema, &sequences).await?;
+        self.describe_schema(schema, &[schema]).await
+    }
 
-        let table_names = self.get_table_names(schema).await?;
-        let mut tables = Vec::with_capacity(table_names.len());
+    #[tracing::instrument]
+    async fn describe_schemas(&self, schemas: &[&str]) -> DescriberResult<SqlSchema> {
+        let mut sql_schema = SqlSchema::empty();
 
-        for table_name in &table_names {
-            tables.push(self.get_table(&table_name, &mut columns, &mut foreign_keys, &mut indexes));
+        for schema in schemas {
+            sql_schema.merge_schema(schema, self.describe_schema(schema, schemas).await?);
         }
 
-        let views = self.get_views(schema).await?;
-        let procedures = self.get_procedures(schema).await?;
-
-        Ok(SqlSchema {
-            enums,
-            sequences,
-            tables,
-            views,
-            procedures,
-        })
+        Ok(sql_schema)
     }
 
     #[tracing::instrument]
@@ -83,6 +72,45 @@ impl SqlSchemaDescriber {
         SqlSchemaDescriber { conn }
     }
 
+    /// Describe one schema. Foreign keys may reference tables in any of the
+    /// `referenceable_schemas`.
+    async fn describe_schema(&self, schema: &str, referenceable_schemas: &[&str]) -> DescriberResult<SqlSchema> {
+        let sequences = self.get_sequences(schema).await?;
+        let enums = self.get_enums(schema).await?;
+        let mut columns = self.get_columns(schema, &enums, &sequences).await?;
+        let mut foreign_keys = self.get_foreign_keys(schema, referenceable_schemas).await?;
+        let mut indexes = self.get_indices(schema, &sequences).await?;
+
+        let table_names = self.get_table_names(schema).await?;
+        let mut tables = Vec::with_capacity(table_names.len());
+
+        for table_name in &table_names {
+            tables.push(self.get_table(&table_name, &mut columns, &mut foreign_keys, &mut indexes));
+        }
+
+        let mut check_constraints = self.get_check_constraints(schema).await?;
+
+        for table in tables.iter_mut() {
+            table.check_constraints = check_constraints.remove(&table.name).unwrap_or_default();
+        }
+
+        let mut views = self.get_views(schema).await?;
+
+        for view in views.iter_mut() {
+            view.columns = columns.remove(&view.name).unwrap_or_default();
+        }
+
+        let procedures = self.get_procedures(schema).await?;
+
+        Ok(SqlSchema {
+            enums,
+            sequences,
+            tables,
+            views,
+            procedures,
+        })
+    }
+
     #[tracing::instrument]
     async fn get_databases(&self) -> DescriberResult<Vec<String>> {
         let sql = "select schema_name from information_schema.schemata;";
@@ -173,9 +201,48 @@ impl SqlSchemaDescriber {
             foreign_keys,
             indices,
             primary_key,
+            schema: None,
+            check_constraints: Vec::new(),
         }
     }
 
+    #[tracing::instrument]
+    async fn get_check_constraints(&self, schema: &str) -> DescriberResult<HashMap<String, Vec<CheckConstraint>>> {
+        let sql = indoc! {r#"
+            SELECT rel.relname AS table_name, con.conname AS constraint_name,
+                pg_get_constraintdef(con.oid) AS constraint_definition
+            FROM pg_constraint con
+            JOIN pg_class rel ON rel.oid = con.conrelid
+            JOIN pg_namespace ns ON ns.oid = rel.relnamespace
+            WHERE con.contype = 'c' AND ns.nspname = $1
+            ORDER BY con.conname
+        "#};
+
+        let rows = self.conn.query_raw(sql, &[schema.into()]).await?;
+        let mut check_constraints: HashMap<String, Vec<CheckConstraint>> = HashMap::new();
+
+        for row in rows.into_iter() {
+            // The definition is rendered as `CHECK (<expression>)`.
+            let definition = row.get_expect_string("constraint_definition");
+            let expression = definition
+                .strip_prefix("CHECK (")
+                .and_then(|expression| expression.strip_suffix(')'))
+                .unwrap_or(&definition);
+
+            check_constraints
+                .entry(row.get_expect_string("table_name"))
+                .or_default()
+                .push(CheckConstraint {
+                    name: Some(row.get_expect_string("constraint_name")),
+                    expression: expression.to_owned(),
+                });
+        }
+
+        trace!("Found check constraints: {:?}", check_constraints);
+
+        Ok(check_constraints)
+    }
+
     #[tracing::instrument]
     async fn get_views(&self, schema: &str) -> DescriberResult<Vec<View>> {
         let sql = indoc! {r#"
@@ -191,6 +258,7 @@ impl SqlSchemaDescriber {
             views.push(View {
                 name: row.get_expect_string("view_name"),
                 definition: row.get_expect_string("view_sql"),
+                columns: Vec::new(),
             })
         }
 
@@ -331,7 +399,11 @@ impl SqlSchemaDescriber {
     }
 
     /// Returns a map from table name to foreign keys.
-    async fn get_foreign_keys(&self, schema: &str) -> DescriberResult<HashMap<String, Vec<ForeignKey>>> {
+    async fn get_foreign_keys(
+        &self,
+        schema: &str,
+        referenceable_schemas: &[&str],
+    ) -> DescriberResult<HashMap<String, Vec<ForeignKey>>> {
         // The `generate_subscripts` in the inner select is needed because the optimizer is free to reorganize the unnested rows if not explicitly ordered.
         let sql = r#"
             SELECT con.oid         as "con_id",
@@ -390,7 +462,7 @@ impl SqlSchemaDescriber {
 
             let referenced_schema_name = row.get_expect_string("referenced_schema_name");
 
-            if schema != referenced_schema_name {
+            if !referenceable_schemas.contains(&referenced_schema_name.as_str()) {
                 return Err(DescriberError::from(DescriberErrorKind::CrossSchemaReference {
                     from: format!("{}.{}", schema, table_name),
                     to: format!("{}.{}", referenced_schema_name, referenced_table),
@@ -424,6 +496,7 @@ impl SqlSchemaDescriber {
                         constraint_name: Some(constraint_name),
                         columns: vec![column],
                         referenced_table,
+                        referenced_schema: Some(referenced_schema_name).filter(|referenced| referenced != schema),
                         referenced_columns: vec![referenced_column],
                         on_delete_action,
                         on_update_action,
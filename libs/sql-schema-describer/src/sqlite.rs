@@ -52,7 +52,12 @@ impl super::SqlSchemaDescriberBackend for SqlSchemaDescriber {
             tables[table_index].foreign_keys[fk_index].referenced_columns = columns
         }
 
-        let views = self.get_views().await?;
+        let mut views = self.get_views().await?;
+
+        for view in views.iter_mut() {
+            let (columns, _) = self.get_columns(&view.name).await?;
+            view.columns = columns;
+        }
 
         Ok(SqlSchema {
             // There's no enum type in SQLite.
@@ -133,6 +138,7 @@ impl SqlSchemaDescriber {
         let (columns, primary_key) = self.get_columns(name).await?;
         let foreign_keys = self.get_foreign_keys(name).await?;
         let indices = self.get_indices(name).await?;
+        let check_constraints = self.get_check_constraints(name).await?;
 
         Ok(Table {
             name: name.to_string(),
@@ -140,9 +146,26 @@ impl SqlSchemaDescriber {
             indices,
             primary_key,
             foreign_keys,
+            schema: None,
+            check_constraints,
         })
     }
 
+    /// SQLite has no catalog for check constraints, so they are read from the `CREATE TABLE`
+    /// statement stored in `sqlite_master`.
+    #[tracing::instrument]
+    async fn get_check_constraints(&self, table: &str) -> DescriberResult<Vec<CheckConstraint>> {
+        let sql = "SELECT sql FROM sqlite_master WHERE type = 'table' AND name = ?";
+        let result_set = self.conn.query_raw(sql, &[table.into()]).await?;
+
+        let create_table = match result_set.into_iter().next().and_then(|row| row.get_string("sql")) {
+            Some(create_table) => create_table,
+            None => return Ok(Vec::new()),
+        };
+
+        Ok(parse_check_constraints(&create_table))
+    }
+
     #[tracing::instrument]
     async fn get_views(&self) -> DescriberResult<Vec<View>> {
         let sql = "SELECT name AS view_name, sql AS view_sql FROM sqlite_master WHERE type = 'view'";
@@ -153,6 +176,7 @@ impl SqlSchemaDescriber {
             views.push(View {
                 name: row.get_expect_string("view_name"),
                 definition: row.get_expect_string("view_sql"),
+                columns: Vec::new(),
             })
         }
 
@@ -392,6 +416,7 @@ impl SqlSchemaDescriber {
                 let fk = ForeignKey {
                     columns,
                     referenced_table: intermediate_fk.referenced_table.to_owned(),
+                    referenced_schema: None,
                     referenced_columns,
                     on_delete_action: intermediate_fk.on_delete_action.to_owned(),
                     on_update_action: intermediate_fk.on_update_action.to_owned(),
@@ -515,6 +540,63 @@ fn unquote_sqlite_string_default(s: &str) -> Cow<'_, str> {
     }
 }
 
+/// Extract the `[CONSTRAINT <name>] CHECK (<expression>)` clauses of a `CREATE TABLE` statement.
+fn parse_check_constraints(create_table: &str) -> Vec<CheckConstraint> {
+    static CHECK_RE: Lazy<Regex> = Lazy::new(|| {
+        Regex::new(r#"(?i)(?:\bCONSTRAINT\s+(?:"([^"]+)"|`([^`]+)`|\[([^\]]+)\]|(\w+))\s+)?\bCHECK\s*\("#).unwrap()
+    });
+
+    let mut check_constraints = Vec::new();
+    let mut search_from = 0;
+
+    while let Some(captures) = CHECK_RE.captures(&create_table[search_from..]) {
+        let whole_match = captures.get(0).unwrap();
+        let expression_start = search_from + whole_match.end();
+
+        // Find the parenthesis closing the expression, skipping over string literals.
+        let mut depth = 1;
+        let mut quote: Option<char> = None;
+        let mut expression_end = None;
+
+        for (idx, c) in create_table[expression_start..].char_indices() {
+            match (quote, c) {
+                (Some(q), c) if c == q => quote = None,
+                (Some(_), _) => (),
+                (None, '\'') | (None, '"') | (None, '`') => quote = Some(c),
+                (None, '(') => depth += 1,
+                (None, ')') => {
+                    depth -= 1;
+
+                    if depth == 0 {
+                        expression_end = Some(expression_start + idx);
+                        break;
+                    }
+                }
+                _ => (),
+            }
+        }
+
+        let expression_end = match expression_end {
+            Some(end) => end,
+            None => break,
+        };
+
+        let name = (1..=4)
+            .filter_map(|group| captures.get(group))
+            .map(|name| name.as_str().to_owned())
+            .next();
+
+        check_constraints.push(CheckConstraint {
+            name,
+            expression: create_table[expression_start..expression_end].trim().to_owned(),
+        });
+
+        search_from = expression_end + 1;
+    }
+
+    check_constraints
+}
+
 /// Returns whether a table is one of the SQLite system tables.
 fn is_system_table(table_name: &str) -> bool {
     SQLITE_SYSTEM_TABLES
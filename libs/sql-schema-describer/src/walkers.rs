@@ -3,8 +3,8 @@
 #![deny(missing_docs)]
 
 use crate::{
-    Column, ColumnArity, ColumnType, ColumnTypeFamily, DefaultValue, Enum, ForeignKey, ForeignKeyAction, Index,
-    IndexType, PrimaryKey, SqlSchema, Table, View,
+    CheckConstraint, Column, ColumnArity, ColumnType, ColumnTypeFamily, DefaultValue, Enum, ForeignKey,
+    ForeignKeyAction, Index, IndexType, PrimaryKey, SqlSchema, Table, View,
 };
 use serde::de::DeserializeOwned;
 use std::fmt;
@@ -165,6 +165,11 @@ impl<'a> ViewWalker<'a> {
         &self.view().definition
     }
 
+    /// The columns of the view.
+    pub fn columns(&self) -> &'a [Column] {
+        &self.view().columns
+    }
+
     /// The index of the view in the schema.
     pub fn view_index(&self) -> usize {
         self.view_index
@@ -224,6 +229,11 @@ impl<'a> TableWalker<'a> {
         })
     }
 
+    /// The check constraints on the table.
+    pub fn check_constraints(&self) -> &'a [CheckConstraint] {
+        &self.table().check_constraints
+    }
+
     /// The number of foreign key constraints on the table.
     pub fn foreign_key_count(&self) -> usize {
         self.table().foreign_keys.len()
@@ -287,6 +297,11 @@ impl<'a> TableWalker<'a> {
         &self.table().name
     }
 
+    /// The database schema of the table, if it was qualified with one.
+    pub fn schema(&self) -> Option<&'a str> {
+        self.table().schema.as_deref()
+    }
+
     /// Try to traverse a foreign key for a single column.
     pub fn foreign_key_for_column(&self, column: &str) -> Option<&'a ForeignKey> {
         self.table().foreign_key_for_column(column)
@@ -393,14 +408,20 @@ impl<'schema> ForeignKeyWalker<'schema> {
 
     /// The table the foreign key "points to".
     pub fn referenced_table(&self) -> TableWalker<'schema> {
+        let foreign_key = self.foreign_key();
+
+        let table_index = self
+            .schema
+            .tables
+            .iter()
+            .position(|table| {
+                table.name == foreign_key.referenced_table && table.schema == foreign_key.referenced_schema
+            })
+            .unwrap_or_else(|| panic!("Foreign key references unknown table. {:?}", self));
+
         TableWalker {
             schema: self.schema,
-            table_index: self
-                .schema
-                .table_walker(&self.foreign_key().referenced_table)
-                .ok_or_else(|| format!("Foreign key references unknown table. {:?}", self))
-                .unwrap()
-                .table_index,
+            table_index,
         }
     }
 
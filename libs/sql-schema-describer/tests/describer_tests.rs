@@ -164,9 +164,12 @@ async fn foreign_keys_must_work(api: &TestApi) {
                 columns: vec!["city".to_string()],
                 referenced_columns: vec!["id".to_string()],
                 referenced_table: "City".to_string(),
+                referenced_schema: None,
                 on_delete_action,
                 on_update_action: ForeignKeyAction::NoAction,
             }],
+            schema: None,
+            check_constraints: vec![],
         }
     );
 }
@@ -271,9 +274,12 @@ async fn multi_column_foreign_keys_must_work(api: &TestApi) {
                 columns: vec!["city_name".to_string(), "city".to_string()],
                 referenced_columns: vec!["name".to_string(), "id".to_string(),],
                 referenced_table: "City".to_string(),
+                referenced_schema: None,
                 on_delete_action,
                 on_update_action: ForeignKeyAction::NoAction,
             },],
+            schema: None,
+            check_constraints: vec![],
         }
     );
 }
@@ -378,6 +384,8 @@ async fn composite_primary_keys_must_work(api: &TestApi) {
                 }
             }),
             foreign_keys: vec![],
+            schema: None,
+            check_constraints: vec![],
         }
     );
 }
@@ -561,6 +569,8 @@ async fn column_uniqueness_must_be_detected(api: &TestApi) {
                     indices: expected_indices,
                     primary_key: None,
                     foreign_keys: vec![],
+                    schema: None,
+                    check_constraints: vec![],
                 }
             );
         }
@@ -588,6 +588,7 @@ async fn mssql_foreign_key_on_delete_must_be_handled() {
                     columns: vec!["city".to_string()],
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
+                    referenced_schema: None,
                     on_update_action: ForeignKeyAction::NoAction,
                     on_delete_action: ForeignKeyAction::NoAction,
                 },
@@ -596,10 +597,13 @@ async fn mssql_foreign_key_on_delete_must_be_handled() {
                     columns: vec!["city_cascade".to_string()],
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
+                    referenced_schema: None,
                     on_update_action: ForeignKeyAction::Cascade,
                     on_delete_action: ForeignKeyAction::Cascade,
                 },
             ],
+            schema: None,
+            check_constraints: vec![],
         }
     );
 }
@@ -599,6 +599,8 @@ async fn all_mysql_column_types_must_work() {
                 constraint_name: None,
             }),
             foreign_keys: vec![],
+            schema: None,
+            check_constraints: vec![],
         }
     );
 }
@@ -720,6 +722,7 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
                     columns: vec!["city".to_string()],
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
+                    referenced_schema: None,
                     on_delete_action: ForeignKeyAction::NoAction,
                     on_update_action: ForeignKeyAction::NoAction,
                 },
@@ -728,6 +731,7 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
                     columns: vec!["city_cascade".to_string()],
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
+                    referenced_schema: None,
                     on_delete_action: ForeignKeyAction::Cascade,
                     on_update_action: ForeignKeyAction::NoAction,
                 },
@@ -736,6 +740,7 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
                     columns: vec!["city_restrict".to_string()],
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
+                    referenced_schema: None,
                     on_delete_action: ForeignKeyAction::Restrict,
                     on_update_action: ForeignKeyAction::NoAction,
                 },
@@ -744,10 +749,13 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
                     columns: vec!["city_set_null".to_string()],
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
+                    referenced_schema: None,
                     on_delete_action: ForeignKeyAction::SetNull,
                     on_update_action: ForeignKeyAction::NoAction,
                 },
             ],
+            schema: None,
+            check_constraints: vec![],
         }
     );
 }
@@ -850,6 +858,7 @@ async fn constraints_from_other_databases_should_not_be_introspected() {
             constraint_name: Some("Post_ibfk_1".into()),
             columns: vec!["user_id".into()],
             referenced_table: "User".into(),
+            referenced_schema: None,
             referenced_columns: vec!["id".into()],
             on_delete_action: ForeignKeyAction::Cascade,
             on_update_action: ForeignKeyAction::NoAction,
@@ -882,6 +891,7 @@ async fn constraints_from_other_databases_should_not_be_introspected() {
             constraint_name: Some("Post_ibfk_1".into()),
             columns: vec!["user_id".into()],
             referenced_table: "User".into(),
+            referenced_schema: None,
             referenced_columns: vec!["id".into()],
             on_delete_action: ForeignKeyAction::Restrict,
             on_update_action: ForeignKeyAction::NoAction,
@@ -998,3 +1008,34 @@ async fn escaped_backslashes_in_string_literals_must_be_unescaped(api: &TestApi)
 
     Ok(())
 }
+
+#[test_each_connector(tags("mysql"))]
+async fn fulltext_indexes_must_be_described(api: &TestApi) -> TestResult {
+    let create_table = format!(
+        r#"
+            CREATE TABLE `{0}`.`Post` (
+                `id` INTEGER PRIMARY KEY,
+                `title` VARCHAR(200) NOT NULL,
+                `content` TEXT NOT NULL,
+                FULLTEXT INDEX `post_search` (`title`, `content`)
+            );
+        "#,
+        api.schema_name()
+    );
+
+    api.database().query_raw(&create_table, &[]).await?;
+
+    let schema = api.describe().await?;
+    let table = schema.table_bang("Post");
+
+    assert_eq!(
+        table.indices,
+        &[Index {
+            name: "post_search".into(),
+            columns: vec!["title".to_owned(), "content".to_owned()],
+            tpe: IndexType::Fulltext,
+        }]
+    );
+
+    Ok(())
+}
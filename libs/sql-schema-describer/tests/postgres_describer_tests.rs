@@ -588,6 +588,8 @@ async fn all_postgres_column_types_must_work() {
                 constraint_name: Some("User_pkey".into()),
             }),
             foreign_keys: vec![],
+            schema: None,
+            check_constraints: vec![],
         }
     );
 }
@@ -724,6 +726,7 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                     columns: vec!["city".into()],
                     referenced_columns: vec!["id".into()],
                     referenced_table: "City".into(),
+                    referenced_schema: None,
                     on_update_action: ForeignKeyAction::NoAction,
                     on_delete_action: ForeignKeyAction::NoAction,
                 },
@@ -732,6 +735,7 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                     columns: vec!["city_cascade".into()],
                     referenced_columns: vec!["id".into()],
                     referenced_table: "City".into(),
+                    referenced_schema: None,
                     on_update_action: ForeignKeyAction::NoAction,
                     on_delete_action: ForeignKeyAction::Cascade,
                 },
@@ -740,6 +744,7 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                     columns: vec!["city_restrict".into()],
                     referenced_columns: vec!["id".into()],
                     referenced_table: "City".into(),
+                    referenced_schema: None,
                     on_update_action: ForeignKeyAction::NoAction,
                     on_delete_action: ForeignKeyAction::Restrict,
                 },
@@ -748,6 +753,7 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                     columns: vec!["city_set_default".into()],
                     referenced_columns: vec!["id".into()],
                     referenced_table: "City".into(),
+                    referenced_schema: None,
                     on_update_action: ForeignKeyAction::NoAction,
                     on_delete_action: ForeignKeyAction::SetDefault,
                 },
@@ -756,10 +762,13 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                     columns: vec!["city_set_null".into()],
                     referenced_columns: vec!["id".into()],
                     referenced_table: "City".into(),
+                    referenced_schema: None,
                     on_update_action: ForeignKeyAction::NoAction,
                     on_delete_action: ForeignKeyAction::SetNull,
                 },
             ],
+            schema: None,
+            check_constraints: vec![],
         }
     );
 }
@@ -126,6 +126,8 @@ async fn sqlite_column_types_must_work() {
                 constraint_name: None,
             }),
             foreign_keys: vec![],
+            schema: None,
+            check_constraints: vec![],
         }
     );
 }
@@ -232,6 +234,7 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                     columns: vec!["city".to_string()],
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
+                    referenced_schema: None,
                     on_update_action: ForeignKeyAction::NoAction,
                     on_delete_action: ForeignKeyAction::NoAction,
                 },
@@ -240,6 +243,7 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                     columns: vec!["city_cascade".to_string()],
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
+                    referenced_schema: None,
                     on_update_action: ForeignKeyAction::NoAction,
                     on_delete_action: ForeignKeyAction::Cascade,
                 },
@@ -248,6 +252,7 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                     columns: vec!["city_restrict".to_string()],
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
+                    referenced_schema: None,
                     on_update_action: ForeignKeyAction::NoAction,
                     on_delete_action: ForeignKeyAction::Restrict,
                 },
@@ -256,6 +261,7 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                     columns: vec!["city_set_default".to_string()],
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
+                    referenced_schema: None,
                     on_update_action: ForeignKeyAction::NoAction,
                     on_delete_action: ForeignKeyAction::SetDefault,
                 },
@@ -264,10 +270,13 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                     columns: vec!["city_set_null".to_string()],
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
+                    referenced_schema: None,
                     on_update_action: ForeignKeyAction::NoAction,
                     on_delete_action: ForeignKeyAction::SetNull,
                 },
             ],
+            schema: None,
+            check_constraints: vec![],
         }
     );
 }
@@ -384,3 +393,38 @@ async fn escaped_backslashes_in_string_literals_must_be_unescaped(api: &TestApi)
 
     Ok(())
 }
+
+#[test_each_connector(tags("sqlite"))]
+async fn check_constraints_are_described(api: &TestApi) -> TestResult {
+    let create_table = format!(
+        r#"
+            CREATE TABLE "{0}"."products" (
+                id INTEGER PRIMARY KEY,
+                price INTEGER NOT NULL CHECK (price > 0),
+                discount INTEGER NOT NULL,
+                CONSTRAINT "discount_below_price" CHECK ("discount" < (price - 1))
+            );
+        "#,
+        api.schema_name()
+    );
+
+    api.database().query_raw(&create_table, &[]).await?;
+
+    let schema = api.describe().await?;
+    let table = schema.table_bang("products");
+
+    let expected = vec![
+        CheckConstraint {
+            name: None,
+            expression: "price > 0".into(),
+        },
+        CheckConstraint {
+            name: Some("discount_below_price".into()),
+            expression: "\"discount\" < (price - 1)".into(),
+        },
+    ];
+
+    assert_eq!(table.check_constraints, expected);
+
+    Ok(())
+}
@@ -5,6 +5,7 @@ use once_cell::sync::Lazy;
 #[repr(u8)]
 pub enum Features {
     Other = 0b1,
+    NoForeignKeys = 0b10,
 }
 
 impl Features {
@@ -39,4 +40,5 @@ impl std::fmt::Display for UnknownFeatureError {
 impl std::error::Error for UnknownFeatureError {}
 
 /// All the features, sorted by name.
-static FEATURE_NAMES: Lazy<Vec<(&str, BitFlags<Features>)>> = Lazy::new(Vec::new);
+static FEATURE_NAMES: Lazy<Vec<(&str, BitFlags<Features>)>> =
+    Lazy::new(|| vec![("noForeignKeys", BitFlags::from(Features::NoForeignKeys))]);
@@ -267,3 +267,9 @@ pub struct PoolTimeout {
 pub struct RecordRequiredButNotFound {
     pub cause: String,
 }
+
+#[derive(Debug, UserFacingError, Serialize)]
+#[user_facing(code = "P2026", message = "Transaction API error: {error}")]
+pub struct InteractiveTransactionError {
+    pub error: String,
+}
@@ -17,6 +17,11 @@ pub trait DatabaseMigrationInferrer<T>: Send + Sync {
     /// database as a starting point.
     fn infer_from_empty(&self, next: &Datamodel) -> ConnectorResult<T>;
 
+    /// Infer the database migration reverting the passed in migration, taking
+    /// the database from the migration's target schema back to its starting
+    /// point.
+    fn infer_down_migration(&self, migration: &T) -> T;
+
     /// Look at the previous migrations and the target Prisma schema, and infer
     /// a database migration taking the database to the target Prisma schema.
     async fn infer_next_migration(
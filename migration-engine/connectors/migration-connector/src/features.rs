@@ -4,8 +4,18 @@ use datamodel::Configuration;
 use enumflags2::BitFlags;
 
 /// Parse features from data model configuration.
-pub fn from_config(_config: &Configuration) -> BitFlags<MigrationFeature> {
-    BitFlags::empty()
+pub fn from_config(config: &Configuration) -> BitFlags<MigrationFeature> {
+    let mut features = BitFlags::empty();
+
+    if config
+        .datasources
+        .iter()
+        .any(|datasource| datasource.emulates_referential_actions())
+    {
+        features.insert(MigrationFeature::NoForeignKeys);
+    }
+
+    features
 }
 
 #[derive(BitFlags, Copy, Clone, Debug, PartialEq)]
@@ -14,4 +24,7 @@ pub fn from_config(_config: &Configuration) -> BitFlags<MigrationFeature> {
 pub enum MigrationFeature {
     /// Placeholder.
     Other = 0b1,
+    /// Never create foreign keys, because Prisma emulates the referential integrity of relations
+    /// (`referentialIntegrity = "prisma"` on the datasource).
+    NoForeignKeys = 0b10,
 }
@@ -4,6 +4,7 @@
 //! migrations directory. At the top level it contains a migration_lock.toml file which lists the provider.
 //! It also contains multiple subfolders, named after the migration id, and each containing:
 //! - A migration script
+//! - Optionally, a down migration script reverting it
 
 use crate::{ConnectorError, ConnectorResult, FormatChecksum};
 use sha2::{Digest, Sha256, Sha512};
@@ -20,6 +21,9 @@ use user_facing_errors::migration_engine::ProviderSwitchedError;
 /// The file name for migration scripts, not including the file extension.
 pub const MIGRATION_SCRIPT_FILENAME: &str = "migration";
 
+/// The file name for down migration scripts, not including the file extension.
+pub const DOWN_MIGRATION_SCRIPT_FILENAME: &str = "down";
+
 /// The file name for the migration lock file, not including the file extension.
 pub const MIGRATION_LOCK_FILENAME: &str = "migration_lock";
 
@@ -218,7 +222,17 @@ impl MigrationDirectory {
     /// Write the migration script to the directory.
     #[tracing::instrument]
     pub fn write_migration_script(&self, script: &str, extension: &str) -> std::io::Result<()> {
-        let mut path = self.path.join(MIGRATION_SCRIPT_FILENAME);
+        self.write_script(MIGRATION_SCRIPT_FILENAME, script, extension)
+    }
+
+    /// Write the down migration script, reverting the migration, to the directory.
+    #[tracing::instrument]
+    pub fn write_down_migration_script(&self, script: &str, extension: &str) -> std::io::Result<()> {
+        self.write_script(DOWN_MIGRATION_SCRIPT_FILENAME, script, extension)
+    }
+
+    fn write_script(&self, file_name: &str, script: &str, extension: &str) -> std::io::Result<()> {
+        let mut path = self.path.join(file_name);
 
         path.set_extension(extension);
 
@@ -237,6 +251,22 @@ impl MigrationDirectory {
         Ok(std::fs::read_to_string(&path).map_err(|ioerr| ReadMigrationScriptError::new(ioerr, &path))?)
     }
 
+    /// Read the down migration script with the given file extension to a
+    /// string. Returns `None` if the migration has no down migration script,
+    /// for example because it was created before down migrations were
+    /// generated.
+    #[tracing::instrument]
+    pub fn read_down_migration_script(&self, extension: &str) -> Result<Option<String>, ReadMigrationScriptError> {
+        let mut path = self.path.join(DOWN_MIGRATION_SCRIPT_FILENAME);
+        path.set_extension(extension);
+
+        match std::fs::read_to_string(&path) {
+            Ok(script) => Ok(Some(script)),
+            Err(err) if matches!(err.kind(), io::ErrorKind::NotFound) => Ok(None),
+            Err(err) => Err(ReadMigrationScriptError::new(err, &path)),
+        }
+    }
+
     /// The filesystem path to the directory.
     pub fn path(&self) -> &Path {
         &self.path
@@ -21,7 +21,7 @@ use datamodel::Datamodel;
 use enumflags2::BitFlags;
 use migration_connector::{ConnectorResult, MigrationDirectory, MigrationFeature};
 use quaint::prelude::{ConnectionInfo, Table};
-use sql_schema_describer::SqlSchema;
+use sql_schema_describer::{DescriberResult, SqlSchema, SqlSchemaDescriberBackend};
 use std::fmt::Debug;
 
 /// The maximum size of identifiers on MySQL, in bytes.
@@ -50,6 +50,43 @@ pub(crate) fn from_connection_info(
     }
 }
 
+/// Describe the connection's schema together with the database schemas from the `schemas`
+/// datasource property. Tables in the connection's schema, and foreign keys referencing them, are
+/// left unqualified, like the ones calculated from models without a `@@schema` attribute.
+async fn describe_schemas(
+    describer: &dyn SqlSchemaDescriberBackend,
+    connection_schema: &str,
+    schemas: &[String],
+) -> DescriberResult<SqlSchema> {
+    if schemas.is_empty() {
+        return describer.describe(connection_schema).await;
+    }
+
+    let mut all_schemas: Vec<&str> = vec![connection_schema];
+    all_schemas.extend(
+        schemas
+            .iter()
+            .map(String::as_str)
+            .filter(|schema| *schema != connection_schema),
+    );
+
+    let mut described = describer.describe_schemas(&all_schemas).await?;
+
+    for table in described.tables.iter_mut() {
+        if table.schema.as_deref() == Some(connection_schema) {
+            table.schema = None;
+        }
+
+        for foreign_key in table.foreign_keys.iter_mut() {
+            if foreign_key.referenced_schema.as_deref() == Some(connection_schema) {
+                foreign_key.referenced_schema = None;
+            }
+        }
+    }
+
+    Ok(described)
+}
+
 #[async_trait::async_trait]
 pub(crate) trait SqlFlavour:
     DestructiveChangeCheckerFlavour + SqlRenderer + SqlSchemaDifferFlavour + SqlSchemaCalculatorFlavour + Debug
@@ -110,4 +147,13 @@ pub(crate) trait SqlFlavour:
 
     /// Feature flags for the flavor
     fn features(&self) -> BitFlags<MigrationFeature>;
+
+    /// The database schema unqualified tables live in, on connectors with multi-schema support.
+    fn default_schema_name(&self) -> Option<&str> {
+        None
+    }
+
+    /// Set the database schemas the migration engine manages, from the `schemas` datasource
+    /// property. Only meaningful on connectors with multi-schema support.
+    fn set_schemas(&mut self, _schemas: Vec<String>) {}
 }
@@ -6,7 +6,7 @@ use enumflags2::BitFlags;
 use indoc::formatdoc;
 use migration_connector::{ConnectorError, ConnectorResult, MigrationDirectory, MigrationFeature};
 use quaint::{connector::MssqlUrl, prelude::Table};
-use sql_schema_describer::{DescriberErrorKind, SqlSchema, SqlSchemaDescriberBackend};
+use sql_schema_describer::{DescriberErrorKind, SqlSchema};
 use std::str::FromStr;
 use user_facing_errors::{introspection_engine::DatabaseSchemaInconsistent, KnownError};
 
@@ -14,11 +14,18 @@ use user_facing_errors::{introspection_engine::DatabaseSchemaInconsistent, Known
 pub(crate) struct MssqlFlavour {
     pub(crate) url: MssqlUrl,
     features: BitFlags<MigrationFeature>,
+    /// The database schemas from the `schemas` datasource property. Empty when only the
+    /// connection's schema is used.
+    schemas: Vec<String>,
 }
 
 impl MssqlFlavour {
     pub fn new(url: MssqlUrl, features: BitFlags<MigrationFeature>) -> Self {
-        Self { url, features }
+        Self {
+            url,
+            features,
+            schemas: Vec::new(),
+        }
     }
 
     pub(crate) fn schema_name(&self) -> &str {
@@ -138,21 +145,20 @@ impl SqlFlavour for MssqlFlavour {
     }
 
     async fn describe_schema<'a>(&'a self, connection: &Connection) -> ConnectorResult<SqlSchema> {
-        sql_schema_describer::mssql::SqlSchemaDescriber::new(connection.quaint().clone())
-            .describe(connection.connection_info().schema_name())
-            .await
-            .map_err(|err| match err.into_kind() {
-                DescriberErrorKind::QuaintError(err) => {
-                    quaint_error_to_connector_error(err, connection.connection_info())
-                }
-                e @ DescriberErrorKind::CrossSchemaReference { .. } => {
-                    let err = KnownError::new(DatabaseSchemaInconsistent {
-                        explanation: format!("{}", e),
-                    });
+        let describer = sql_schema_describer::mssql::SqlSchemaDescriber::new(connection.quaint().clone());
 
-                    ConnectorError::from(err)
-                }
-            })
+        let described = super::describe_schemas(&describer, self.schema_name(), &self.schemas).await;
+
+        described.map_err(|err| match err.into_kind() {
+            DescriberErrorKind::QuaintError(err) => quaint_error_to_connector_error(err, connection.connection_info()),
+            e @ DescriberErrorKind::CrossSchemaReference { .. } => {
+                let err = KnownError::new(DatabaseSchemaInconsistent {
+                    explanation: format!("{}", e),
+                });
+
+                ConnectorError::from(err)
+            }
+        })
     }
 
     async fn drop_database(&self, _database_url: &str) -> ConnectorResult<()> {
@@ -365,4 +371,12 @@ impl SqlFlavour for MssqlFlavour {
     fn features(&self) -> BitFlags<MigrationFeature> {
         self.features
     }
+
+    fn default_schema_name(&self) -> Option<&str> {
+        Some(self.schema_name())
+    }
+
+    fn set_schemas(&mut self, schemas: Vec<String>) {
+        self.schemas = schemas;
+    }
 }
@@ -5,7 +5,7 @@ use enumflags2::BitFlags;
 use indoc::indoc;
 use migration_connector::{ConnectorError, ConnectorResult, MigrationDirectory, MigrationFeature};
 use quaint::{connector::PostgresUrl, error::ErrorKind as QuaintKind};
-use sql_schema_describer::{DescriberErrorKind, SqlSchema, SqlSchemaDescriberBackend};
+use sql_schema_describer::{DescriberErrorKind, SqlSchema};
 use std::collections::HashMap;
 use url::Url;
 use user_facing_errors::{
@@ -19,11 +19,18 @@ const ADVISORY_LOCK_TIMEOUT: std::time::Duration = std::time::Duration::from_sec
 pub(crate) struct PostgresFlavour {
     pub(crate) url: PostgresUrl,
     features: BitFlags<MigrationFeature>,
+    /// The database schemas from the `schemas` datasource property. Empty when only the
+    /// connection's schema is used.
+    schemas: Vec<String>,
 }
 
 impl PostgresFlavour {
     pub fn new(url: PostgresUrl, features: BitFlags<MigrationFeature>) -> Self {
-        Self { url, features }
+        Self {
+            url,
+            features,
+            schemas: Vec::new(),
+        }
     }
 
     pub(crate) fn schema_name(&self) -> &str {
@@ -163,21 +170,20 @@ impl SqlFlavour for PostgresFlavour {
     }
 
     async fn describe_schema<'a>(&'a self, connection: &Connection) -> ConnectorResult<SqlSchema> {
-        sql_schema_describer::postgres::SqlSchemaDescriber::new(connection.quaint().clone())
-            .describe(connection.connection_info().schema_name())
-            .await
-            .map_err(|err| match err.into_kind() {
-                DescriberErrorKind::QuaintError(err) => {
-                    quaint_error_to_connector_error(err, connection.connection_info())
-                }
-                e @ DescriberErrorKind::CrossSchemaReference { .. } => {
-                    let err = KnownError::new(DatabaseSchemaInconsistent {
-                        explanation: format!("{}", e),
-                    });
+        let describer = sql_schema_describer::postgres::SqlSchemaDescriber::new(connection.quaint().clone());
 
-                    ConnectorError::from(err)
-                }
-            })
+        let described = super::describe_schemas(&describer, self.schema_name(), &self.schemas).await;
+
+        described.map_err(|err| match err.into_kind() {
+            DescriberErrorKind::QuaintError(err) => quaint_error_to_connector_error(err, connection.connection_info()),
+            e @ DescriberErrorKind::CrossSchemaReference { .. } => {
+                let err = KnownError::new(DatabaseSchemaInconsistent {
+                    explanation: format!("{}", e),
+                });
+
+                ConnectorError::from(err)
+            }
+        })
     }
 
     async fn drop_database(&self, database_str: &str) -> ConnectorResult<()> {
@@ -321,6 +327,14 @@ impl SqlFlavour for PostgresFlavour {
     fn features(&self) -> BitFlags<MigrationFeature> {
         self.features
     }
+
+    fn default_schema_name(&self) -> Option<&str> {
+        Some(self.schema_name())
+    }
+
+    fn set_schemas(&mut self, schemas: Vec<String>) {
+        self.schemas = schemas;
+    }
 }
 
 fn strip_schema_param_from_url(url: &mut Url) {
@@ -54,6 +54,12 @@ impl SqlMigrationConnector {
         })
     }
 
+    /// Manage the given database schemas instead of only the one from the connection string.
+    pub fn with_schemas(mut self, schemas: Vec<String>) -> Self {
+        self.flavour.set_schemas(schemas);
+        self
+    }
+
     /// Create the database corresponding to the connection string, without initializing the connector.
     pub async fn create_database(database_str: &str) -> ConnectorResult<String> {
         let connection_info =
@@ -22,6 +22,11 @@ impl DatabaseMigrationInferrer<SqlMigration> for SqlMigrationConnector {
         Ok(infer(current_database_schema, expected_database_schema, self.flavour()))
     }
 
+    /// Diff the migration's schemas in reverse.
+    fn infer_down_migration(&self, migration: &SqlMigration) -> SqlMigration {
+        infer(migration.after.clone(), migration.before.clone(), self.flavour())
+    }
+
     #[tracing::instrument(skip(self, previous_migrations, target_schema))]
     async fn infer_next_migration(
         &self,
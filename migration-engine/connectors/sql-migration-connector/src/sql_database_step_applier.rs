@@ -135,7 +135,7 @@ fn render_raw_sql(
             vec![renderer.render_create_table(&table)]
         }
         SqlMigrationStep::DropTable(DropTable { table_index }) => {
-            renderer.render_drop_table(schemas.previous().table_walker_at(*table_index).name())
+            renderer.render_drop_table(&schemas.previous().table_walker_at(*table_index))
         }
         SqlMigrationStep::RedefineIndex { table, index } => {
             renderer.render_drop_and_recreate_index(schemas.tables(table).indexes(index).as_ref())
@@ -172,6 +172,26 @@ fn render_raw_sql(
         SqlMigrationStep::AlterIndex { table, index } => {
             renderer.render_alter_index(schemas.tables(table).indexes(index).as_ref())
         }
+        SqlMigrationStep::AddCheckConstraint(add_check_constraint) => {
+            let table = schemas.next().table_walker_at(add_check_constraint.table_index);
+            let check_constraint = &table.check_constraints()[add_check_constraint.check_constraint_index];
+
+            vec![renderer.render_add_check_constraint(&table, check_constraint)]
+        }
+        SqlMigrationStep::DropCheckConstraint(drop_check_constraint) => {
+            let table = schemas.previous().table_walker_at(drop_check_constraint.table_index);
+            let check_constraint = &table.check_constraints()[drop_check_constraint.check_constraint_index];
+
+            vec![renderer.render_drop_check_constraint(&table, check_constraint)]
+        }
+        SqlMigrationStep::CreateSchema(create_schema) => {
+            vec![renderer.render_create_schema(&create_schema.schema_name)]
+        }
+        SqlMigrationStep::CreateView(create_view) => {
+            let view = schemas.next().view_walker_at(create_view.view_index);
+
+            vec![renderer.render_create_view(&view)]
+        }
         SqlMigrationStep::DropView(drop_view) => {
             let view = schemas.previous().view_walker_at(drop_view.view_index);
 
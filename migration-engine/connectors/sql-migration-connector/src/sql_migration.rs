@@ -39,7 +39,11 @@ pub(crate) enum SqlMigrationStep {
     CreateEnum(CreateEnum),
     DropEnum(DropEnum),
     AlterEnum(AlterEnum),
+    CreateView(CreateView),
     DropView(DropView),
+    CreateSchema(CreateSchema),
+    AddCheckConstraint(AddCheckConstraint),
+    DropCheckConstraint(DropCheckConstraint),
 }
 
 impl SqlMigrationStep {
@@ -58,7 +62,11 @@ impl SqlMigrationStep {
             SqlMigrationStep::CreateEnum(_) => "CreateEnum",
             SqlMigrationStep::DropEnum(_) => "DropEnum",
             SqlMigrationStep::AlterEnum(_) => "AlterEnum",
+            SqlMigrationStep::CreateView(_) => "CreateView",
             SqlMigrationStep::DropView(_) => "DropView",
+            SqlMigrationStep::CreateSchema(_) => "CreateSchema",
+            SqlMigrationStep::AddCheckConstraint(_) => "AddCheckConstraint",
+            SqlMigrationStep::DropCheckConstraint(_) => "DropCheckConstraint",
         }
     }
 }
@@ -106,6 +114,31 @@ impl TableChange {
     }
 }
 
+#[derive(Debug)]
+pub(crate) struct AddCheckConstraint {
+    /// Index in the next schema.
+    pub table_index: usize,
+    pub check_constraint_index: usize,
+}
+
+#[derive(Debug)]
+pub(crate) struct DropCheckConstraint {
+    /// Index in the previous schema.
+    pub table_index: usize,
+    pub check_constraint_index: usize,
+}
+
+#[derive(Debug)]
+pub(crate) struct CreateSchema {
+    pub schema_name: String,
+}
+
+#[derive(Debug, Copy, Clone)]
+pub(crate) struct CreateView {
+    /// Index in the next schema.
+    pub view_index: usize,
+}
+
 #[derive(Debug, Copy, Clone)]
 pub(crate) struct DropView {
     pub view_index: usize,
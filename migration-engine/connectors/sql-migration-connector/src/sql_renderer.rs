@@ -23,7 +23,7 @@ use crate::{
 use common::Quoted;
 use sql_schema_describer::{
     walkers::{EnumWalker, ForeignKeyWalker, IndexWalker, TableWalker, ViewWalker},
-    SqlSchema,
+    CheckConstraint, SqlSchema,
 };
 
 pub(crate) trait SqlRenderer {
@@ -31,6 +31,27 @@ pub(crate) trait SqlRenderer {
 
     fn render_add_foreign_key(&self, foreign_key: &ForeignKeyWalker<'_>) -> String;
 
+    /// Render an `AddCheckConstraint` step.
+    fn render_add_check_constraint(&self, table: &TableWalker<'_>, check_constraint: &CheckConstraint) -> String {
+        format!(
+            "ALTER TABLE {} ADD {}",
+            self.quote(table.name()),
+            self.render_check_constraint(check_constraint)
+        )
+    }
+
+    /// Render a check constraint definition. Unnamed constraints are named by the database.
+    fn render_check_constraint(&self, check_constraint: &CheckConstraint) -> String {
+        match &check_constraint.name {
+            Some(name) => format!(
+                "CONSTRAINT {} CHECK ({})",
+                self.quote(name),
+                check_constraint.expression
+            ),
+            None => format!("CHECK ({})", check_constraint.expression),
+        }
+    }
+
     fn render_alter_enum(&self, alter_enum: &AlterEnum, schemas: &Pair<&SqlSchema>) -> Vec<String>;
 
     fn render_alter_index(&self, _indexes: Pair<&IndexWalker<'_>>) -> Vec<String> {
@@ -44,6 +65,11 @@ pub(crate) trait SqlRenderer {
 
     fn render_create_index(&self, index: &IndexWalker<'_>) -> String;
 
+    /// Render a `CreateSchema` step.
+    fn render_create_schema(&self, schema_name: &str) -> String {
+        format!("CREATE SCHEMA IF NOT EXISTS {}", self.quote(schema_name))
+    }
+
     /// Render a table creation step.
     fn render_create_table(&self, table: &TableWalker<'_>) -> String {
         self.render_create_table_as(table, table.name())
@@ -56,6 +82,15 @@ pub(crate) trait SqlRenderer {
         unreachable!("unreachable render_drop_and_recreate_index")
     }
 
+    /// Render a `DropCheckConstraint` step.
+    fn render_drop_check_constraint(&self, table: &TableWalker<'_>, check_constraint: &CheckConstraint) -> String {
+        format!(
+            "ALTER TABLE {} DROP CONSTRAINT {}",
+            self.quote(table.name()),
+            self.quote(check_constraint.name.as_deref().unwrap())
+        )
+    }
+
     /// Render a `DropEnum` step.
     fn render_drop_enum(&self, dropped_enum: &EnumWalker<'_>) -> Vec<String>;
 
@@ -66,8 +101,8 @@ pub(crate) trait SqlRenderer {
     fn render_drop_index(&self, index: &IndexWalker<'_>) -> String;
 
     /// Render a `DropTable` step.
-    fn render_drop_table(&self, table_name: &str) -> Vec<String> {
-        vec![format!("DROP TABLE {}", self.quote(&table_name))]
+    fn render_drop_table(&self, table: &TableWalker<'_>) -> Vec<String> {
+        vec![format!("DROP TABLE {}", self.quote(table.name()))]
     }
 
     /// Render a `RedefineTables` step.
@@ -76,5 +111,21 @@ pub(crate) trait SqlRenderer {
     /// Render a table renaming step.
     fn render_rename_table(&self, name: &str, new_name: &str) -> String;
 
+    /// Render a `CreateView` step. Definitions that already are a full `CREATE VIEW` statement are
+    /// rendered as-is.
+    fn render_create_view(&self, view: &ViewWalker<'_>) -> String {
+        render_create_view_as(view, &self.quote(view.name()))
+    }
+
     fn render_drop_view(&self, view: &ViewWalker<'_>) -> String;
 }
+
+fn render_create_view_as(view: &ViewWalker<'_>, quoted_name: &dyn std::fmt::Display) -> String {
+    let definition = view.definition().trim().trim_end_matches(';').trim_end();
+
+    if definition.to_uppercase().starts_with("CREATE") {
+        definition.to_owned()
+    } else {
+        format!("CREATE VIEW {} AS {}", quoted_name, definition)
+    }
+}
@@ -1,6 +1,6 @@
 mod alter_table;
 
-use super::common::render_on_delete;
+use super::common::{render_on_delete, render_on_update};
 use super::{common, IteratorJoin, Quoted, SqlRenderer};
 use crate::{
     flavour::MssqlFlavour,
@@ -12,7 +12,7 @@ use native_types::{MsSqlType, MsSqlTypeParameter};
 use prisma_value::PrismaValue;
 use sql_schema_describer::{
     walkers::{ColumnWalker, EnumWalker, ForeignKeyWalker, IndexWalker, TableWalker, ViewWalker},
-    ColumnTypeFamily, DefaultKind, DefaultValue, IndexType, SqlSchema,
+    CheckConstraint, ColumnTypeFamily, DefaultKind, DefaultValue, IndexType, SqlSchema,
 };
 use std::{
     borrow::Cow,
@@ -39,6 +39,29 @@ impl MssqlFlavour {
         }
     }
 
+    /// Quote the table name with the table's database schema, or the default one when it has none.
+    fn quote_table<'a>(&'a self, table: &TableWalker<'a>) -> QuotedWithSchema<'a> {
+        self.quote_in_table_schema(table, table.name())
+    }
+
+    /// Quote a name with the database schema of the given table.
+    fn quote_in_table_schema<'a>(&'a self, table: &TableWalker<'a>, name: &'a str) -> QuotedWithSchema<'a> {
+        QuotedWithSchema {
+            schema_name: table.schema().unwrap_or_else(|| self.schema_name()),
+            name,
+        }
+    }
+
+    fn render_rename_table_in(&self, schema_name: &str, name: &str, new_name: &str) -> String {
+        let with_schema = format!("{}.{}", schema_name, name);
+
+        format!(
+            "EXEC SP_RENAME N{}, N{}",
+            Quoted::Single(with_schema),
+            Quoted::Single(new_name),
+        )
+    }
+
     fn render_column(&self, column: &ColumnWalker<'_>) -> String {
         let column_name = self.quote(column.name());
 
@@ -95,10 +118,11 @@ impl MssqlFlavour {
             .join(",");
 
         format!(
-            " REFERENCES {}({}) {} ON UPDATE CASCADE",
-            self.quote_with_schema(&foreign_key.referenced_table().name()),
+            " REFERENCES {}({}) {} {}",
+            self.quote_table(&foreign_key.referenced_table()),
             cols,
             render_on_delete(&foreign_key.on_delete_action()),
+            render_on_update(&foreign_key.on_update_action()),
         )
     }
 }
@@ -142,11 +166,12 @@ impl SqlRenderer for MssqlFlavour {
         let index_type = match index.index_type() {
             IndexType::Unique => "UNIQUE ",
             IndexType::Normal => "",
+            IndexType::Fulltext => unreachable!("Fulltext index on Microsoft SQL Server"),
         };
 
         let index_name = index.name().replace('.', "_");
         let index_name = self.quote(&index_name);
-        let table_reference = self.quote_with_schema(index.table().name()).to_string();
+        let table_reference = self.quote_table(&index.table()).to_string();
 
         let columns = index.columns().map(|c| self.quote(c.name()));
 
@@ -201,12 +226,17 @@ impl SqlRenderer for MssqlFlavour {
             String::new()
         };
 
+        let constraints = table.check_constraints().iter().fold(constraints, |mut acc, check| {
+            acc.push_str(&format!(",\n    {}", self.render_check_constraint(check)));
+            acc
+        });
+
         formatdoc!(
             r#"
             CREATE TABLE {table_name} (
                 {columns}{primary_key}{constraints}
             )"#,
-            table_name = self.quote_with_schema(table_name),
+            table_name = self.quote_in_table_schema(table, table_name),
             columns = columns,
             primary_key = primary_key,
             constraints = constraints,
@@ -217,24 +247,32 @@ impl SqlRenderer for MssqlFlavour {
         unreachable!("render_drop_enum on MSSQL")
     }
 
+    fn render_drop_check_constraint(&self, table: &TableWalker<'_>, check_constraint: &CheckConstraint) -> String {
+        format!(
+            "ALTER TABLE {} DROP CONSTRAINT {}",
+            self.quote_table(table),
+            self.quote(check_constraint.name.as_deref().unwrap())
+        )
+    }
+
     fn render_drop_foreign_key(&self, foreign_key: &ForeignKeyWalker<'_>) -> String {
         format!(
             "ALTER TABLE {table} DROP CONSTRAINT {constraint_name}",
-            table = self.quote_with_schema(foreign_key.table().name()),
+            table = self.quote_table(&foreign_key.table()),
             constraint_name = Quoted::mssql_ident(foreign_key.constraint_name().unwrap()),
         )
     }
 
     fn render_drop_index(&self, index: &IndexWalker<'_>) -> String {
         match index.index_type() {
-            IndexType::Normal => format!(
+            IndexType::Normal | IndexType::Fulltext => format!(
                 "DROP INDEX {} ON {}",
                 self.quote(index.name()),
-                self.quote_with_schema(index.table().name())
+                self.quote_table(&index.table())
             ),
             IndexType::Unique => format!(
                 "ALTER TABLE {} DROP CONSTRAINT {}",
-                self.quote_with_schema(index.table().name()),
+                self.quote_table(&index.table()),
                 self.quote(index.name()),
             ),
         }
@@ -300,7 +338,7 @@ impl SqlRenderer for MssqlFlavour {
                     AND OBJECT_NAME(PARENT_OBJECT_ID) = '{table}'
                     AND SCHEMA_NAME(SCHEMA_ID) = '{schema}'
                 EXEC sp_executesql @SQL
-            "#, table = tables.previous().name(), schema = self.schema_name()});
+            "#, table = tables.previous().name(), schema = tables.previous().schema().unwrap_or_else(|| self.schema_name())});
 
             // Create the new table.
             result.push(self.render_create_table_as(tables.next(), &temporary_table_name));
@@ -311,7 +349,7 @@ impl SqlRenderer for MssqlFlavour {
             if needs_autoincrement {
                 result.push(format!(
                     r#"SET IDENTITY_INSERT {} ON"#,
-                    self.quote_with_schema(&temporary_table_name)
+                    self.quote_in_table_schema(tables.next(), &temporary_table_name)
                 ));
             }
 
@@ -320,23 +358,27 @@ impl SqlRenderer for MssqlFlavour {
                 IF EXISTS(SELECT * FROM {table})
                     EXEC('INSERT INTO {tmp_table} ({columns}) SELECT {columns} FROM {table} WITH (holdlock tablockx)')"#,
                 columns = columns.join(","),
-                table = self.quote_with_schema(tables.previous().name()),
-                tmp_table = self.quote_with_schema(&temporary_table_name),
+                table = self.quote_table(tables.previous()),
+                tmp_table = self.quote_in_table_schema(tables.next(), &temporary_table_name),
             });
 
             // When done copying, disallow identity inserts again if needed.
             if needs_autoincrement {
                 result.push(format!(
                     r#"SET IDENTITY_INSERT {} OFF"#,
-                    self.quote_with_schema(&temporary_table_name)
+                    self.quote_in_table_schema(tables.next(), &temporary_table_name)
                 ));
             }
 
             // Drop the old, now empty table.
-            result.extend(self.render_drop_table(tables.previous().name()));
+            result.extend(self.render_drop_table(tables.previous()));
 
             // Rename the temporary table with the name defined in the migration.
-            result.push(self.render_rename_table(&temporary_table_name, tables.next().name()));
+            result.push(self.render_rename_table_in(
+                tables.next().schema().unwrap_or_else(|| self.schema_name()),
+                &temporary_table_name,
+                tables.next().name(),
+            ));
 
             // Recreating all foreign keys pointing to this table
             for fk in tables.next().referencing_foreign_keys() {
@@ -355,12 +397,14 @@ impl SqlRenderer for MssqlFlavour {
     }
 
     fn render_rename_table(&self, name: &str, new_name: &str) -> String {
-        let with_schema = format!("{}.{}", self.schema_name(), name);
+        self.render_rename_table_in(self.schema_name(), name, new_name)
+    }
 
+    fn render_add_check_constraint(&self, table: &TableWalker<'_>, check_constraint: &CheckConstraint) -> String {
         format!(
-            "EXEC SP_RENAME N{}, N{}",
-            Quoted::Single(with_schema),
-            Quoted::Single(new_name),
+            "ALTER TABLE {} ADD {}",
+            self.quote_table(table),
+            self.render_check_constraint(check_constraint)
         )
     }
 
@@ -370,7 +414,7 @@ impl SqlRenderer for MssqlFlavour {
         write!(
             add_constraint,
             "ALTER TABLE {table} ADD ",
-            table = self.quote_with_schema(foreign_key.table().name())
+            table = self.quote_table(&foreign_key.table())
         )
         .unwrap();
 
@@ -402,8 +446,21 @@ impl SqlRenderer for MssqlFlavour {
         add_constraint
     }
 
-    fn render_drop_table(&self, table_name: &str) -> Vec<String> {
-        vec![format!("DROP TABLE {}", self.quote_with_schema(&table_name))]
+    fn render_drop_table(&self, table: &TableWalker<'_>) -> Vec<String> {
+        vec![format!("DROP TABLE {}", self.quote_table(table))]
+    }
+
+    fn render_create_schema(&self, schema_name: &str) -> String {
+        // `CREATE SCHEMA` must be alone in its batch, hence the `EXEC`.
+        format!(
+            "IF NOT EXISTS (SELECT * FROM sys.schemas WHERE name = {}) EXEC('CREATE SCHEMA {}')",
+            Quoted::Single(schema_name),
+            self.quote(schema_name),
+        )
+    }
+
+    fn render_create_view(&self, view: &ViewWalker<'_>) -> String {
+        super::render_create_view_as(view, &self.quote_with_schema(view.name()))
     }
 
     fn render_drop_view(&self, view: &ViewWalker<'_>) -> String {
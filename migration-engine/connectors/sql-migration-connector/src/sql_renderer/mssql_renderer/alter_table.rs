@@ -78,7 +78,7 @@ impl<'a> AlterTableConstructor<'a> {
         if !self.drop_constraints.is_empty() {
             statements.push(format!(
                 "ALTER TABLE {} DROP CONSTRAINT {}",
-                self.renderer.quote_with_schema(self.tables.previous().name()),
+                self.renderer.quote_table(self.tables.previous()),
                 self.drop_constraints.iter().join(",\n"),
             ));
         }
@@ -90,7 +90,7 @@ impl<'a> AlterTableConstructor<'a> {
         if !self.drop_columns.is_empty() {
             statements.push(format!(
                 "ALTER TABLE {} DROP COLUMN {}",
-                self.renderer.quote_with_schema(self.tables.previous().name()),
+                self.renderer.quote_table(self.tables.previous()),
                 self.drop_columns.join(",\n"),
             ));
         }
@@ -98,7 +98,7 @@ impl<'a> AlterTableConstructor<'a> {
         if !self.add_constraints.is_empty() {
             statements.push(format!(
                 "ALTER TABLE {} ADD {}",
-                self.renderer.quote_with_schema(self.tables.previous().name()),
+                self.renderer.quote_table(self.tables.previous()),
                 self.add_constraints.iter().join(", ")
             ));
         }
@@ -106,7 +106,7 @@ impl<'a> AlterTableConstructor<'a> {
         if !self.add_columns.is_empty() {
             statements.push(format!(
                 "ALTER TABLE {} ADD {}",
-                self.renderer.quote_with_schema(self.tables.previous().name()),
+                self.renderer.quote_table(self.tables.previous()),
                 self.add_columns.join(",\n"),
             ));
         }
@@ -195,7 +195,7 @@ impl<'a> AlterTableConstructor<'a> {
 
                     self.column_mods.push(format!(
                         "ALTER TABLE {table} ALTER COLUMN {column_name} {column_type} {nullability}",
-                        table = self.renderer.quote_with_schema(self.tables.previous().name()),
+                        table = self.renderer.quote_table(self.tables.previous()),
                         column_name = self.renderer.quote(&columns.next().name()),
                         column_type = super::render_column_type(columns.next()),
                         nullability = nullability,
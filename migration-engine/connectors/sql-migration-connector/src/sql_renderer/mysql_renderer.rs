@@ -15,7 +15,7 @@ use regex::Regex;
 use sql_ddl::mysql as ddl;
 use sql_schema_describer::{
     walkers::{ColumnWalker, EnumWalker, ForeignKeyWalker, IndexWalker, TableWalker, ViewWalker},
-    ColumnTypeFamily, DefaultKind, DefaultValue, ForeignKeyAction, SqlSchema,
+    CheckConstraint, ColumnTypeFamily, DefaultKind, DefaultValue, ForeignKeyAction, IndexType, SqlSchema,
 };
 use std::borrow::Cow;
 
@@ -79,14 +79,14 @@ impl SqlRenderer for MysqlFlavour {
                     .collect(),
                 on_delete: Some(match foreign_key.on_delete_action() {
                     ForeignKeyAction::Cascade => ddl::ForeignKeyAction::Cascade,
-                    ForeignKeyAction::NoAction => ddl::ForeignKeyAction::DoNothing,
+                    ForeignKeyAction::NoAction => ddl::ForeignKeyAction::NoAction,
                     ForeignKeyAction::Restrict => ddl::ForeignKeyAction::Restrict,
                     ForeignKeyAction::SetDefault => ddl::ForeignKeyAction::SetDefault,
                     ForeignKeyAction::SetNull => ddl::ForeignKeyAction::SetNull,
                 }),
                 on_update: Some(match foreign_key.on_update_action() {
                     ForeignKeyAction::Cascade => ddl::ForeignKeyAction::Cascade,
-                    ForeignKeyAction::NoAction => ddl::ForeignKeyAction::DoNothing,
+                    ForeignKeyAction::NoAction => ddl::ForeignKeyAction::NoAction,
                     ForeignKeyAction::Restrict => ddl::ForeignKeyAction::Restrict,
                     ForeignKeyAction::SetDefault => ddl::ForeignKeyAction::SetDefault,
                     ForeignKeyAction::SetNull => ddl::ForeignKeyAction::SetNull,
@@ -189,8 +189,14 @@ impl SqlRenderer for MysqlFlavour {
             &name
         };
 
+        let index_type = match index.index_type() {
+            IndexType::Unique => ddl::IndexType::Unique,
+            IndexType::Normal => ddl::IndexType::Normal,
+            IndexType::Fulltext => ddl::IndexType::Fulltext,
+        };
+
         ddl::CreateIndex {
-            unique: index.index_type().is_unique(),
+            index_type,
             index_name: name.into(),
             on: (
                 index.table().name().into(),
@@ -216,7 +222,11 @@ impl SqlRenderer for MysqlFlavour {
             let indices: String = table
                 .indexes()
                 .map(|index| {
-                    let tpe = if index.index_type().is_unique() { "UNIQUE " } else { "" };
+                    let tpe = match index.index_type() {
+                        IndexType::Unique => "UNIQUE ",
+                        IndexType::Normal => "",
+                        IndexType::Fulltext => "FULLTEXT ",
+                    };
                     let index_name = if index.name().len() > MYSQL_IDENTIFIER_SIZE_LIMIT {
                         &index.name()[0..MYSQL_IDENTIFIER_SIZE_LIMIT]
                     } else {
@@ -237,12 +247,19 @@ impl SqlRenderer for MysqlFlavour {
             String::new()
         };
 
+        let check_constraints: String = table
+            .check_constraints()
+            .iter()
+            .map(|check| format!(",\n{}{}", SQL_INDENTATION, self.render_check_constraint(check)))
+            .collect();
+
         format!(
-            "CREATE TABLE {} (\n{columns}{indexes}{primary_key}\n) DEFAULT CHARACTER SET utf8mb4 COLLATE utf8mb4_unicode_ci",
+            "CREATE TABLE {} (\n{columns}{indexes}{primary_key}{check_constraints}\n) DEFAULT CHARACTER SET utf8mb4 COLLATE utf8mb4_unicode_ci",
             table_name = self.quote(table_name),
             columns = columns,
             indexes = indexes,
             primary_key = primary_key,
+            check_constraints = check_constraints,
         )
     }
 
@@ -258,6 +275,14 @@ impl SqlRenderer for MysqlFlavour {
         ]
     }
 
+    fn render_drop_check_constraint(&self, table: &TableWalker<'_>, check_constraint: &CheckConstraint) -> String {
+        format!(
+            "ALTER TABLE {} DROP CHECK {}",
+            self.quote(table.name()),
+            self.quote(check_constraint.name.as_deref().unwrap())
+        )
+    }
+
     fn render_drop_enum(&self, _: &EnumWalker<'_>) -> Vec<String> {
         Vec::new()
     }
@@ -278,9 +303,9 @@ impl SqlRenderer for MysqlFlavour {
         .to_string()
     }
 
-    fn render_drop_table(&self, table_name: &str) -> Vec<String> {
+    fn render_drop_table(&self, table: &TableWalker<'_>) -> Vec<String> {
         vec![sql_ddl::mysql::DropTable {
-            table_name: table_name.into(),
+            table_name: table.name().into(),
         }
         .to_string()]
     }
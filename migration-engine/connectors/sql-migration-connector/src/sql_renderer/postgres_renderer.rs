@@ -14,6 +14,14 @@ use sql_schema_describer::{walkers::*, *};
 use std::borrow::Cow;
 
 impl PostgresFlavour {
+    /// The table name, qualified with its database schema when it lives outside of the default one.
+    fn table_identifier<'a>(&self, table: &TableWalker<'a>) -> ddl::PostgresIdentifier<'a> {
+        match table.schema() {
+            Some(schema_name) => ddl::PostgresIdentifier::WithSchema(schema_name.into(), table.name().into()),
+            None => ddl::PostgresIdentifier::Simple(table.name().into()),
+        }
+    }
+
     fn render_column(&self, column: &ColumnWalker<'_>) -> String {
         let column_name = self.quote(column.name());
         let tpe_str = render_column_type(column);
@@ -31,6 +39,36 @@ impl PostgresFlavour {
         )
     }
 
+    fn render_create_table_named(&self, table: &TableWalker<'_>, table_name: ddl::PostgresIdentifier<'_>) -> String {
+        let columns: String = table.columns().map(|column| self.render_column(&column)).join(",\n");
+
+        let primary_columns = table.primary_key_column_names();
+        let pk_column_names = primary_columns
+            .into_iter()
+            .flat_map(|cols| cols.iter())
+            .map(|col| self.quote(col))
+            .join(",");
+        let pk = if !pk_column_names.is_empty() {
+            format!(",\n\n{}PRIMARY KEY ({})", SQL_INDENTATION, pk_column_names)
+        } else {
+            String::new()
+        };
+
+        let check_constraints: String = table
+            .check_constraints()
+            .iter()
+            .map(|check| format!(",\n{}{}", SQL_INDENTATION, self.render_check_constraint(check)))
+            .collect();
+
+        format!(
+            "CREATE TABLE {table_name} (\n{columns}{primary_key}{check_constraints}\n)",
+            table_name = table_name,
+            columns = columns,
+            primary_key = pk,
+            check_constraints = check_constraints,
+        )
+    }
+
     fn render_default<'a>(&self, default: &'a DefaultValue, family: &ColumnTypeFamily) -> Cow<'a, str> {
         match (default.kind(), family) {
             (DefaultKind::DBGENERATED(val), _) => val.as_str().into(),
@@ -58,22 +96,22 @@ impl SqlRenderer for PostgresFlavour {
 
     fn render_add_foreign_key(&self, foreign_key: &ForeignKeyWalker<'_>) -> String {
         ddl::AlterTable {
-            table_name: ddl::PostgresIdentifier::Simple(foreign_key.table().name().into()),
+            table_name: self.table_identifier(&foreign_key.table()),
             clauses: vec![ddl::AlterTableClause::AddForeignKey(ddl::ForeignKey {
                 constrained_columns: foreign_key.constrained_columns().map(|c| c.name().into()).collect(),
                 referenced_columns: foreign_key.referenced_column_names().iter().map(|c| c.into()).collect(),
                 constraint_name: foreign_key.constraint_name().map(From::from),
-                referenced_table: foreign_key.referenced_table().name().into(),
+                referenced_table: self.table_identifier(&foreign_key.referenced_table()),
                 on_delete: Some(match foreign_key.on_delete_action() {
                     ForeignKeyAction::Cascade => ddl::ForeignKeyAction::Cascade,
-                    ForeignKeyAction::NoAction => ddl::ForeignKeyAction::DoNothing,
+                    ForeignKeyAction::NoAction => ddl::ForeignKeyAction::NoAction,
                     ForeignKeyAction::Restrict => ddl::ForeignKeyAction::Restrict,
                     ForeignKeyAction::SetDefault => ddl::ForeignKeyAction::SetDefault,
                     ForeignKeyAction::SetNull => ddl::ForeignKeyAction::SetNull,
                 }),
                 on_update: Some(match foreign_key.on_update_action() {
                     ForeignKeyAction::Cascade => ddl::ForeignKeyAction::Cascade,
-                    ForeignKeyAction::NoAction => ddl::ForeignKeyAction::DoNothing,
+                    ForeignKeyAction::NoAction => ddl::ForeignKeyAction::NoAction,
                     ForeignKeyAction::Restrict => ddl::ForeignKeyAction::Restrict,
                     ForeignKeyAction::SetDefault => ddl::ForeignKeyAction::SetDefault,
                     ForeignKeyAction::SetNull => ddl::ForeignKeyAction::SetNull,
@@ -83,6 +121,14 @@ impl SqlRenderer for PostgresFlavour {
         .to_string()
     }
 
+    fn render_add_check_constraint(&self, table: &TableWalker<'_>, check_constraint: &CheckConstraint) -> String {
+        format!(
+            "ALTER TABLE {} ADD {}",
+            self.table_identifier(table),
+            self.render_check_constraint(check_constraint)
+        )
+    }
+
     fn render_alter_enum(&self, alter_enum: &AlterEnum, schemas: &Pair<&SqlSchema>) -> Vec<String> {
         if alter_enum.dropped_variants.is_empty() {
             let mut stmts: Vec<String> = alter_enum
@@ -143,7 +189,7 @@ impl SqlRenderer for PostgresFlavour {
                     "ALTER TABLE {schema_name}.{table_name} \
                             ALTER COLUMN {column_name} TYPE {tmp_name} \
                                 USING ({column_name}::text::{tmp_name})",
-                    schema_name = Quoted::postgres_ident(self.schema_name()),
+                    schema_name = Quoted::postgres_ident(column.table().schema().unwrap_or_else(|| self.schema_name())),
                     table_name = Quoted::postgres_ident(column.table().name()),
                     column_name = Quoted::postgres_ident(column.name()),
                     tmp_name = Quoted::postgres_ident(&tmp_name),
@@ -270,7 +316,7 @@ impl SqlRenderer for PostgresFlavour {
 
         let alter_table = format!(
             "ALTER TABLE {} {}",
-            self.quote(tables.previous().name()),
+            self.table_identifier(tables.previous()),
             lines.join(",\n")
         );
 
@@ -293,32 +339,25 @@ impl SqlRenderer for PostgresFlavour {
         CreateIndex {
             index_name: index.name().into(),
             is_unique: index.index_type().is_unique(),
-            table_reference: index.table().name().into(),
+            table_reference: self.table_identifier(&index.table()),
             columns: index.columns().map(|c| c.name().into()).collect(),
         }
         .to_string()
     }
 
-    fn render_create_table_as(&self, table: &TableWalker<'_>, table_name: &str) -> String {
-        let columns: String = table.columns().map(|column| self.render_column(&column)).join(",\n");
+    fn render_create_table(&self, table: &TableWalker<'_>) -> String {
+        self.render_create_table_named(table, self.table_identifier(table))
+    }
 
-        let primary_columns = table.primary_key_column_names();
-        let pk_column_names = primary_columns
-            .into_iter()
-            .flat_map(|cols| cols.iter())
-            .map(|col| self.quote(col))
-            .join(",");
-        let pk = if !pk_column_names.is_empty() {
-            format!(",\n\n{}PRIMARY KEY ({})", SQL_INDENTATION, pk_column_names)
-        } else {
-            String::new()
-        };
+    fn render_create_table_as(&self, table: &TableWalker<'_>, table_name: &str) -> String {
+        self.render_create_table_named(table, ddl::PostgresIdentifier::Simple(table_name.into()))
+    }
 
+    fn render_drop_check_constraint(&self, table: &TableWalker<'_>, check_constraint: &CheckConstraint) -> String {
         format!(
-            "CREATE TABLE {table_name} (\n{columns}{primary_key}\n)",
-            table_name = self.quote(table_name),
-            columns = columns,
-            primary_key = pk,
+            "ALTER TABLE {} DROP CONSTRAINT {}",
+            self.table_identifier(table),
+            self.quote(check_constraint.name.as_deref().unwrap())
         )
     }
 
@@ -334,17 +373,20 @@ impl SqlRenderer for PostgresFlavour {
     fn render_drop_foreign_key(&self, foreign_key: &ForeignKeyWalker<'_>) -> String {
         format!(
             "ALTER TABLE {table} DROP CONSTRAINT {constraint_name}",
-            table = self.quote(foreign_key.table().name()),
+            table = self.table_identifier(&foreign_key.table()),
             constraint_name = Quoted::postgres_ident(foreign_key.constraint_name().unwrap()),
         )
     }
 
     fn render_drop_index(&self, index: &IndexWalker<'_>) -> String {
-        format!("DROP INDEX {}", self.quote(index.name()))
+        match index.table().schema() {
+            Some(schema_name) => format!("DROP INDEX {}.{}", self.quote(schema_name), self.quote(index.name())),
+            None => format!("DROP INDEX {}", self.quote(index.name())),
+        }
     }
 
-    fn render_drop_table(&self, table_name: &str) -> Vec<String> {
-        vec![format!("DROP TABLE {}", self.quote(&table_name))]
+    fn render_drop_table(&self, table: &TableWalker<'_>) -> Vec<String> {
+        vec![format!("DROP TABLE {}", self.table_identifier(table))]
     }
 
     fn render_redefine_tables(&self, _names: &[RedefineTable], _schemas: &Pair<&SqlSchema>) -> Vec<String> {
@@ -24,6 +24,7 @@ impl SqlRenderer for SqliteFlavour {
         let index_type = match index.index_type() {
             IndexType::Unique => "UNIQUE ",
             IndexType::Normal => "",
+            IndexType::Fulltext => unreachable!("Fulltext index on SQLite"),
         };
         let index_name = self.quote(index.name());
         let table_reference = self.quote(index.table().name());
@@ -100,7 +101,21 @@ impl SqlRenderer for SqliteFlavour {
                         ForeignKeyAction::SetNull => sql_ddl::sqlite::ForeignKeyAction::SetNull,
                         ForeignKeyAction::SetDefault => sql_ddl::sqlite::ForeignKeyAction::SetDefault,
                     }),
-                    on_update: Some(sql_ddl::sqlite::ForeignKeyAction::Cascade),
+                    on_update: Some(match fk.on_update_action() {
+                        ForeignKeyAction::NoAction => sql_ddl::sqlite::ForeignKeyAction::NoAction,
+                        ForeignKeyAction::Restrict => sql_ddl::sqlite::ForeignKeyAction::Restrict,
+                        ForeignKeyAction::Cascade => sql_ddl::sqlite::ForeignKeyAction::Cascade,
+                        ForeignKeyAction::SetNull => sql_ddl::sqlite::ForeignKeyAction::SetNull,
+                        ForeignKeyAction::SetDefault => sql_ddl::sqlite::ForeignKeyAction::SetDefault,
+                    }),
+                })
+                .collect(),
+            check_constraints: table
+                .check_constraints()
+                .iter()
+                .map(|check| sql_ddl::sqlite::CheckConstraint {
+                    name: check.name.as_deref().map(Into::into),
+                    expression: check.expression.as_str().into(),
                 })
                 .collect(),
         };
@@ -122,18 +137,26 @@ impl SqlRenderer for SqliteFlavour {
         unreachable!("render_drop_foreign_key on SQLite")
     }
 
+    fn render_add_check_constraint(&self, _table: &TableWalker<'_>, _check_constraint: &CheckConstraint) -> String {
+        unreachable!("render_add_check_constraint on SQLite")
+    }
+
+    fn render_drop_check_constraint(&self, _table: &TableWalker<'_>, _check_constraint: &CheckConstraint) -> String {
+        unreachable!("render_drop_check_constraint on SQLite")
+    }
+
     fn render_drop_index(&self, index: &IndexWalker<'_>) -> String {
         format!("DROP INDEX {}", self.quote(index.name()))
     }
 
-    fn render_drop_table(&self, table_name: &str) -> Vec<String> {
+    fn render_drop_table(&self, table: &TableWalker<'_>) -> Vec<String> {
         // Turning off the pragma is safe, because schema validation would forbid foreign keys
         // to a non-existent model. There appears to be no other way to deal with cyclic
         // dependencies in the dropping order of tables in the presence of foreign key
         // constraints on SQLite.
         vec![
             "PRAGMA foreign_keys=off".to_string(),
-            format!("DROP TABLE {}", self.quote(&table_name)),
+            format!("DROP TABLE {}", self.quote(table.name())),
             "PRAGMA foreign_keys=on".to_string(),
         ]
     }
@@ -5,8 +5,9 @@ pub(super) use sql_schema_calculator_flavour::SqlSchemaCalculatorFlavour;
 use crate::{flavour::SqlFlavour, sql_renderer::IteratorJoin};
 use datamodel::{
     walkers::{walk_models, walk_relations, ModelWalker, ScalarFieldWalker, TypeWalker},
-    Datamodel, DefaultValue, FieldArity, IndexDefinition, IndexType, ScalarType,
+    Datamodel, DefaultValue, FieldArity, IndexDefinition, IndexType, ReferentialAction, ScalarType,
 };
+use migration_connector::MigrationFeature;
 use prisma_value::PrismaValue;
 use sql_schema_describer::{self as sql, walkers::SqlSchemaExt, ColumnType};
 
@@ -21,14 +22,31 @@ pub(crate) fn calculate_sql_schema(datamodel: &Datamodel, flavour: &dyn SqlFlavo
     let relation_tables: Vec<_> = calculate_relation_tables(datamodel, flavour, &schema).collect();
     schema.tables.extend(relation_tables.into_iter());
 
+    schema.views.extend(calculate_views(datamodel));
+
     schema
 }
 
+/// Views are only managed by migrations when their SQL definition is part of the schema.
+fn calculate_views(datamodel: &Datamodel) -> impl Iterator<Item = sql::View> + '_ {
+    walk_models(datamodel)
+        .filter(|model| model.is_view())
+        .filter_map(|model| {
+            model.view_definition().map(|definition| sql::View {
+                name: model.database_name().to_owned(),
+                definition: definition.to_owned(),
+                columns: Vec::new(),
+            })
+        })
+}
+
 fn calculate_model_tables<'a>(
     datamodel: &'a Datamodel,
     flavour: &'a dyn SqlFlavour,
 ) -> impl Iterator<Item = sql::Table> + 'a {
-    walk_models(datamodel).map(move |model| {
+    let models = walk_models(datamodel).filter(|model| !model.is_view());
+
+    models.map(move |model| {
         let columns = model
             .scalar_fields()
             .map(|field| column_for_scalar_field(&field, flavour))
@@ -61,6 +79,7 @@ fn calculate_model_tables<'a>(
             let index_type = match index_definition.tpe {
                 IndexType::Unique => sql::IndexType::Unique,
                 IndexType::Normal => sql::IndexType::Normal,
+                IndexType::Fulltext => sql::IndexType::Fulltext,
             };
 
             let index_name = index_definition.name.clone().unwrap_or_else(|| {
@@ -68,7 +87,11 @@ fn calculate_model_tables<'a>(
                     "{table}.{fields}_{qualifier}",
                     table = &model.db_name(),
                     fields = referenced_fields.iter().map(|field| field.db_name()).join("_"),
-                    qualifier = if index_type.is_unique() { "unique" } else { "index" },
+                    qualifier = match index_type {
+                        sql::IndexType::Unique => "unique",
+                        sql::IndexType::Normal => "index",
+                        sql::IndexType::Fulltext => "fulltext",
+                    },
                 )
             });
 
@@ -90,15 +113,38 @@ fn calculate_model_tables<'a>(
             indices: single_field_indexes.chain(multiple_field_indexes).collect(),
             primary_key,
             foreign_keys: Vec::new(),
+            schema: table_schema(model, flavour),
+            check_constraints: calculate_check_constraints(model),
         };
 
-        push_inline_relations(model, &mut table);
+        push_inline_relations(model, flavour, &mut table);
 
         table
     })
 }
 
-fn push_inline_relations(model: ModelWalker<'_>, table: &mut sql::Table) {
+/// The schema of a model's table. Tables in the default schema stay unqualified, like the described ones.
+fn table_schema(model: ModelWalker<'_>, flavour: &dyn SqlFlavour) -> Option<String> {
+    model
+        .schema()
+        .filter(|schema| Some(*schema) != flavour.default_schema_name())
+        .map(String::from)
+}
+
+/// Check constraints without a name in the schema are named by the database, so removing one of
+/// them doesn't rename the others.
+fn calculate_check_constraints(model: ModelWalker<'_>) -> Vec<sql::CheckConstraint> {
+    model
+        .check_constraints()
+        .iter()
+        .map(|check_constraint| sql::CheckConstraint {
+            name: check_constraint.name.clone(),
+            expression: check_constraint.expression.clone(),
+        })
+        .collect()
+}
+
+fn push_inline_relations(model: ModelWalker<'_>, flavour: &dyn SqlFlavour, table: &mut sql::Table) {
     let relation_fields = model
         .relation_fields()
         .filter(|relation_field| !relation_field.is_virtual());
@@ -112,17 +158,15 @@ fn push_inline_relations(model: ModelWalker<'_>, table: &mut sql::Table) {
         }
 
         // Foreign key
-        {
+        if creates_foreign_keys(flavour) {
             let fk = sql::ForeignKey {
                 constraint_name: None,
                 columns: fk_columns,
                 referenced_table: relation_field.referenced_model().database_name().to_owned(),
+                referenced_schema: table_schema(relation_field.referenced_model(), flavour),
                 referenced_columns: relation_field.referenced_columns().map(String::from).collect(),
-                on_update_action: sql::ForeignKeyAction::Cascade,
-                on_delete_action: match column_arity(relation_field.arity()) {
-                    sql::ColumnArity::Required => sql::ForeignKeyAction::Cascade,
-                    _ => sql::ForeignKeyAction::SetNull,
-                },
+                on_update_action: foreign_key_action(relation_field.on_update_action()),
+                on_delete_action: foreign_key_action(relation_field.on_delete_action()),
             };
 
             table.foreign_keys.push(fk);
@@ -172,24 +216,31 @@ fn calculate_relation_tables<'a>(
             let model_a = model_a_id.model();
             let model_b = model_b_id.model();
 
-            let foreign_keys = vec![
-                sql::ForeignKey {
-                    constraint_name: None,
-                    columns: vec![m2m.model_a_column().into()],
-                    referenced_table: model_a.db_name().into(),
-                    referenced_columns: vec![model_a_id.db_name().into()],
-                    on_update_action: flavour.m2m_foreign_key_action(&model_a, &model_b),
-                    on_delete_action: flavour.m2m_foreign_key_action(&model_a, &model_b),
-                },
-                sql::ForeignKey {
-                    constraint_name: None,
-                    columns: vec![m2m.model_b_column().into()],
-                    referenced_table: model_b.db_name().into(),
-                    referenced_columns: vec![model_b_id.db_name().into()],
-                    on_update_action: flavour.m2m_foreign_key_action(&model_a, &model_b),
-                    on_delete_action: flavour.m2m_foreign_key_action(&model_a, &model_b),
-                },
-            ];
+            // With emulated referential integrity, the join table is not constrained.
+            let foreign_keys = if creates_foreign_keys(flavour) {
+                vec![
+                    sql::ForeignKey {
+                        constraint_name: None,
+                        columns: vec![m2m.model_a_column().into()],
+                        referenced_table: model_a.db_name().into(),
+                        referenced_schema: table_schema(model_a, flavour),
+                        referenced_columns: vec![model_a_id.db_name().into()],
+                        on_update_action: flavour.m2m_foreign_key_action(&model_a, &model_b),
+                        on_delete_action: flavour.m2m_foreign_key_action(&model_a, &model_b),
+                    },
+                    sql::ForeignKey {
+                        constraint_name: None,
+                        columns: vec![m2m.model_b_column().into()],
+                        referenced_table: model_b.db_name().into(),
+                        referenced_schema: table_schema(model_b, flavour),
+                        referenced_columns: vec![model_b_id.db_name().into()],
+                        on_update_action: flavour.m2m_foreign_key_action(&model_a, &model_b),
+                        on_delete_action: flavour.m2m_foreign_key_action(&model_a, &model_b),
+                    },
+                ]
+            } else {
+                Vec::new()
+            };
 
             let indexes = vec![
                 sql::Index {
@@ -225,6 +276,8 @@ fn calculate_relation_tables<'a>(
                 indices: indexes,
                 primary_key: None,
                 foreign_keys,
+                schema: None,
+                check_constraints: Vec::new(),
             }
         })
 }
@@ -335,6 +388,21 @@ fn column_arity(arity: FieldArity) -> sql::ColumnArity {
     }
 }
 
+/// Foreign keys are not created when Prisma emulates the referential integrity of relations.
+fn creates_foreign_keys(flavour: &dyn SqlFlavour) -> bool {
+    !flavour.features().contains(MigrationFeature::NoForeignKeys)
+}
+
+fn foreign_key_action(action: ReferentialAction) -> sql::ForeignKeyAction {
+    match action {
+        ReferentialAction::Cascade => sql::ForeignKeyAction::Cascade,
+        ReferentialAction::Restrict => sql::ForeignKeyAction::Restrict,
+        ReferentialAction::NoAction => sql::ForeignKeyAction::NoAction,
+        ReferentialAction::SetNull => sql::ForeignKeyAction::SetNull,
+        ReferentialAction::SetDefault => sql::ForeignKeyAction::SetDefault,
+    }
+}
+
 fn db_generated(default: &DefaultValue) -> Option<sql::DefaultValue> {
     default.db_generated_description().map(sql::DefaultValue::db_generated)
 }
@@ -1,6 +1,7 @@
 mod column;
 mod enums;
 mod index;
+mod sql_expression;
 mod sql_schema_differ_flavour;
 mod table;
 
@@ -10,15 +11,16 @@ pub(crate) use sql_schema_differ_flavour::SqlSchemaDifferFlavour;
 use crate::{
     pair::Pair,
     sql_migration::{
-        self, AddColumn, AddForeignKey, AlterColumn, AlterEnum, AlterTable, CreateEnum, CreateIndex, CreateTable,
-        DropColumn, DropEnum, DropForeignKey, DropIndex, DropTable, RedefineTable, SqlMigrationStep, TableChange,
+        self, AddCheckConstraint, AddColumn, AddForeignKey, AlterColumn, AlterEnum, AlterTable, CreateEnum,
+        CreateIndex, CreateSchema, CreateTable, CreateView, DropCheckConstraint, DropColumn, DropEnum, DropForeignKey,
+        DropIndex, DropTable, DropView, RedefineTable, SqlMigrationStep, TableChange,
     },
     SqlFlavour, SqlSchema,
 };
 use column::ColumnTypeChange;
 use enums::EnumDiffer;
 use sql_schema_describer::{
-    walkers::{EnumWalker, ForeignKeyWalker, TableWalker},
+    walkers::{EnumWalker, ForeignKeyWalker, TableWalker, ViewWalker},
     ColumnTypeFamily,
 };
 use std::collections::HashSet;
@@ -49,16 +51,30 @@ pub(crate) fn calculate_steps(schemas: Pair<&SqlSchema>, flavour: &dyn SqlFlavou
     let redefine_tables = differ.redefine_tables(&tables_to_redefine);
     let add_foreign_keys = differ.add_foreign_keys(&tables_to_redefine);
     let create_enums = differ.create_enums();
+    let (drop_views, create_views) = differ.create_and_replace_views();
+    let create_schemas = differ.create_schemas();
+    let drop_check_constraints = differ.drop_check_constraints(&tables_to_redefine);
+    let add_check_constraints = differ.add_check_constraints(&tables_to_redefine);
 
     let redefine_tables = Some(redefine_tables)
         .filter(|tables| !tables.is_empty())
         .map(SqlMigrationStep::RedefineTables);
 
-    create_enums
+    // Order matters: views are dropped before and created after all the table changes, since
+    // they can depend on the tables.
+    drop_views
         .into_iter()
-        .map(SqlMigrationStep::CreateEnum)
+        .map(SqlMigrationStep::DropView)
+        .chain(create_schemas.into_iter().map(SqlMigrationStep::CreateSchema))
+        .chain(create_enums.into_iter().map(SqlMigrationStep::CreateEnum))
         .chain(differ.alter_enums().into_iter().map(SqlMigrationStep::AlterEnum))
         .chain(drop_foreign_keys.into_iter().map(SqlMigrationStep::DropForeignKey))
+        // Order matters: check constraints can reference columns dropped in ALTER TABLEs.
+        .chain(
+            drop_check_constraints
+                .into_iter()
+                .map(SqlMigrationStep::DropCheckConstraint),
+        )
         .chain(drop_indexes.into_iter().map(SqlMigrationStep::DropIndex))
         .chain(alter_tables.into_iter().map(SqlMigrationStep::AlterTable))
         // Order matters: we must drop enums before we create tables,
@@ -77,6 +93,12 @@ pub(crate) fn calculate_steps(schemas: Pair<&SqlSchema>, flavour: &dyn SqlFlavou
         // Order matters: this needs to come after create_indexes, because the foreign keys can depend on unique
         // indexes created there.
         .chain(add_foreign_keys.into_iter().map(SqlMigrationStep::AddForeignKey))
+        // Order matters: check constraints can reference columns created in ALTER TABLEs.
+        .chain(
+            add_check_constraints
+                .into_iter()
+                .map(SqlMigrationStep::AddCheckConstraint),
+        )
         .chain(alter_indexes.into_iter().map(|idxs| SqlMigrationStep::AlterIndex {
             table: idxs.as_ref().map(|(table, _)| *table),
             index: idxs.as_ref().map(|(_, idx)| *idx),
@@ -89,6 +111,7 @@ pub(crate) fn calculate_steps(schemas: Pair<&SqlSchema>, flavour: &dyn SqlFlavou
                     index: idxs.as_ref().map(|(_, idx)| *idx),
                 }),
         )
+        .chain(create_views.into_iter().map(SqlMigrationStep::CreateView))
         .collect()
 }
 
@@ -342,6 +365,42 @@ impl<'schema> SqlSchemaDiffer<'schema> {
         steps
     }
 
+    /// Check constraints of created tables are part of the `CREATE TABLE` statement, and the
+    /// redefined tables get theirs when they are recreated.
+    fn add_check_constraints(&self, tables_to_redefine: &HashSet<String>) -> Vec<AddCheckConstraint> {
+        self.table_pairs()
+            .filter(|tables| !tables_to_redefine.contains(tables.next().name()))
+            .flat_map(|tables| {
+                let table_index = tables.next().table_index();
+
+                tables
+                    .created_check_constraints()
+                    .map(move |check_constraint_index| AddCheckConstraint {
+                        table_index,
+                        check_constraint_index,
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+
+    fn drop_check_constraints(&self, tables_to_redefine: &HashSet<String>) -> Vec<DropCheckConstraint> {
+        self.table_pairs()
+            .filter(|tables| !tables_to_redefine.contains(tables.next().name()))
+            .flat_map(|tables| {
+                let table_index = tables.previous().table_index();
+
+                tables
+                    .dropped_check_constraints()
+                    .map(move |check_constraint_index| DropCheckConstraint {
+                        table_index,
+                        check_constraint_index,
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+
     fn drop_indexes(&self, tables_to_redefine: &HashSet<String>) -> Vec<DropIndex> {
         let mut drop_indexes = HashSet::new();
 
@@ -390,6 +449,55 @@ impl<'schema> SqlSchemaDiffer<'schema> {
         })
     }
 
+    /// The database schemas the created tables live in, when no table of the previous schema lives
+    /// there already.
+    fn create_schemas(&self) -> Vec<CreateSchema> {
+        let mut schema_names: Vec<&str> = Vec::new();
+
+        for schema_name in self.created_tables().filter_map(|table| table.schema()) {
+            let is_new = !schema_names.contains(&schema_name)
+                && !self.previous_tables().any(|table| table.schema() == Some(schema_name));
+
+            if is_new {
+                schema_names.push(schema_name);
+            }
+        }
+
+        schema_names
+            .into_iter()
+            .map(|schema_name| CreateSchema {
+                schema_name: schema_name.to_owned(),
+            })
+            .collect()
+    }
+
+    /// Views are only created or replaced, never dropped on their own: the views in the next
+    /// schema are the ones with a definition in the Prisma schema, and a view missing there may
+    /// just be unmanaged.
+    fn create_and_replace_views(&self) -> (Vec<DropView>, Vec<CreateView>) {
+        let mut drop_views = Vec::new();
+        let mut create_views = Vec::new();
+
+        for next_view in self.schemas.next().view_walkers() {
+            let previous_view = self.schemas.previous().view_walkers().find(|previous_view| {
+                self.flavour
+                    .table_names_match(Pair::new(previous_view.name(), next_view.name()))
+            });
+
+            match previous_view {
+                Some(previous_view) if views_match(&previous_view, &next_view) => continue,
+                Some(previous_view) => drop_views.push(DropView::new(previous_view.view_index())),
+                None => (),
+            }
+
+            create_views.push(CreateView {
+                view_index: next_view.view_index(),
+            });
+        }
+
+        (drop_views, create_views)
+    }
+
     fn alter_enums(&self) -> Vec<AlterEnum> {
         self.flavour.alter_enums(self)
     }
@@ -437,10 +545,7 @@ impl<'schema> SqlSchemaDiffer<'schema> {
                 self.schemas
                     .next()
                     .table_walkers()
-                    .find(move |next_table| {
-                        self.flavour
-                            .table_names_match(Pair::new(previous_table.name(), next_table.name()))
-                    })
+                    .find(move |next_table| self.tables_match(Pair::new(previous_table, *next_table)))
                     .map(move |next_table| TableDiffer {
                         flavour: self.flavour,
                         tables: Pair::new(previous_table, next_table),
@@ -468,22 +573,26 @@ impl<'schema> SqlSchemaDiffer<'schema> {
 
     fn created_tables(&self) -> impl Iterator<Item = TableWalker<'_>> {
         self.next_tables().filter(move |next_table| {
-            !self.previous_tables().any(|previous_table| {
-                self.flavour
-                    .table_names_match(Pair::new(previous_table.name(), next_table.name()))
-            })
+            !self
+                .previous_tables()
+                .any(|previous_table| self.tables_match(Pair::new(previous_table, *next_table)))
         })
     }
 
     fn dropped_tables<'a>(&'a self) -> impl Iterator<Item = TableWalker<'schema>> + 'a {
         self.previous_tables().filter(move |previous_table| {
-            !self.next_tables().any(|next_table| {
-                self.flavour
-                    .table_names_match(Pair::new(previous_table.name(), next_table.name()))
-            })
+            !self
+                .next_tables()
+                .any(|next_table| self.tables_match(Pair::new(*previous_table, next_table)))
         })
     }
 
+    /// Tables match when their names match and they live in the same database schema.
+    fn tables_match(&self, tables: Pair<TableWalker<'_>>) -> bool {
+        self.flavour.table_names_match(tables.map(|table| table.name()))
+            && tables.previous().schema() == tables.next().schema()
+    }
+
     fn previous_tables<'a>(&'a self) -> impl Iterator<Item = TableWalker<'schema>> + 'a {
         self.schemas
             .previous()
@@ -558,7 +667,8 @@ fn push_foreign_keys_from_created_tables<'a>(
 /// Compare two [ForeignKey](/sql-schema-describer/struct.ForeignKey.html)s and return whether they
 /// should be considered equivalent for schema diffing purposes.
 fn foreign_keys_match(fks: Pair<&ForeignKeyWalker<'_>>, flavour: &dyn SqlFlavour) -> bool {
-    let references_same_table = flavour.table_names_match(fks.map(|fk| fk.referenced_table().name()));
+    let references_same_table = flavour.table_names_match(fks.map(|fk| fk.referenced_table().name()))
+        && fks.previous().referenced_table().schema() == fks.next().referenced_table().schema();
     let references_same_column_count =
         fks.previous().referenced_columns_count() == fks.next().referenced_columns_count();
     let constrains_same_column_count =
@@ -578,13 +688,37 @@ fn foreign_keys_match(fks: Pair<&ForeignKeyWalker<'_>>, flavour: &dyn SqlFlavour
         .interleave(|fk| fk.referenced_column_names())
         .all(|pair| pair.previous() == pair.next());
 
+    // Changing a referential action requires recreating the foreign key.
+    let same_on_delete_action = fks.previous().on_delete_action() == fks.next().on_delete_action();
+    let same_on_update_action = fks.previous().on_update_action() == fks.next().on_update_action();
+
     references_same_table
         && references_same_column_count
         && constrains_same_column_count
         && constrains_same_columns
         && references_same_columns
+        && same_on_delete_action
+        && same_on_update_action
 }
 
 fn enums_match(previous: &EnumWalker<'_>, next: &EnumWalker<'_>) -> bool {
     previous.name() == next.name()
 }
+
+/// Compare the definitions of two views, disregarding a leading `CREATE VIEW ... AS` and the
+/// rewrites the database applies to stored definitions (see `sql_expression::normalize`).
+fn views_match(previous: &ViewWalker<'_>, next: &ViewWalker<'_>) -> bool {
+    fn normalize(definition: &str) -> Vec<String> {
+        let mut tokens = sql_expression::normalize(definition);
+
+        if tokens.first().map(|token| token == "create").unwrap_or(false) {
+            if let Some(position) = tokens.iter().position(|token| token == "as") {
+                tokens.drain(..=position);
+            }
+        }
+
+        tokens
+    }
+
+    normalize(previous.definition()) == normalize(next.definition())
+}
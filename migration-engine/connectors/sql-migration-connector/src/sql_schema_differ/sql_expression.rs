@@ -0,0 +1,179 @@
+//! Normalization of SQL snippets written in the Prisma schema (view definitions, check constraint
+//! expressions), so they can be compared with the versions the database gives back after
+//! rewriting them.
+//!
+//! Databases store these snippets in their own canonical form. Postgres qualifies columns with
+//! their table, adds parentheses and casts string literals (`'a'::text`), MySQL additionally
+//! quotes every identifier, aliases every selected column (`` `cats`.`name` AS `name` ``) and
+//! prefixes string literals with their character set (`_utf8mb4'a'`), SQL Server brackets
+//! identifiers and numbers (`[price]>(0)`).
+//! Both sides of a comparison go through the same normalization, so a snippet matches the
+//! rewritten version of itself, while string literals are compared exactly.
+
+/// Splits a SQL snippet into normalized tokens:
+///
+/// - Keywords and identifiers are lowercased, quoted identifiers (`"a"`, `` `a` ``, `[a]`) are unquoted.
+/// - String literals are kept as written, including their quotes.
+/// - Parentheses, casts (`::text`, `::text[]`), character set introducers (`_utf8mb4'a'`), table
+///   and schema qualifiers (`cats.name`), aliases repeating the aliased column (`name AS name`) and a
+///   trailing semicolon are dropped.
+pub(super) fn normalize(sql: &str) -> Vec<String> {
+    let tokens = tokenize(sql);
+    let mut normalized: Vec<Token> = Vec::with_capacity(tokens.len());
+    let mut iter = tokens.into_iter().peekable();
+
+    while let Some(token) = iter.next() {
+        match token {
+            Token::Punctuation(c) if c == '(' || c == ')' => (),
+            Token::Cast => {
+                // The type name, possibly two words (`character varying`, `double precision`).
+                if let Some(Token::Word(_)) = iter.peek() {
+                    iter.next();
+                }
+
+                if let Some(Token::Word(word)) = iter.peek() {
+                    if word == "varying" || word == "precision" {
+                        iter.next();
+                    }
+                }
+
+                // Array types (`text[]`).
+                while matches!(iter.peek(), Some(Token::Punctuation('[')))
+                    && matches!(iter.clone().nth(1), Some(Token::Punctuation(']')))
+                {
+                    iter.nth(1);
+                }
+            }
+            Token::Word(word) if word.starts_with('_') && matches!(iter.peek(), Some(Token::Literal(_))) => (),
+            Token::Word(word) if matches!(iter.peek(), Some(Token::Punctuation('.'))) => {
+                // A qualifier: skip it together with the dot, if a name follows.
+                iter.next();
+
+                if !matches!(iter.peek(), Some(Token::Word(_))) {
+                    normalized.push(Token::Word(word));
+                    normalized.push(Token::Punctuation('.'));
+                }
+            }
+            Token::Word(word) => {
+                let is_repeated_alias = matches!(
+                    normalized.as_slice(),
+                    [.., Token::Word(column), Token::Word(keyword)] if keyword == "as" && *column == word
+                );
+
+                if is_repeated_alias {
+                    normalized.pop();
+                } else {
+                    normalized.push(Token::Word(word));
+                }
+            }
+            token => normalized.push(token),
+        }
+    }
+
+    while let Some(Token::Punctuation(';')) = normalized.last() {
+        normalized.pop();
+    }
+
+    normalized.into_iter().map(Token::into_string).collect()
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    /// A keyword or identifier, lowercased and unquoted.
+    Word(String),
+    /// A string literal, as written.
+    Literal(String),
+    /// `::`
+    Cast,
+    Punctuation(char),
+}
+
+impl Token {
+    fn into_string(self) -> String {
+        match self {
+            Token::Word(s) | Token::Literal(s) => s,
+            Token::Cast => "::".to_owned(),
+            Token::Punctuation(c) => c.to_string(),
+        }
+    }
+}
+
+fn tokenize(sql: &str) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let mut chars = sql.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            c if c.is_whitespace() => (),
+            '\'' => {
+                let mut literal = String::from("'");
+
+                while let Some(c) = chars.next() {
+                    literal.push(c);
+
+                    if c == '\'' {
+                        // An escaped quote (`''`) continues the literal.
+                        if chars.peek() == Some(&'\'') {
+                            literal.push(chars.next().unwrap());
+                        } else {
+                            break;
+                        }
+                    }
+                }
+
+                tokens.push(Token::Literal(literal));
+            }
+            '"' | '`' => {
+                let identifier: String = chars.by_ref().take_while(|next| *next != c).collect();
+                tokens.push(Token::Word(identifier.to_lowercase()));
+            }
+            // SQL Server quotes identifiers in brackets. Anything else in brackets, e.g. the elements of a
+            // Postgres `ARRAY['a']`, is tokenized normally.
+            '[' if is_bracket_quoted_identifier(chars.clone()) => {
+                let identifier: String = chars.by_ref().take_while(|next| *next != ']').collect();
+                tokens.push(Token::Word(identifier.to_lowercase()));
+            }
+            ':' if chars.peek() == Some(&':') => {
+                chars.next();
+                tokens.push(Token::Cast);
+            }
+            c if is_word_char(c) => {
+                let mut word: String = c.to_lowercase().collect();
+                // Numbers keep their decimal point, it is not a qualifier.
+                let is_number = c.is_ascii_digit();
+
+                while let Some(c) = chars
+                    .peek()
+                    .copied()
+                    .filter(|c| is_word_char(*c) || (is_number && *c == '.'))
+                {
+                    word.extend(c.to_lowercase());
+                    chars.next();
+                }
+
+                tokens.push(Token::Word(word));
+            }
+            c => tokens.push(Token::Punctuation(c)),
+        }
+    }
+
+    tokens
+}
+
+fn is_bracket_quoted_identifier(chars: impl Iterator<Item = char>) -> bool {
+    let mut is_empty = true;
+
+    for c in chars {
+        match c {
+            ']' => return !is_empty,
+            c if is_word_char(c) || c == ' ' => is_empty = false,
+            _ => return false,
+        }
+    }
+
+    false
+}
+
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_' || c == '$'
+}
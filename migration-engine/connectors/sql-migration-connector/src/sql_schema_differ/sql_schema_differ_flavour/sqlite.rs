@@ -34,6 +34,9 @@ impl SqlSchemaDifferFlavour for SqliteFlavour {
                     || differ.index_pairs().any(|pair| self.index_should_be_renamed(&pair))
                     || differ.created_foreign_keys().next().is_some()
                     || differ.dropped_foreign_keys().next().is_some()
+                    // ALTER TABLE ... ADD/DROP CONSTRAINT does not exist on SQLite
+                    || differ.created_check_constraints().next().is_some()
+                    || differ.dropped_check_constraints().next().is_some()
             })
             .map(|table| table.next().name().to_owned())
             .collect()
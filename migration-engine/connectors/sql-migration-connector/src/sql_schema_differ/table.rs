@@ -1,8 +1,8 @@
-use super::column::ColumnDiffer;
+use super::{column::ColumnDiffer, sql_expression::normalize};
 use crate::{flavour::SqlFlavour, pair::Pair};
 use sql_schema_describer::{
     walkers::{ColumnWalker, ForeignKeyWalker, IndexWalker, TableWalker},
-    PrimaryKey,
+    CheckConstraint, PrimaryKey,
 };
 
 pub(crate) struct TableDiffer<'a> {
@@ -81,6 +81,38 @@ impl<'schema> TableDiffer<'schema> {
         })
     }
 
+    /// The indexes of the check constraints present in `next` but not `previous`.
+    pub(crate) fn created_check_constraints<'a>(&'a self) -> impl Iterator<Item = usize> + 'a {
+        let previous_check_constraints = self.previous().check_constraints();
+
+        self.next()
+            .check_constraints()
+            .iter()
+            .enumerate()
+            .filter(move |(_, next)| {
+                !previous_check_constraints
+                    .iter()
+                    .any(|previous| check_constraints_match(previous, next))
+            })
+            .map(|(idx, _)| idx)
+    }
+
+    /// The indexes of the check constraints present in `previous` but not `next`.
+    pub(crate) fn dropped_check_constraints<'a>(&'a self) -> impl Iterator<Item = usize> + 'a {
+        let next_check_constraints = self.next().check_constraints();
+
+        self.previous()
+            .check_constraints()
+            .iter()
+            .enumerate()
+            .filter(move |(_, previous)| {
+                !next_check_constraints
+                    .iter()
+                    .any(|next| check_constraints_match(previous, next))
+            })
+            .map(|(idx, _)| idx)
+    }
+
     /// The primary key present in `next` but not `previous`, if applicable.
     pub(crate) fn created_primary_key(&self) -> Option<&'schema PrimaryKey> {
         match self.tables.as_ref().map(|t| t.primary_key()).as_tuple() {
@@ -162,6 +194,19 @@ pub(crate) fn columns_match(a: &ColumnWalker<'_>, b: &ColumnWalker<'_>) -> bool
     a.name() == b.name()
 }
 
+/// Databases store check constraint expressions in their own canonical form (PostgreSQL adds
+/// parentheses and casts, MySQL quotes identifiers, SQL Server brackets them...), so the
+/// expressions are compared after normalization. String literals must match exactly. Unnamed
+/// constraints are named by the database, so they match any name.
+fn check_constraints_match(previous: &CheckConstraint, next: &CheckConstraint) -> bool {
+    let names_match = match (&previous.name, &next.name) {
+        (Some(previous_name), Some(next_name)) => previous_name == next_name,
+        _ => true,
+    };
+
+    names_match && normalize(&previous.expression) == normalize(&next.expression)
+}
+
 /// Compare two SQL indexes and return whether they only differ by name.
 fn indexes_match(first: &IndexWalker<'_>, second: &IndexWalker<'_>) -> bool {
     first.column_names() == second.column_names() && first.index_type() == second.index_type()
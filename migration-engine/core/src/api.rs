@@ -15,6 +15,9 @@ pub trait GenericApi: Send + Sync + 'static {
     /// Return the database version as a string.
     async fn version(&self, input: &serde_json::Value) -> CoreResult<String>;
 
+    /// Revert the last applied migration with its down migration script.
+    async fn apply_down_migration(&self, input: &ApplyDownMigrationInput) -> CoreResult<ApplyDownMigrationOutput>;
+
     /// Apply all the unapplied migrations from the migrations folder.
     async fn apply_migrations(&self, input: &ApplyMigrationsInput) -> CoreResult<ApplyMigrationsOutput>;
 
@@ -73,6 +76,12 @@ impl<C: MigrationConnector> GenericApi for C {
             .await
     }
 
+    async fn apply_down_migration(&self, input: &ApplyDownMigrationInput) -> CoreResult<ApplyDownMigrationOutput> {
+        ApplyDownMigrationCommand::execute(input, self)
+            .instrument(tracing::info_span!("ApplyDownMigration"))
+            .await
+    }
+
     async fn apply_migrations(&self, input: &ApplyMigrationsInput) -> CoreResult<ApplyMigrationsOutput> {
         ApplyMigrationsCommand::execute(input, self)
             .instrument(tracing::info_span!("ApplyMigrations"))
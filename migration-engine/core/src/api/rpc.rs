@@ -12,6 +12,7 @@ pub struct RpcApi {
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 enum RpcCommand {
+    ApplyDownMigration,
     ApplyMigrations,
     ApplyScript,
     CreateMigration,
@@ -31,6 +32,7 @@ enum RpcCommand {
 impl RpcCommand {
     fn name(&self) -> &'static str {
         match self {
+            RpcCommand::ApplyDownMigration => "applyDownMigration",
             RpcCommand::ApplyMigrations => "applyMigrations",
             RpcCommand::ApplyScript => "applyScript",
             RpcCommand::CreateMigration => "createMigration",
@@ -50,6 +52,7 @@ impl RpcCommand {
 }
 
 const AVAILABLE_COMMANDS: &[RpcCommand] = &[
+    RpcCommand::ApplyDownMigration,
     RpcCommand::ApplyMigrations,
     RpcCommand::ApplyScript,
     RpcCommand::CreateMigration,
@@ -116,6 +119,7 @@ impl RpcApi {
     ) -> Result<serde_json::Value, RunCommandError> {
         tracing::debug!(?cmd, "running the command");
         Ok(match cmd {
+            RpcCommand::ApplyDownMigration => render(executor.apply_down_migration(&params.parse()?).await?),
             RpcCommand::ApplyScript => render(executor.apply_script(&params.parse()?).await?),
             RpcCommand::ApplyMigrations => render(executor.apply_migrations(&params.parse()?).await?),
             RpcCommand::CreateMigration => render(executor.create_migration(&params.parse()?).await?),
@@ -1,6 +1,7 @@
 //! The commands exposed by the migration engine core are defined in this
 //! module.
 
+mod apply_down_migration;
 mod apply_migrations;
 mod apply_script;
 mod command;
@@ -16,6 +17,7 @@ mod mark_migration_rolled_back;
 mod plan_migration;
 mod schema_push;
 
+pub use apply_down_migration::{ApplyDownMigrationCommand, ApplyDownMigrationInput, ApplyDownMigrationOutput};
 pub use apply_migrations::{ApplyMigrationsCommand, ApplyMigrationsInput, ApplyMigrationsOutput};
 pub use apply_script::{ApplyScriptCommand, ApplyScriptInput, ApplyScriptOutput};
 pub use command::MigrationCommand;
@@ -0,0 +1,105 @@
+use super::{apply_migrations::detect_failed_migrations, MigrationCommand};
+use crate::{CoreError, CoreResult};
+use migration_connector::{
+    ConnectorError, DatabaseMigrationMarker, MigrationConnector, PersistenceNotInitializedError,
+};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// The input to the `applyDownMigration` command.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ApplyDownMigrationInput {
+    /// The location of the migrations directory.
+    pub migrations_directory_path: String,
+}
+
+/// The output of the `applyDownMigration` command.
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ApplyDownMigrationOutput {
+    /// The name of the migration that was reverted. None if no migration was
+    /// applied to the database.
+    pub reverted_migration_name: Option<String>,
+}
+
+/// Revert the last applied migration by running the down migration script from
+/// its directory, then mark the migration as rolled back in the migrations
+/// table. A later `applyMigrations` will apply it again.
+pub struct ApplyDownMigrationCommand;
+
+#[async_trait::async_trait]
+impl MigrationCommand for ApplyDownMigrationCommand {
+    type Input = ApplyDownMigrationInput;
+    type Output = ApplyDownMigrationOutput;
+
+    async fn execute<C: MigrationConnector>(input: &Self::Input, connector: &C) -> CoreResult<Self::Output> {
+        let applier = connector.database_migration_step_applier();
+        let migration_persistence = connector.migration_persistence();
+
+        migration_connector::error_on_changed_provider(&input.migrations_directory_path, connector.connector_type())?;
+
+        connector.acquire_lock().await?;
+
+        let migrations_from_filesystem =
+            migration_connector::list_migrations(&Path::new(&input.migrations_directory_path))?;
+        let migrations_from_database = migration_persistence
+            .list_migrations()
+            .await?
+            .map_err(PersistenceNotInitializedError::into_connector_error)?;
+
+        detect_failed_migrations(&migrations_from_database)?;
+
+        // The records are ordered by `started_at`.
+        let last_applied_migration = match migrations_from_database
+            .iter()
+            .rev()
+            .find(|migration| migration.finished_at.is_some() && migration.rolled_back_at.is_none())
+        {
+            Some(migration) => migration,
+            None => {
+                tracing::info!("No applied migration to revert.");
+
+                return Ok(ApplyDownMigrationOutput {
+                    reverted_migration_name: None,
+                });
+            }
+        };
+
+        let migration_directory = migrations_from_filesystem
+            .iter()
+            .find(|fs_migration| fs_migration.migration_name() == last_applied_migration.migration_name)
+            .ok_or_else(|| {
+                CoreError::Generic(anyhow::anyhow!(
+                    "The last applied migration `{}` is not in the migrations directory.",
+                    last_applied_migration.migration_name
+                ))
+            })?;
+
+        let script = migration_directory
+            .read_down_migration_script(C::DatabaseMigration::FILE_EXTENSION)
+            .map_err(ConnectorError::from)?
+            .ok_or_else(|| {
+                CoreError::Generic(anyhow::anyhow!(
+                    "The migration `{}` has no down migration script.",
+                    last_applied_migration.migration_name
+                ))
+            })?;
+
+        tracing::info!(
+            script = script.as_str(),
+            "Reverting `{}`",
+            last_applied_migration.migration_name
+        );
+
+        applier.apply_script(&script).await?;
+
+        migration_persistence
+            .mark_migration_rolled_back_by_id(&last_applied_migration.id)
+            .await?;
+
+        Ok(ApplyDownMigrationOutput {
+            reverted_migration_name: Some(last_applied_migration.migration_name.clone()),
+        })
+    }
+}
@@ -114,7 +114,7 @@ impl<'a> MigrationCommand for ApplyMigrationsCommand {
     }
 }
 
-fn detect_failed_migrations(migrations_from_database: &[MigrationRecord]) -> CoreResult<()> {
+pub(super) fn detect_failed_migrations(migrations_from_database: &[MigrationRecord]) -> CoreResult<()> {
     use std::fmt::Write as _;
 
     tracing::debug!("Checking for failed migrations.");
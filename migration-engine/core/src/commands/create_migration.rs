@@ -78,6 +78,10 @@ impl<'a> MigrationCommand for CreateMigrationCommand {
 
         let migration_script = applier.render_script(&migration, &destructive_change_diagnostics);
 
+        // The down migration is the same diff, in reverse.
+        let down_migration = database_migration_inferrer.infer_down_migration(&migration);
+        let down_migration_script = applier.render_script(&down_migration, &checker.pure_check(&down_migration));
+
         // Write the migration script to a file.
         let directory = migration_connector::create_migration_directory(
             &Path::new(&input.migrations_directory_path),
@@ -94,6 +98,15 @@ impl<'a> MigrationCommand for CreateMigrationCommand {
                 )))
             })?;
 
+        directory
+            .write_down_migration_script(&down_migration_script, C::DatabaseMigration::FILE_EXTENSION)
+            .map_err(|err| {
+                CoreError::Generic(anyhow::Error::new(err).context(format!(
+                    "Failed to write the down migration script to `{:?}`",
+                    directory.path(),
+                )))
+            })?;
+
         migration_connector::write_migration_lock_file(&input.migrations_directory_path, connector_type).map_err(
             |err| {
                 CoreError::Generic(anyhow::Error::new(err).context(format!(
@@ -1,24 +1,40 @@
-use super::MigrationCommand;
-use crate::CoreResult;
-use migration_connector::MigrationConnector;
+use super::{MigrationCommand, MigrationFeedback};
+use crate::{parse_datamodel, CoreResult};
+use migration_connector::{list_migrations, MigrationConnector};
 use serde::{Deserialize, Serialize};
 
 /// The input to the `planMigration` command.
 #[derive(Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
-pub struct PlanMigrationInput {}
+pub struct PlanMigrationInput {
+    /// The location of the migrations directory.
+    pub migrations_directory_path: String,
+    /// The prisma schema to migrate to.
+    pub prisma_schema: String,
+}
 
 /// The output of the `planMigration` command.
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Debug, PartialEq)]
 #[serde(rename_all = "camelCase")]
-pub struct PlanMigrationOutput {}
+pub struct PlanMigrationOutput {
+    /// The steps of the planned migration, in the order they would be executed.
+    pub migration_steps: Vec<String>,
+    /// The migration script `createMigration` would write for the planned migration.
+    pub migration_script: String,
+    /// Destructive change warnings for the planned migration. These do not
+    /// depend on the data in the database.
+    pub warnings: Vec<MigrationFeedback>,
+    /// Steps of the planned migration that may not be executable, for example
+    /// adding a required column without a default to an existing table.
+    pub unexecutable_steps: Vec<MigrationFeedback>,
+}
 
-/// Development command for migrations. At this stage, the engine does not
-/// create or mutate anything in the database nor in the migrations directory.
+/// Development command for migrations. Plan the next migration the engine would
+/// generate, without writing anything to the migrations directory.
 ///
-/// This is where we will return information about potential renamings and other
-/// forms of changes that need user decisions in the future, so the CLI can
-/// prompt the user.
+/// At this stage, the engine does not create or mutate anything in the database
+/// nor in the migrations directory. The returned script is the one
+/// `createMigration` would write for the same input.
 pub struct PlanMigrationCommand;
 
 #[async_trait::async_trait]
@@ -26,7 +42,53 @@ impl<'a> MigrationCommand for PlanMigrationCommand {
     type Input = PlanMigrationInput;
     type Output = PlanMigrationOutput;
 
-    async fn execute<C: MigrationConnector>(_input: &Self::Input, _engine: &C) -> CoreResult<Self::Output> {
-        unreachable!("PlanMigration command")
+    async fn execute<C: MigrationConnector>(input: &Self::Input, connector: &C) -> CoreResult<Self::Output> {
+        let inferrer = connector.database_migration_inferrer();
+        let applier = connector.database_migration_step_applier();
+        let checker = connector.destructive_change_checker();
+
+        migration_connector::error_on_changed_provider(&input.migrations_directory_path, connector.connector_type())?;
+
+        let migrations_from_directory = list_migrations(input.migrations_directory_path.as_ref())?;
+        let target_schema = parse_datamodel(&input.prisma_schema)?;
+
+        let migration = inferrer
+            .infer_next_migration(&migrations_from_directory, &target_schema)
+            .await?;
+
+        let migration_steps = applier
+            .render_steps_pretty(&migration)?
+            .into_iter()
+            .map(|pretty_step| pretty_step.raw)
+            .collect();
+
+        // Same diagnostics as `createMigration`, so the script matches what it would write.
+        let diagnostics = checker.pure_check(&migration);
+        let migration_script = applier.render_script(&migration, &diagnostics);
+
+        let warnings = diagnostics
+            .warnings
+            .into_iter()
+            .map(|warning| MigrationFeedback {
+                message: warning.description,
+                step_index: warning.step_index,
+            })
+            .collect();
+
+        let unexecutable_steps = diagnostics
+            .unexecutable_migrations
+            .into_iter()
+            .map(|unexecutable| MigrationFeedback {
+                message: unexecutable.description,
+                step_index: unexecutable.step_index,
+            })
+            .collect();
+
+        Ok(PlanMigrationOutput {
+            migration_steps,
+            migration_script,
+            warnings,
+            unexecutable_steps,
+        })
     }
 }
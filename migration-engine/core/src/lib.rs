@@ -73,6 +73,7 @@ pub async fn migration_api(datamodel: &str) -> CoreResult<Box<dyn api::GenericAp
                 source.shadow_database_url.as_ref().map(|url| url.value.clone()),
             )
             .await?
+            .with_schemas(source.schemas.clone())
         }
         #[cfg(feature = "sql")]
         provider if [MYSQL_SOURCE_NAME, SQLITE_SOURCE_NAME, MSSQL_SOURCE_NAME].contains(&provider.as_str()) => {
@@ -82,6 +83,7 @@ pub async fn migration_api(datamodel: &str) -> CoreResult<Box<dyn api::GenericAp
                 source.shadow_database_url.as_ref().map(|url| url.value.clone()),
             )
             .await?
+            .with_schemas(source.schemas.clone())
         }
         x => unimplemented!("Connector {} is not supported yet", x),
     };
@@ -161,7 +163,9 @@ pub async fn qe_setup(prisma_schema: &str) -> CoreResult<()> {
         {
             // 1. creates schema & database
             SqlMigrationConnector::qe_setup(&source.url().value).await?;
-            SqlMigrationConnector::new(&source.url().value, features, None).await?
+            SqlMigrationConnector::new(&source.url().value, features, None)
+                .await?
+                .with_schemas(source.schemas.clone())
         }
         x => unimplemented!("Connector {} is not supported yet", x),
     };
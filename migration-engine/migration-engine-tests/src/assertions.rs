@@ -69,6 +69,17 @@ impl SchemaAssertion {
         Ok(self)
     }
 
+    pub fn assert_has_view(self, view_name: &str) -> AssertionResult<Self> {
+        anyhow::ensure!(
+            self.0.get_view(view_name).is_some(),
+            "Assertion failed. View {} not found. Views in database: {:?}",
+            view_name,
+            self.0.views.iter().map(|view| &view.name).collect::<Vec<_>>(),
+        );
+
+        Ok(self)
+    }
+
     pub fn assert_has_no_enum(self, enum_name: &str) -> AssertionResult<Self> {
         assert!(self.0.get_enum(enum_name).is_none());
 
@@ -176,6 +187,31 @@ impl<'a> TableAssertion<'a> {
         Ok(self)
     }
 
+    pub fn assert_check_constraints_count(self, n: usize) -> AssertionResult<Self> {
+        let check_constraints_count = self.0.check_constraints.len();
+
+        anyhow::ensure!(
+            check_constraints_count == n,
+            anyhow::anyhow!("Expected {} check constraints, found {}.", n, check_constraints_count)
+        );
+
+        Ok(self)
+    }
+
+    pub fn assert_has_check_constraint(self, name: &str) -> AssertionResult<Self> {
+        anyhow::ensure!(
+            self.0
+                .check_constraints
+                .iter()
+                .any(|check| check.name.as_deref() == Some(name)),
+            "Assertion failed: could not find check constraint `{}` on `{}`.",
+            name,
+            self.0.name
+        );
+
+        Ok(self)
+    }
+
     pub fn assert_does_not_have_column(self, column_name: &str) -> AssertionResult<Self> {
         if self.0.column(column_name).is_some() {
             anyhow::bail!("Assertion failed: found column `{}` on `{}`.", column_name, self.0.name);
@@ -560,6 +596,28 @@ impl<'a> ForeignKeyAssertion<'a> {
 
         Ok(self)
     }
+
+    pub fn assert_referential_action_on_delete(self, action: ForeignKeyAction) -> AssertionResult<Self> {
+        anyhow::ensure!(
+            self.0.on_delete_action == action,
+            "Assertion failed: expected foreign key to {:?} on delete, but it was {:?}.",
+            action,
+            self.0.on_delete_action
+        );
+
+        Ok(self)
+    }
+
+    pub fn assert_referential_action_on_update(self, action: ForeignKeyAction) -> AssertionResult<Self> {
+        anyhow::ensure!(
+            self.0.on_update_action == action,
+            "Assertion failed: expected foreign key to {:?} on update, but it was {:?}.",
+            action,
+            self.0.on_update_action
+        );
+
+        Ok(self)
+    }
 }
 
 pub struct IndexAssertion<'a>(&'a Index);
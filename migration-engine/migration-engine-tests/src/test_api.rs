@@ -1,3 +1,4 @@
+mod apply_down_migration;
 mod apply_migrations;
 mod create_migration;
 mod dev_diagnostic;
@@ -6,14 +7,17 @@ mod evaluate_data_loss;
 mod list_migration_directories;
 mod mark_migration_applied;
 mod mark_migration_rolled_back;
+mod plan_migration;
 mod reset;
 mod schema_push;
 
+pub use apply_down_migration::ApplyDownMigration;
 pub use apply_migrations::ApplyMigrations;
 pub use create_migration::CreateMigration;
 pub use diagnose_migration_history::DiagnoseMigrationHistory;
 pub use evaluate_data_loss::EvaluateDataLoss;
 pub use mark_migration_applied::MarkMigrationApplied;
+pub use plan_migration::PlanMigration;
 pub use reset::Reset;
 pub use schema_push::SchemaPush;
 
@@ -177,6 +181,10 @@ impl TestApi {
         Ok(())
     }
 
+    pub fn apply_down_migration<'a>(&'a self, migrations_directory: &'a TempDir) -> ApplyDownMigration<'a> {
+        ApplyDownMigration::new(&self.api, migrations_directory)
+    }
+
     pub fn apply_migrations<'a>(&'a self, migrations_directory: &'a TempDir) -> ApplyMigrations<'a> {
         ApplyMigrations::new(&self.api, migrations_directory)
     }
@@ -233,6 +241,15 @@ impl TestApi {
         MarkMigrationRolledBack::new(&self.api, migration_name.into())
     }
 
+    /// Builder and assertions to call the `planMigration` command.
+    pub fn plan_migration<'a>(
+        &'a self,
+        migrations_directory: &'a TempDir,
+        prisma_schema: impl Into<String>,
+    ) -> PlanMigration<'a> {
+        PlanMigration::new(&self.api, migrations_directory, prisma_schema.into())
+    }
+
     pub fn reset(&self) -> Reset<'_> {
         Reset::new(&self.api)
     }
@@ -363,6 +380,7 @@ impl<'a> TestApiSelect<'a> {
 fn preview_features(features: BitFlags<Features>) -> BitFlags<MigrationFeature> {
     features.iter().fold(BitFlags::empty(), |acc, feature| match feature {
         Features::Other => acc,
+        Features::NoForeignKeys => acc | MigrationFeature::NoForeignKeys,
     })
 }
 
@@ -0,0 +1,64 @@
+use migration_core::{
+    commands::{ApplyDownMigrationInput, ApplyDownMigrationOutput},
+    CoreResult, GenericApi,
+};
+use tempfile::TempDir;
+
+use crate::AssertionResult;
+
+#[must_use = "This struct does nothing on its own. See ApplyDownMigration::send()"]
+pub struct ApplyDownMigration<'a> {
+    api: &'a dyn GenericApi,
+    migrations_directory: &'a TempDir,
+}
+
+impl<'a> ApplyDownMigration<'a> {
+    pub fn new(api: &'a dyn GenericApi, migrations_directory: &'a TempDir) -> Self {
+        ApplyDownMigration {
+            api,
+            migrations_directory,
+        }
+    }
+
+    pub async fn send(self) -> CoreResult<ApplyDownMigrationAssertion<'a>> {
+        let output = self
+            .api
+            .apply_down_migration(&ApplyDownMigrationInput {
+                migrations_directory_path: self.migrations_directory.path().to_str().unwrap().to_owned(),
+            })
+            .await?;
+
+        Ok(ApplyDownMigrationAssertion {
+            output,
+            _api: self.api,
+            _migrations_directory: self.migrations_directory,
+        })
+    }
+}
+
+pub struct ApplyDownMigrationAssertion<'a> {
+    output: ApplyDownMigrationOutput,
+    _api: &'a dyn GenericApi,
+    _migrations_directory: &'a TempDir,
+}
+
+impl std::fmt::Debug for ApplyDownMigrationAssertion<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ApplyDownMigrationAssertion {{ .. }}")
+    }
+}
+
+impl<'a> ApplyDownMigrationAssertion<'a> {
+    pub fn assert_reverted_migration(self, name: Option<&str>) -> AssertionResult<Self> {
+        let found_name = self.output.reverted_migration_name.as_deref().map(|name| &name[15..]);
+
+        anyhow::ensure!(
+            found_name == name,
+            "Assertion failed. The reverted migration does not match the expectation. ({:?} vs {:?})",
+            found_name,
+            name
+        );
+
+        Ok(self)
+    }
+}
@@ -174,4 +174,14 @@ impl MigrationAssertion<'_> {
 
         Ok(self)
     }
+
+    pub fn assert_down_contents(self, expected_contents: &str) -> AssertionResult<Self> {
+        let down_migration_file_path = self.path.join("down.sql");
+        let contents: String = std::fs::read_to_string(&down_migration_file_path)
+            .with_context(|| format!("Trying to read down migration file at {:?}", down_migration_file_path))?;
+
+        assert_eq!(expected_contents, contents);
+
+        Ok(self)
+    }
 }
@@ -0,0 +1,81 @@
+use crate::AssertionResult;
+use migration_core::{
+    commands::{PlanMigrationInput, PlanMigrationOutput},
+    CoreResult, GenericApi,
+};
+use tempfile::TempDir;
+
+#[must_use = "This struct does nothing on its own. See PlanMigration::send()"]
+pub struct PlanMigration<'a> {
+    api: &'a dyn GenericApi,
+    migrations_directory: &'a TempDir,
+    prisma_schema: String,
+}
+
+impl<'a> PlanMigration<'a> {
+    pub fn new(api: &'a dyn GenericApi, migrations_directory: &'a TempDir, prisma_schema: String) -> Self {
+        PlanMigration {
+            api,
+            migrations_directory,
+            prisma_schema,
+        }
+    }
+
+    pub async fn send(self) -> CoreResult<PlanMigrationAssertion<'a>> {
+        let output = self
+            .api
+            .plan_migration(&PlanMigrationInput {
+                migrations_directory_path: self.migrations_directory.path().to_str().unwrap().to_owned(),
+                prisma_schema: self.prisma_schema,
+            })
+            .await?;
+
+        Ok(PlanMigrationAssertion {
+            output,
+            _api: self.api,
+            _migrations_directory: self.migrations_directory,
+        })
+    }
+}
+
+pub struct PlanMigrationAssertion<'a> {
+    output: PlanMigrationOutput,
+    _api: &'a dyn GenericApi,
+    _migrations_directory: &'a TempDir,
+}
+
+impl std::fmt::Debug for PlanMigrationAssertion<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PlanMigrationAssertion").finish()
+    }
+}
+
+impl<'a> PlanMigrationAssertion<'a> {
+    pub fn assert_steps_count(self, count: usize) -> AssertionResult<Self> {
+        anyhow::ensure!(
+            self.output.migration_steps.len() == count,
+            "Assertion failed. Expected planMigration to return {} steps, found {}.\n{:?}",
+            count,
+            self.output.migration_steps.len(),
+            self.output.migration_steps,
+        );
+
+        Ok(self)
+    }
+
+    pub fn assert_warnings_count(self, count: usize) -> AssertionResult<Self> {
+        anyhow::ensure!(
+            self.output.warnings.len() == count,
+            "Expected {} warnings, got {}.\n{:#?}",
+            count,
+            self.output.warnings.len(),
+            self.output.warnings
+        );
+
+        Ok(self)
+    }
+
+    pub fn into_output(self) -> PlanMigrationOutput {
+        self.output
+    }
+}
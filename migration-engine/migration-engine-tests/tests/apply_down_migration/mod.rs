@@ -0,0 +1,88 @@
+use crate::*;
+
+#[test_each_connector]
+async fn apply_down_migration_without_applied_migrations_left_does_nothing(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Cat {
+            id Int @id
+            name String
+        }
+    "#;
+
+    let migrations_directory = api.create_migrations_directory()?;
+
+    api.create_migration("init", dm, &migrations_directory).send().await?;
+    api.apply_migrations(&migrations_directory).send().await?;
+
+    api.apply_down_migration(&migrations_directory)
+        .send()
+        .await?
+        .assert_reverted_migration(Some("init"))?;
+
+    api.apply_down_migration(&migrations_directory)
+        .send()
+        .await?
+        .assert_reverted_migration(None)?;
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn apply_down_migration_reverts_the_last_applied_migration(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id Int @id
+            name String
+        }
+    "#;
+
+    let migrations_directory = api.create_migrations_directory()?;
+
+    api.create_migration("initial", dm1, &migrations_directory)
+        .send()
+        .await?;
+
+    let dm2 = r#"
+        model Cat {
+            id Int @id
+            name String
+        }
+
+        model Dog {
+            id Int @id
+            name String
+        }
+    "#;
+
+    api.create_migration("add-dog", dm2, &migrations_directory)
+        .send()
+        .await?;
+
+    api.apply_migrations(&migrations_directory)
+        .send()
+        .await?
+        .assert_applied_migrations(&["initial", "add-dog"])?;
+
+    api.apply_down_migration(&migrations_directory)
+        .send()
+        .await?
+        .assert_reverted_migration(Some("add-dog"))?;
+
+    api.assert_schema()
+        .await?
+        .assert_has_table("Cat")?
+        .assert_tables_count(2)?;
+
+    // The reverted migration is applied again on the next deploy.
+    api.apply_migrations(&migrations_directory)
+        .send()
+        .await?
+        .assert_applied_migrations(&["add-dog"])?;
+
+    api.assert_schema()
+        .await?
+        .assert_has_table("Cat")?
+        .assert_has_table("Dog")?;
+
+    Ok(())
+}
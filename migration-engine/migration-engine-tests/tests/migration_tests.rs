@@ -1,3 +1,4 @@
+mod apply_down_migration;
 mod apply_migrations;
 mod create_migration;
 mod diagnose_migration_history;
@@ -9,6 +10,7 @@ mod initialization;
 mod list_migration_directories;
 mod migrations;
 mod native_types;
+mod plan_migration;
 mod schema_push;
 
 use migration_engine_tests::{sql::*, TestResult};
@@ -974,6 +976,7 @@ async fn changing_a_relation_field_to_a_scalar_field_must_work(api: &TestApi) ->
                 },
                 columns: vec!["b".to_owned()],
                 referenced_table: "B".to_string(),
+                referenced_schema: None,
                 referenced_columns: vec!["id".to_string()],
                 on_delete_action: ForeignKeyAction::Cascade,
                 on_update_action: ForeignKeyAction::NoAction,
@@ -0,0 +1,187 @@
+use migration_engine_tests::sql::*;
+
+#[test_each_connector(tags("postgres", "sqlite", "mysql_8", "mssql"))]
+async fn check_constraints_can_be_created(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Product {
+            id    Int @id
+            price Int
+
+            @@check("price > 0", name: "price_is_positive")
+        }
+    "#;
+
+    api.schema_push(dm).send().await?.assert_green()?;
+
+    api.assert_schema().await?.assert_table("Product", |table| {
+        table
+            .assert_check_constraints_count(1)?
+            .assert_has_check_constraint("price_is_positive")
+    })?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres", "sqlite", "mysql_8", "mssql"))]
+async fn check_constraints_can_be_added_and_dropped(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Product {
+            id       Int @id
+            price    Int
+            discount Int
+        }
+    "#;
+
+    api.schema_push(dm1).send().await?.assert_green()?;
+    api.assert_schema()
+        .await?
+        .assert_table("Product", |table| table.assert_check_constraints_count(0))?;
+
+    let dm2 = r#"
+        model Product {
+            id       Int @id
+            price    Int
+            discount Int
+
+            @@check("price > 0")
+            @@check("discount < price", name: "discount_below_price")
+        }
+    "#;
+
+    api.schema_push(dm2).send().await?.assert_green()?;
+    api.assert_schema().await?.assert_table("Product", |table| {
+        table
+            .assert_check_constraints_count(2)?
+            .assert_has_check_constraint("discount_below_price")
+    })?;
+
+    api.schema_push(dm2).send().await?.assert_green()?.assert_no_steps()?;
+
+    api.schema_push(dm1).send().await?.assert_green()?;
+    api.assert_schema()
+        .await?
+        .assert_table("Product", |table| table.assert_check_constraints_count(0))?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres", "sqlite", "mysql_8", "mssql"))]
+async fn removing_an_unnamed_check_constraint_keeps_the_others(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Product {
+            id       Int @id
+            price    Int
+            discount Int
+
+            @@check("price > 0")
+            @@check("discount >= 0")
+        }
+    "#;
+
+    api.schema_push(dm1).send().await?.assert_green()?;
+    api.schema_push(dm1).send().await?.assert_green()?.assert_no_steps()?;
+
+    let dm2 = r#"
+        model Product {
+            id       Int @id
+            price    Int
+            discount Int
+
+            @@check("discount >= 0")
+        }
+    "#;
+
+    api.schema_push(dm2).send().await?.assert_green()?;
+    api.assert_schema()
+        .await?
+        .assert_table("Product", |table| table.assert_check_constraints_count(1))?;
+    api.schema_push(dm2).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres", "mysql_8", "mssql"))]
+async fn check_constraints_with_string_literals_are_not_recreated(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Product {
+            id     Int    @id
+            price  Int
+            status String
+
+            @@check("status <> 'Archived' AND (price > 0 OR status = 'Draft')", name: "price_is_set")
+        }
+    "#;
+
+    api.schema_push(dm).send().await?.assert_green()?;
+    api.schema_push(dm).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres", "mysql_8", "mssql"))]
+async fn changing_a_string_literal_recreates_the_check_constraint(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Product {
+            id     Int    @id
+            status String
+
+            @@check("status <> 'Archived'", name: "status_is_active")
+        }
+    "#;
+
+    api.schema_push(dm1).send().await?.assert_green()?;
+
+    let dm2 = r#"
+        model Product {
+            id     Int    @id
+            status String
+
+            @@check("status <> 'archived'", name: "status_is_active")
+        }
+    "#;
+
+    api.schema_push(dm2)
+        .send()
+        .await?
+        .assert_green()?
+        .assert_has_executed_steps()?;
+    api.schema_push(dm2).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres", "mysql_8", "mssql"))]
+async fn renaming_a_check_constraint_recreates_it(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Product {
+            id    Int @id
+            price Int
+
+            @@check("price > 0", name: "price_is_positive")
+        }
+    "#;
+
+    api.schema_push(dm1).send().await?.assert_green()?;
+
+    let dm2 = r#"
+        model Product {
+            id    Int @id
+            price Int
+
+            @@check("price > 0", name: "price_above_zero")
+        }
+    "#;
+
+    api.schema_push(dm2)
+        .send()
+        .await?
+        .assert_green()?
+        .assert_has_executed_steps()?;
+    api.assert_schema().await?.assert_table("Product", |table| {
+        table
+            .assert_check_constraints_count(1)?
+            .assert_has_check_constraint("price_above_zero")
+    })?;
+
+    Ok(())
+}
@@ -1,5 +1,6 @@
 mod advisory_locking;
 mod apply_script_tests;
+mod check_constraints;
 mod dev_diagnostic_tests;
 mod enums;
 mod indexes;
@@ -20,3 +21,4 @@ mod sqlite;
 mod squashing_tests;
 mod types;
 mod unsupported_types;
+mod views;
@@ -210,3 +210,54 @@ async fn uuids_do_not_generate_drift_issue_5282(api: &TestApi) -> TestResult {
 
     Ok(())
 }
+
+#[test_each_connector(tags("postgres"))]
+async fn models_with_a_schema_attribute_are_created_in_that_schema(api: &TestApi) -> TestResult {
+    api.database()
+        .raw_cmd("DROP SCHEMA IF EXISTS \"schema_attribute_billing\" CASCADE")
+        .await?;
+
+    let dm = r#"
+        datasource pg {
+            provider = "postgresql"
+            url = "postgres://localhost:5432"
+            schemas = ["schema_attribute_billing"]
+        }
+
+        model Invoice {
+            id     Int  @id
+            userId Int
+            user   User @relation(fields: [userId], references: [id])
+
+            @@schema("schema_attribute_billing")
+        }
+
+        model User {
+            id       Int       @id
+            invoices Invoice[]
+        }
+    "#;
+
+    api.schema_push(dm).send().await?.assert_green()?;
+
+    let invoice_table_exists = api
+        .database()
+        .query_raw(
+            "SELECT EXISTS(SELECT 1 FROM information_schema.tables WHERE table_schema = 'schema_attribute_billing' AND table_name = 'Invoice')",
+            &[],
+        )
+        .await?
+        .into_single()?
+        .at(0)
+        .and_then(|value| value.as_bool())
+        .unwrap_or(false);
+
+    assert!(invoice_table_exists);
+
+    api.assert_schema().await?.assert_has_table("User")?;
+
+    // The cross-schema foreign key must be described back as it was created.
+    api.schema_push(dm).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
@@ -110,6 +110,7 @@ async fn adding_an_inline_relation_must_result_in_a_foreign_key_in_the_model_tab
                 },
                 columns: vec![b_column.name.clone()],
                 referenced_table: "B".to_string(),
+                referenced_schema: None,
                 referenced_columns: vec!["id".to_string()],
                 on_delete_action: ForeignKeyAction::Cascade, // required relations can't set ON DELETE SET NULL
                 on_update_action: ForeignKeyAction::NoAction,
@@ -123,6 +124,7 @@ async fn adding_an_inline_relation_must_result_in_a_foreign_key_in_the_model_tab
                 },
                 columns: vec![c_column.name.clone()],
                 referenced_table: "C".to_string(),
+                referenced_schema: None,
                 referenced_columns: vec!["id".to_string()],
                 on_delete_action: ForeignKeyAction::SetNull,
                 on_update_action: ForeignKeyAction::NoAction,
@@ -166,6 +168,7 @@ async fn specifying_a_db_name_for_an_inline_relation_must_work(api: &TestApi) ->
             },
             columns: vec![column.name.clone()],
             referenced_table: "B".to_string(),
+            referenced_schema: None,
             referenced_columns: vec!["id".to_string()],
             on_delete_action: ForeignKeyAction::Cascade,
             on_update_action: ForeignKeyAction::NoAction,
@@ -208,6 +211,7 @@ async fn adding_an_inline_relation_to_a_model_with_an_exotic_id_type(api: &TestA
             },
             columns: vec![column.name.clone()],
             referenced_table: "B".to_string(),
+            referenced_schema: None,
             referenced_columns: vec!["id".to_string()],
             on_delete_action: ForeignKeyAction::Cascade,
             on_update_action: ForeignKeyAction::NoAction,
@@ -296,6 +300,7 @@ async fn compound_foreign_keys_should_work_in_correct_order(api: &TestApi) -> Te
             },
             columns: vec!["a".to_string(), "b".to_string(), "d".to_string()],
             referenced_table: "B".to_string(),
+            referenced_schema: None,
             referenced_columns: vec!["a_id".to_string(), "b_id".to_string(), "d_id".to_string()],
             on_delete_action: ForeignKeyAction::Cascade,
             on_update_action: ForeignKeyAction::NoAction,
@@ -336,6 +341,7 @@ async fn moving_an_inline_relation_to_the_other_side_must_work(api: &TestApi) ->
             },
             columns: vec!["b_id".to_string()],
             referenced_table: "B".to_string(),
+            referenced_schema: None,
             referenced_columns: vec!["id".to_string()],
             on_delete_action: ForeignKeyAction::Cascade,
             on_update_action: ForeignKeyAction::NoAction,
@@ -371,6 +377,7 @@ async fn moving_an_inline_relation_to_the_other_side_must_work(api: &TestApi) ->
             },
             columns: vec!["a_id".to_string()],
             referenced_table: "A".to_string(),
+            referenced_schema: None,
             referenced_columns: vec!["id".to_string()],
             on_delete_action: ForeignKeyAction::Cascade,
             on_update_action: ForeignKeyAction::NoAction,
@@ -384,3 +391,89 @@ async fn moving_an_inline_relation_to_the_other_side_must_work(api: &TestApi) ->
 
     Ok(())
 }
+
+#[test_each_connector]
+async fn changing_the_referential_actions_of_a_relation_recreates_the_foreign_key(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model A {
+            id Int @id
+            b  B[]
+        }
+
+        model B {
+            id   Int @id
+            a_id Int
+            a    A   @relation(fields: [a_id], references: [id])
+        }
+    "#;
+
+    api.schema_push(dm1).send().await?.assert_green()?;
+
+    api.assert_schema().await?.assert_table("B", |table| {
+        table.assert_fk_on_columns(&["a_id"], |fk| {
+            fk.assert_referential_action_on_delete(ForeignKeyAction::Cascade)?
+                .assert_referential_action_on_update(ForeignKeyAction::Cascade)
+        })
+    })?;
+
+    let dm2 = r#"
+        model A {
+            id Int @id
+            b  B[]
+        }
+
+        model B {
+            id   Int @id
+            a_id Int
+            a    A   @relation(fields: [a_id], references: [id], onDelete: NoAction, onUpdate: NoAction)
+        }
+    "#;
+
+    api.schema_push(dm2).send().await?.assert_green()?;
+
+    api.assert_schema().await?.assert_table("B", |table| {
+        table
+            .assert_foreign_keys_count(1)?
+            .assert_fk_on_columns(&["a_id"], |fk| {
+                fk.assert_referential_action_on_delete(ForeignKeyAction::NoAction)?
+                    .assert_referential_action_on_update(ForeignKeyAction::NoAction)
+            })
+    })?;
+
+    api.schema_push(dm2).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
+
+#[test_each_connector(features("noForeignKeys"))]
+async fn relations_do_not_create_foreign_keys_with_emulated_referential_integrity(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model A {
+            id Int @id
+            b  B[]
+            cs C[]
+        }
+
+        model B {
+            id   Int @id
+            a_id Int
+            a    A   @relation(fields: [a_id], references: [id])
+        }
+
+        model C {
+            id Int @id
+            as A[]
+        }
+    "#;
+
+    api.schema_push(dm).send().await?.assert_green()?;
+
+    api.assert_schema()
+        .await?
+        .assert_table("B", |table| table.assert_foreign_keys_count(0))?
+        .assert_table("_AToC", |table| table.assert_foreign_keys_count(0))?;
+
+    api.schema_push(dm).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
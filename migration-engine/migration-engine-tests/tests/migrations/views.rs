@@ -0,0 +1,121 @@
+use migration_engine_tests::sql::*;
+
+#[test_each_connector(tags("sql"), ignore("mssql"))]
+async fn views_with_a_definition_are_created(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Cat {
+            id   Int    @id
+            name String
+
+            @@map("cats")
+        }
+
+        view CatName {
+            name String @unique
+
+            @@definition("SELECT name FROM cats")
+        }
+    "#;
+
+    api.schema_push(dm).send().await?.assert_green()?;
+
+    api.assert_schema()
+        .await?
+        .assert_tables_count(1)?
+        .assert_has_view("CatName")?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("sql"), ignore("mssql"))]
+async fn views_are_replaced_when_their_definition_changes(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id   Int    @id
+            name String
+
+            @@map("cats")
+        }
+
+        view CatName {
+            name String @unique
+
+            @@definition("SELECT name FROM cats")
+        }
+    "#;
+
+    api.schema_push(dm1).send().await?.assert_green()?;
+
+    let dm2 = r#"
+        model Cat {
+            id   Int    @id
+            name String
+
+            @@map("cats")
+        }
+
+        view CatName {
+            name String @unique
+
+            @@definition("SELECT name FROM cats WHERE id > 10")
+        }
+    "#;
+
+    api.schema_push(dm2)
+        .send()
+        .await?
+        .assert_green()?
+        .assert_has_executed_steps()?;
+
+    api.assert_schema().await?.assert_has_view("CatName")?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("sql"), ignore("mssql"))]
+async fn views_are_not_recreated_when_their_definition_is_unchanged(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Cat {
+            id   Int    @id
+            name String
+
+            @@map("cats")
+        }
+
+        view CatName {
+            id   Int    @unique
+            name String
+
+            @@definition("SELECT id, name FROM cats WHERE name <> 'Garfield' AND id > 10")
+        }
+    "#;
+
+    api.schema_push(dm).send().await?.assert_green()?;
+    api.schema_push(dm).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("sql"))]
+async fn views_without_a_definition_are_not_created(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Cat {
+            id   Int    @id
+            name String
+
+            @@map("cats")
+        }
+
+        view CatName {
+            name String @unique
+        }
+    "#;
+
+    api.schema_push(dm).send().await?.assert_green()?;
+
+    let schema = api.assert_schema().await?.assert_tables_count(1)?.into_schema();
+
+    assert!(schema.views.is_empty());
+
+    Ok(())
+}
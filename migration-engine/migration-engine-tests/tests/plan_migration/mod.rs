@@ -0,0 +1 @@
+mod plan_migration_tests;
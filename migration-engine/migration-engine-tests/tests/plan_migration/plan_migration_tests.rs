@@ -0,0 +1,102 @@
+use crate::*;
+
+#[test_each_connector]
+async fn plan_migration_with_an_up_to_date_migrations_directory_returns_no_step(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Cat {
+            id Int @id
+            name String
+        }
+    "#;
+
+    let directory = api.create_migrations_directory()?;
+    api.create_migration("initial", dm, &directory).send().await?;
+
+    let output = api
+        .plan_migration(&directory, dm)
+        .send()
+        .await?
+        .assert_steps_count(0)?
+        .assert_warnings_count(0)?
+        .into_output();
+
+    assert!(output.unexecutable_steps.is_empty());
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn plan_migration_returns_the_script_without_writing_a_migration(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id Int @id
+            name String
+        }
+    "#;
+
+    let directory = api.create_migrations_directory()?;
+    api.create_migration("initial", dm1, &directory).send().await?;
+
+    let dm2 = r#"
+        model Cat {
+            id Int @id
+            name String
+        }
+
+        model Dog {
+            id Int @id
+            name String
+        }
+    "#;
+
+    let output = api
+        .plan_migration(&directory, dm2)
+        .send()
+        .await?
+        .assert_steps_count(1)?
+        .assert_warnings_count(0)?
+        .into_output();
+
+    assert!(output.migration_script.contains("Dog"));
+    assert_eq!(std::fs::read_dir(directory.path())?.count(), 2);
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn plan_migration_returns_destructive_change_warnings(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id Int @id
+            name String
+        }
+
+        model Dog {
+            id Int @id
+            name String
+        }
+    "#;
+
+    let directory = api.create_migrations_directory()?;
+    api.create_migration("initial", dm1, &directory).send().await?;
+
+    let dm2 = r#"
+        model Cat {
+            id Int @id
+            name String
+        }
+    "#;
+
+    let output = api
+        .plan_migration(&directory, dm2)
+        .send()
+        .await?
+        .assert_steps_count(1)?
+        .assert_warnings_count(1)?
+        .into_output();
+
+    assert_eq!(output.warnings[0].step_index, 0);
+    assert!(output.migration_script.contains("Warnings:"));
+
+    Ok(())
+}
@@ -0,0 +1,366 @@
+use crate::CodeActionsOpts;
+use datamodel::{
+    ast::{self, reformat::Reformatter, Expression, SchemaAst, Span},
+    diagnostics::{DatamodelError, DatamodelWarning},
+};
+use std::io::{self, Read};
+
+/// A fix for a validation error or warning, as a list of edits of the schema.
+#[derive(serde::Serialize)]
+pub struct CodeAction {
+    pub title: String,
+    pub edits: Vec<TextEdit>,
+}
+
+/// Replaces the text between the byte offsets `start` and `end` of the schema with `text`.
+#[derive(serde::Serialize)]
+pub struct TextEdit {
+    pub start: usize,
+    pub end: usize,
+    pub text: String,
+}
+
+pub fn run(opts: CodeActionsOpts) {
+    let mut datamodel_string = String::new();
+
+    io::stdin()
+        .read_to_string(&mut datamodel_string)
+        .expect("Unable to read from stdin.");
+
+    let actions = compute(&datamodel_string, Span::new(opts.start, opts.end));
+    let json = serde_json::to_string(&actions).expect("Failed to render JSON");
+
+    print!("{}", json)
+}
+
+/// The code actions fixing the validation errors and warnings overlapping the span.
+pub fn compute(schema: &str, span: Span) -> Vec<CodeAction> {
+    let schema_ast = match datamodel::parse_schema_ast(schema) {
+        Ok(schema_ast) => schema_ast,
+        Err(_) => return Vec::new(),
+    };
+
+    let (errors, warnings) = match datamodel::parse_datamodel_and_ignore_datasource_urls(schema) {
+        Ok(validated_datamodel) => (Vec::new(), validated_datamodel.warnings),
+        Err(diagnostics) => (diagnostics.errors, diagnostics.warnings),
+    };
+
+    let mut actions = Vec::new();
+
+    for error in errors.iter().filter(|err| overlaps(err.span(), span)) {
+        match error {
+            DatamodelError::MissingOppositeRelationFieldError {
+                model, related_model, ..
+            } => {
+                actions.extend(add_opposite_relation_field(schema, &schema_ast, model, related_model));
+            }
+            DatamodelError::ReferencesNotUniqueError { span: field_span, .. } => {
+                actions.extend(add_unique_to_referenced_field(schema, &schema_ast, *field_span));
+            }
+            DatamodelError::TypeNotFoundError { type_name, .. } => {
+                actions.push(create_enum(schema, type_name));
+            }
+            _ => (),
+        }
+    }
+
+    for warning in warnings.iter().filter(|warn| overlaps(warn.span(), span)) {
+        if let DatamodelWarning::DeprecatedPreviewFeature { preview_feature, span } = warning {
+            actions.extend(remove_preview_feature(&schema_ast, preview_feature, *span));
+        }
+    }
+
+    actions
+}
+
+/// Adds the back relation fields the formatter would add to the related model for the relation fields of the model,
+/// together with the scalar fields they reference.
+fn add_opposite_relation_field(
+    schema: &str,
+    schema_ast: &SchemaAst,
+    model_name: &str,
+    related_model_name: &str,
+) -> Vec<CodeAction> {
+    let missing_fields = match Reformatter::find_all_missing_fields(schema) {
+        Ok(missing_fields) => missing_fields.subject,
+        Err(_) => return Vec::new(),
+    };
+
+    missing_fields
+        .iter()
+        .filter(|missing_field| missing_field.model == related_model_name)
+        .filter(|missing_field| missing_field.field.field_type.name == model_name)
+        .filter_map(|missing_field| {
+            let related_model = schema_ast.find_model(&missing_field.model)?;
+            let referenced_fields = relation_fields_argument(&missing_field.field);
+
+            let rendered_fields: Vec<String> = std::iter::once(missing_field.render())
+                .chain(
+                    missing_fields
+                        .iter()
+                        .filter(|field| field.model == missing_field.model)
+                        .filter(|field| referenced_fields.contains(&field.field.name.name))
+                        .map(|field| field.render()),
+                )
+                .collect();
+
+            Some(CodeAction {
+                title: format!(
+                    "Add the opposite relation field `{}` to model `{}`",
+                    missing_field.field.name.name, related_model.name.name
+                ),
+                edits: vec![insert_fields(schema, related_model, &rendered_fields)],
+            })
+        })
+        .collect()
+}
+
+/// The names listed in the `fields` argument of the `@relation` attribute of the field.
+fn relation_fields_argument(field: &ast::Field) -> Vec<String> {
+    field
+        .attributes
+        .iter()
+        .filter(|attr| attr.name.name == "relation")
+        .flat_map(|attr| attr.arguments.iter())
+        .filter(|arg| arg.name.name == "fields")
+        .flat_map(|arg| match &arg.value {
+            Expression::Array(values, _) => values.iter().map(|value| value.to_string()).collect(),
+            value => vec![value.to_string()],
+        })
+        .collect()
+}
+
+/// Adds `@unique` to the field referenced by a relation, if it references a single field.
+fn add_unique_to_referenced_field(schema: &str, schema_ast: &SchemaAst, field_span: Span) -> Option<CodeAction> {
+    let field = schema_ast
+        .models()
+        .into_iter()
+        .flat_map(|model| model.fields.iter())
+        .find(|field| field.span == field_span)?;
+
+    let relation = field.attributes.iter().find(|attr| attr.name.name == "relation")?;
+    let references = relation.arguments.iter().find(|arg| arg.name.name == "references")?;
+
+    let referenced_field_name = match &references.value {
+        Expression::Array(values, _) if values.len() == 1 => values[0].to_string(),
+        _ => return None,
+    };
+
+    let related_model = schema_ast.find_model(&field.field_type.name)?;
+    let referenced_field = related_model
+        .fields
+        .iter()
+        .find(|field| field.name.name == referenced_field_name)?;
+
+    let end = field_end(schema, referenced_field);
+
+    Some(CodeAction {
+        title: format!(
+            "Make `{}.{}` unique",
+            related_model.name.name, referenced_field.name.name
+        ),
+        edits: vec![TextEdit {
+            start: end,
+            end,
+            text: String::from(" @unique"),
+        }],
+    })
+}
+
+/// Appends an enum with the name of the unknown type to the schema, with a placeholder value since enums can't be
+/// empty.
+fn create_enum(schema: &str, type_name: &str) -> CodeAction {
+    let separator = if schema.ends_with('\n') { "\n" } else { "\n\n" };
+
+    CodeAction {
+        title: format!("Create enum `{}`", type_name),
+        edits: vec![TextEdit {
+            start: schema.len(),
+            end: schema.len(),
+            text: format!("{}enum {} {{\n  VALUE\n}}\n", separator, type_name),
+        }],
+    }
+}
+
+/// Removes a deprecated preview feature from the list at the span.
+fn remove_preview_feature(schema_ast: &SchemaAst, preview_feature: &str, span: Span) -> Option<CodeAction> {
+    let values = schema_ast
+        .generators()
+        .into_iter()
+        .flat_map(|generator| generator.properties.iter())
+        .find_map(|property| match &property.value {
+            Expression::Array(values, array_span) if *array_span == span => Some(values),
+            _ => None,
+        })?;
+
+    let remaining: Vec<String> = values
+        .iter()
+        .map(|value| value.to_string())
+        .filter(|value| value != preview_feature)
+        .map(|value| format!("\"{}\"", value))
+        .collect();
+
+    Some(CodeAction {
+        title: format!("Remove deprecated preview feature `{}`", preview_feature),
+        edits: vec![TextEdit {
+            start: span.start,
+            end: span.end,
+            text: format!("[{}]", remaining.join(", ")),
+        }],
+    })
+}
+
+/// Inserts fields after the last field of the model, using the indentation of that field.
+fn insert_fields(schema: &str, model: &ast::Model, rendered_fields: &[String]) -> TextEdit {
+    let (position, indentation) = match model.fields.last() {
+        Some(last_field) => {
+            let line_start = schema[..last_field.name.span.start]
+                .rfind('\n')
+                .map(|idx| idx + 1)
+                .unwrap_or(0);
+
+            (
+                field_end(schema, last_field),
+                &schema[line_start..last_field.name.span.start],
+            )
+        }
+        None => {
+            let block_open = schema[model.name.span.end..].find('{').unwrap_or(0);
+            (model.name.span.end + block_open + 1, "  ")
+        }
+    };
+
+    let text = rendered_fields
+        .iter()
+        .map(|field| format!("\n{}{}", indentation, field))
+        .collect();
+
+    TextEdit {
+        start: position,
+        end: position,
+        text,
+    }
+}
+
+/// The byte offset after the type and attributes of the field, before any trailing comment or newline.
+fn field_end(schema: &str, field: &ast::Field) -> usize {
+    let end = field
+        .attributes
+        .iter()
+        .map(|attr| attr.span.end)
+        .chain(std::iter::once(field.field_type.span.end))
+        .max()
+        .unwrap_or(field.span.end);
+
+    schema[..end].trim_end().len()
+}
+
+fn overlaps(a: Span, b: Span) -> bool {
+    a.start <= b.end && b.start <= a.end
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Applies the edits of the action to the schema, from the last to the first.
+    fn apply(schema: &str, action: &CodeAction) -> String {
+        let mut edits: Vec<&TextEdit> = action.edits.iter().collect();
+        edits.sort_by_key(|edit| std::cmp::Reverse(edit.start));
+
+        let mut result = schema.to_owned();
+
+        for edit in edits {
+            result.replace_range(edit.start..edit.end, &edit.text);
+        }
+
+        result
+    }
+
+    /// The single action for the whole schema, applied to it. Panics unless the fixed schema validates without
+    /// warnings.
+    fn fix(schema: &str) -> String {
+        let actions = compute(schema, Span::new(0, schema.len()));
+        assert_eq!(
+            actions.len(),
+            1,
+            "{:?}",
+            actions.iter().map(|a| &a.title).collect::<Vec<_>>()
+        );
+
+        let fixed = apply(schema, &actions[0]);
+
+        match datamodel::parse_datamodel_and_ignore_datasource_urls(&fixed) {
+            Ok(validated) => assert!(validated.warnings.is_empty(), "{:?}\n{}", validated.warnings, fixed),
+            Err(diagnostics) => panic!("{:?}\n{}", diagnostics.errors, fixed),
+        }
+
+        fixed
+    }
+
+    #[test]
+    fn adding_the_opposite_relation_field_fixes_the_schema() {
+        let schema = r#"
+model User {
+  id Int @id
+}
+
+model Post {
+  id       Int  @id
+  authorId Int
+  author   User @relation(fields: [authorId], references: [id])
+}
+"#;
+
+        let fixed = fix(schema);
+
+        assert!(fixed.contains("Post[]\n}\n\nmodel Post"), "{}", fixed);
+    }
+
+    #[test]
+    fn making_the_referenced_field_unique_fixes_the_schema() {
+        let schema = r#"
+model User {
+  id    Int    @id
+  email String // the login
+  posts Post[]
+}
+
+model Post {
+  id          Int    @id
+  authorEmail String
+  author      User   @relation(fields: [authorEmail], references: [email])
+}
+"#;
+
+        let fixed = fix(schema);
+
+        assert!(fixed.contains("email String @unique // the login"), "{}", fixed);
+    }
+
+    #[test]
+    fn creating_the_enum_fixes_the_schema() {
+        let schema = "model User {\n  id   Int  @id\n  role Role\n}";
+        let fixed = fix(schema);
+
+        assert!(fixed.ends_with("}\n\nenum Role {\n  VALUE\n}\n"), "{}", fixed);
+    }
+
+    #[test]
+    fn removing_the_deprecated_preview_feature_fixes_the_schema() {
+        let schema = r#"
+generator client {
+  provider        = "prisma-client-js"
+  previewFeatures = ["groupBy", "nativeTypes"]
+}
+
+model User {
+  id Int @id
+}
+"#;
+
+        let fixed = fix(schema);
+
+        assert!(fixed.contains(r#"previewFeatures = ["groupBy"]"#), "{}", fixed);
+    }
+}
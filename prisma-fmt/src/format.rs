@@ -1,4 +1,4 @@
-use datamodel::ast::reformat::Reformatter;
+use datamodel::ast::{reformat::Reformatter, SourceFiles};
 use std::{
     fs::{self, File},
     io::{self, BufWriter, Read},
@@ -7,6 +7,10 @@ use std::{
 use crate::FormatOpts;
 
 pub fn run(opts: FormatOpts) {
+    if !opts.files.is_empty() {
+        return format_files(opts);
+    }
+
     let datamodel_string = match opts.input {
         Some(file_name) => {
             fs::read_to_string(&file_name).unwrap_or_else(|_| panic!("Unable to open file {}", file_name.display()))
@@ -34,3 +38,26 @@ pub fn run(opts: FormatOpts) {
         }
     }
 }
+
+/// Formats every file of a schema split across multiple files in place.
+fn format_files(opts: FormatOpts) {
+    let files = opts.files.iter().map(|file_name| {
+        let content =
+            fs::read_to_string(&file_name).unwrap_or_else(|_| panic!("Unable to open file {}", file_name.display()));
+
+        (file_name.display().to_string(), content)
+    });
+
+    let source_files = SourceFiles::new(files);
+
+    for file_name in &opts.files {
+        let name = file_name.display().to_string();
+        let mut formatted = Vec::new();
+
+        Reformatter::new_for_file(&source_files, &name)
+            .expect("The file is part of the schema.")
+            .reformat_to(&mut formatted, opts.tabwidth);
+
+        fs::write(&file_name, formatted).unwrap_or_else(|_| panic!("Unable to write file {}", file_name.display()));
+    }
+}
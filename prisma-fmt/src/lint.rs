@@ -1,40 +1,59 @@
 use crate::{LintOpts, MiniError};
-use datamodel::diagnostics::{DatamodelError, DatamodelWarning};
-use std::io::{self, Read};
+use datamodel::{
+    ast::{SourceFiles, Span},
+    diagnostics::{DatamodelError, DatamodelWarning},
+};
+use std::{
+    fs,
+    io::{self, Read},
+};
 
 pub fn run(opts: LintOpts) {
-    let mut datamodel_string = String::new();
+    let source_files = if opts.files.is_empty() {
+        None
+    } else {
+        let files = opts.files.iter().map(|file_name| {
+            let content = fs::read_to_string(&file_name)
+                .unwrap_or_else(|_| panic!("Unable to open file {}", file_name.display()));
 
-    io::stdin()
-        .read_to_string(&mut datamodel_string)
-        .expect("Unable to read from stdin.");
+            (file_name.display().to_string(), content)
+        });
 
-    let datamodel_result = if opts.no_env_errors {
-        datamodel::parse_datamodel_and_ignore_datasource_urls(&datamodel_string)
-    } else {
-        datamodel::parse_datamodel(&datamodel_string)
+        Some(SourceFiles::new(files))
+    };
+
+    let datamodel_result = match &source_files {
+        Some(source_files) if opts.no_env_errors => {
+            datamodel::parse_datamodel_from_files_and_ignore_datasource_urls(source_files)
+        }
+        Some(source_files) => datamodel::parse_datamodel_from_files(source_files),
+        None => {
+            let mut datamodel_string = String::new();
+
+            io::stdin()
+                .read_to_string(&mut datamodel_string)
+                .expect("Unable to read from stdin.");
+
+            if opts.no_env_errors {
+                datamodel::parse_datamodel_and_ignore_datasource_urls(&datamodel_string)
+            } else {
+                datamodel::parse_datamodel(&datamodel_string)
+            }
+        }
     };
 
+    let source_files = source_files.as_ref();
+
     match datamodel_result {
         Err(err) => {
             let mut mini_errors: Vec<MiniError> = err
                 .to_error_iter()
-                .map(|err: &DatamodelError| MiniError {
-                    start: err.span().start,
-                    end: err.span().end,
-                    text: format!("{}", err),
-                    is_warning: false,
-                })
+                .map(|err: &DatamodelError| mini_error(source_files, err.span(), format!("{}", err), false))
                 .collect();
 
             let mut mini_warnings: Vec<MiniError> = err
                 .to_warning_iter()
-                .map(|warn: &DatamodelWarning| MiniError {
-                    start: warn.span().start,
-                    end: warn.span().end,
-                    text: format!("{}", warn),
-                    is_warning: true,
-                })
+                .map(|warn: &DatamodelWarning| mini_error(source_files, warn.span(), format!("{}", warn), true))
                 .collect();
 
             mini_errors.append(&mut mini_warnings);
@@ -45,12 +64,7 @@ pub fn run(opts: LintOpts) {
             let mini_warnings: Vec<MiniError> = validated_datamodel
                 .warnings
                 .into_iter()
-                .map(|warn: DatamodelWarning| MiniError {
-                    start: warn.span().start,
-                    end: warn.span().end,
-                    text: format!("{}", warn),
-                    is_warning: true,
-                })
+                .map(|warn: DatamodelWarning| mini_error(source_files, warn.span(), format!("{}", warn), true))
                 .collect();
 
             print_diagnostics(mini_warnings);
@@ -58,6 +72,22 @@ pub fn run(opts: LintOpts) {
     }
 }
 
+/// A diagnostic at the span. For a schema split across multiple files, the span is relative to the file it occurred in.
+fn mini_error(source_files: Option<&SourceFiles>, span: Span, text: String, is_warning: bool) -> MiniError {
+    let (file, span) = match source_files.and_then(|files| files.locate(span)) {
+        Some((file, span)) => (Some(file.name.clone()), span),
+        None => (None, span),
+    };
+
+    MiniError {
+        start: span.start,
+        end: span.end,
+        text,
+        is_warning,
+        file,
+    }
+}
+
 fn print_diagnostics(diagnostics: Vec<MiniError>) {
     let json = serde_json::to_string(&diagnostics).expect("Failed to render JSON");
 
@@ -0,0 +1,378 @@
+use super::position::{offset_to_position, position_to_offset, Position};
+use datamodel::common::preview_features::{
+    DATASOURCE_PREVIEW_FEATURES, DEPRECATED_GENERATOR_PREVIEW_FEATURES, GENERATOR_PREVIEW_FEATURES,
+};
+use serde_json::{json, Value};
+
+const KIND_PROPERTY: u8 = 10;
+const KIND_VALUE: u8 = 12;
+const KIND_ENUM: u8 = 13;
+const KIND_KEYWORD: u8 = 14;
+const KIND_CLASS: u8 = 7;
+
+const KEYWORDS: &[&str] = &["model", "enum", "type", "datasource", "generator"];
+
+const SCALAR_TYPES: &[&str] = &[
+    "String",
+    "Boolean",
+    "Int",
+    "BigInt",
+    "Float",
+    "Decimal",
+    "DateTime",
+    "Json",
+    "Bytes",
+    "Unsupported",
+];
+
+const FIELD_ATTRIBUTES: &[(&str, &str)] = &[
+    ("id", "Defines a single-field ID on the model."),
+    ("unique", "Defines a unique constraint for this field."),
+    ("default", "Defines a default value for this field."),
+    (
+        "updatedAt",
+        "Automatically stores the time when a record was last updated.",
+    ),
+    (
+        "map",
+        "Maps a field name from the Prisma schema to a different column name.",
+    ),
+    ("relation", "Defines meta information about the relation."),
+    ("ignore", "Excludes this field from the Prisma Client."),
+];
+
+const BLOCK_ATTRIBUTES: &[(&str, &str)] = &[
+    ("id", "Defines a multi-field ID on the model."),
+    (
+        "unique",
+        "Defines a compound unique constraint for the specified fields.",
+    ),
+    ("index", "Defines an index on the specified fields."),
+    ("fulltext", "Defines a full text index on the specified fields."),
+    (
+        "map",
+        "Maps the model name from the Prisma schema to a different table name.",
+    ),
+    ("ignore", "Excludes this model from the Prisma Client."),
+];
+
+/// The kind of block enclosing a position in the schema.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Block {
+    Model,
+    Enum,
+    Datasource,
+    Generator,
+}
+
+/// The completion items at the position. The schema is inspected textually, since it usually doesn't parse while
+/// being edited.
+pub fn compute(text: &str, position: Position) -> Vec<Value> {
+    let lines: Vec<&str> = text.split('\n').collect();
+
+    let line = match lines.get(position.line) {
+        Some(line) => line,
+        None => return Vec::new(),
+    };
+
+    let line_start: usize = lines[..position.line].iter().map(|line| line.len() + 1).sum();
+    let cursor = line_start + position_to_offset(line, Position { line: 0, ..position });
+    let line_prefix = &text[line_start..cursor];
+
+    let word_start = line_prefix
+        .rfind(|c: char| !(c.is_alphanumeric() || c == '_' || c == '@' || c == '.'))
+        .map(|idx| idx + 1)
+        .unwrap_or(0);
+
+    let word = &line_prefix[word_start..];
+    let range = json!({
+        "start": offset_to_position(text, line_start + word_start).to_json(),
+        "end": position.to_json(),
+    });
+
+    let block = enclosing_block(&lines[..position.line]);
+
+    match block {
+        Some(Block::Model) if word.starts_with("@@") => attribute_items(BLOCK_ATTRIBUTES, "@@", &range),
+        Some(Block::Model) if word.starts_with('@') && word.contains('.') => {
+            let source_name = &word[1..word.find('.').unwrap()];
+            native_type_items(&lines, source_name, &range)
+        }
+        Some(Block::Model) if word.starts_with('@') => attribute_items(FIELD_ATTRIBUTES, "@", &range),
+        Some(Block::Model) if is_field_type_position(&line_prefix[..word_start]) => type_items(&lines, &range),
+        Some(Block::Generator) if is_preview_features_list(line_prefix) => {
+            let features = GENERATOR_PREVIEW_FEATURES
+                .iter()
+                .filter(|feature| !DEPRECATED_GENERATOR_PREVIEW_FEATURES.contains(feature));
+
+            preview_feature_items(features, line_prefix)
+        }
+        Some(Block::Datasource) if is_preview_features_list(line_prefix) => {
+            preview_feature_items(DATASOURCE_PREVIEW_FEATURES.iter(), line_prefix)
+        }
+        None if line_prefix[..word_start].trim().is_empty() && !word.contains(|c: char| c == '@' || c == '.') => {
+            KEYWORDS
+                .iter()
+                .map(|keyword| item(keyword, KIND_KEYWORD, None, &range, keyword))
+                .collect()
+        }
+        _ => Vec::new(),
+    }
+}
+
+/// The block enclosing the end of the lines, if any.
+fn enclosing_block(lines: &[&str]) -> Option<Block> {
+    let mut block = None;
+
+    for line in lines {
+        let line = line.trim();
+
+        if line.starts_with('}') {
+            block = None;
+        } else if line.ends_with('{') {
+            block = match line.split_whitespace().next() {
+                Some("model") | Some("view") => Some(Block::Model),
+                Some("enum") => Some(Block::Enum),
+                Some("datasource") => Some(Block::Datasource),
+                Some("generator") => Some(Block::Generator),
+                _ => block,
+            };
+        }
+    }
+
+    block
+}
+
+/// Whether the text before the current word on the line is a field name.
+fn is_field_type_position(before_word: &str) -> bool {
+    let tokens: Vec<&str> = before_word.split_whitespace().collect();
+
+    tokens.len() == 1 && before_word.ends_with(char::is_whitespace) && !tokens[0].starts_with("//")
+}
+
+/// Whether the cursor is within the list of a `previewFeatures` property.
+fn is_preview_features_list(line_prefix: &str) -> bool {
+    let line_prefix = line_prefix.trim_start();
+
+    line_prefix.starts_with("previewFeatures") && line_prefix.contains('[') && !line_prefix.contains(']')
+}
+
+fn attribute_items(attributes: &[(&str, &str)], prefix: &str, range: &Value) -> Vec<Value> {
+    attributes
+        .iter()
+        .map(|(name, documentation)| {
+            let label = format!("{}{}", prefix, name);
+            item(&label, KIND_PROPERTY, Some(*documentation), range, &label)
+        })
+        .collect()
+}
+
+/// The scalar types and the models, enums and type aliases declared in the schema.
+fn type_items(lines: &[&str], range: &Value) -> Vec<Value> {
+    let scalar_types = SCALAR_TYPES
+        .iter()
+        .map(|name| item(name, KIND_VALUE, None, range, name));
+
+    let declared_types = lines.iter().filter_map(|line| {
+        let mut tokens = line.split_whitespace();
+
+        let kind = match tokens.next()? {
+            "model" | "view" | "type" => KIND_CLASS,
+            "enum" => KIND_ENUM,
+            _ => return None,
+        };
+
+        let name = tokens.next()?.trim_end_matches('{');
+
+        Some(item(name, kind, None, range, name))
+    });
+
+    scalar_types.chain(declared_types).collect()
+}
+
+/// The native types of the connector of the datasource with the name.
+fn native_type_items(lines: &[&str], source_name: &str, range: &Value) -> Vec<Value> {
+    let datasource_block = match datasource_block(lines, source_name) {
+        Some(block) => block,
+        None => return Vec::new(),
+    };
+
+    let configuration = match datamodel::parse_configuration_and_ignore_datasource_urls(&datasource_block) {
+        Ok(configuration) => configuration.subject,
+        Err(_) => return Vec::new(),
+    };
+
+    let datasource = match configuration.datasources.first() {
+        Some(datasource) => datasource,
+        None => return Vec::new(),
+    };
+
+    datasource
+        .active_connector
+        .available_native_type_constructors()
+        .iter()
+        .map(|constructor| {
+            let types: Vec<String> = constructor.prisma_types.iter().map(|t| t.to_string()).collect();
+            let detail = format!("Compatible with: {}", types.join(", "));
+            let new_text = format!("@{}.{}", source_name, constructor.name);
+
+            item(
+                &constructor.name,
+                KIND_PROPERTY,
+                Some(detail.as_str()),
+                range,
+                &new_text,
+            )
+        })
+        .collect()
+}
+
+/// The text of the datasource block with the name, which can be validated on its own.
+fn datasource_block(lines: &[&str], source_name: &str) -> Option<String> {
+    let start = lines.iter().position(|line| {
+        let mut tokens = line.split_whitespace();
+        tokens.next() == Some("datasource") && tokens.next().map(|name| name.trim_end_matches('{')) == Some(source_name)
+    })?;
+
+    let end = start + lines[start..].iter().position(|line| line.trim().starts_with('}'))?;
+
+    Some(lines[start..=end].join("\n"))
+}
+
+fn preview_feature_items<'a>(features: impl Iterator<Item = &'a &'static str>, line_prefix: &str) -> Vec<Value> {
+    let quoted = line_prefix.matches('"').count() % 2 == 1;
+
+    features
+        .map(|feature| {
+            let insert_text = if quoted {
+                feature.to_string()
+            } else {
+                format!("\"{}\"", feature)
+            };
+
+            json!({ "label": feature, "kind": KIND_VALUE, "insertText": insert_text })
+        })
+        .collect()
+}
+
+fn item(label: &str, kind: u8, detail: Option<&str>, range: &Value, new_text: &str) -> Value {
+    let mut item = json!({
+        "label": label,
+        "kind": kind,
+        "textEdit": { "range": range, "newText": new_text },
+    });
+
+    if let Some(detail) = detail {
+        item["detail"] = json!(detail);
+    }
+
+    item
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn labels(items: &[Value]) -> Vec<&str> {
+        items.iter().map(|item| item["label"].as_str().unwrap()).collect()
+    }
+
+    #[test]
+    fn field_attributes_are_completed_after_an_at_sign() {
+        let text = "model A {\n  id Int @\n}";
+        let items = compute(text, Position { line: 1, character: 10 });
+
+        assert_eq!(
+            labels(&items),
+            &[
+                "@id",
+                "@unique",
+                "@default",
+                "@updatedAt",
+                "@map",
+                "@relation",
+                "@ignore"
+            ]
+        );
+
+        assert_eq!(
+            items[0]["textEdit"],
+            json!({
+                "range": { "start": { "line": 1, "character": 9 }, "end": { "line": 1, "character": 10 } },
+                "newText": "@id",
+            })
+        );
+    }
+
+    #[test]
+    fn block_attributes_are_completed_after_two_at_signs() {
+        let text = "model A {\n  id Int\n  @@\n}";
+        let items = compute(text, Position { line: 2, character: 4 });
+
+        assert_eq!(
+            labels(&items),
+            &["@@id", "@@unique", "@@index", "@@fulltext", "@@map", "@@ignore"]
+        );
+    }
+
+    #[test]
+    fn attributes_are_not_completed_outside_of_models() {
+        let text = "enum Role {\n  A @\n}";
+
+        assert!(compute(text, Position { line: 1, character: 5 }).is_empty());
+    }
+
+    #[test]
+    fn scalar_and_declared_types_are_completed_after_a_field_name() {
+        let text = "model Ünïcödé {\n  id   Int @id\n  role R\n}\n\nenum Role {\n  A\n}";
+        let items = compute(text, Position { line: 2, character: 8 });
+
+        assert_eq!(
+            labels(&items),
+            &[
+                "String",
+                "Boolean",
+                "Int",
+                "BigInt",
+                "Float",
+                "Decimal",
+                "DateTime",
+                "Json",
+                "Bytes",
+                "Unsupported",
+                "Ünïcödé",
+                "Role",
+            ]
+        );
+
+        let role = items.iter().find(|item| item["label"] == "Role").unwrap();
+
+        assert_eq!(role["kind"], json!(KIND_ENUM));
+        assert_eq!(
+            role["textEdit"]["range"],
+            json!({ "start": { "line": 2, "character": 7 }, "end": { "line": 2, "character": 8 } })
+        );
+    }
+
+    #[test]
+    fn native_types_of_the_datasource_are_completed() {
+        let text = "datasource db {\n  provider = \"postgresql\"\n  url      = \"postgresql://localhost:5432/db\"\n}\n\nmodel A {\n  id String @db.\n}";
+        let items = compute(text, Position { line: 6, character: 16 });
+        let labels = labels(&items);
+
+        assert!(labels.contains(&"VarChar"), "{:?}", labels);
+        assert!(labels.contains(&"Uuid"), "{:?}", labels);
+
+        let varchar = items.iter().find(|item| item["label"] == "VarChar").unwrap();
+
+        assert_eq!(varchar["textEdit"]["newText"], json!("@db.VarChar"));
+        assert_eq!(varchar["detail"], json!("Compatible with: String"));
+    }
+
+    #[test]
+    fn native_types_of_unknown_datasources_are_not_completed() {
+        let text = "model A {\n  id String @db.\n}";
+
+        assert!(compute(text, Position { line: 1, character: 16 }).is_empty());
+    }
+}
@@ -0,0 +1,17 @@
+use super::position::span_to_range;
+use super::symbols::{self, Symbol};
+use datamodel::ast::{SchemaAst, WithIdentifier};
+use serde_json::{json, Value};
+
+/// The location of the declaration of the symbol at the byte offset: the model, enum or type alias of a field type,
+/// or the field referenced in the arguments of an attribute.
+pub fn compute(uri: &str, text: &str, ast: &SchemaAst, offset: usize) -> Option<Value> {
+    let span = match symbols::symbol_at(ast, offset)? {
+        Symbol::Top(top) => top.identifier().span,
+        Symbol::Field(_, field) => field.name.span,
+        Symbol::EnumValue(enm, idx) => enm.values[idx].name.span,
+        Symbol::ScalarType(_) => return None,
+    };
+
+    Some(json!({ "uri": uri, "range": span_to_range(text, span) }))
+}
@@ -0,0 +1,37 @@
+use super::position::span_to_range;
+use datamodel::ast::Span;
+use serde_json::{json, Value};
+
+const SEVERITY_ERROR: u8 = 1;
+const SEVERITY_WARNING: u8 = 2;
+
+/// The errors and warnings of validating the schema, as Language Server Protocol diagnostics.
+pub fn compute(text: &str) -> Vec<Value> {
+    match datamodel::parse_datamodel_and_ignore_datasource_urls(text) {
+        Err(err) => {
+            let errors = err
+                .to_error_iter()
+                .map(|err| diagnostic(text, err.span(), err.to_string(), SEVERITY_ERROR));
+
+            let warnings = err
+                .to_warning_iter()
+                .map(|warn| diagnostic(text, warn.span(), warn.to_string(), SEVERITY_WARNING));
+
+            errors.chain(warnings).collect()
+        }
+        Ok(validated_datamodel) => validated_datamodel
+            .warnings
+            .iter()
+            .map(|warn| diagnostic(text, warn.span(), warn.to_string(), SEVERITY_WARNING))
+            .collect(),
+    }
+}
+
+fn diagnostic(text: &str, span: Span, message: String, severity: u8) -> Value {
+    json!({
+        "range": span_to_range(text, span),
+        "severity": severity,
+        "source": "prisma",
+        "message": message,
+    })
+}
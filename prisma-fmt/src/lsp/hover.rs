@@ -0,0 +1,63 @@
+use super::position::span_to_range;
+use super::symbols::{self, Symbol};
+use datamodel::ast::{SchemaAst, Span, WithDocumentation, WithIdentifier, WithSpan};
+use serde_json::{json, Value};
+
+/// The hover contents for the symbol at the byte offset: its declaration and its `///` documentation.
+pub fn compute(text: &str, ast: &SchemaAst, offset: usize) -> Option<Value> {
+    let (declaration, documentation, span) = match symbols::symbol_at(ast, offset)? {
+        Symbol::Top(top) => (
+            declaration(text, *top.span()),
+            symbols::top_documentation(top),
+            top.identifier().span,
+        ),
+        Symbol::Field(model, field) => (
+            format!(
+                "{} {{\n  {}\n}}",
+                declaration(text, model.span),
+                declaration(text, field.span)
+            ),
+            field.documentation().as_ref().map(|comment| comment.text.as_str()),
+            field.name.span,
+        ),
+        Symbol::EnumValue(enm, idx) => {
+            let value = &enm.values[idx];
+
+            (
+                format!("{} {{\n  {}\n}}", declaration(text, enm.span), value.name.name),
+                value.documentation.as_ref().map(|comment| comment.text.as_str()),
+                value.name.span,
+            )
+        }
+        Symbol::ScalarType(name) => (name.to_owned(), None, Span::empty()),
+    };
+
+    let mut contents = format!("```prisma\n{}\n```", declaration);
+
+    if let Some(documentation) = documentation {
+        contents.push_str("\n___\n");
+        contents.push_str(documentation);
+    }
+
+    let mut hover = json!({ "contents": { "kind": "markdown", "value": contents } });
+
+    if span != Span::empty() {
+        hover["range"] = span_to_range(text, span);
+    }
+
+    Some(hover)
+}
+
+/// The first line of the text of a declaration without the opening brace, e.g. `model User` or `posts Post[]`.
+fn declaration(text: &str, span: Span) -> String {
+    let source = &text[span.start..span.end.min(text.len())];
+
+    source
+        .lines()
+        .map(str::trim)
+        .find(|line| !line.is_empty() && !line.starts_with("//"))
+        .unwrap_or("")
+        .trim_end_matches('{')
+        .trim_end()
+        .to_owned()
+}
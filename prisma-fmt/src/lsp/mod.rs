@@ -0,0 +1,277 @@
+//! A language server for Prisma schemas, speaking the Language Server Protocol over stdio.
+
+mod completion;
+mod definition;
+mod diagnostics;
+mod hover;
+mod position;
+mod rename;
+mod symbols;
+mod transport;
+
+use datamodel::ast::{reformat::Reformatter, Span};
+use position::Position;
+use serde_json::{json, Value};
+use std::{
+    collections::HashMap,
+    io,
+    panic::{self, AssertUnwindSafe},
+};
+
+const METHOD_NOT_FOUND: i64 = -32601;
+const INVALID_PARAMS: i64 = -32602;
+const INTERNAL_ERROR: i64 = -32603;
+
+pub fn run() {
+    let stdin = io::stdin();
+    let stdout = io::stdout();
+    let mut input = stdin.lock();
+    let mut output = stdout.lock();
+    let mut server = Server::default();
+
+    while let Some(message) = transport::read_message(&mut input).expect("Unable to read from stdin.") {
+        let id = message.get("id").cloned();
+        let responses = match panic::catch_unwind(AssertUnwindSafe(|| server.handle(&message))) {
+            Ok(responses) => responses,
+            Err(_) => id
+                .map(|id| vec![error_response(id, INTERNAL_ERROR, "The request caused a panic.")])
+                .unwrap_or_default(),
+        };
+
+        for response in responses {
+            transport::write_message(&mut output, &response).expect("Unable to write to stdout.");
+        }
+
+        if server.exited {
+            break;
+        }
+    }
+}
+
+/// The state of the language server: the open documents by URI.
+#[derive(Default)]
+struct Server {
+    documents: HashMap<String, String>,
+    exited: bool,
+}
+
+type RequestResult = Result<Value, (i64, String)>;
+
+impl Server {
+    /// Handles a request or notification from the client, returning the messages to send back.
+    fn handle(&mut self, message: &Value) -> Vec<Value> {
+        let method = message["method"].as_str().unwrap_or_default();
+        let params = &message["params"];
+
+        match message.get("id") {
+            Some(id) => {
+                let response = match self.handle_request(method, params) {
+                    Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+                    Err((code, message)) => error_response(id.clone(), code, &message),
+                };
+
+                vec![response]
+            }
+            None => self.handle_notification(method, params),
+        }
+    }
+
+    fn handle_request(&self, method: &str, params: &Value) -> RequestResult {
+        match method {
+            "initialize" => Ok(json!({
+                "capabilities": {
+                    "textDocumentSync": 1,
+                    "completionProvider": { "triggerCharacters": ["@", ".", "\""] },
+                    "hoverProvider": true,
+                    "definitionProvider": true,
+                    "renameProvider": true,
+                    "documentFormattingProvider": true,
+                    "codeActionProvider": true,
+                },
+                "serverInfo": { "name": "prisma-fmt" },
+            })),
+            "shutdown" => Ok(Value::Null),
+            "textDocument/completion" => {
+                let (text, position) = self.document_position(params)?;
+                Ok(json!(completion::compute(text, position)))
+            }
+            "textDocument/hover" => self.with_ast(params, |_, text, ast, offset| {
+                Ok(hover::compute(text, ast, offset).unwrap_or(Value::Null))
+            }),
+            "textDocument/definition" => self.with_ast(params, |uri, text, ast, offset| {
+                Ok(definition::compute(uri, text, ast, offset).unwrap_or(Value::Null))
+            }),
+            "textDocument/rename" => {
+                let new_name = params["newName"]
+                    .as_str()
+                    .ok_or_else(|| (INVALID_PARAMS, "Missing `newName`.".to_owned()))?
+                    .to_owned();
+
+                self.with_ast(params, |uri, text, ast, offset| {
+                    rename::compute(uri, text, ast, offset, &new_name).map_err(|err| (INVALID_PARAMS, err))
+                })
+            }
+            "textDocument/codeAction" => {
+                let (uri, text) = self.document(params)?;
+                let range = &params["range"];
+
+                let (start, end) = match (Position::from_json(&range["start"]), Position::from_json(&range["end"])) {
+                    (Some(start), Some(end)) => (start, end),
+                    _ => return Err((INVALID_PARAMS, "Missing `range`.".to_owned())),
+                };
+
+                let span = Span::new(
+                    position::position_to_offset(text, start),
+                    position::position_to_offset(text, end),
+                );
+
+                Ok(code_actions(uri, text, span))
+            }
+            "textDocument/formatting" => {
+                let (_, text) = self.document(params)?;
+                let tab_width = params["options"]["tabSize"].as_u64().unwrap_or(2) as usize;
+
+                Ok(format(text, tab_width))
+            }
+            _ => Err((METHOD_NOT_FOUND, format!("Unknown method `{}`.", method))),
+        }
+    }
+
+    fn handle_notification(&mut self, method: &str, params: &Value) -> Vec<Value> {
+        let uri = params["textDocument"]["uri"].as_str().unwrap_or_default().to_owned();
+
+        match method {
+            "textDocument/didOpen" => {
+                let text = params["textDocument"]["text"].as_str().unwrap_or_default().to_owned();
+                self.documents.insert(uri.clone(), text);
+
+                vec![self.publish_diagnostics(&uri)]
+            }
+            "textDocument/didChange" => {
+                let changes = params["contentChanges"].as_array();
+
+                if let Some(text) = changes.and_then(|changes| changes.last()?["text"].as_str()) {
+                    self.documents.insert(uri.clone(), text.to_owned());
+                }
+
+                vec![self.publish_diagnostics(&uri)]
+            }
+            "textDocument/didClose" => {
+                self.documents.remove(&uri);
+
+                vec![self.publish_diagnostics(&uri)]
+            }
+            "exit" => {
+                self.exited = true;
+
+                Vec::new()
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    fn publish_diagnostics(&self, uri: &str) -> Value {
+        let diagnostics = self
+            .documents
+            .get(uri)
+            .map(|text| diagnostics::compute(text))
+            .unwrap_or_default();
+
+        json!({
+            "jsonrpc": "2.0",
+            "method": "textDocument/publishDiagnostics",
+            "params": { "uri": uri, "diagnostics": diagnostics },
+        })
+    }
+
+    /// The URI and text of the document the request refers to.
+    fn document<'a>(&'a self, params: &'a Value) -> Result<(&'a str, &'a str), (i64, String)> {
+        let uri = params["textDocument"]["uri"]
+            .as_str()
+            .ok_or_else(|| (INVALID_PARAMS, "Missing `textDocument.uri`.".to_owned()))?;
+
+        let text = self
+            .documents
+            .get(uri)
+            .ok_or_else(|| (INVALID_PARAMS, format!("The document `{}` is not open.", uri)))?;
+
+        Ok((uri, text))
+    }
+
+    /// The text of the document the request refers to and the position in it.
+    fn document_position<'a>(&'a self, params: &'a Value) -> Result<(&'a str, Position), (i64, String)> {
+        let (_, text) = self.document(params)?;
+        let position = Position::from_json(&params["position"])
+            .ok_or_else(|| (INVALID_PARAMS, "Missing `position`.".to_owned()))?;
+
+        Ok((text, position))
+    }
+
+    /// Runs the handler on the parsed document the request refers to and the byte offset of its position. Returns
+    /// `null` if the document doesn't parse.
+    fn with_ast<F>(&self, params: &Value, handler: F) -> RequestResult
+    where
+        F: FnOnce(&str, &str, &datamodel::ast::SchemaAst, usize) -> RequestResult,
+    {
+        let (uri, _) = self.document(params)?;
+        let (text, position) = self.document_position(params)?;
+
+        match datamodel::parse_schema_ast(text) {
+            Ok(ast) => handler(uri, text, &ast, position::position_to_offset(text, position)),
+            Err(_) => Ok(Value::Null),
+        }
+    }
+}
+
+/// The edits formatting the document, replacing all of its text. Documents with syntax errors are left untouched.
+fn format(text: &str, tab_width: usize) -> Value {
+    if datamodel::parse_schema_ast(text).is_err() {
+        return Value::Null;
+    }
+
+    let mut buf = Vec::new();
+    Reformatter::new(text).reformat_to(&mut buf, tab_width);
+
+    let formatted = String::from_utf8(buf).expect("Formatted schema is not valid UTF-8");
+    let end = position::offset_to_position(text, text.len());
+
+    json!([{
+        "range": { "start": Position { line: 0, character: 0 }.to_json(), "end": end.to_json() },
+        "newText": formatted,
+    }])
+}
+
+/// The quick fixes for the diagnostics overlapping the span, as Language Server Protocol code actions.
+fn code_actions(uri: &str, text: &str, span: Span) -> Value {
+    let actions: Vec<Value> = crate::code_actions::compute(text, span)
+        .into_iter()
+        .map(|action| {
+            let edits: Vec<Value> = action
+                .edits
+                .into_iter()
+                .map(|edit| {
+                    json!({
+                        "range": position::span_to_range(text, Span::new(edit.start, edit.end)),
+                        "newText": edit.text,
+                    })
+                })
+                .collect();
+
+            json!({
+                "title": action.title,
+                "kind": "quickfix",
+                "edit": { "changes": { uri: edits } },
+            })
+        })
+        .collect();
+
+    json!(actions)
+}
+
+fn error_response(id: Value, code: i64, message: &str) -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": id,
+        "error": { "code": code, "message": message },
+    })
+}
@@ -0,0 +1,113 @@
+use datamodel::ast::Span;
+use serde_json::{json, Value};
+
+/// A position in a document as defined by the Language Server Protocol: a zero-based line and a zero-based
+/// character offset on the line, counted in UTF-16 code units.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Position {
+    pub line: usize,
+    pub character: usize,
+}
+
+impl Position {
+    pub fn from_json(value: &Value) -> Option<Position> {
+        Some(Position {
+            line: value.get("line")?.as_u64()? as usize,
+            character: value.get("character")?.as_u64()? as usize,
+        })
+    }
+
+    pub fn to_json(self) -> Value {
+        json!({ "line": self.line, "character": self.character })
+    }
+}
+
+/// The position of a byte offset into the text.
+pub fn offset_to_position(text: &str, offset: usize) -> Position {
+    let offset = offset.min(text.len());
+    let before = &text[..offset];
+    let line = before.matches('\n').count();
+    let line_start = before.rfind('\n').map(|idx| idx + 1).unwrap_or(0);
+    let character = before[line_start..].encode_utf16().count();
+
+    Position { line, character }
+}
+
+/// The byte offset into the text of a position. Positions past the end of a line resolve to the end of the line.
+pub fn position_to_offset(text: &str, position: Position) -> usize {
+    let mut line_start = 0;
+
+    for _ in 0..position.line {
+        match text[line_start..].find('\n') {
+            Some(idx) => line_start += idx + 1,
+            None => return text.len(),
+        }
+    }
+
+    let line_end = text[line_start..]
+        .find('\n')
+        .map(|idx| line_start + idx)
+        .unwrap_or_else(|| text.len());
+
+    let mut utf16_count = 0;
+
+    for (idx, c) in text[line_start..line_end].char_indices() {
+        if utf16_count >= position.character {
+            return line_start + idx;
+        }
+
+        utf16_count += c.len_utf16();
+    }
+
+    line_end
+}
+
+/// The range of a span of the text.
+pub fn span_to_range(text: &str, span: Span) -> Value {
+    json!({
+        "start": offset_to_position(text, span.start).to_json(),
+        "end": offset_to_position(text, span.end).to_json(),
+    })
+}
+
+/// Whether the byte offset lies within the span, including its end.
+pub fn span_contains(span: Span, offset: usize) -> bool {
+    span.start <= offset && offset <= span.end
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn positions_count_utf16_code_units() {
+        // `é` is two bytes and one UTF-16 code unit, `😀` four bytes and two UTF-16 code units.
+        let text = "é😀b\nc";
+
+        assert_eq!(offset_to_position(text, 0), Position { line: 0, character: 0 });
+        assert_eq!(offset_to_position(text, 2), Position { line: 0, character: 1 });
+        assert_eq!(offset_to_position(text, 6), Position { line: 0, character: 3 });
+        assert_eq!(offset_to_position(text, 7), Position { line: 0, character: 4 });
+        assert_eq!(offset_to_position(text, 8), Position { line: 1, character: 0 });
+        assert_eq!(offset_to_position(text, 9), Position { line: 1, character: 1 });
+    }
+
+    #[test]
+    fn offsets_and_positions_round_trip_on_non_ascii_text() {
+        let text = "model Ünïcödé {\n  emoji String // 😀 and 🦀\n}\n";
+
+        for (offset, _) in text.char_indices() {
+            let position = offset_to_position(text, offset);
+            assert_eq!(position_to_offset(text, position), offset, "{:?}", position);
+        }
+    }
+
+    #[test]
+    fn positions_past_the_end_of_a_line_resolve_to_its_end() {
+        let text = "ab😀\ncd";
+
+        assert_eq!(position_to_offset(text, Position { line: 0, character: 10 }), 6);
+        assert_eq!(position_to_offset(text, Position { line: 1, character: 10 }), 9);
+        assert_eq!(position_to_offset(text, Position { line: 5, character: 0 }), 9);
+    }
+}
@@ -0,0 +1,45 @@
+use super::position::span_to_range;
+use super::symbols::{self, Symbol};
+use datamodel::ast::{SchemaAst, Span, Top, WithIdentifier};
+use serde_json::{json, Value};
+
+/// The workspace edit renaming the model, enum, type alias or field at the byte offset, together with all references
+/// to it in the schema.
+pub fn compute(uri: &str, text: &str, ast: &SchemaAst, offset: usize, new_name: &str) -> Result<Value, String> {
+    let spans = match symbols::symbol_at(ast, offset) {
+        Some(Symbol::Top(top)) if !matches!(top, Top::Source(_) | Top::Generator(_)) => top_spans(ast, top),
+        Some(Symbol::Field(model, field)) => {
+            let references = symbols::field_references(ast, model)
+                .into_iter()
+                .filter(|(_, reference)| reference.name == field.name.name)
+                .map(|(_, reference)| reference.span);
+
+            std::iter::once(field.name.span).chain(references).collect()
+        }
+        _ => return Err("Only models, enums, type aliases and fields can be renamed.".to_owned()),
+    };
+
+    let edits: Vec<Value> = spans
+        .into_iter()
+        .map(|span| json!({ "range": span_to_range(text, span), "newText": new_name }))
+        .collect();
+
+    Ok(json!({ "changes": { uri: edits } }))
+}
+
+/// The span of the name of the declaration and of every field type referencing it.
+fn top_spans(ast: &SchemaAst, top: &Top) -> Vec<Span> {
+    let name = top.name();
+
+    let fields = ast
+        .models()
+        .into_iter()
+        .flat_map(|model| model.fields.iter())
+        .chain(ast.types());
+
+    let references = fields
+        .filter(|field| field.field_type.name == name)
+        .map(symbols::field_type_name_span);
+
+    std::iter::once(top.identifier().span).chain(references).collect()
+}
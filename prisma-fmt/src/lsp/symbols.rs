@@ -0,0 +1,179 @@
+use super::position::span_contains;
+use datamodel::ast::{Attribute, Enum, Expression, Field, Model, SchemaAst, Span, Top, WithDocumentation};
+
+/// A named element of the schema at a position, either a declaration or a reference to one.
+pub enum Symbol<'a> {
+    /// A top level declaration (model, enum or type alias) or a field type referencing it.
+    Top(&'a Top),
+    /// A field of a model.
+    Field(&'a Model, &'a Field),
+    /// A value of an enum.
+    EnumValue(&'a Enum, usize),
+    /// A scalar or unsupported field type.
+    ScalarType(&'a str),
+}
+
+/// A reference to a field of a model in the arguments of an attribute, e.g. `authorId` in
+/// `@relation(fields: [authorId], references: [id])`.
+pub struct FieldReference<'a> {
+    pub name: &'a str,
+    pub span: Span,
+}
+
+/// The symbol at the byte offset, resolving field types and field references to the referenced declarations.
+pub fn symbol_at(ast: &SchemaAst, offset: usize) -> Option<Symbol<'_>> {
+    for top in &ast.tops {
+        match top {
+            Top::Model(model) => {
+                if span_contains(model.name.span, offset) {
+                    return Some(Symbol::Top(top));
+                }
+
+                if !span_contains(model.span, offset) {
+                    continue;
+                }
+
+                for field in &model.fields {
+                    if span_contains(field.name.span, offset) {
+                        return Some(Symbol::Field(model, field));
+                    }
+
+                    if span_contains(field.field_type.span, offset) {
+                        return Some(field_type_symbol(ast, field));
+                    }
+                }
+
+                let reference = all_field_references(ast)
+                    .into_iter()
+                    .find(|(_, reference)| span_contains(reference.span, offset));
+
+                if let Some((owner, reference)) = reference {
+                    return owner
+                        .fields
+                        .iter()
+                        .find(|field| field.name.name == reference.name)
+                        .map(|field| Symbol::Field(owner, field));
+                }
+            }
+            Top::Enum(enm) => {
+                if span_contains(enm.name.span, offset) {
+                    return Some(Symbol::Top(top));
+                }
+
+                if let Some(idx) = enm
+                    .values
+                    .iter()
+                    .position(|value| span_contains(value.name.span, offset))
+                {
+                    return Some(Symbol::EnumValue(enm, idx));
+                }
+            }
+            Top::Type(alias) => {
+                if span_contains(alias.name.span, offset) {
+                    return Some(Symbol::Top(top));
+                }
+
+                if span_contains(alias.field_type.span, offset) {
+                    return Some(field_type_symbol(ast, alias));
+                }
+            }
+            Top::Source(_) | Top::Generator(_) => (),
+        }
+    }
+
+    None
+}
+
+fn field_type_symbol<'a>(ast: &'a SchemaAst, field: &'a Field) -> Symbol<'a> {
+    match find_top(ast, &field.field_type.name) {
+        Some(top) => Symbol::Top(top),
+        None => Symbol::ScalarType(&field.field_type.name),
+    }
+}
+
+/// The model, enum or type alias with the name.
+pub fn find_top<'a>(ast: &'a SchemaAst, name: &str) -> Option<&'a Top> {
+    ast.tops
+        .iter()
+        .find(|top| !matches!(top, Top::Source(_) | Top::Generator(_)) && top.name() == name)
+}
+
+/// The span of the type name of the field, excluding the arity (`?` or `[]`).
+pub fn field_type_name_span(field: &Field) -> Span {
+    let start = field.field_type.span.start;
+    Span::new(start, start + field.field_type.name.len())
+}
+
+/// The documentation of a top level declaration.
+pub fn top_documentation(top: &Top) -> Option<&str> {
+    let documentation = match top {
+        Top::Model(model) => model.documentation(),
+        Top::Enum(enm) => enm.documentation(),
+        Top::Type(alias) => alias.documentation(),
+        Top::Source(source) => source.documentation(),
+        Top::Generator(generator) => generator.documentation(),
+    };
+
+    documentation.as_ref().map(|comment| comment.text.as_str())
+}
+
+/// All references to the fields of the model in the arguments of attributes, together with the model owning the
+/// referenced fields.
+pub fn field_references<'a>(ast: &'a SchemaAst, model: &'a Model) -> Vec<(&'a Model, FieldReference<'a>)> {
+    all_field_references(ast)
+        .into_iter()
+        .filter(|(owner, _)| owner.name.name == model.name.name)
+        .collect()
+}
+
+/// All references to fields in the arguments of attributes of the schema, together with the model owning the
+/// referenced fields:
+/// - The fields of block level `@@id`, `@@unique`, `@@index` and `@@fulltext` attributes.
+/// - The `fields` of `@relation` attributes, referencing fields of the same model.
+/// - The `references` of `@relation` attributes, referencing fields of the related model.
+fn all_field_references(ast: &SchemaAst) -> Vec<(&Model, FieldReference<'_>)> {
+    let mut references = Vec::new();
+
+    for model in ast.models() {
+        for attribute in &model.attributes {
+            if matches!(attribute.name.name.as_str(), "id" | "unique" | "index" | "fulltext") {
+                for reference in argument_references(attribute, &["", "fields"]) {
+                    references.push((model, reference));
+                }
+            }
+        }
+
+        for field in &model.fields {
+            for attribute in field.attributes.iter().filter(|attr| attr.name.name == "relation") {
+                for reference in argument_references(attribute, &["fields"]) {
+                    references.push((model, reference));
+                }
+
+                if let Some(related_model) = ast.find_model(&field.field_type.name) {
+                    for reference in argument_references(attribute, &["references"]) {
+                        references.push((related_model, reference));
+                    }
+                }
+            }
+        }
+    }
+
+    references
+}
+
+/// The constants listed in the arguments of the attribute with one of the names. Unnamed arguments have an empty name.
+fn argument_references<'a>(attribute: &'a Attribute, argument_names: &[&str]) -> Vec<FieldReference<'a>> {
+    attribute
+        .arguments
+        .iter()
+        .filter(|arg| argument_names.contains(&arg.name.name.as_str()))
+        .flat_map(|arg| match &arg.value {
+            Expression::Array(values, _) => values.iter().collect(),
+            value => vec![value],
+        })
+        .filter_map(|value| match value {
+            Expression::ConstantValue(name, span) => Some(FieldReference { name, span: *span }),
+            _ => None,
+        })
+        .collect()
+}
@@ -0,0 +1,101 @@
+use serde_json::Value;
+use std::io::{self, BufRead, Read, Write};
+
+/// Reads the next message from the client, framed by a `Content-Length` header. Returns `None` at the end of the input.
+pub fn read_message(input: &mut impl BufRead) -> io::Result<Option<Value>> {
+    let mut content_length: Option<usize> = None;
+
+    loop {
+        let mut header = String::new();
+
+        if input.read_line(&mut header)? == 0 {
+            return Ok(None);
+        }
+
+        let header = header.trim_end();
+
+        if header.is_empty() {
+            break;
+        }
+
+        if let Some(length) = header.strip_prefix("Content-Length:") {
+            content_length = length.trim().parse().ok();
+        }
+    }
+
+    let content_length =
+        content_length.ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "Missing Content-Length header."))?;
+
+    let mut content = vec![0; content_length];
+    input.read_exact(&mut content)?;
+
+    let message = serde_json::from_slice(&content).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+
+    Ok(Some(message))
+}
+
+/// Writes a message to the client, framed by a `Content-Length` header.
+pub fn write_message(output: &mut impl Write, message: &Value) -> io::Result<()> {
+    let content = serde_json::to_string(message).expect("Failed to render JSON");
+
+    write!(output, "Content-Length: {}\r\n\r\n{}", content.len(), content)?;
+    output.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use std::io::Cursor;
+
+    #[test]
+    fn read_message_reads_consecutive_framed_messages() {
+        let first = r#"{"jsonrpc":"2.0","id":1,"method":"initialize"}"#;
+        let second = r#"{"jsonrpc":"2.0","method":"initialized","params":{"text":"ünïcödé"}}"#;
+        let input = format!(
+            "Content-Length: {}\r\nContent-Type: application/vscode-jsonrpc; charset=utf-8\r\n\r\n{}Content-Length: {}\r\n\r\n{}",
+            first.len(),
+            first,
+            second.len(),
+            second
+        );
+
+        let mut input = Cursor::new(input.into_bytes());
+
+        assert_eq!(
+            read_message(&mut input).unwrap(),
+            Some(json!({ "jsonrpc": "2.0", "id": 1, "method": "initialize" }))
+        );
+        assert_eq!(
+            read_message(&mut input).unwrap(),
+            Some(json!({ "jsonrpc": "2.0", "method": "initialized", "params": { "text": "ünïcödé" } }))
+        );
+        assert_eq!(read_message(&mut input).unwrap(), None);
+    }
+
+    #[test]
+    fn read_message_fails_without_content_length() {
+        let mut input = Cursor::new(b"Content-Type: application/json\r\n\r\n{}".to_vec());
+        let err = read_message(&mut input).unwrap_err();
+
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn read_message_fails_on_truncated_content() {
+        let mut input = Cursor::new(b"Content-Length: 10\r\n\r\n{}".to_vec());
+        let err = read_message(&mut input).unwrap_err();
+
+        assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+    }
+
+    #[test]
+    fn written_messages_can_be_read_back() {
+        let message = json!({ "jsonrpc": "2.0", "id": 2, "result": { "contents": "😀" } });
+        let mut output = Vec::new();
+
+        write_message(&mut output, &message).unwrap();
+
+        assert_eq!(read_message(&mut Cursor::new(output)).unwrap(), Some(message));
+    }
+}
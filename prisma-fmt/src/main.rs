@@ -1,5 +1,7 @@
+mod code_actions;
 mod format;
 mod lint;
+mod lsp;
 mod native;
 mod preview;
 
@@ -11,6 +13,10 @@ pub struct LintOpts {
     /// If set, silences all `environment variable not found` errors
     #[structopt(long)]
     no_env_errors: bool,
+    /// Lints a schema split across the given files instead of reading it from
+    /// STDIN. The diagnostics specify the file they occurred in
+    #[structopt(long = "file")]
+    files: Vec<PathBuf>,
 }
 
 #[derive(Debug, StructOpt, Clone)]
@@ -26,6 +32,10 @@ pub struct FormatOpts {
     /// Specifies which tab width to use when formatting
     #[structopt(short = "s", long, default_value = "2")]
     tabwidth: usize,
+    /// Formats a schema split across the given files instead of reading it
+    /// from STDIN. Each file is rewritten in place
+    #[structopt(long = "file")]
+    files: Vec<PathBuf>,
 }
 
 #[derive(Debug, StructOpt, Clone)]
@@ -35,6 +45,16 @@ pub struct PreviewFeaturesOpts {
     datasource_only: bool,
 }
 
+#[derive(Debug, StructOpt, Clone)]
+pub struct CodeActionsOpts {
+    /// The start of the span of the diagnostic to fix, as a byte offset
+    #[structopt(long)]
+    start: usize,
+    /// The end of the span of the diagnostic to fix, as a byte offset
+    #[structopt(long)]
+    end: usize,
+}
+
 #[derive(Debug, StructOpt, Clone)]
 #[structopt(version = env!("GIT_HASH"))]
 /// Prisma Datamodel v2 formatter
@@ -47,6 +67,10 @@ pub enum FmtOpts {
     NativeTypes,
     /// Specifies preview features mode
     PreviewFeatures(PreviewFeaturesOpts),
+    /// Specifies code actions mode, returning quick fixes for the diagnostics at a span
+    CodeActions(CodeActionsOpts),
+    /// Starts a language server speaking the Language Server Protocol over stdio
+    Lsp,
 }
 
 #[derive(serde::Serialize)]
@@ -55,6 +79,8 @@ pub struct MiniError {
     pub end: usize,
     pub text: String,
     pub is_warning: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file: Option<String>,
 }
 
 fn main() {
@@ -63,5 +89,7 @@ fn main() {
         FmtOpts::Format(opts) => format::run(opts),
         FmtOpts::NativeTypes => native::run(),
         FmtOpts::PreviewFeatures(opts) => preview::run(opts),
+        FmtOpts::CodeActions(opts) => code_actions::run(opts),
+        FmtOpts::Lsp => lsp::run(),
     }
 }
@@ -143,6 +143,66 @@ class DistinctQuerySpec extends FlatSpec with Matchers with ApiSpecBase {
     result.toString() should be("""{"data":{"findManyModelA":[{"fieldA":"3","fieldB":1},{"fieldA":"1","fieldB":1},{"fieldA":"2","fieldB":2}]}}""")
   }
 
+  "Select distinct with take and ordering" should "return only distinct records up to the take, ordered correctly" in {
+    createRecord("1", 1)
+    createRecord("2", 2)
+    createRecord("1", 1)
+    createRecord("3", 3)
+
+    val result = server.query(
+      s"""{
+         |  findManyModelA(distinct: [fieldA], take: 2, orderBy: { fieldB: desc }) {
+         |    fieldA
+         |    fieldB
+         |  }
+         |}""".stripMargin,
+      project,
+      legacy = false
+    )
+
+    result.toString() should be("""{"data":{"findManyModelA":[{"fieldA":"3","fieldB":3},{"fieldA":"2","fieldB":2}]}}""")
+  }
+
+  "Select distinct with a negative take" should "return only the last distinct records, ordered correctly" in {
+    createRecord("1", 1)
+    createRecord("2", 2)
+    createRecord("1", 1)
+    createRecord("3", 3)
+
+    val result = server.query(
+      s"""{
+         |  findManyModelA(distinct: [fieldA], take: -2, orderBy: { fieldB: asc }) {
+         |    fieldA
+         |    fieldB
+         |  }
+         |}""".stripMargin,
+      project,
+      legacy = false
+    )
+
+    result.toString() should be("""{"data":{"findManyModelA":[{"fieldA":"2","fieldB":2},{"fieldA":"3","fieldB":3}]}}""")
+  }
+
+  "Find first with distinct and skip" should "skip distinct records only" in {
+    createRecord("1", 1)
+    createRecord("2", 2)
+    createRecord("1", 1)
+    createRecord("3", 3)
+
+    val result = server.query(
+      s"""{
+         |  findFirstModelA(distinct: [fieldA], orderBy: { fieldB: desc }, skip: 1) {
+         |    fieldA
+         |    fieldB
+         |  }
+         |}""".stripMargin,
+      project,
+      legacy = false
+    )
+
+    result.toString() should be("""{"data":{"findFirstModelA":{"fieldA":"2","fieldB":2}}}""")
+  }
+
   // todo change to comparable ids
   "Select distinct on top level and relation" should "return only distinct records for top record, and only for those the distinct relation records" in {
     createRecord("1", 1, Some(Seq("3", "1", "1", "2", "1"))) // Lowest ID (nested: lowest first, highest last)
@@ -201,4 +261,52 @@ class DistinctQuerySpec extends FlatSpec with Matchers with ApiSpecBase {
     result.toString() should be(
       """{"data":{"findManyModelA":[{"fieldA":"1","fieldB":5,"b":[{"field":"2"},{"field":"3"}]},{"fieldA":"1","fieldB":4,"b":[{"field":"1"}]},{"fieldA":"1","fieldB":3,"b":[]},{"fieldA":"1","fieldB":1,"b":[{"field":"1"},{"field":"2"},{"field":"3"}]}]}}""")
   }
+
+  "Select distinct on top level with a relation" should "return the relation records of the distinct records" in {
+    createRecord("1", 1, Some(Seq("1", "2")))
+    createRecord("1", 0, Some(Seq("3")))
+    createRecord("2", 2, Some(Seq("4")))
+
+    val result = server.query(
+      s"""{
+         |  findManyModelA(distinct: [fieldA], orderBy: { fieldB: asc }) {
+         |    fieldA
+         |    fieldB
+         |    b(orderBy: { id: asc }) {
+         |      field
+         |    }
+         |  }
+         |}""".stripMargin,
+      project,
+      legacy = false
+    )
+
+    result.toString() should be(
+      """{"data":{"findManyModelA":[{"fieldA":"1","fieldB":0,"b":[{"field":"3"}]},{"fieldA":"2","fieldB":2,"b":[{"field":"4"}]}]}}""")
+  }
+
+  "Select distinct on a relation with skip and take" should "paginate the distinct relation records of each record" in {
+    createRecord("1", 1, Some(Seq("3", "1", "1", "2", "1")))
+    createRecord("2", 2, Some(Seq("1", "2")))
+    createRecord("3", 3, None)
+
+    val result = server.query(
+      s"""{
+         |  findManyModelA(orderBy: { fieldB: asc }) {
+         |    fieldB
+         |    b(distinct: [field], orderBy: { id: asc }, skip: 1, take: 1) {
+         |      field
+         |    }
+         |  }
+         |}""".stripMargin,
+      project,
+      legacy = false
+    )
+
+    // 1 => 3, 1, 2 => 1
+    // 2 => 1, 2 => 2
+    // 3 => -
+    result.toString() should be(
+      """{"data":{"findManyModelA":[{"fieldB":1,"b":[{"field":"1"}]},{"fieldB":2,"b":[{"field":"2"}]},{"fieldB":3,"b":[]}]}}""")
+  }
 }
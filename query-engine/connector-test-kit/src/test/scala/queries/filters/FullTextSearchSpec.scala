@@ -0,0 +1,73 @@
+package queries.filters
+
+import org.scalatest.{FlatSpec, Matchers}
+import util.ConnectorTag.{MySqlConnectorTag, PostgresConnectorTag}
+import util._
+
+class FullTextSearchSpec extends FlatSpec with Matchers with ApiSpecBase with ConnectorAwareTest {
+  override def runOnlyForConnectors: Set[ConnectorTag] = Set(MySqlConnectorTag, PostgresConnectorTag)
+
+  // MySQL can only search columns covered by a fulltext index.
+  lazy val fulltextIndex = if (connectorTag == PostgresConnectorTag) "" else "@@fulltext([title, content])"
+
+  lazy val project = ProjectDsl.fromString {
+    s"""
+       |model Post {
+       |  id      Int    @id
+       |  title   String
+       |  content String
+       |
+       |  $fulltextIndex
+       |}"""
+  }
+
+  override def beforeEach(): Unit = {
+    database.setup(project)
+    super.beforeEach()
+
+    create(1, "Cats", "Cats are independent pets.")
+    create(2, "Dogs", "Dogs are loyal pets.")
+    create(3, "Birds", "Birds can fly.")
+  }
+
+  "The search filter" should "find records matching the search query on all searched fields" in {
+    server
+      .query(
+        """query { findManyPost(where: { title: { search: "cats" }, content: { search: "cats" } }, orderBy: { id: asc }) { id }}""",
+        project,
+        legacy = false
+      )
+      .toString should be("""{"data":{"findManyPost":[{"id":1}]}}""")
+
+    server
+      .query(
+        """query { findManyPost(where: { NOT: { title: { search: "pets" }, content: { search: "pets" } } }, orderBy: { id: asc }) { id }}""",
+        project,
+        legacy = false
+      )
+      .toString should be("""{"data":{"findManyPost":[{"id":3}]}}""")
+  }
+
+  "Ordering by relevance" should "order records by how well they match the search query" in {
+    server
+      .query(
+        """query {
+          |  findManyPost(
+          |    where: { title: { search: "dogs | pets" }, content: { search: "dogs | pets" } },
+          |    orderBy: { _relevance: { fields: [title, content], search: "dogs", sort: desc } }
+          |  ) { id }
+          |}""".stripMargin,
+        project,
+        legacy = false
+      )
+      .toString should be("""{"data":{"findManyPost":[{"id":2},{"id":1}]}}""")
+  }
+
+  def create(id: Int, title: String, content: String): Unit = {
+    server.query(
+      s"""mutation { createOnePost(data: { id: $id, title: "$title", content: "$content" }) { id }}""",
+      project,
+      legacy = false
+    )
+  }
+}
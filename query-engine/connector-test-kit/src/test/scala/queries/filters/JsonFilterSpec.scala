@@ -77,6 +77,62 @@ class JsonFilterSpec extends FlatSpec with Matchers with ApiSpecBase {
       )
   }
 
+  // SQLite and SQL Server don't support the Json type, so there are no Json path filters to test on them.
+  "Json path filters" should "work with array paths" taggedAs (IgnoreMySql, IgnoreSQLite, IgnoreMsSql) in {
+    create(1, Some("""{\"settings\":{\"theme\":\"dark-blue\",\"size\":3,\"tags\":[\"a\",\"b\"]}}"""))
+    create(2, Some("""{\"settings\":{\"theme\":\"light\",\"size\":10,\"tags\":[\"b\",\"c\"]}}"""))
+    create(3, None)
+
+    server
+      .query("""query { findManyModel(where: { json: { path: ["settings", "theme"], string_contains: "dark" }}) { id }}""", project, legacy = false)
+      .toString should be("""{"data":{"findManyModel":[{"id":1}]}}""")
+
+    server
+      .query("""query { findManyModel(where: { json: { path: ["settings", "theme"], equals: "\"light\"" }}) { id }}""", project, legacy = false)
+      .toString should be("""{"data":{"findManyModel":[{"id":2}]}}""")
+
+    server
+      .query("""query { findManyModel(where: { json: { path: ["settings", "tags"], array_contains: "[\"b\"]" }}) { id }}""", project, legacy = false)
+      .toString should be("""{"data":{"findManyModel":[{"id":1},{"id":2}]}}""")
+
+    server
+      .query("""query { findManyModel(where: { json: { path: ["settings", "tags"], array_starts_with: "\"b\"" }}) { id }}""", project, legacy = false)
+      .toString should be("""{"data":{"findManyModel":[{"id":2}]}}""")
+
+    server
+      .query("""query { findManyModel(where: { json: { path: ["settings", "size"], gt: "5" }}) { id }}""", project, legacy = false)
+      .toString should be("""{"data":{"findManyModel":[{"id":2}]}}""")
+  }
+
+  "Json path filters" should "work with JSON path strings" taggedAs (IgnoreMySql56, IgnorePostgres, IgnoreSQLite, IgnoreMsSql) in {
+    create(1, Some("""{\"settings\":{\"theme\":\"dark-blue\",\"size\":3,\"tags\":[\"a\",\"b\"]}}"""))
+    create(2, Some("""{\"settings\":{\"theme\":\"light\",\"size\":10,\"tags\":[\"b\",\"c\"]}}"""))
+    create(3, None)
+
+    server
+      .query("""query { findManyModel(where: { json: { path: "$.settings.theme", string_starts_with: "dark" }}) { id }}""", project, legacy = false)
+      .toString should be("""{"data":{"findManyModel":[{"id":1}]}}""")
+
+    server
+      .query("""query { findManyModel(where: { json: { path: "$.settings.tags", array_ends_with: "\"c\"" }}) { id }}""", project, legacy = false)
+      .toString should be("""{"data":{"findManyModel":[{"id":2}]}}""")
+
+    server
+      .query("""query { findManyModel(where: { json: { path: "$.settings.size", lte: "3" }}) { id }}""", project, legacy = false)
+      .toString should be("""{"data":{"findManyModel":[{"id":1}]}}""")
+  }
+
+  "String filters on Json fields" should "require a path" taggedAs (IgnoreMySql56, IgnoreSQLite, IgnoreMsSql) in {
+    server
+      .queryThatMustFail(
+        """query { findManyModel(where: { json: { string_contains: "dark" }}) { id }}""",
+        project,
+        errorCode = 2019,
+        errorContains = """The `string_contains` filter on the Json field `json` requires a `path`.""",
+        legacy = false
+      )
+  }
+
   def create(id: Int, json: Option[String]): Unit = {
     val j = json match {
       case Some(x) => s""""$x""""
@@ -0,0 +1,105 @@
+package queries.locking
+
+import org.scalatest.{FlatSpec, Matchers}
+import util.ConnectorTag.{MsSqlConnectorTag, MySqlConnectorTag, PostgresConnectorTag}
+import util._
+
+class RowLockingSpec extends FlatSpec with Matchers with ApiSpecBase with ConnectorAwareTest {
+  override def runOnlyForConnectors: Set[ConnectorTag] = Set(PostgresConnectorTag, MySqlConnectorTag, MsSqlConnectorTag)
+
+  val project = ProjectDsl.fromString {
+    """model Job {
+      |  id     Int    @id
+      |  status String
+      |}
+    """.stripMargin
+  }
+
+  override protected def beforeAll(): Unit = {
+    super.beforeAll()
+    database.setup(project)
+  }
+
+  override def beforeEach(): Unit = {
+    database.truncateProjectTables(project)
+
+    Seq(1 -> "pending", 2 -> "pending", 3 -> "done").foreach {
+      case (id, status) =>
+        server.query(s"""mutation { createOneJob(data: { id: $id, status: "$status" }) { id } }""", project, legacy = false)
+    }
+  }
+
+  "A findMany locking the rows" should "return the records inside a transaction" in {
+    val queries = Seq(
+      """{ findManyJob(where: { status: { equals: "pending" } }, orderBy: { id: asc }, take: 1, lock: { mode: update, skipLocked: true }) { id } }""",
+      """mutation { updateManyJob(where: { id: { equals: 1 } }, data: { status: { set: "claimed" } }) { count } }""",
+    )
+
+    server.batch(queries, transaction = true, project, legacy = false).toString should be(
+      """{"batchResult":[{"data":{"findManyJob":[{"id":1}]}},{"data":{"updateManyJob":{"count":1}}}]}"""
+    )
+  }
+
+  "A findFirst locking the row" should "return the record inside a transaction" in {
+    val queries = Seq(
+      """{ findFirstJob(where: { status: { equals: "done" } }, lock: { mode: share, noWait: true }) { id status } }""",
+      """mutation { deleteManyJob(where: { id: { equals: 3 } }) { count } }""",
+    )
+
+    server.batch(queries, transaction = true, project, legacy = false).toString should be(
+      """{"batchResult":[{"data":{"findFirstJob":{"id":3,"status":"done"}}},{"data":{"deleteManyJob":{"count":1}}}]}"""
+    )
+  }
+
+  "A findMany locking the rows" should "fail outside of a transaction" in {
+    server.queryThatMustFail(
+      """{ findManyJob(lock: { mode: update }) { id } }""",
+      project,
+      errorCode = 0,
+      errorContains = "is only valid inside transactions",
+      legacy = false
+    )
+  }
+
+  "A findMany locking the rows" should "fail if skipLocked and noWait are combined" in {
+    server.queryThatMustFail(
+      """{ findManyJob(lock: { mode: update, skipLocked: true, noWait: true }) { id } }""",
+      project,
+      errorCode = 0,
+      errorContains = "can't be combined",
+      legacy = false
+    )
+  }
+
+  "A findMany locking the rows" should "fail if combined with distinct" in {
+    server.queryThatMustFail(
+      """{ findManyJob(distinct: [status], lock: { mode: update }) { id } }""",
+      project,
+      errorCode = 0,
+      errorContains = "The `lock` and `distinct` arguments can't be combined.",
+      legacy = false
+    )
+  }
+
+  "An updateMany of the first records of an ordering" should "lock and update records already locked by its own transaction" in {
+    val queries = Seq(
+      """{ findManyJob(where: { id: { equals: 1 } }, lock: { mode: update }) { id } }""",
+      """mutation { updateManyJobAndReturn(where: { status: { equals: "pending" } }, orderBy: { id: asc }, take: 1, data: { status: { set: "claimed" } }) { id status } }""",
+    )
+
+    server.batch(queries, transaction = true, project, legacy = false).toString should be(
+      """{"batchResult":[{"data":{"findManyJob":[{"id":1}]}},{"data":{"updateManyJobAndReturn":[{"id":1,"status":"claimed"}]}}]}"""
+    )
+  }
+
+  "A deleteMany returning the first records of an ordering" should "lock and delete records already locked by its own transaction" in {
+    val queries = Seq(
+      """{ findManyJob(where: { id: { equals: 2 } }, lock: { mode: share }) { id } }""",
+      """mutation { deleteManyJobAndReturn(where: { status: { equals: "pending" } }, orderBy: { id: desc }, take: 1) { id } }""",
+    )
+
+    server.batch(queries, transaction = true, project, legacy = false).toString should be(
+      """{"batchResult":[{"data":{"findManyJob":[{"id":2}]}},{"data":{"deleteManyJobAndReturn":[{"id":2}]}}]}"""
+    )
+  }
+}
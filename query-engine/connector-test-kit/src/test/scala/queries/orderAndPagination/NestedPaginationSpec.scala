@@ -99,6 +99,22 @@ class NestedPaginationSpec extends FlatSpec with Matchers with ApiSpecBase {
     }
   }
 
+  "Middle level cursor take 1" should "return the cursor item and return nothing for other tops" in {
+    testDataModels.testV11 { project =>
+      createData(project)
+      val result = server.query(
+        """
+          |{
+          |  tops{t, middles(cursor: { m: "M22" }, take: 1, orderBy: { id: asc }){ m }}
+          |}
+        """,
+        project
+      )
+
+      result.toString() should be("""{"data":{"tops":[{"t":"T1","middles":[]},{"t":"T2","middles":[{"m":"M22"}]},{"t":"T3","middles":[]}]}}""")
+    }
+  }
+
   /****************
     * Skip tests. *
     ***************/
@@ -119,6 +135,23 @@ class NestedPaginationSpec extends FlatSpec with Matchers with ApiSpecBase {
     }
   }
 
+  "Middle level skip 1 take -1 with a descending order" should "return the second to last item of each parent" in {
+    testDataModels.testV11 { project =>
+      createData(project)
+      val result = server.query(
+        """
+        |{
+        |  tops{t, middles(skip: 1, take: -1, orderBy: { m: desc }){m}}
+        |}
+      """,
+        project
+      )
+
+      result.toString() should be(
+        """{"data":{"tops":[{"t":"T1","middles":[{"m":"M12"}]},{"t":"T2","middles":[{"m":"M22"}]},{"t":"T3","middles":[{"m":"M32"}]}]}}""")
+    }
+  }
+
   "Middle level skip 3" should "skip all items" in {
     testDataModels.testV11 { project =>
       createData(project)
@@ -1005,4 +1038,97 @@ class NestedPaginationSpec extends FlatSpec with Matchers with ApiSpecBase {
     result.toString() should be(
       """{"data":{"findManyModelA":[{"id":"A1","manyB":[{"id":"B3"},{"id":"B4"}]},{"id":"A2","manyB":[{"id":"B2"},{"id":"B3"}]},{"id":"A3","manyB":[]}]}}""")
   }
+
+  // Special case: m:n relations, child is connected to many parents, paginated per parent
+  // A1 <> B1, B2, B3, B4, B5, B6
+  // A2 <> B2, B3, B5, B7, B8
+  // A3
+  "A many-to-many relationship with multiple connected children" should "return the items of each parent with nested skip / take" in {
+    val project = SchemaDsl.fromStringV11() {
+      """
+        |model ModelA {
+        |  id    String   @id
+        |  manyB ModelB[]
+        |}
+        |
+        |model ModelB {
+        |  id    String   @id
+        |  manyA ModelA[]
+        |}
+      """.stripMargin
+    }
+    database.setup(project)
+
+    server.query(
+      s"""mutation {
+         |  createOneModelA(data: { id: "A1", manyB: { create: [{ id: "B1" }, { id: "B2" }, { id: "B3" }, { id: "B4" }, { id: "B5" }, { id: "B6" }] } }) { id }
+         |}
+      """,
+      project,
+      legacy = false,
+    )
+
+    server.query(
+      s"""mutation {
+         |  createOneModelA(data: { id: "A2", manyB: { connect: [{ id: "B2" }, { id: "B3" }, { id: "B5" }], create: [{ id: "B7" }, { id: "B8" }] } }) { id }
+         |}
+      """,
+      project,
+      legacy = false,
+    )
+
+    server.query("""mutation { createOneModelA(data: { id: "A3" }) { id } }""", project, legacy = false)
+
+    var result = server.query(
+      s"""{
+         |  findManyModelA {
+         |    id
+         |    manyB(skip: 1, take: 2, orderBy: { id: asc }) {
+         |      id
+         |    }
+         |  }
+         |}
+      """,
+      project,
+      legacy = false,
+    )
+
+    result.toString() should be(
+      """{"data":{"findManyModelA":[{"id":"A1","manyB":[{"id":"B2"},{"id":"B3"}]},{"id":"A2","manyB":[{"id":"B3"},{"id":"B5"}]},{"id":"A3","manyB":[]}]}}""")
+
+    result = server.query(
+      s"""{
+         |  findManyModelA {
+         |    id
+         |    manyB(take: -2, orderBy: { id: asc }) {
+         |      id
+         |    }
+         |  }
+         |}
+      """,
+      project,
+      legacy = false,
+    )
+
+    result.toString() should be(
+      """{"data":{"findManyModelA":[{"id":"A1","manyB":[{"id":"B5"},{"id":"B6"}]},{"id":"A2","manyB":[{"id":"B7"},{"id":"B8"}]},{"id":"A3","manyB":[]}]}}""")
+
+    result = server.query(
+      s"""{
+         |  findManyModelA {
+         |    id
+         |    manyB(skip: 1, take: -2, orderBy: { id: desc }) {
+         |      id
+         |    }
+         |  }
+         |}
+      """,
+      project,
+      legacy = false,
+    )
+
+    // In descending order, the last item of each parent is skipped and the two items before it are taken.
+    result.toString() should be(
+      """{"data":{"findManyModelA":[{"id":"A1","manyB":[{"id":"B3"},{"id":"B2"}]},{"id":"A2","manyB":[{"id":"B5"},{"id":"B3"}]},{"id":"A3","manyB":[]}]}}""")
+  }
 }
@@ -0,0 +1,121 @@
+package queries.orderAndPagination
+
+import org.scalatest.{FlatSpec, Matchers}
+import util._
+
+class OrderByAggregateRelationSpec extends FlatSpec with Matchers with ApiSpecBase {
+  val project = ProjectDsl.fromString {
+    """
+      |model User {
+      |  id    Int    @id
+      |  posts Post[]
+      |}
+      |
+      |model Post {
+      |  id         Int        @id
+      |  score      Int
+      |  authorId   Int
+      |  author     User       @relation(fields: [authorId], references: [id])
+      |  categories Category[]
+      |}
+      |
+      |model Category {
+      |  id    Int    @id
+      |  posts Post[]
+      |}
+    """.stripMargin
+  }
+
+  override protected def beforeEach(): Unit = {
+    super.beforeEach()
+    database.setup(project)
+
+    server.query(
+      """mutation {
+        |  createOneUser(data: {
+        |    id: 1,
+        |    posts: { create: [
+        |      { id: 1, score: 10, categories: { create: [{ id: 1 }, { id: 2 }] } },
+        |      { id: 2, score: 20, categories: { connect: [{ id: 1 }] } }
+        |    ]}
+        |  }) { id }
+        |}""".stripMargin,
+      project,
+      legacy = false
+    )
+
+    server.query("""mutation { createOneUser(data: { id: 2 }) { id } }""", project, legacy = false)
+
+    server.query(
+      """mutation { createOneUser(data: { id: 3, posts: { create: [{ id: 3, score: 50 }] } }) { id } }""",
+      project,
+      legacy = false
+    )
+
+    server.query("""mutation { createOneCategory(data: { id: 3 }) { id } }""", project, legacy = false)
+  }
+
+  "Ordering by the count of a one-to-many relation" should "work" in {
+    server
+      .query(
+        """query { findManyUser(orderBy: { posts: { _count: desc } }) { id } }""",
+        project,
+        legacy = false
+      )
+      .toString should be("""{"data":{"findManyUser":[{"id":1},{"id":3},{"id":2}]}}""")
+
+    server
+      .query(
+        """query { findManyUser(orderBy: [{ posts: { _count: asc } }, { id: asc }]) { id } }""",
+        project,
+        legacy = false
+      )
+      .toString should be("""{"data":{"findManyUser":[{"id":2},{"id":3},{"id":1}]}}""")
+  }
+
+  "Ordering by the count of a many-to-many relation" should "work" in {
+    server
+      .query(
+        """query { findManyCategory(orderBy: { posts: { _count: desc } }) { id } }""",
+        project,
+        legacy = false
+      )
+      .toString should be("""{"data":{"findManyCategory":[{"id":1},{"id":2},{"id":3}]}}""")
+  }
+
+  "Ordering by an aggregate of a related field" should "work" in {
+    server
+      .query(
+        """query { findManyUser(where: { id: { in: [1, 3] } }, orderBy: { posts: { _sum: { score: desc } } }) { id } }""",
+        project,
+        legacy = false
+      )
+      .toString should be("""{"data":{"findManyUser":[{"id":3},{"id":1}]}}""")
+
+    server
+      .query(
+        """query { findManyUser(where: { id: { in: [1, 3] } }, orderBy: { posts: { _min: { score: asc } } }) { id } }""",
+        project,
+        legacy = false
+      )
+      .toString should be("""{"data":{"findManyUser":[{"id":1},{"id":3}]}}""")
+  }
+
+  "Ordering by a relation count" should "work with cursors" in {
+    server
+      .query(
+        """query { findManyUser(orderBy: [{ posts: { _count: desc } }, { id: asc }], cursor: { id: 3 }) { id } }""",
+        project,
+        legacy = false
+      )
+      .toString should be("""{"data":{"findManyUser":[{"id":3},{"id":2}]}}""")
+
+    server
+      .query(
+        """query { findManyUser(orderBy: [{ posts: { _count: desc } }, { id: asc }], cursor: { id: 3 }, take: -2) { id } }""",
+        project,
+        legacy = false
+      )
+      .toString should be("""{"data":{"findManyUser":[{"id":1},{"id":3}]}}""")
+  }
+}
@@ -0,0 +1,89 @@
+package queries.relations
+
+import org.scalatest.{FlatSpec, Matchers}
+import util._
+
+class RelationCountSpec extends FlatSpec with Matchers with ApiSpecBase {
+  val project = ProjectDsl.fromString {
+    """
+      |model User {
+      |  id        Int     @id
+      |  posts     Post[]
+      |  followers User[]  @relation("Follows")
+      |  following User[]  @relation("Follows")
+      |}
+      |
+      |model Post {
+      |  id         Int        @id
+      |  authorId   Int
+      |  author     User       @relation(fields: [authorId], references: [id])
+      |  categories Category[]
+      |}
+      |
+      |model Category {
+      |  id    Int    @id
+      |  posts Post[]
+      |}
+    """.stripMargin
+  }
+
+  override protected def beforeEach(): Unit = {
+    super.beforeEach()
+    database.setup(project)
+
+    server.query(
+      """mutation {
+        |  createOneUser(data: {
+        |    id: 1,
+        |    posts: { create: [{ id: 1, categories: { create: [{ id: 1 }, { id: 2 }] } }, { id: 2 }] }
+        |  }) { id }
+        |}""".stripMargin,
+      project,
+      legacy = false
+    )
+
+    server.query(
+      """mutation { createOneUser(data: { id: 2, followers: { connect: [{ id: 1 }] } }) { id } }""",
+      project,
+      legacy = false
+    )
+  }
+
+  "Selecting _count" should "return the number of related records of one-to-many relations" in {
+    server
+      .query(
+        """query { findManyUser(orderBy: { id: asc }) { id _count { posts } } }""",
+        project,
+        legacy = false
+      )
+      .toString should be("""{"data":{"findManyUser":[{"id":1,"_count":{"posts":2}},{"id":2,"_count":{"posts":0}}]}}""")
+  }
+
+  "Selecting _count" should "return the number of related records of many-to-many and self relations" in {
+    server
+      .query(
+        """query { findUniqueUser(where: { id: 2 }) { _count { followers following } } }""",
+        project,
+        legacy = false
+      )
+      .toString should be("""{"data":{"findUniqueUser":{"_count":{"followers":1,"following":0}}}}""")
+
+    server
+      .query(
+        """query { findManyPost(orderBy: { id: asc }) { id _count { categories } } }""",
+        project,
+        legacy = false
+      )
+      .toString should be("""{"data":{"findManyPost":[{"id":1,"_count":{"categories":2}},{"id":2,"_count":{"categories":0}}]}}""")
+  }
+
+  "Selecting _count" should "work on nested relations" in {
+    server
+      .query(
+        """query { findUniqueUser(where: { id: 1 }) { posts(orderBy: { id: asc }) { id _count { categories } } } }""",
+        project,
+        legacy = false
+      )
+      .toString should be("""{"data":{"findUniqueUser":{"posts":[{"id":1,"_count":{"categories":2}},{"id":2,"_count":{"categories":0}}]}}}""")
+  }
+}
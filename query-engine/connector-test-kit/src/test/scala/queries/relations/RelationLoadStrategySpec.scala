@@ -0,0 +1,146 @@
+package queries.relations
+
+import org.scalatest.{FlatSpec, Matchers}
+import util.ConnectorTag.{MySqlConnectorTag, PostgresConnectorTag}
+import util._
+
+class RelationLoadStrategySpec extends FlatSpec with Matchers with ApiSpecBase with ConnectorAwareTest {
+  override def runOnlyForConnectors: Set[ConnectorTag] = Set(MySqlConnectorTag, PostgresConnectorTag)
+
+  val project = ProjectDsl.fromString {
+    """
+      |model User {
+      |  id      Int      @id
+      |  name    String
+      |  posts   Post[]
+      |  profile Profile?
+      |}
+      |
+      |model Profile {
+      |  id     Int    @id
+      |  bio    String
+      |  userId Int    @unique
+      |  user   User   @relation(fields: [userId], references: [id])
+      |}
+      |
+      |model Post {
+      |  id         Int        @id
+      |  title      String
+      |  published  Boolean
+      |  createdAt  DateTime
+      |  authorId   Int
+      |  author     User       @relation(fields: [authorId], references: [id])
+      |  categories Category[]
+      |}
+      |
+      |model Category {
+      |  id    Int    @id
+      |  name  String
+      |  posts Post[]
+      |}
+    """.stripMargin
+  }
+
+  override protected def beforeEach(): Unit = {
+    super.beforeEach()
+    database.setup(project)
+
+    server.query(
+      """mutation {
+        |  createOneUser(data: {
+        |    id: 1,
+        |    name: "Alice",
+        |    profile: { create: { id: 1, bio: "Writes a lot" } },
+        |    posts: { create: [
+        |      { id: 1, title: "First", published: true, createdAt: "2021-01-01T00:00:00.000Z", categories: { create: [{ id: 1, name: "News" }, { id: 2, name: "Tech" }] } },
+        |      { id: 2, title: "Second", published: false, createdAt: "2021-02-01T00:00:00.000Z", categories: { connect: [{ id: 2 }] } },
+        |      { id: 3, title: "Third", published: true, createdAt: "2021-03-01T00:00:00.000Z" }
+        |    ]}
+        |  }) { id }
+        |}""".stripMargin,
+      project,
+      legacy = false
+    )
+
+    server.query("""mutation { createOneUser(data: { id: 2, name: "Bob" }) { id } }""", project, legacy = false)
+  }
+
+  "Joining relations" should "return the same result as reading them with separate queries" in {
+    val selection =
+      """{ id name profile { bio } posts { id title published createdAt categories { id name } author { name } } }"""
+
+    val joined = server.query(
+      s"""query { findManyUser(relationLoadStrategy: join) $selection }""",
+      project,
+      legacy = false
+    )
+
+    val queried = server.query(
+      s"""query { findManyUser(relationLoadStrategy: query) $selection }""",
+      project,
+      legacy = false
+    )
+
+    joined.toString should be(queried.toString)
+    joined.toString should be(
+      """{"data":{"findManyUser":[{"id":1,"name":"Alice","profile":{"bio":"Writes a lot"},"posts":[{"id":1,"title":"First","published":true,"createdAt":"2021-01-01T00:00:00.000Z","categories":[{"id":1,"name":"News"},{"id":2,"name":"Tech"}],"author":{"name":"Alice"}},{"id":2,"title":"Second","published":false,"createdAt":"2021-02-01T00:00:00.000Z","categories":[{"id":2,"name":"Tech"}],"author":{"name":"Alice"}},{"id":3,"title":"Third","published":true,"createdAt":"2021-03-01T00:00:00.000Z","categories":[],"author":{"name":"Alice"}}]},{"id":2,"name":"Bob","profile":null,"posts":[]}]}}""")
+  }
+
+  "Joining relations" should "apply filters, ordering and pagination to the related records" in {
+    server
+      .query(
+        """query {
+          |  findManyUser(relationLoadStrategy: join, where: { id: 1 }) {
+          |    id
+          |    posts(where: { published: true }, orderBy: { createdAt: desc }, take: 1) { id }
+          |  }
+          |}""".stripMargin,
+        project,
+        legacy = false
+      )
+      .toString should be("""{"data":{"findManyUser":[{"id":1,"posts":[{"id":3}]}]}}""")
+
+    server
+      .query(
+        """query {
+          |  findManyUser(relationLoadStrategy: join, orderBy: { id: desc }) {
+          |    id
+          |    posts(orderBy: { id: asc }, skip: 1, take: -1, cursor: { id: 3 }) { id categories(orderBy: { name: desc }) { name } }
+          |  }
+          |}""".stripMargin,
+        project,
+        legacy = false
+      )
+      .toString should be(
+      """{"data":{"findManyUser":[{"id":2,"posts":[]},{"id":1,"posts":[{"id":2,"categories":[{"name":"Tech"}]}]}]}}""")
+  }
+
+  "Joining relations" should "apply distinct to the related records" in {
+    val selection = """{ id posts(distinct: [published], orderBy: { id: asc }) { published } }"""
+
+    val joined = server.query(
+      s"""query { findManyUser(relationLoadStrategy: join, where: { id: 1 }) $selection }""",
+      project,
+      legacy = false
+    )
+
+    val queried = server.query(
+      s"""query { findManyUser(relationLoadStrategy: query, where: { id: 1 }) $selection }""",
+      project,
+      legacy = false
+    )
+
+    joined.toString should be(queried.toString)
+    joined.pathAsJsArray("data.findManyUser.[0].posts").value.length should be(2)
+  }
+
+  "Joining relations" should "work with findFirst and paginated top-level records" in {
+    server
+      .query(
+        """query { findFirstUser(relationLoadStrategy: join, orderBy: { id: desc }, skip: 1) { id posts(take: 2) { id } } }""",
+        project,
+        legacy = false
+      )
+      .toString should be("""{"data":{"findFirstUser":{"id":1,"posts":[{"id":1},{"id":2}]}}}""")
+  }
+}
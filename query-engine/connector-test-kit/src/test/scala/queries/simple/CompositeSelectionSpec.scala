@@ -0,0 +1,71 @@
+package queries.simple
+
+import org.scalatest.{FlatSpec, Matchers}
+import util.ConnectorTag.{MySqlConnectorTag, PostgresConnectorTag}
+import util._
+
+class CompositeSelectionSpec extends FlatSpec with Matchers with ApiSpecBase with ConnectorAwareTest {
+  override def runOnlyForConnectors: Set[ConnectorTag] = Set(PostgresConnectorTag, MySqlConnectorTag)
+
+  val project = ProjectDsl.fromString {
+    """model User {
+      |  id        Int       @id
+      |  address   Address
+      |  addresses Address[]
+      |}
+      |
+      |type Address {
+      |  street String
+      |  zip    String?
+      |  tags   String[]
+      |  geo    Geo?
+      |}
+      |
+      |type Geo {
+      |  lat Float
+      |  lng Float
+      |}
+    """.stripMargin
+  }
+
+  override protected def beforeAll(): Unit = {
+    super.beforeAll()
+    database.setup(project)
+  }
+
+  override def beforeEach(): Unit = database.truncateProjectTables(project)
+
+  "Selecting fields of a composite value" should "only return the selected fields, recursively" in {
+    server.query(
+      """mutation {
+        |  createOneUser(data: {
+        |    id: 1,
+        |    address: { street: "Main", zip: "1234", tags: ["home"], geo: { lat: 1.5, lng: 2.5 } },
+        |    addresses: [{ street: "First", zip: "1" }, { street: "Second", zip: "2" }]
+        |  }) { id }
+        |}""".stripMargin,
+      project,
+      legacy = false
+    )
+
+    server
+      .query("""{ findManyUser { id address { geo { lat } street } addresses { zip } } }""", project, legacy = false)
+      .toString should be(
+      """{"data":{"findManyUser":[{"id":1,"address":{"geo":{"lat":1.5},"street":"Main"},"addresses":[{"zip":"1"},{"zip":"2"}]}]}}""")
+  }
+
+  "Selecting fields missing in a composite value" should "return null, or an empty list for list fields" in {
+    server.query(
+      """mutation { createOneUser(data: { id: 1, address: { street: "Main" }, addresses: [] }) { id } }""",
+      project,
+      legacy = false
+    )
+
+    server
+      .query("""{ findUniqueUser(where: { id: 1 }) { address { street zip tags geo { lat } } addresses { street } } }""",
+             project,
+             legacy = false)
+      .toString should be(
+      """{"data":{"findUniqueUser":{"address":{"street":"Main","zip":null,"tags":[],"geo":null},"addresses":[]}}}""")
+  }
+}
@@ -9,6 +9,7 @@ import org.scalatest.Suite
 case class Project(
     id: String,
     dataModel: String,
+    referentialIntegrity: Option[String] = None,
 ) {
   val dataSourceUrl: String = {
     ConnectorConfig.instance.url
@@ -18,11 +19,13 @@ case class Project(
 
   val dataSourceConfig: String = {
     val config = ConnectorConfig.instance
+    val referentialIntegrityLine = referentialIntegrity.map(ri => s"""referentialIntegrity = "$ri"""").getOrElse("")
 
     s"""
            |datasource test {
            |  provider = "${config.provider.stripSuffix("56")}"
            |  url = "$dataSourceUrl"
+           |  $referentialIntegrityLine
            |}
     """.stripMargin
   }
@@ -0,0 +1,107 @@
+package writes.dataTypes.json
+
+import org.scalatest.{FlatSpec, Matchers}
+import util.ConnectorTag.{MySqlConnectorTag, PostgresConnectorTag}
+import util._
+
+class JsonOperationsSpec extends FlatSpec with Matchers with ApiSpecBase with ConnectorAwareTest {
+  override def runOnlyForConnectors: Set[ConnectorTag] = Set(MySqlConnectorTag, PostgresConnectorTag)
+
+  val project = ProjectDsl.fromString {
+    """|model Model {
+       | id    String @id
+       | field Json?
+       |}"""
+  }
+
+  override protected def beforeAll(): Unit = {
+    super.beforeAll()
+    database.setup(project)
+  }
+
+  override def beforeEach(): Unit = {
+    database.truncateProjectTables(project)
+
+    server.query(
+      """mutation { createOneModel(data: { id: "A", field: "{\"a\":1,\"b\":{\"c\":2}}" }) { id } }""",
+      project,
+      legacy = false
+    )
+  }
+
+  "Merging into a Json field" should "replace the given top-level keys" in {
+    server
+      .query(
+        """mutation { updateOneModel(where: { id: "A" }, data: { field: { merge: "{\"b\":3,\"d\":4}" } }) { field } }""",
+        project,
+        legacy = false
+      )
+      .toString should be("""{"data":{"updateOneModel":{"field":"{\"a\":1,\"b\":3,\"d\":4}"}}}""")
+  }
+
+  "Merging into a Json field" should "replace nested objects and keep null values" in {
+    server
+      .query(
+        """mutation { updateOneModel(where: { id: "A" }, data: { field: { merge: "{\"a\":null,\"b\":{\"d\":3}}" } }) { field } }""",
+        project,
+        legacy = false
+      )
+      .toString should be("""{"data":{"updateOneModel":{"field":"{\"a\":null,\"b\":{\"d\":3}}"}}}""")
+  }
+
+  "Merging a Json value that is not an object" should "fail" in {
+    server.queryThatMustFail(
+      """mutation { updateOneModel(where: { id: "A" }, data: { field: { merge: "[1,2]" } }) { field } }""",
+      project,
+      errorCode = 2019,
+      errorContains = "The `merge` operation requires a Json object.",
+      legacy = false
+    )
+  }
+
+  "Merging into a null Json field" should "result in the merged object" in {
+    server.query(
+      """mutation { createOneModel(data: { id: "B" }) { id } }""",
+      project,
+      legacy = false
+    )
+
+    server
+      .query(
+        """mutation { updateOneModel(where: { id: "B" }, data: { field: { merge: "{\"a\":1}" } }) { field } }""",
+        project,
+        legacy = false
+      )
+      .toString should be("""{"data":{"updateOneModel":{"field":"{\"a\":1}"}}}""")
+  }
+
+  "Setting a Json value at a path" should "only replace the value at that path" in {
+    server
+      .query(
+        """mutation { updateOneModel(where: { id: "A" }, data: { field: { setAtPath: { path: ["b", "c"], value: "[1,2]" } } }) { field } }""",
+        project,
+        legacy = false
+      )
+      .toString should be("""{"data":{"updateOneModel":{"field":"{\"a\":1,\"b\":{\"c\":[1,2]}}"}}}""")
+  }
+
+  "Setting a Json value at a path with a missing parent key" should "leave the value unchanged" in {
+    server
+      .query(
+        """mutation { updateOneModel(where: { id: "A" }, data: { field: { setAtPath: { path: ["x", "y"], value: "1" } } }) { field } }""",
+        project,
+        legacy = false
+      )
+      .toString should be("""{"data":{"updateOneModel":{"field":"{\"a\":1,\"b\":{\"c\":2}}"}}}""")
+  }
+
+  "Setting a Json field with the set operation" should "replace the whole value" in {
+    server
+      .query(
+        """mutation { updateOneModel(where: { id: "A" }, data: { field: { set: "{\"x\":true}" } }) { field } }""",
+        project,
+        legacy = false
+      )
+      .toString should be("""{"data":{"updateOneModel":{"field":"{\"x\":true}"}}}""")
+  }
+}
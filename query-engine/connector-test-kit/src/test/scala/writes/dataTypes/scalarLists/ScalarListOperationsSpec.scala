@@ -0,0 +1,97 @@
+package writes.dataTypes.scalarLists
+
+import org.scalatest.{FlatSpec, Matchers}
+import util.ConnectorCapability.ScalarListsCapability
+import util._
+
+class ScalarListOperationsSpec extends FlatSpec with Matchers with ApiSpecBase {
+
+  override def runOnlyForCapabilities = Set(ScalarListsCapability)
+
+  val project = ProjectDsl.fromString {
+    """
+      |model ScalarModel {
+      |  id      Int      @id
+      |  strings String[]
+      |  ints    Int[]
+      |}
+    """.stripMargin
+  }
+
+  override protected def beforeAll(): Unit = {
+    super.beforeAll()
+    database.setup(project)
+  }
+
+  override def beforeEach(): Unit = {
+    database.truncateProjectTables(project)
+
+    server.query(
+      """mutation { createOneScalarModel(data: { id: 1, strings: { set: ["a", "b"] }, ints: { set: [1, 2, 1] } }) { id } }""",
+      project,
+      legacy = false
+    )
+  }
+
+  "Pushing to a scalar list" should "append single values and lists" in {
+    server
+      .query(
+        """mutation { updateOneScalarModel(where: { id: 1 }, data: { strings: { push: "c" }, ints: { push: [3, 4] } }) { strings ints } }""",
+        project,
+        legacy = false
+      )
+      .toString should be("""{"data":{"updateOneScalarModel":{"strings":["a","b","c"],"ints":[1,2,1,3,4]}}}""")
+  }
+
+  "Removing from a scalar list" should "remove all occurrences of the values" in {
+    server
+      .query(
+        """mutation { updateOneScalarModel(where: { id: 1 }, data: { strings: { remove: ["b", "x"] }, ints: { remove: 1 } }) { strings ints } }""",
+        project,
+        legacy = false
+      )
+      .toString should be("""{"data":{"updateOneScalarModel":{"strings":["a"],"ints":[2]}}}""")
+  }
+
+  "Unsetting a scalar list" should "clear it" in {
+    server
+      .query(
+        """mutation { updateOneScalarModel(where: { id: 1 }, data: { strings: { unset: true }, ints: { unset: false } }) { strings ints } }""",
+        project,
+        legacy = false
+      )
+      .toString should be("""{"data":{"updateOneScalarModel":{"strings":[],"ints":[1,2,1]}}}""")
+
+    server
+      .query(
+        """mutation { updateOneScalarModel(where: { id: 1 }, data: { strings: { push: "a" } }) { strings } }""",
+        project,
+        legacy = false
+      )
+      .toString should be("""{"data":{"updateOneScalarModel":{"strings":["a"]}}}""")
+  }
+
+  "Scalar list operations" should "work with updateMany" in {
+    server.query(
+      """mutation { createOneScalarModel(data: { id: 2, ints: { set: [5] } }) { id } }""",
+      project,
+      legacy = false
+    )
+
+    server
+      .query(
+        """mutation { updateManyScalarModel(data: { ints: { push: 6 } }) { count } }""",
+        project,
+        legacy = false
+      )
+      .toString should be("""{"data":{"updateManyScalarModel":{"count":2}}}""")
+
+    server
+      .query(
+        """query { findManyScalarModel(orderBy: { id: asc }) { ints } }""",
+        project,
+        legacy = false
+      )
+      .toString should be("""{"data":{"findManyScalarModel":[{"ints":[1,2,1,6]},{"ints":[5,6]}]}}""")
+  }
+}
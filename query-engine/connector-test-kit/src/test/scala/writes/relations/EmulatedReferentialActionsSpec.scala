@@ -0,0 +1,315 @@
+package writes.relations
+
+import org.scalatest.{FlatSpec, Matchers}
+import util.ConnectorTag.{MySqlConnectorTag, PostgresConnectorTag, SQLiteConnectorTag}
+import util._
+
+class EmulatedReferentialActionsSpec extends FlatSpec with Matchers with ApiSpecBase with ConnectorAwareTest {
+  override def runOnlyForConnectors: Set[ConnectorTag] = Set(PostgresConnectorTag, MySqlConnectorTag, SQLiteConnectorTag)
+
+  val project = ProjectDsl
+    .fromString {
+      """model User {
+        |  id       Int       @id
+        |  email    String    @unique
+        |  posts    Post[]
+        |  comments Comment[]
+        |  likes    Like[]
+        |}
+        |
+        |model Post {
+        |  id          Int       @id
+        |  authorEmail String
+        |  author      User      @relation(fields: [authorEmail], references: [email], onDelete: Cascade, onUpdate: Cascade)
+        |  comments    Comment[]
+        |  tags        Tag[]
+        |}
+        |
+        |model Tag {
+        |  id    Int    @id
+        |  posts Post[]
+        |}
+        |
+        |model Comment {
+        |  id       Int   @id
+        |  postId   Int
+        |  post     Post  @relation(fields: [postId], references: [id], onDelete: Cascade)
+        |  authorId Int?
+        |  author   User? @relation(fields: [authorId], references: [id], onDelete: SetNull)
+        |}
+        |
+        |model Like {
+        |  id        Int    @id
+        |  userEmail String
+        |  user      User   @relation(fields: [userEmail], references: [email], onDelete: NoAction, onUpdate: NoAction)
+        |}
+        |
+        |model Category {
+        |  id       Int        @id
+        |  parentId Int?
+        |  parent   Category?  @relation("CategoryTree", fields: [parentId], references: [id], onDelete: Cascade)
+        |  children Category[] @relation("CategoryTree")
+        |}
+        |
+        |model Person {
+        |  id        Int      @id
+        |  managerId Int?
+        |  manager   Person?  @relation("Management", fields: [managerId], references: [id], onDelete: Cascade)
+        |  reports   Person[] @relation("Management")
+        |  mentorId  Int?
+        |  mentor    Person?  @relation("Mentoring", fields: [mentorId], references: [id], onDelete: Cascade)
+        |  mentees   Person[] @relation("Mentoring")
+        |}
+      """.stripMargin
+    }
+    .copy(referentialIntegrity = Some("prisma"))
+
+  override protected def beforeAll(): Unit = {
+    super.beforeAll()
+    database.setup(project)
+  }
+
+  override def beforeEach(): Unit = database.truncateProjectTables(project)
+
+  "Deleting a record with emulated onDelete: Cascade" should "delete the dependent records recursively" in {
+    server.query(
+      """mutation {
+        |  createOneUser(data: {
+        |    id: 1,
+        |    email: "a@prisma.io",
+        |    posts: { create: [{ id: 1, comments: { create: [{ id: 1 }, { id: 2 }] } }] }
+        |  }) { id }
+        |}""".stripMargin,
+      project,
+      legacy = false
+    )
+
+    server.query("""mutation { deleteOneUser(where: { id: 1 }) { id } }""", project, legacy = false).toString should be(
+      """{"data":{"deleteOneUser":{"id":1}}}"""
+    )
+
+    server.query("""{ findManyPost { id } }""", project, legacy = false).toString should be("""{"data":{"findManyPost":[]}}""")
+    server.query("""{ findManyComment { id } }""", project, legacy = false).toString should be("""{"data":{"findManyComment":[]}}""")
+  }
+
+  "Deleting a record with emulated onDelete: SetNull" should "set the foreign keys of the dependent records to null" in {
+    server.query(
+      """mutation { createOneUser(data: { id: 2, email: "b@prisma.io", posts: { create: [{ id: 1 }] } }) { id } }""",
+      project,
+      legacy = false
+    )
+
+    server.query(
+      """mutation {
+        |  createOneComment(data: {
+        |    id: 1,
+        |    post: { connect: { id: 1 } },
+        |    author: { create: { id: 1, email: "a@prisma.io" } }
+        |  }) { id }
+        |}""".stripMargin,
+      project,
+      legacy = false
+    )
+
+    server.query("""mutation { deleteOneUser(where: { id: 1 }) { id } }""", project, legacy = false)
+
+    server.query("""{ findManyComment { id authorId } }""", project, legacy = false).toString should be(
+      """{"data":{"findManyComment":[{"id":1,"authorId":null}]}}"""
+    )
+  }
+
+  "Deleting a record with emulated onDelete: NoAction" should "fail if dependent records exist" in {
+    server.query(
+      """mutation { createOneUser(data: { id: 1, email: "a@prisma.io", likes: { create: [{ id: 1 }] } }) { id } }""",
+      project,
+      legacy = false
+    )
+
+    server.queryThatMustFail(
+      """mutation { deleteManyUser(where: { id: { equals: 1 } }) { count } }""",
+      project,
+      errorCode = 2014,
+      errorContains = "The change you are trying to make would violate the required relation 'LikeToUser' between the `Like` and `User` models.",
+      legacy = false
+    )
+
+    server.query("""{ findManyUser { id } }""", project, legacy = false).toString should be("""{"data":{"findManyUser":[{"id":1}]}}""")
+  }
+
+  "Deleting a record of an implicit many-to-many relation with emulated referential actions" should "delete its rows of the relation table" in {
+    server.query(
+      """mutation {
+        |  createOneUser(data: {
+        |    id: 1,
+        |    email: "a@prisma.io",
+        |    posts: { create: [{ id: 1, tags: { create: [{ id: 1 }, { id: 2 }] } }] }
+        |  }) { id }
+        |}""".stripMargin,
+      project,
+      legacy = false
+    )
+
+    server.query("""mutation { deleteOnePost(where: { id: 1 }) { id } }""", project, legacy = false)
+
+    server.query("""{ findManyTag { id posts { id } } }""", project, legacy = false).toString should be(
+      """{"data":{"findManyTag":[{"id":1,"posts":[]},{"id":2,"posts":[]}]}}"""
+    )
+
+    // A new record with the same ID must not be connected by rows left behind in the relation table.
+    server.query(
+      """mutation { createOnePost(data: { id: 1, author: { connect: { id: 1 } } }) { id tags { id } } }""",
+      project,
+      legacy = false
+    ).toString should be("""{"data":{"createOnePost":{"id":1,"tags":[]}}}""")
+  }
+
+  "Deleting a record of a self-relation with emulated onDelete: Cascade" should "delete the whole chain of dependent records" in {
+    server.query(
+      """mutation {
+        |  createOneCategory(data: {
+        |    id: 1,
+        |    children: { create: [{ id: 2, children: { create: [{ id: 3, children: { create: [{ id: 4 }] } }] } }] }
+        |  }) { id }
+        |}""".stripMargin,
+      project,
+      legacy = false
+    )
+
+    server.query("""mutation { deleteOneCategory(where: { id: 1 }) { id } }""", project, legacy = false)
+
+    server.query("""{ findManyCategory { id } }""", project, legacy = false).toString should be(
+      """{"data":{"findManyCategory":[]}}"""
+    )
+  }
+
+  "Deleting a record of two interlocking cycles with emulated onDelete: Cascade" should "delete the dependent records of both cycles" in {
+    server.query(
+      """mutation {
+        |  createOnePerson(data: {
+        |    id: 1,
+        |    reports: { create: [{ id: 2, mentees: { create: [{ id: 3, reports: { create: [{ id: 4 }] } }] } }] },
+        |    mentees: { create: [{ id: 5, mentees: { create: [{ id: 6 }] } }] }
+        |  }) { id }
+        |}""".stripMargin,
+      project,
+      legacy = false
+    )
+
+    server.query("""mutation { deleteOnePerson(where: { id: 1 }) { id } }""", project, legacy = false)
+
+    server.query("""{ findManyPerson { id } }""", project, legacy = false).toString should be("""{"data":{"findManyPerson":[]}}""")
+  }
+
+  "Deleting a record of two interlocking cycles with emulated onDelete: Cascade" should "fail if the chain of dependent records is too deep" in {
+    server.query("""mutation { createOnePerson(data: { id: 1 }) { id } }""", project, legacy = false)
+
+    (2 to 10).foreach { id =>
+      server.query(
+        s"""mutation { createOnePerson(data: { id: $id, manager: { connect: { id: ${id - 1} } } }) { id } }""",
+        project,
+        legacy = false
+      )
+    }
+
+    server.queryThatMustFail(
+      """mutation { deleteOnePerson(where: { id: 1 }) { id } }""",
+      project,
+      errorCode = 2019,
+      errorContains = "its chain of dependent records is deeper than 8 levels",
+      legacy = false
+    )
+
+    server.query("""{ aggregatePerson { count { _all } } }""", project, legacy = false).toString should be(
+      """{"data":{"aggregatePerson":{"count":{"_all":10}}}}"""
+    )
+  }
+
+  "Updating a referenced field with emulated onUpdate: Cascade" should "update the foreign keys of the dependent records" in {
+    server.query(
+      """mutation { createOneUser(data: { id: 1, email: "a@prisma.io", posts: { create: [{ id: 1 }] } }) { id } }""",
+      project,
+      legacy = false
+    )
+
+    server.query(
+      """mutation { updateOneUser(where: { id: 1 }, data: { email: { set: "b@prisma.io" } }) { id } }""",
+      project,
+      legacy = false
+    )
+
+    server.query("""{ findManyPost { id authorEmail } }""", project, legacy = false).toString should be(
+      """{"data":{"findManyPost":[{"id":1,"authorEmail":"b@prisma.io"}]}}"""
+    )
+  }
+
+  "Updating a referenced field in a nested update with emulated onUpdate: Cascade" should "update the foreign keys of the dependent records" in {
+    server.query(
+      """mutation {
+        |  createOneComment(data: {
+        |    id: 1,
+        |    post: { create: { id: 1, author: { create: { id: 1, email: "a@prisma.io" } } } }
+        |  }) { id }
+        |}""".stripMargin,
+      project,
+      legacy = false
+    )
+
+    server.query(
+      """mutation {
+        |  updateOneComment(where: { id: 1 }, data: {
+        |    post: { update: { author: { update: { email: { set: "b@prisma.io" } } } } }
+        |  }) { id }
+        |}""".stripMargin,
+      project,
+      legacy = false
+    )
+
+    server.query("""{ findManyPost { id authorEmail } }""", project, legacy = false).toString should be(
+      """{"data":{"findManyPost":[{"id":1,"authorEmail":"b@prisma.io"}]}}"""
+    )
+  }
+
+  "Updating a referenced field in an upsert with emulated onUpdate: Cascade" should "update the foreign keys of the dependent records" in {
+    server.query(
+      """mutation { createOneUser(data: { id: 1, email: "a@prisma.io", posts: { create: [{ id: 1 }] } }) { id } }""",
+      project,
+      legacy = false
+    )
+
+    server.query(
+      """mutation {
+        |  upsertOneUser(
+        |    where: { id: 1 },
+        |    create: { id: 1, email: "c@prisma.io" },
+        |    update: { email: { set: "b@prisma.io" } }
+        |  ) { id }
+        |}""".stripMargin,
+      project,
+      legacy = false
+    )
+
+    server.query("""{ findManyPost { id authorEmail } }""", project, legacy = false).toString should be(
+      """{"data":{"findManyPost":[{"id":1,"authorEmail":"b@prisma.io"}]}}"""
+    )
+  }
+
+  "Updating a referenced field with emulated onUpdate: NoAction" should "fail if dependent records exist" in {
+    server.query(
+      """mutation { createOneUser(data: { id: 1, email: "a@prisma.io", likes: { create: [{ id: 1 }] } }) { id } }""",
+      project,
+      legacy = false
+    )
+
+    server.queryThatMustFail(
+      """mutation { updateManyUser(where: { id: { equals: 1 } }, data: { email: { set: "b@prisma.io" } }) { count } }""",
+      project,
+      errorCode = 2014,
+      errorContains = "The change you are trying to make would violate the required relation 'LikeToUser' between the `Like` and `User` models.",
+      legacy = false
+    )
+
+    server.query("""{ findManyLike { id userEmail } }""", project, legacy = false).toString should be(
+      """{"data":{"findManyLike":[{"id":1,"userEmail":"a@prisma.io"}]}}"""
+    )
+  }
+}
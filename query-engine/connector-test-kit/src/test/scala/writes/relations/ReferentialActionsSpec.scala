@@ -0,0 +1,92 @@
+package writes.relations
+
+import org.scalatest.{FlatSpec, Matchers}
+import util.ConnectorTag.{MySqlConnectorTag, PostgresConnectorTag, SQLiteConnectorTag}
+import util._
+
+class ReferentialActionsSpec extends FlatSpec with Matchers with ApiSpecBase with ConnectorAwareTest {
+  override def runOnlyForConnectors: Set[ConnectorTag] = Set(PostgresConnectorTag, MySqlConnectorTag, SQLiteConnectorTag)
+
+  val project = ProjectDsl.fromString {
+    """model User {
+      |  id       Int       @id
+      |  posts    Post[]
+      |  comments Comment[]
+      |  likes    Like[]
+      |}
+      |
+      |model Post {
+      |  id       Int  @id
+      |  authorId Int
+      |  author   User @relation(fields: [authorId], references: [id], onDelete: Cascade)
+      |}
+      |
+      |model Comment {
+      |  id       Int   @id
+      |  authorId Int?
+      |  author   User? @relation(fields: [authorId], references: [id], onDelete: SetNull)
+      |}
+      |
+      |model Like {
+      |  id     Int  @id
+      |  userId Int
+      |  user   User @relation(fields: [userId], references: [id], onDelete: Restrict)
+      |}
+    """.stripMargin
+  }
+
+  override protected def beforeAll(): Unit = {
+    super.beforeAll()
+    database.setup(project)
+  }
+
+  override def beforeEach(): Unit = database.truncateProjectTables(project)
+
+  "Deleting a record with onDelete: Cascade" should "delete the dependent records" in {
+    server.query(
+      """mutation { createOneUser(data: { id: 1, posts: { create: [{ id: 1 }, { id: 2 }] } }) { id } }""",
+      project,
+      legacy = false
+    )
+
+    server.query("""mutation { deleteOneUser(where: { id: 1 }) { id } }""", project, legacy = false).toString should be(
+      """{"data":{"deleteOneUser":{"id":1}}}"""
+    )
+
+    server.query("""{ findManyPost { id } }""", project, legacy = false).toString should be("""{"data":{"findManyPost":[]}}""")
+  }
+
+  "Deleting a record with onDelete: SetNull" should "set the foreign keys of the dependent records to null" in {
+    server.query(
+      """mutation { createOneUser(data: { id: 1, comments: { create: [{ id: 1 }] } }) { id } }""",
+      project,
+      legacy = false
+    )
+
+    server.query("""mutation { deleteOneUser(where: { id: 1 }) { id } }""", project, legacy = false).toString should be(
+      """{"data":{"deleteOneUser":{"id":1}}}"""
+    )
+
+    server.query("""{ findManyComment { id authorId } }""", project, legacy = false).toString should be(
+      """{"data":{"findManyComment":[{"id":1,"authorId":null}]}}"""
+    )
+  }
+
+  "Deleting a record with onDelete: Restrict" should "fail if dependent records exist" in {
+    server.query(
+      """mutation { createOneUser(data: { id: 1, likes: { create: [{ id: 1 }] } }) { id } }""",
+      project,
+      legacy = false
+    )
+
+    server.queryThatMustFail(
+      """mutation { deleteOneUser(where: { id: 1 }) { id } }""",
+      project,
+      errorCode = 2014,
+      errorContains = "The change you are trying to make would violate the required relation 'LikeToUser' between the `Like` and `User` models.",
+      legacy = false
+    )
+
+    server.query("""{ findManyLike { id } }""", project, legacy = false).toString should be("""{"data":{"findManyLike":[{"id":1}]}}""")
+  }
+}
@@ -0,0 +1,92 @@
+package writes.topLevelMutations
+
+import org.scalatest.{FlatSpec, Matchers}
+import util.ConnectorTag.{MsSqlConnectorTag, PostgresConnectorTag}
+import util._
+
+class CreateManyAndReturnMutationSpec extends FlatSpec with Matchers with ApiSpecBase with ConnectorAwareTest {
+  override def runOnlyForConnectors: Set[ConnectorTag] = Set(PostgresConnectorTag, MsSqlConnectorTag)
+
+  "A createMany returning the records" should "return the created records with their defaults" in {
+    val project = ProjectDsl.fromString {
+      """
+        |model Test {
+        |  id   Int     @id
+        |  str1 String
+        |  str2 String? @default("SOME_DEFAULT")
+        |}
+        |""".stripMargin
+    }
+    database.setup(project)
+
+    val result = server.query(
+      """
+        |mutation {
+        |  createManyTestAndReturn(data: [
+        |    { id: 1, str1: "1", str2: "1" },
+        |    { id: 2, str1: "2" },
+        |  ]) {
+        |    id
+        |    str1
+        |    str2
+        |  }
+        |}
+      """.stripMargin,
+      project,
+      legacy = false
+    )
+
+    result.toString() should be(
+      """{"data":{"createManyTestAndReturn":[{"id":1,"str1":"1","str2":"1"},{"id":2,"str1":"2","str2":"SOME_DEFAULT"}]}}""")
+  }
+
+  // Covers: Autoincrement ID working with basic functionality.
+  "A createMany returning the records" should "return the generated ids" taggedAs IgnoreMsSql in {
+    val project = ProjectDsl.fromString {
+      """
+        |model Test {
+        |  id   Int    @id @default(autoincrement())
+        |  str1 String
+        |}
+        |""".stripMargin
+    }
+    database.setup(project)
+
+    val result = server.query(
+      """mutation { createManyTestAndReturn(data: [{ str1: "1" }, { str1: "2" }]) { id str1 } }""",
+      project,
+      legacy = false
+    )
+
+    result.toString() should be("""{"data":{"createManyTestAndReturn":[{"id":1,"str1":"1"},{"id":2,"str1":"2"}]}}""")
+  }
+
+  "A createMany returning the records" should "only return the created records when skipping duplicates" taggedAs IgnoreMsSql in {
+    val project = ProjectDsl.fromString {
+      """
+        |model Test {
+        |  id   Int    @id
+        |  str1 String
+        |}
+        |""".stripMargin
+    }
+    database.setup(project)
+
+    server.query("""mutation { createOneTest(data: { id: 10, str1: "existing" }) { id } }""", project, legacy = false)
+
+    val result = server.query(
+      """
+        |mutation {
+        |  createManyTestAndReturn(data: [{ id: 10, str1: "10" }, { id: 11, str1: "11" }], skipDuplicates: true) {
+        |    id
+        |    str1
+        |  }
+        |}
+      """.stripMargin,
+      project,
+      legacy = false
+    )
+
+    result.toString() should be("""{"data":{"createManyTestAndReturn":[{"id":11,"str1":"11"}]}}""")
+  }
+}
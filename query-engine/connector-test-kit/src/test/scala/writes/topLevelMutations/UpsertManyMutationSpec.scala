@@ -0,0 +1,109 @@
+package writes.topLevelMutations
+
+import org.scalatest.{FlatSpec, Matchers}
+import util.ConnectorTag.{MsSqlConnectorTag, MySqlConnectorTag, PostgresConnectorTag}
+import util._
+
+class UpsertManyMutationSpec extends FlatSpec with Matchers with ApiSpecBase with ConnectorAwareTest {
+  override def runOnlyForConnectors: Set[ConnectorTag] = Set(PostgresConnectorTag, MySqlConnectorTag, MsSqlConnectorTag)
+
+  val project = ProjectDsl.fromString {
+    """
+      |model Test {
+      |  id        Int      @id
+      |  str1      String
+      |  str2      String?  @default("SOME_DEFAULT")
+      |  createdAt DateTime @default(now())
+      |  updatedAt DateTime @updatedAt
+      |}
+      |
+      |model Tag {
+      |  id Int @id
+      |}
+      |
+      |model User {
+      |  id    Int    @id
+      |  email String @unique
+      |}
+      |""".stripMargin
+  }
+
+  override protected def beforeEach(): Unit = {
+    super.beforeEach()
+    database.setup(project)
+
+    server.query(
+      """mutation {
+        |  createManyTest(data: [
+        |    { id: 1, str1: "1", str2: "1", createdAt: "2020-01-01T00:00:00.000Z", updatedAt: "2020-01-01T00:00:00.000Z" },
+        |    { id: 2, str1: "2", str2: "2", createdAt: "2020-01-01T00:00:00.000Z", updatedAt: "2020-01-01T00:00:00.000Z" }
+        |  ]) { count }
+        |}""".stripMargin,
+      project,
+      legacy = false
+    )
+  }
+
+  "An upsertMany" should "insert new records and update the existing ones" in {
+    server
+      .query(
+        """
+          |mutation {
+          |  upsertManyTest(data: [
+          |    { id: 1, str1: "updated" },
+          |    { id: 3, str1: "3" },
+          |    { id: 2, str1: "updated", str2: null },
+          |  ]) {
+          |    count
+          |  }
+          |}
+        """.stripMargin,
+        project,
+        legacy = false
+      )
+      .toString() should be("""{"data":{"upsertManyTest":{"count":3}}}""")
+
+    server
+      .query("""query { findManyTest(orderBy: { id: asc }) { id str1 str2 } }""", project, legacy = false)
+      .toString() should be(
+      """{"data":{"findManyTest":[{"id":1,"str1":"updated","str2":"1"},{"id":2,"str1":"updated","str2":null},{"id":3,"str1":"3","str2":"SOME_DEFAULT"}]}}""")
+  }
+
+  "An upsertMany" should "only update the fields set in the query and the updatedAt field" in {
+    server.query(
+      """mutation { upsertManyTest(data: [{ id: 1, str1: "updated" }, { id: 3, str1: "3" }]) { count } }""",
+      project,
+      legacy = false
+    )
+
+    val result = server.query("""query { findManyTest(orderBy: { id: asc }) { id str2 createdAt updatedAt } }""", project, legacy = false)
+
+    result.pathAsString("data.findManyTest.[0].str2") should be("1")
+    result.pathAsString("data.findManyTest.[0].createdAt") should be("2020-01-01T00:00:00.000Z")
+    result.pathAsString("data.findManyTest.[0].updatedAt") should not be("2020-01-01T00:00:00.000Z")
+    result.pathAsString("data.findManyTest.[1].createdAt") should be("2020-01-01T00:00:00.000Z")
+    result.pathAsString("data.findManyTest.[1].updatedAt") should be("2020-01-01T00:00:00.000Z")
+    result.pathAsString("data.findManyTest.[2].str2") should be("SOME_DEFAULT")
+    result.pathAsString("data.findManyTest.[2].createdAt") should not be("2020-01-01T00:00:00.000Z")
+  }
+
+  "An upsertMany without fields to update" should "only count the inserted records" in {
+    server.query("""mutation { createOneTag(data: { id: 1 }) { id } }""", project, legacy = false)
+
+    server
+      .query("""mutation { upsertManyTag(data: [{ id: 1 }, { id: 2 }]) { count } }""", project, legacy = false)
+      .toString() should be("""{"data":{"upsertManyTag":{"count":1}}}""")
+  }
+
+  "An upsertMany on MySQL" should "fail for models with unique criteria besides the primary identifier" in {
+    if (connectorTag == ConnectorTag.MySqlConnectorTag) {
+      server.queryThatMustFail(
+        """mutation { upsertManyUser(data: [{ id: 1, email: "a@b.c" }]) { count } }""",
+        project,
+        errorCode = 0,
+        errorContains = "Model `User` has unique criteria besides its primary identifier and can't be upserted on MySQL.",
+        legacy = false
+      )
+    }
+  }
+}
@@ -0,0 +1,145 @@
+package writes.topLevelMutations
+
+import org.scalatest.{FlatSpec, Matchers}
+import util._
+
+class WriteManyAndReturnMutationSpec extends FlatSpec with Matchers with ApiSpecBase {
+  val project = ProjectDsl.fromString {
+    """model Job {
+      |  id     Int    @id
+      |  status String
+      |}
+    """.stripMargin
+  }
+
+  override protected def beforeAll(): Unit = {
+    super.beforeAll()
+    database.setup(project)
+  }
+
+  override def beforeEach(): Unit = {
+    database.truncateProjectTables(project)
+    createJobs()
+  }
+
+  "An updateMany mutation returning the records" should "only update and return the first records of the ordering" in {
+    val result = server.query(
+      """mutation {
+        |  updateManyJobAndReturn(
+        |    where: { status: { equals: "pending" } }
+        |    orderBy: { id: asc }
+        |    take: 2
+        |    data: { status: { set: "claimed" } }
+        |  ) {
+        |    id
+        |    status
+        |  }
+        |}
+      """.stripMargin,
+      project,
+      legacy = false,
+    )
+
+    result.toString() should be(
+      """{"data":{"updateManyJobAndReturn":[{"id":2,"status":"claimed"},{"id":3,"status":"claimed"}]}}""")
+
+    readJobs() should be(
+      """[{"id":1,"status":"done"},{"id":2,"status":"claimed"},{"id":3,"status":"claimed"},{"id":4,"status":"pending"}]""")
+  }
+
+  "An updateMany mutation returning the records" should "return all updated records without take" in {
+    val result = server.query(
+      """mutation {
+        |  updateManyJobAndReturn(where: { status: { equals: "pending" } }, data: { status: { set: "claimed" } }) {
+        |    id
+        |  }
+        |}
+      """.stripMargin,
+      project,
+      legacy = false,
+    )
+
+    result.pathAsJsValue("data.updateManyJobAndReturn").toString should be("""[{"id":2},{"id":3},{"id":4}]""")
+  }
+
+  "An updateMany mutation returning the records" should "return an empty list if no record matches" in {
+    val result = server.query(
+      """mutation {
+        |  updateManyJobAndReturn(where: { status: { equals: "failed" } }, data: { status: { set: "claimed" } }) {
+        |    id
+        |  }
+        |}
+      """.stripMargin,
+      project,
+      legacy = false,
+    )
+
+    result.toString() should be("""{"data":{"updateManyJobAndReturn":[]}}""")
+  }
+
+  "An updateMany mutation" should "only update the first records of the ordering" in {
+    val result = server.query(
+      """mutation {
+        |  updateManyJob(orderBy: { id: desc }, take: 1, data: { status: { set: "claimed" } }) {
+        |    count
+        |  }
+        |}
+      """.stripMargin,
+      project,
+      legacy = false,
+    )
+
+    result.pathAsLong("data.updateManyJob.count") should equal(1)
+    readJobs() should be(
+      """[{"id":1,"status":"done"},{"id":2,"status":"pending"},{"id":3,"status":"pending"},{"id":4,"status":"claimed"}]""")
+  }
+
+  "A deleteMany mutation returning the records" should "only delete and return the first records of the ordering" in {
+    val result = server.query(
+      """mutation {
+        |  deleteManyJobAndReturn(where: { status: { equals: "pending" } }, orderBy: { id: desc }, take: 2) {
+        |    id
+        |    status
+        |  }
+        |}
+      """.stripMargin,
+      project,
+      legacy = false,
+    )
+
+    result.toString() should be(
+      """{"data":{"deleteManyJobAndReturn":[{"id":4,"status":"pending"},{"id":3,"status":"pending"}]}}""")
+
+    readJobs() should be("""[{"id":1,"status":"done"},{"id":2,"status":"pending"}]""")
+  }
+
+  "A deleteMany mutation" should "only delete the first records of the ordering" in {
+    val result = server.query(
+      """mutation {
+        |  deleteManyJob(orderBy: { id: asc }, take: 3) {
+        |    count
+        |  }
+        |}
+      """.stripMargin,
+      project,
+      legacy = false,
+    )
+
+    result.pathAsLong("data.deleteManyJob.count") should equal(3)
+    readJobs() should be("""[{"id":4,"status":"pending"}]""")
+  }
+
+  def createJobs(): Unit = {
+    Seq(1 -> "done", 2 -> "pending", 3 -> "pending", 4 -> "pending").foreach {
+      case (id, status) =>
+        server.query(s"""mutation { createOneJob(data: { id: $id, status: "$status" }) { id } }""", project, legacy = false)
+    }
+  }
+
+  def readJobs(): String = {
+    server
+      .query("""{ findManyJob(orderBy: { id: asc }) { id status } }""", project, legacy = false)
+      .pathAsJsValue("data.findManyJob")
+      .toString
+  }
+}
@@ -139,6 +139,16 @@ impl Filter {
         }
     }
 
+    pub fn set_json_path(&mut self, path: JsonFilterPath) {
+        match self {
+            Filter::And(inner) => inner.iter_mut().for_each(|f| f.set_json_path(path.clone())),
+            Filter::Or(inner) => inner.iter_mut().for_each(|f| f.set_json_path(path.clone())),
+            Filter::Not(inner) => inner.iter_mut().for_each(|f| f.set_json_path(path.clone())),
+            Filter::Scalar(sf) => sf.set_json_path(path),
+            _ => {}
+        }
+    }
+
     pub fn count(condition: Filter) -> Self {
         Self::Aggregation(AggregationFilter::Count(Box::new(condition)))
     }
@@ -130,6 +130,64 @@ pub enum ScalarCondition {
     GreaterThanOrEquals(PrismaValue),
     In(PrismaListValue),
     NotIn(PrismaListValue),
+    /// A condition on a value inside of a Json field.
+    JsonCompare(JsonCondition),
+    /// Full-text search for the query on the projected field. Search filters with the same query
+    /// on other fields of the model are merged into the condition as additional projections.
+    Search(PrismaValue, Vec<ScalarProjection>),
+    NotSearch(PrismaValue, Vec<ScalarProjection>),
+}
+
+/// Applies a condition to the value found at `path` inside of a Json field, or to the whole
+/// Json value if there is no path.
+///
+/// ```graphql
+/// findManyUser(where: { settings: { path: ["theme", "name"], string_contains: "dark" } })
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct JsonCondition {
+    pub condition: Box<ScalarCondition>,
+    pub path: Option<JsonFilterPath>,
+    /// The kind of value the condition expects at the path. `None` compares Json values.
+    pub target_type: Option<JsonTargetType>,
+}
+
+/// The path syntax depends on the database: MySQL uses JSON path strings (`$.theme.colors`),
+/// Postgres uses a list of keys and array indices.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum JsonFilterPath {
+    String(String),
+    Array(Vec<String>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum JsonTargetType {
+    String,
+    Array,
+}
+
+impl ScalarCondition {
+    pub fn is_search(&self) -> bool {
+        matches!(self, ScalarCondition::Search(..) | ScalarCondition::NotSearch(..))
+    }
+}
+
+impl ScalarFilter {
+    /// Scopes the filter to the value at `path` inside of the Json field.
+    pub fn set_json_path(&mut self, path: JsonFilterPath) {
+        match self.condition {
+            ScalarCondition::JsonCompare(ref mut json_condition) => json_condition.path = Some(path),
+            _ => {
+                let condition = std::mem::replace(&mut self.condition, ScalarCondition::In(vec![]));
+
+                self.condition = ScalarCondition::JsonCompare(JsonCondition {
+                    condition: Box::new(condition),
+                    path: Some(path),
+                    target_type: None,
+                });
+            }
+        }
+    }
 }
 
 impl ScalarCompare for ScalarFieldRef {
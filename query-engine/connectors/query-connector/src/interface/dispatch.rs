@@ -9,10 +9,17 @@ impl<'conn, 'tx> ReadOperations for ConnectionLike<'conn, 'tx> {
         model: &ModelRef,
         filter: &Filter,
         selected_fields: &ModelProjection,
+        aggregation_selections: &[RelAggregationSelection],
     ) -> crate::Result<Option<SingleRecord>> {
         match self {
-            Self::Connection(c) => c.get_single_record(model, filter, selected_fields).await,
-            Self::Transaction(tx) => tx.get_single_record(model, filter, selected_fields).await,
+            Self::Connection(c) => {
+                c.get_single_record(model, filter, selected_fields, aggregation_selections)
+                    .await
+            }
+            Self::Transaction(tx) => {
+                tx.get_single_record(model, filter, selected_fields, aggregation_selections)
+                    .await
+            }
         }
     }
 
@@ -21,10 +28,100 @@ impl<'conn, 'tx> ReadOperations for ConnectionLike<'conn, 'tx> {
         model: &ModelRef,
         query_arguments: QueryArguments,
         selected_fields: &ModelProjection,
+        aggregation_selections: &[RelAggregationSelection],
     ) -> crate::Result<ManyRecords> {
         match self {
-            Self::Connection(c) => c.get_many_records(model, query_arguments, selected_fields).await,
-            Self::Transaction(tx) => tx.get_many_records(model, query_arguments, selected_fields).await,
+            Self::Connection(c) => {
+                c.get_many_records(model, query_arguments, selected_fields, aggregation_selections)
+                    .await
+            }
+            Self::Transaction(tx) => {
+                tx.get_many_records(model, query_arguments, selected_fields, aggregation_selections)
+                    .await
+            }
+        }
+    }
+
+    async fn get_many_records_per_parent(
+        &self,
+        model: &ModelRef,
+        query_arguments: QueryArguments,
+        partition_by: &ModelProjection,
+        selected_fields: &ModelProjection,
+        aggregation_selections: &[RelAggregationSelection],
+    ) -> crate::Result<Option<ManyRecords>> {
+        match self {
+            Self::Connection(c) => {
+                c.get_many_records_per_parent(
+                    model,
+                    query_arguments,
+                    partition_by,
+                    selected_fields,
+                    aggregation_selections,
+                )
+                .await
+            }
+            Self::Transaction(tx) => {
+                tx.get_many_records_per_parent(
+                    model,
+                    query_arguments,
+                    partition_by,
+                    selected_fields,
+                    aggregation_selections,
+                )
+                .await
+            }
+        }
+    }
+
+    async fn get_many_records_with_relations(
+        &self,
+        model: &ModelRef,
+        query_arguments: QueryArguments,
+        selected_fields: &ModelProjection,
+        relation_selections: &[RelationSelection],
+    ) -> crate::Result<Option<Vec<JoinedRecord>>> {
+        match self {
+            Self::Connection(c) => {
+                c.get_many_records_with_relations(model, query_arguments, selected_fields, relation_selections)
+                    .await
+            }
+            Self::Transaction(tx) => {
+                tx.get_many_records_with_relations(model, query_arguments, selected_fields, relation_selections)
+                    .await
+            }
+        }
+    }
+
+    async fn get_related_m2m_records_per_parent(
+        &self,
+        from_field: &RelationFieldRef,
+        from_record_ids: &[RecordProjection],
+        query_arguments: QueryArguments,
+        selected_fields: &ModelProjection,
+        aggregation_selections: &[RelAggregationSelection],
+    ) -> crate::Result<Option<ManyRecords>> {
+        match self {
+            Self::Connection(c) => {
+                c.get_related_m2m_records_per_parent(
+                    from_field,
+                    from_record_ids,
+                    query_arguments,
+                    selected_fields,
+                    aggregation_selections,
+                )
+                .await
+            }
+            Self::Transaction(tx) => {
+                tx.get_related_m2m_records_per_parent(
+                    from_field,
+                    from_record_ids,
+                    query_arguments,
+                    selected_fields,
+                    aggregation_selections,
+                )
+                .await
+            }
         }
     }
 
@@ -81,6 +178,25 @@ impl<'conn, 'tx> WriteOperations for ConnectionLike<'conn, 'tx> {
         }
     }
 
+    async fn create_records_returning(
+        &self,
+        model: &ModelRef,
+        args: Vec<WriteArgs>,
+        skip_duplicates: bool,
+    ) -> crate::Result<Vec<RecordProjection>> {
+        match self {
+            Self::Connection(c) => c.create_records_returning(model, args, skip_duplicates).await,
+            Self::Transaction(tx) => tx.create_records_returning(model, args, skip_duplicates).await,
+        }
+    }
+
+    async fn upsert_records(&self, model: &ModelRef, args: Vec<UpsertArgs>) -> crate::Result<usize> {
+        match self {
+            Self::Connection(c) => c.upsert_records(model, args).await,
+            Self::Transaction(tx) => tx.upsert_records(model, args).await,
+        }
+    }
+
     async fn update_records(
         &self,
         model: &ModelRef,
@@ -124,6 +240,17 @@ impl<'conn, 'tx> WriteOperations for ConnectionLike<'conn, 'tx> {
         }
     }
 
+    async fn delete_relation_records(
+        &self,
+        field: &RelationFieldRef,
+        parent_ids: &[RecordProjection],
+    ) -> crate::Result<()> {
+        match self {
+            Self::Connection(c) => c.delete_relation_records(field, parent_ids).await,
+            Self::Transaction(tx) => tx.delete_relation_records(field, parent_ids).await,
+        }
+    }
+
     async fn query_raw(&self, query: String, parameters: Vec<PrismaValue>) -> crate::Result<serde_json::Value> {
         match self {
             Self::Connection(c) => c.query_raw(query, parameters).await,
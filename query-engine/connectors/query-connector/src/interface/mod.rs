@@ -1,7 +1,7 @@
 mod dispatch;
 pub use dispatch::*;
 
-use crate::{Filter, QueryArguments, WriteArgs};
+use crate::{Filter, QueryArguments, UpsertArgs, WriteArgs};
 use async_trait::async_trait;
 use dml::FieldArity;
 use prisma_models::*;
@@ -159,6 +159,65 @@ pub enum AggregationResult {
     Max(ScalarFieldRef, PrismaValue),
 }
 
+/// Aggregations on the relations of the records selected by a read query,
+/// returned alongside the scalar values of each record.
+#[derive(Debug, Clone)]
+pub enum RelAggregationSelection {
+    /// Counts the related records of a to-many relation field.
+    Count(RelationFieldRef),
+}
+
+impl RelAggregationSelection {
+    /// The name under which the connector returns the aggregated value
+    /// next to the selected fields of a record.
+    pub fn db_alias(&self) -> String {
+        match self {
+            RelAggregationSelection::Count(rf) => format!("_aggr_count_{}", rf.name),
+        }
+    }
+
+    pub fn type_identifier_with_arity(&self) -> (TypeIdentifier, FieldArity) {
+        match self {
+            RelAggregationSelection::Count(_) => (TypeIdentifier::Int, FieldArity::Required),
+        }
+    }
+
+    pub fn into_result(self, value: PrismaValue) -> RelAggregationResult {
+        match self {
+            RelAggregationSelection::Count(rf) => RelAggregationResult::Count(rf, value),
+        }
+    }
+}
+
+pub type RelAggregationRow = Vec<RelAggregationResult>;
+
+/// Result of an aggregation on the relations of a single record.
+#[derive(Debug, Clone)]
+pub enum RelAggregationResult {
+    Count(RelationFieldRef, PrismaValue),
+}
+
+/// A relation read in the same query as the records of its parent model, with the
+/// related records returned as part of their parent `JoinedRecord`.
+#[derive(Debug, Clone)]
+pub struct RelationSelection {
+    /// The relation field on the parent model.
+    pub field: RelationFieldRef,
+    pub args: QueryArguments,
+    pub selected_fields: ModelProjection,
+    pub nested: Vec<RelationSelection>,
+}
+
+/// A record read together with its related records.
+#[derive(Debug, Clone)]
+pub struct JoinedRecord {
+    /// The values of the selected fields, in the order of their scalar fields.
+    pub values: Vec<PrismaValue>,
+
+    /// The related records of each relation selection, in the order of the selections.
+    pub relations: Vec<Vec<JoinedRecord>>,
+}
+
 #[async_trait]
 pub trait ReadOperations {
     /// Gets a single record or `None` back from the database.
@@ -167,11 +226,14 @@ pub trait ReadOperations {
     /// - The `Filter` defines what item we want back and is guaranteed to be
     ///   defined to filter at most one item by the core.
     /// - The `SelectedFields` defines the values to be returned.
+    /// - The `RelAggregationSelection`s are returned as additional values after
+    ///   the selected fields, named by their `db_alias`.
     async fn get_single_record(
         &self,
         model: &ModelRef,
         filter: &Filter,
         selected_fields: &ModelProjection,
+        aggregation_selections: &[RelAggregationSelection],
     ) -> crate::Result<Option<SingleRecord>>;
 
     /// Gets multiple records from the database.
@@ -180,13 +242,61 @@ pub trait ReadOperations {
     /// - The `QueryArguments` defines various constraints (see docs for detailed explanation).
     /// - The `SelectedFields` defines the fields (e.g. columns or document fields)
     ///   to be returned as a projection of fields of the model it queries.
+    /// - The `RelAggregationSelection`s are returned as additional values after
+    ///   the selected fields, named by their `db_alias`.
     async fn get_many_records(
         &self,
         model: &ModelRef,
         query_arguments: QueryArguments,
         selected_fields: &ModelProjection,
+        aggregation_selections: &[RelAggregationSelection],
     ) -> crate::Result<ManyRecords>;
 
+    /// Gets the records of a to-many relation for many parents at once, with the pagination
+    /// (`take` and `skip`) of the `QueryArguments` applied per parent instead of across all records.
+    ///
+    /// - The `partition_by` fields are the fields of the model linking each record to its parent.
+    /// - Returns `None` if the connector can't paginate per parent on the database level,
+    ///   in which case all records have to be fetched and paginated in memory.
+    async fn get_many_records_per_parent(
+        &self,
+        model: &ModelRef,
+        query_arguments: QueryArguments,
+        partition_by: &ModelProjection,
+        selected_fields: &ModelProjection,
+        aggregation_selections: &[RelAggregationSelection],
+    ) -> crate::Result<Option<ManyRecords>>;
+
+    /// Gets the records of the many-to-many relation `from_field` for many parents at once,
+    /// with the pagination of the `QueryArguments` applied per parent, like `get_many_records_per_parent`.
+    ///
+    /// - Every returned record has the id of its parent set. Records related to several
+    ///   of the parents are returned once for each of them.
+    /// - Returns `None` if the connector can't paginate per parent on the database level.
+    async fn get_related_m2m_records_per_parent(
+        &self,
+        from_field: &RelationFieldRef,
+        from_record_ids: &[RecordProjection],
+        query_arguments: QueryArguments,
+        selected_fields: &ModelProjection,
+        aggregation_selections: &[RelAggregationSelection],
+    ) -> crate::Result<Option<ManyRecords>>;
+
+    /// Gets multiple records together with the records of the given relations, recursively,
+    /// in a single query instead of one query per relation.
+    ///
+    /// - The `QueryArguments` of the records and of each `RelationSelection` are applied
+    ///   the same way as for `get_many_records`, per parent record for the relations.
+    /// - Returns `None` if the connector can't read relations as part of their parent query,
+    ///   in which case they have to be read separately.
+    async fn get_many_records_with_relations(
+        &self,
+        model: &ModelRef,
+        query_arguments: QueryArguments,
+        selected_fields: &ModelProjection,
+        relation_selections: &[RelationSelection],
+    ) -> crate::Result<Option<Vec<JoinedRecord>>>;
+
     /// Retrieves pairs of IDs that belong together from a intermediate join
     /// table.
     ///
@@ -227,6 +337,20 @@ pub trait WriteOperations {
         skip_duplicates: bool,
     ) -> crate::Result<usize>;
 
+    /// Inserts many records at once into the database, returning the identifiers of the created records.
+    async fn create_records_returning(
+        &self,
+        model: &ModelRef,
+        args: Vec<WriteArgs>,
+        skip_duplicates: bool,
+    ) -> crate::Result<Vec<RecordProjection>>;
+
+    /// Inserts many records at once into the database, updating the `update_fields` of the existing records
+    /// with the same primary identifier instead. Returns the number of affected rows, existing records without
+    /// fields to update don't count. Connectors that update records conflicting on any unique criterion reject
+    /// models with unique criteria besides the primary identifier.
+    async fn upsert_records(&self, model: &ModelRef, args: Vec<UpsertArgs>) -> crate::Result<usize>;
+
     /// Update records in the `Model` with the given `WriteArgs` filtered by the
     /// `Filter`.
     async fn update_records(
@@ -257,6 +381,13 @@ pub trait WriteOperations {
         child_ids: &[RecordProjection],
     ) -> crate::Result<()>;
 
+    /// Disconnect the parents from all their children of the many-to-many relation `field`.
+    async fn delete_relation_records(
+        &self,
+        field: &RelationFieldRef,
+        parent_ids: &[RecordProjection],
+    ) -> crate::Result<()>;
+
     /// Execute the raw query in the database as-is. The `parameters` are
     /// parameterized values for databases that support prepared statements.
     ///
@@ -12,6 +12,8 @@ pub struct SkipAndLimit {
 /// - `filter` scopes the data by defining conditions (akin to `WHERE` in SQL).
 /// - `order_by` defines the ordering of records, from most high to low precedence.
 /// - `distinct` designates the fields on which the records should be distinct.
+/// - `native_distinct` tells the connector to apply `distinct` itself instead of the core doing it in-memory.
+/// - `lock` locks the read records for the remainder of the transaction (e.g. `FOR UPDATE`).
 /// - The `ignore_*` flags are a temporary bandaid to tell the connector to do not
 ///   include certain constraints when building queries, because the core is already
 ///   performing these action in a different manner (e.g. in-memory on all records).
@@ -28,10 +30,30 @@ pub struct QueryArguments {
     pub filter: Option<Filter>,
     pub order_by: Vec<OrderBy>,
     pub distinct: Option<ModelProjection>,
+    pub native_distinct: bool,
+    pub lock: Option<RowLock>,
     pub ignore_skip: bool,
     pub ignore_take: bool,
 }
 
+/// A lock on the rows of a read, held until the end of the transaction.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RowLock {
+    pub mode: LockMode,
+    /// Skips rows locked by other transactions instead of waiting for them.
+    pub skip_locked: bool,
+    /// Fails instead of waiting for rows locked by other transactions.
+    pub no_wait: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LockMode {
+    /// Exclusive lock for rows that are going to be updated, e.g. `FOR UPDATE`.
+    Update,
+    /// Shared lock preventing updates of the rows by other transactions, e.g. `FOR SHARE`.
+    Share,
+}
+
 impl QueryArguments {
     pub fn new(model: ModelRef) -> Self {
         Self {
@@ -42,6 +64,8 @@ impl QueryArguments {
             filter: None,
             order_by: vec![],
             distinct: None,
+            native_distinct: false,
+            lock: None,
             ignore_take: false,
             ignore_skip: false,
         }
@@ -61,7 +85,15 @@ impl QueryArguments {
     /// retrieved by the connector or if it requires the query engine to fetch a raw set
     /// of records and perform certain operations itself, in-memory.
     pub fn requires_inmemory_processing(&self) -> bool {
-        self.distinct.is_some() || self.contains_unstable_cursor() || self.contains_null_cursor()
+        (self.distinct.is_some() && !self.has_native_distinct())
+            || self.contains_unstable_cursor()
+            || self.contains_null_cursor()
+    }
+
+    /// Native distinct deduplicates records before paginating them in the query ordering, which only matches the
+    /// in-memory processing if the ordering is not reversed by a negative `take`.
+    pub fn has_native_distinct(&self) -> bool {
+        self.native_distinct && self.distinct.is_some() && !matches!(self.take, Some(take) if take < 0)
     }
 
     /// An unstable cursor is a cursor that is used in conjunction with an unstable (non-unique) combination of orderBys.
@@ -71,7 +103,12 @@ impl QueryArguments {
 
     /// A null cursor is a cursor that is used in conjunction with a nullable order by (i.e. a field is optional).
     pub fn contains_null_cursor(&self) -> bool {
-        self.cursor.is_some() && self.order_by.iter().any(|o| !o.field.is_required)
+        self.cursor.is_some()
+            && self
+                .order_by
+                .iter()
+                .filter_map(|o| o.as_scalar())
+                .any(|o| !o.field.is_required)
     }
 
     /// Checks if the orderBy provided is guaranteeing a stable ordering of records for the model. Assumes that `model`
@@ -79,10 +116,15 @@ impl QueryArguments {
     /// `true` if at least one unique field is present, or contains a combination of fields that is marked as unique.
     /// `false` otherwise.
     pub fn is_stable_ordering(&self) -> bool {
-        let order_fields: Vec<_> = self.order_by.iter().map(|o| &o.field).collect();
+        let order_fields: Vec<_> = self
+            .order_by
+            .iter()
+            .filter_map(|o| o.as_scalar())
+            .map(|o| &o.field)
+            .collect();
 
-        !self.order_by.is_empty()
-            && (self.order_by.iter().any(|o| o.field.unique())
+        !order_fields.is_empty()
+            && (order_fields.iter().any(|f| f.unique())
                 || self
                     .model
                     .unique_indexes()
@@ -100,6 +142,13 @@ impl QueryArguments {
             .map(|filter| filter.should_batch())
             .unwrap_or(false)
             && self.cursor.is_none()
+            && !self.has_aggregation_ordering()
+            && !self.has_native_distinct()
+    }
+
+    /// Aggregation orderings are computed over all related records and can't be split across batches.
+    pub fn has_aggregation_ordering(&self) -> bool {
+        self.order_by.iter().any(|o| matches!(o, OrderBy::Aggregation(_)))
     }
 
     pub fn batched(self) -> Vec<Self> {
@@ -111,6 +160,8 @@ impl QueryArguments {
                 let skip = self.skip;
                 let order_by = self.order_by;
                 let distinct = self.distinct;
+                let native_distinct = self.native_distinct;
+                let lock = self.lock;
                 let ignore_skip = self.ignore_skip;
                 let ignore_take = self.ignore_take;
 
@@ -125,6 +176,8 @@ impl QueryArguments {
                         filter: Some(filter),
                         order_by: order_by.clone(),
                         distinct: distinct.clone(),
+                        native_distinct,
+                        lock,
                         ignore_skip,
                         ignore_take,
                     })
@@ -14,6 +14,14 @@ pub struct WriteArgs {
     pub args: HashMap<DatasourceFieldName, WriteExpression>,
 }
 
+/// A record to upsert: the values to insert if no record with the same primary identifier exists, and the
+/// fields of an existing record to update with them instead. Defaults are inserted, but never update a record.
+#[derive(Debug, PartialEq, Clone)]
+pub struct UpsertArgs {
+    pub args: WriteArgs,
+    pub update_fields: Vec<DatasourceFieldName>,
+}
+
 /// Wrapper struct to force a bit of a reflection whether or not the string passed
 /// to the write arguments is the data source field name, not the model field name.
 /// Also helps to avoid errors with convenient from-field conversions.
@@ -61,6 +69,35 @@ pub enum WriteExpression {
 
     /// Divide field by value.
     Divide(PrismaValue),
+
+    /// Write `NULL` to the field.
+    Unset,
+
+    /// Append a list of values to a scalar list field.
+    Push(PrismaValue),
+
+    /// Remove all occurrences of a list of values from a scalar list field.
+    Remove(PrismaValue),
+
+    /// Merge a Json object into the Json value of the field, replacing its top-level keys.
+    JsonMerge(PrismaValue),
+
+    /// Set the value at the path inside the Json value of the field. Only the last key of the path is
+    /// created if it's missing, the value of the field is left unchanged if any other key is missing.
+    JsonSet { path: Vec<String>, value: PrismaValue },
+}
+
+impl WriteExpression {
+    /// Whether the expression operates on a scalar list or Json value instead of a plain scalar.
+    pub fn is_list_or_json_operation(&self) -> bool {
+        matches!(
+            self,
+            WriteExpression::Push(_)
+                | WriteExpression::Remove(_)
+                | WriteExpression::JsonMerge(_)
+                | WriteExpression::JsonSet { .. }
+        )
+    }
 }
 
 impl From<PrismaValue> for WriteExpression {
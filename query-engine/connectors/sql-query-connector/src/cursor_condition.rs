@@ -1,10 +1,22 @@
-use crate::query_arguments_ext::QueryArgumentsExt;
+use crate::{ordering, query_arguments_ext::QueryArgumentsExt};
 use connector_interface::QueryArguments;
 use prisma_models::*;
 use quaint::ast::*;
 
 static ORDER_TABLE_ALIAS: &str = "order_cmp";
 
+struct CursorOrderDefinition {
+    /// The expression ordered by, e.g. `TestModel.fieldA` or `COALESCE(orderby_aggr_0.orderby_aggregator, 0)`.
+    order_expr: Expression<'static>,
+    /// The column of the comparison subquery holding the value of `order_expr` for the cursor row.
+    cmp_column: Column<'static>,
+    /// The joins `order_expr` depends on.
+    joins: Vec<JoinData<'static>>,
+    sort_order: SortOrder,
+    /// Whether `order_expr` can be null, e.g. for optional fields or fields of optional related records.
+    nullable: bool,
+}
+
 /// Builds a cursor query condition based on the cursor arguments and if necessary a table that the condition depends on.
 ///
 /// An example query for 4 order-by fields is:
@@ -12,29 +24,29 @@ static ORDER_TABLE_ALIAS: &str = "order_cmp";
 /// SELECT
 ///   `TestModel`.`id`
 /// FROM
-///   `TestModel`,
+///   `TestModel`
 ///   -- >>> Begin Part #1
-///   (
+///   INNER JOIN (
 ///       SELECT
-///           `TestModel`.`fieldA`,
-///           `TestModel`.`fieldB`,
-///           `TestModel`.`fieldC`,
-///           `TestModel`.`fieldD`
+///           `TestModel`.`fieldA` AS `order_cmp_0`,
+///           `TestModel`.`fieldB` AS `order_cmp_1`,
+///           `TestModel`.`fieldC` AS `order_cmp_2`,
+///           `TestModel`.`fieldD` AS `order_cmp_3`
 ///       FROM
 ///           `TestModel`
 ///       WHERE
 ///           (`TestModel`.`id`) = (4)
-///   ) AS `order_cmp`
+///   ) AS `order_cmp` ON 1=1
 ///   -- <<< End Part #1
 /// WHERE
 ///   -- >>> Begin Part #2
-///   (`TestModel`.`fieldA` = `order_cmp`.`fieldA` AND `TestModel`.`fieldB` = `order_cmp`.`fieldB` AND `TestModel`.`fieldC` = `order_cmp`.`fieldC` AND `TestModel`.`fieldD` <= `order_cmp`.`fieldD`)
+///   (`TestModel`.`fieldA` = `order_cmp`.`order_cmp_0` AND `TestModel`.`fieldB` = `order_cmp`.`order_cmp_1` AND `TestModel`.`fieldC` = `order_cmp`.`order_cmp_2` AND `TestModel`.`fieldD` <= `order_cmp`.`order_cmp_3`)
 ///   OR
-///   (`TestModel`.`fieldA` = `order_cmp`.`fieldA` AND `TestModel`.`fieldB` = `order_cmp`.`fieldB` AND `TestModel`.`fieldC` > `order_cmp`.`fieldC`)
+///   (`TestModel`.`fieldA` = `order_cmp`.`order_cmp_0` AND `TestModel`.`fieldB` = `order_cmp`.`order_cmp_1` AND `TestModel`.`fieldC` > `order_cmp`.`order_cmp_2`)
 ///   OR
-///   (`TestModel`.`fieldA` = `order_cmp`.`fieldA` AND `TestModel`.`fieldB` > `order_cmp`.`fieldB`)
+///   (`TestModel`.`fieldA` = `order_cmp`.`order_cmp_0` AND `TestModel`.`fieldB` > `order_cmp`.`order_cmp_1`)
 ///   OR
-///   (`TestModel`.`fieldA` < `order_cmp`.`fieldA`)
+///   (`TestModel`.`fieldA` < `order_cmp`.`order_cmp_0`)
 ///   -- <<< End Part #2
 /// ORDER BY
 ///   `TestModel`.`fieldA` DESC,
@@ -48,20 +60,24 @@ static ORDER_TABLE_ALIAS: &str = "order_cmp";
 ///   -- ... The first (4 - condition) block:
 ///   (
 ///     (
-///       `TestModel`.`fieldA` = `order_cmp`.`fieldA`
-///       OR `order_cmp`.`fieldA` IS NULL
+///       `TestModel`.`fieldA` = `order_cmp`.`order_cmp_0`
+///       OR `order_cmp`.`order_cmp_0` IS NULL
 ///       OR `TestModel`.`fieldA` IS NULL
 ///     )
 ///     AND -- ...
 ///   )
 ///   -- ...The other blocks (3, 2) in between, then the single condition block:
 ///   OR (
-///     `TestModel`.`fieldA` < `order_cmp`.`fieldA`
-///     OR `order_cmp`.`fieldA` IS NULL
+///     `TestModel`.`fieldA` < `order_cmp`.`order_cmp_0`
+///     OR `order_cmp`.`order_cmp_0` IS NULL
 ///     OR `TestModel`.`fieldA` IS NULL
 ///   )
 ///   -- ...
 /// ```
+///
+/// Orderings through relations order by expressions over the ordering joins instead of plain columns, e.g.
+/// `COALESCE(orderby_aggr_0.orderby_aggregator, 0)` for the count of a to-many relation. The subquery then
+/// uses the same joins as the main query to compute the values of the cursor row.
 pub fn build(query_arguments: &QueryArguments, model: &ModelRef) -> (Option<Table<'static>>, ConditionTree<'static>) {
     match query_arguments.cursor {
         None => (None, ConditionTree::NoCondition),
@@ -83,9 +99,14 @@ pub fn build(query_arguments: &QueryArguments, model: &ModelRef) -> (Option<Tabl
             // Subquery to find the value of the order field(s) that we need for comparison. Builds part #1 of the query example in the docs.
             let order_subquery = order_definitions
                 .iter()
-                .fold(Select::from_table(model.as_table()), |select, (field, _)| {
-                    select.column(field.as_column())
-                })
+                .fold(Select::from_table(model.as_table()), |acc, def| {
+                    acc.value(def.order_expr.clone().alias(def.cmp_column.name.clone()))
+                });
+
+            let order_subquery = order_definitions
+                .iter()
+                .flat_map(|definition| definition.joins.clone())
+                .fold(order_subquery, |select, join| select.left_join(join))
                 .so_that(cursor_condition);
 
             let subquery_table = Table::from(order_subquery).alias(ORDER_TABLE_ALIAS);
@@ -95,15 +116,15 @@ pub fn build(query_arguments: &QueryArguments, model: &ModelRef) -> (Option<Tabl
             // Builds part #2 of the example query.
             // If we only have one ordering, we only want a single, slightly different, condition of (orderField [<= / >=] cmp_field).
             let condition_tree = if len == 1 {
-                let (field, order) = order_definitions.pop().unwrap();
-                ConditionTree::Single(Box::new(map_orderby_condition(&field, &order, reverse, true)))
+                let definition = order_definitions.pop().unwrap();
+                ConditionTree::Single(Box::new(map_orderby_condition(&definition, reverse, true)))
             } else {
                 let or_conditions = (0..len).fold(Vec::with_capacity(len), |mut conditions_acc, n| {
                     let (head, tail) = order_definitions.split_at(len - n - 1);
                     let mut and_conditions = Vec::with_capacity(head.len() + 1);
 
-                    for (field, _) in head {
-                        and_conditions.push(map_equality_condition(field));
+                    for definition in head {
+                        and_conditions.push(map_equality_condition(definition));
                     }
 
                     if head.len() == len - 1 {
@@ -131,12 +152,12 @@ pub fn build(query_arguments: &QueryArguments, model: &ModelRef) -> (Option<Tabl
                         //
                         // Said differently, we handle all the cases in which the prefixes are equal to len - 1 to account for possible identical comparators,
                         // but everything else must come strictly "after" the cursor.
-                        let (field, order) = tail.first().unwrap();
+                        let definition = tail.first().unwrap();
 
-                        and_conditions.push(map_orderby_condition(field, order, reverse, true));
+                        and_conditions.push(map_orderby_condition(definition, reverse, true));
                     } else {
-                        let (field, order) = tail.first().unwrap();
-                        and_conditions.push(map_orderby_condition(field, order, reverse, false));
+                        let definition = tail.first().unwrap();
+                        and_conditions.push(map_orderby_condition(definition, reverse, false));
                     }
 
                     conditions_acc.push(ConditionTree::And(and_conditions));
@@ -153,16 +174,11 @@ pub fn build(query_arguments: &QueryArguments, model: &ModelRef) -> (Option<Tabl
 
 // A negative `take` value signifies that values should be taken before the cursor,
 // requiring the correct comarison operator to be used to fit the reversed order.
-fn map_orderby_condition(
-    field: &ScalarFieldRef,
-    order: &SortOrder,
-    reverse: bool,
-    include_eq: bool,
-) -> Expression<'static> {
-    let order_column = field.as_column();
-    let cmp_column = Column::from((ORDER_TABLE_ALIAS, field.db_name().to_owned()));
-
-    let order_expr: Expression<'static> = match order {
+fn map_orderby_condition(definition: &CursorOrderDefinition, reverse: bool, include_eq: bool) -> Expression<'static> {
+    let order_column = definition.order_expr.clone();
+    let cmp_column = definition.cmp_column.clone();
+
+    let order_expr: Expression<'static> = match definition.sort_order {
         // If it's ASC but we want to take from the back, the ORDER BY will be DESC, meaning that comparisons done need to be lt(e).
         SortOrder::Ascending if reverse => {
             if include_eq {
@@ -201,23 +217,23 @@ fn map_orderby_condition(
 
     // If we have null values in the ordering or comparison row, those are automatically included because we can't make a
     // statement over their order relative to the cursor.
-    if !field.is_required {
+    if definition.nullable {
         order_expr
-            .or(field.as_column().is_null())
-            .or(Column::from((ORDER_TABLE_ALIAS, field.db_name().to_owned())).is_null())
+            .or(definition.order_expr.clone().is_null())
+            .or(definition.cmp_column.clone().is_null())
             .into()
     } else {
         order_expr
     }
 }
 
-fn map_equality_condition(field: &ScalarFieldRef) -> Expression<'static> {
-    let order_column = field.as_column();
-    let cmp_column = Column::from((ORDER_TABLE_ALIAS, field.db_name().to_owned()));
+fn map_equality_condition(definition: &CursorOrderDefinition) -> Expression<'static> {
+    let order_column = definition.order_expr.clone();
+    let cmp_column = definition.cmp_column.clone();
 
     // If we have null values in the ordering or comparison row, those are automatically included because we can't make a
     // statement over their order relative to the cursor.
-    if !field.is_required {
+    if definition.nullable {
         order_column
             .clone()
             .equals(cmp_column.clone())
@@ -229,20 +245,53 @@ fn map_equality_condition(field: &ScalarFieldRef) -> Expression<'static> {
     }
 }
 
-fn order_definitions(query_arguments: &QueryArguments, model: &ModelRef) -> Vec<(ScalarFieldRef, SortOrder)> {
+/// Relevance orderings are skipped: their values are only known for a given search query and can't be
+/// compared against the cursor row.
+fn order_definitions(query_arguments: &QueryArguments, model: &ModelRef) -> Vec<CursorOrderDefinition> {
+    // The index of the ordering determines the aliases of its joins, which must match the ones of the main query.
     let defined_ordering: Vec<_> = query_arguments
         .order_by
         .iter()
-        .map(|o| (o.field.clone(), o.sort_order))
+        .enumerate()
+        .filter_map(|(index, order_by)| {
+            let nullable = match order_by {
+                OrderBy::Scalar(o) => !o.field.is_required || !o.path.is_empty(),
+                OrderBy::Aggregation(o) => o.is_nullable(),
+                OrderBy::Relevance(_) => return None,
+            };
+
+            let (order_expr, joins) = ordering::order_expression(order_by, index, model);
+
+            Some((order_expr, joins, order_by.sort_order(), nullable))
+        })
         .collect();
 
-    if defined_ordering.is_empty() {
+    let defined_ordering = if defined_ordering.is_empty() {
         model
             .primary_identifier()
             .scalar_fields()
-            .map(|f| (f, SortOrder::Ascending))
+            .map(|f| {
+                (
+                    Expression::from(f.as_column()),
+                    vec![],
+                    SortOrder::Ascending,
+                    !f.is_required,
+                )
+            })
             .collect()
     } else {
         defined_ordering
-    }
+    };
+
+    defined_ordering
+        .into_iter()
+        .enumerate()
+        .map(|(i, (order_expr, joins, sort_order, nullable))| CursorOrderDefinition {
+            order_expr,
+            cmp_column: Column::from((ORDER_TABLE_ALIAS, format!("{}_{}", ORDER_TABLE_ALIAS, i))),
+            joins,
+            sort_order,
+            nullable,
+        })
+        .collect()
 }
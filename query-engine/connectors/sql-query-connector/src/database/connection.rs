@@ -2,8 +2,9 @@ use super::transaction::SqlConnectorTransaction;
 use crate::{database::operations::*, sql_info::SqlInfo, QueryExt, SqlError};
 use async_trait::async_trait;
 use connector_interface::{
-    self as connector, filter::Filter, AggregationRow, AggregationSelection, Connection, QueryArguments,
-    ReadOperations, RecordFilter, Transaction, WriteArgs, WriteOperations,
+    self as connector, filter::Filter, AggregationRow, AggregationSelection, Connection, JoinedRecord, QueryArguments,
+    ReadOperations, RecordFilter, RelAggregationSelection, RelationSelection, Transaction, UpsertArgs, WriteArgs,
+    WriteOperations,
 };
 use prisma_models::prelude::*;
 use prisma_value::PrismaValue;
@@ -13,15 +14,21 @@ use std::future::Future;
 pub struct SqlConnection<C> {
     inner: C,
     connection_info: ConnectionInfo,
+    sql_info: SqlInfo,
 }
 
 impl<C> SqlConnection<C>
 where
     C: QueryExt + Send + Sync + 'static,
 {
-    pub fn new(inner: C, connection_info: &ConnectionInfo) -> Self {
+    pub fn new(inner: C, connection_info: &ConnectionInfo, sql_info: SqlInfo) -> Self {
         let connection_info = connection_info.clone();
-        Self { inner, connection_info }
+
+        Self {
+            inner,
+            connection_info,
+            sql_info,
+        }
     }
 
     async fn catch<O>(
@@ -43,10 +50,11 @@ where
     async fn start_transaction<'a>(&'a self) -> connector::Result<Box<dyn Transaction + 'a>> {
         let fut_tx = self.inner.start_transaction();
         let connection_info = &self.connection_info;
+        let sql_info = self.sql_info;
 
         self.catch(async move {
             let tx: quaint::connector::Transaction = fut_tx.await.map_err(SqlError::from)?;
-            Ok(Box::new(SqlConnectorTransaction::new(tx, &connection_info)) as Box<dyn Transaction>)
+            Ok(Box::new(SqlConnectorTransaction::new(tx, &connection_info, sql_info)) as Box<dyn Transaction>)
         })
         .await
     }
@@ -62,9 +70,12 @@ where
         model: &ModelRef,
         filter: &Filter,
         selected_fields: &ModelProjection,
+        aggregation_selections: &[RelAggregationSelection],
     ) -> connector::Result<Option<SingleRecord>> {
-        self.catch(async move { read::get_single_record(&self.inner, model, filter, selected_fields).await })
-            .await
+        self.catch(async move {
+            read::get_single_record(&self.inner, model, filter, selected_fields, aggregation_selections).await
+        })
+        .await
     }
 
     async fn get_many_records(
@@ -72,9 +83,102 @@ where
         model: &ModelRef,
         query_arguments: QueryArguments,
         selected_fields: &ModelProjection,
+        aggregation_selections: &[RelAggregationSelection],
     ) -> connector::Result<ManyRecords> {
-        self.catch(async move { read::get_many_records(&self.inner, model, query_arguments, selected_fields).await })
+        self.catch(async move {
+            read::get_many_records(
+                &self.inner,
+                &self.sql_info,
+                model,
+                query_arguments,
+                selected_fields,
+                aggregation_selections,
+            )
+            .await
+        })
+        .await
+    }
+
+    async fn get_many_records_per_parent(
+        &self,
+        model: &ModelRef,
+        query_arguments: QueryArguments,
+        partition_by: &ModelProjection,
+        selected_fields: &ModelProjection,
+        aggregation_selections: &[RelAggregationSelection],
+    ) -> connector::Result<Option<ManyRecords>> {
+        if !self.sql_info.supports_window_functions {
+            return Ok(None);
+        }
+
+        self.catch(async move {
+            read::get_many_records_per_parent(
+                &self.inner,
+                model,
+                query_arguments,
+                partition_by,
+                selected_fields,
+                aggregation_selections,
+            )
             .await
+            .map(Some)
+        })
+        .await
+    }
+
+    async fn get_many_records_with_relations(
+        &self,
+        model: &ModelRef,
+        query_arguments: QueryArguments,
+        selected_fields: &ModelProjection,
+        relation_selections: &[RelationSelection],
+    ) -> connector::Result<Option<Vec<JoinedRecord>>> {
+        let sql_info = self.sql_info;
+
+        if !sql_info.supports_relation_joins {
+            return Ok(None);
+        }
+
+        self.catch(async move {
+            read::get_many_records_with_relations(
+                &self.inner,
+                &sql_info.family,
+                model,
+                query_arguments,
+                selected_fields,
+                relation_selections,
+            )
+            .await
+            .map(Some)
+        })
+        .await
+    }
+
+    async fn get_related_m2m_records_per_parent(
+        &self,
+        from_field: &RelationFieldRef,
+        from_record_ids: &[RecordProjection],
+        query_arguments: QueryArguments,
+        selected_fields: &ModelProjection,
+        aggregation_selections: &[RelAggregationSelection],
+    ) -> connector::Result<Option<ManyRecords>> {
+        if !self.sql_info.supports_window_functions {
+            return Ok(None);
+        }
+
+        self.catch(async move {
+            read::get_related_m2m_records_per_parent(
+                &self.inner,
+                from_field,
+                from_record_ids,
+                query_arguments,
+                selected_fields,
+                aggregation_selections,
+            )
+            .await
+            .map(Some)
+        })
+        .await
     }
 
     async fn get_related_m2m_record_ids(
@@ -117,26 +221,34 @@ where
         args: Vec<WriteArgs>,
         skip_duplicates: bool,
     ) -> connector::Result<usize> {
-        self.catch(async move {
-            write::create_records(
-                &self.inner,
-                SqlInfo::from(&self.connection_info),
-                model,
-                args,
-                skip_duplicates,
-            )
+        self.catch(async move { write::create_records(&self.inner, self.sql_info, model, args, skip_duplicates).await })
             .await
+    }
+
+    async fn create_records_returning(
+        &self,
+        model: &ModelRef,
+        args: Vec<WriteArgs>,
+        skip_duplicates: bool,
+    ) -> connector::Result<Vec<RecordProjection>> {
+        self.catch(async move {
+            write::create_records_returning(&self.inner, self.sql_info, model, args, skip_duplicates).await
         })
         .await
     }
 
+    async fn upsert_records(&self, model: &ModelRef, args: Vec<UpsertArgs>) -> connector::Result<usize> {
+        self.catch(async move { write::upsert_records(&self.inner, self.sql_info, model, args).await })
+            .await
+    }
+
     async fn update_records(
         &self,
         model: &ModelRef,
         record_filter: RecordFilter,
         args: WriteArgs,
     ) -> connector::Result<Vec<RecordProjection>> {
-        self.catch(async move { write::update_records(&self.inner, model, record_filter, args).await })
+        self.catch(async move { write::update_records(&self.inner, self.sql_info, model, record_filter, args).await })
             .await
     }
 
@@ -165,6 +277,15 @@ where
             .await
     }
 
+    async fn delete_relation_records(
+        &self,
+        field: &RelationFieldRef,
+        parent_ids: &[RecordProjection],
+    ) -> connector::Result<()> {
+        self.catch(async move { write::delete_relation_records(&self.inner, field, parent_ids).await })
+            .await
+    }
+
     async fn execute_raw(&self, query: String, parameters: Vec<PrismaValue>) -> connector::Result<usize> {
         self.catch(async move { write::execute_raw(&self.inner, query, parameters).await })
             .await
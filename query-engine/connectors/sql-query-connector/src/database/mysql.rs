@@ -1,5 +1,5 @@
 use super::connection::SqlConnection;
-use crate::{FromSource, SqlError};
+use crate::{sql_info::SqlInfo, FromSource, SqlError};
 use async_trait::async_trait;
 use connector_interface::{
     self as connector,
@@ -7,12 +7,31 @@ use connector_interface::{
     Connection, Connector,
 };
 use datamodel::Datasource;
-use quaint::{pooled::Quaint, prelude::ConnectionInfo};
-use std::time::Duration;
+use quaint::{connector::Queryable, pooled::Quaint, prelude::ConnectionInfo};
+use std::{sync::Mutex, time::Duration};
 
 pub struct Mysql {
     pool: Quaint,
     connection_info: ConnectionInfo,
+    /// Depends on the server version, read from the first connection.
+    sql_info: Mutex<Option<SqlInfo>>,
+}
+
+impl Mysql {
+    async fn sql_info(&self, conn: &dyn Queryable) -> crate::Result<SqlInfo> {
+        let cached = *self.sql_info.lock().unwrap();
+
+        if let Some(sql_info) = cached {
+            return Ok(sql_info);
+        }
+
+        let version = conn.version().await?;
+        let sql_info = SqlInfo::mysql_with_version(version.as_deref());
+
+        *self.sql_info.lock().unwrap() = Some(sql_info);
+
+        Ok(sql_info)
+    }
 }
 
 #[async_trait]
@@ -38,7 +57,11 @@ impl FromSource for Mysql {
         let pool = builder.build();
         let connection_info = pool.connection_info().to_owned();
 
-        Ok(Mysql { pool, connection_info })
+        Ok(Mysql {
+            pool,
+            connection_info,
+            sql_info: Mutex::new(None),
+        })
     }
 }
 
@@ -47,7 +70,8 @@ impl Connector for Mysql {
     async fn get_connection<'a>(&'a self) -> connector::Result<Box<dyn Connection + Send + Sync + 'static>> {
         super::catch(&self.connection_info, async move {
             let conn = self.pool.check_out().await.map_err(SqlError::from)?;
-            let conn = SqlConnection::new(conn, &self.connection_info);
+            let sql_info = self.sql_info(&conn).await?;
+            let conn = SqlConnection::new(conn, &self.connection_info, sql_info);
 
             Ok(Box::new(conn) as Box<dyn Connection + Send + Sync + 'static>)
         })
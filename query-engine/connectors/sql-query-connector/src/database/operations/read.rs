@@ -1,24 +1,27 @@
 use crate::{
-    column_metadata,
+    column_metadata, joined_record,
     query_arguments_ext::QueryArgumentsExt,
-    query_builder::{self, read},
-    QueryExt, SqlError,
+    query_builder::{self, read, relation_joins},
+    sql_info::{SqlFamily, SqlInfo},
+    ColumnMetadata, QueryExt, SqlError, SqlRow, ToSqlRow,
 };
 use connector_interface::*;
+use datamodel::FieldArity;
 use futures::stream::{FuturesUnordered, StreamExt};
 use prisma_models::*;
 use quaint::ast::*;
+use std::collections::HashSet;
 
 pub async fn get_single_record(
     conn: &dyn QueryExt,
     model: &ModelRef,
     filter: &Filter,
     selected_fields: &ModelProjection,
+    aggregation_selections: &[RelAggregationSelection],
 ) -> crate::Result<Option<SingleRecord>> {
-    let query = read::get_records(&model, selected_fields.as_columns(), filter);
+    let query = read::get_records(&model, selected_fields.as_columns(), aggregation_selections, filter);
 
-    let field_names: Vec<_> = selected_fields.db_names().collect();
-    let idents = selected_fields.type_identifiers_with_arities();
+    let (field_names, idents) = field_names_and_idents(selected_fields, aggregation_selections);
     let meta = column_metadata::create(field_names.as_slice(), idents.as_slice());
 
     let record = (match conn.find(query, meta.as_slice()).await {
@@ -35,14 +38,17 @@ pub async fn get_single_record(
 
 pub async fn get_many_records(
     conn: &dyn QueryExt,
+    sql_info: &SqlInfo,
     model: &ModelRef,
     mut query_arguments: QueryArguments,
     selected_fields: &ModelProjection,
+    aggregation_selections: &[RelAggregationSelection],
 ) -> crate::Result<ManyRecords> {
+    let family = &sql_info.family;
     let reversed = query_arguments.needs_reversed_order();
+    let lock = query_arguments.lock;
 
-    let field_names: Vec<_> = selected_fields.db_names().collect();
-    let idents = selected_fields.type_identifiers_with_arities();
+    let (field_names, idents) = field_names_and_idents(selected_fields, aggregation_selections);
     let meta = column_metadata::create(field_names.as_slice(), idents.as_slice());
 
     let mut records = ManyRecords::new(field_names.clone());
@@ -51,6 +57,21 @@ pub async fn get_many_records(
         return Ok(records);
     };
 
+    // Without window functions, e.g. on MySQL before version 8, `distinct` is applied to the read records, followed
+    // by the pagination.
+    let distinct_after_read = match query_arguments.distinct {
+        Some(ref distinct) if query_arguments.has_native_distinct() && !sql_info.supports_window_functions => {
+            Some((distinct.clone(), query_arguments.skip, query_arguments.take))
+        }
+        _ => None,
+    };
+
+    if distinct_after_read.is_some() {
+        query_arguments.native_distinct = false;
+        query_arguments.ignore_skip = true;
+        query_arguments.ignore_take = true;
+    }
+
     // Todo: This can't work for all cases. Cursor-based pagination will not work, because it relies on the ordering
     // to determine the right queries to fire, and will default to incorrect orderings if no ordering is found.
     // The should_batch has been adjusted to reflect that as a band-aid, but deeper investigation is necessary.
@@ -62,8 +83,8 @@ pub async fn get_many_records(
         let mut futures = FuturesUnordered::new();
 
         for args in batches.into_iter() {
-            let query = read::get_records(model, selected_fields.as_columns(), args);
-            futures.push(conn.filter(query.into(), meta.as_slice()));
+            let query = read::get_records(model, selected_fields.as_columns(), aggregation_selections, args);
+            futures.push(filter_locked(conn, sql_info, model, query, lock, meta.as_slice()));
         }
 
         while let Some(result) = futures.next().await {
@@ -76,13 +97,254 @@ pub async fn get_many_records(
             records.order_by(&order)
         }
     } else {
-        let query = read::get_records(model, selected_fields.as_columns(), query_arguments);
+        let rows = match family {
+            SqlFamily::Postgres if query_arguments.has_native_distinct() => {
+                let (sql, params) = read::get_distinct_on_records(
+                    family,
+                    model,
+                    selected_fields,
+                    aggregation_selections,
+                    query_arguments,
+                )?;
+
+                conn.query_raw(&sql, &params)
+                    .await?
+                    .into_iter()
+                    .map(|row| row.to_sql_row(meta.as_slice()))
+                    .collect::<crate::Result<Vec<_>>>()?
+            }
+            _ if query_arguments.has_native_distinct() => {
+                let query = read::get_distinct_records(model, selected_fields, aggregation_selections, query_arguments);
+                filter_locked(conn, sql_info, model, query, lock, meta.as_slice()).await?
+            }
+            _ => {
+                let query = read::get_records(
+                    model,
+                    selected_fields.as_columns(),
+                    aggregation_selections,
+                    query_arguments,
+                );
+
+                filter_locked(conn, sql_info, model, query, lock, meta.as_slice()).await?
+            }
+        };
+
+        for item in rows.into_iter() {
+            records.push(Record::from(item))
+        }
+    };
+
+    if let Some((distinct, skip, take)) = distinct_after_read {
+        records = distinct_and_paginate(records, &distinct, skip, take)?;
+    }
+
+    if reversed {
+        records.reverse();
+    }
+
+    Ok(records)
+}
+
+/// Keeps the first record of each set of records sharing the same `distinct` fields, then applies `skip` and `take`.
+fn distinct_and_paginate(
+    mut records: ManyRecords,
+    distinct: &ModelProjection,
+    skip: Option<i64>,
+    take: Option<i64>,
+) -> crate::Result<ManyRecords> {
+    let mut seen = HashSet::new();
+    let mut distinct_records = Vec::with_capacity(records.records.len());
+
+    for record in records.records {
+        if seen.insert(record.projection(&records.field_names, distinct)?) {
+            distinct_records.push(record);
+        }
+    }
+
+    let skip = skip.unwrap_or(0) as usize;
+    let take = take.map(|take| take as usize).unwrap_or(usize::MAX);
+
+    records.records = distinct_records.into_iter().skip(skip).take(take).collect();
+
+    Ok(records)
+}
+
+/// Reads the rows of the select, locking them for the remainder of the transaction if a lock is given.
+async fn filter_locked(
+    conn: &dyn QueryExt,
+    sql_info: &SqlInfo,
+    model: &ModelRef,
+    select: Select<'static>,
+    lock: Option<RowLock>,
+    meta: &[ColumnMetadata<'_>],
+) -> crate::Result<Vec<SqlRow>> {
+    match lock {
+        Some(lock) => {
+            let (sql, params) = read::lock_records(sql_info, model, select, lock)?;
+
+            conn.query_raw(&sql, &params)
+                .await?
+                .into_iter()
+                .map(|row| row.to_sql_row(meta))
+                .collect()
+        }
+        None => conn.filter(select.into(), meta).await,
+    }
+}
+
+/// Reads the records of a to-many relation with the pagination applied per parent, see `read::get_records_per_parent`.
+pub async fn get_many_records_per_parent(
+    conn: &dyn QueryExt,
+    model: &ModelRef,
+    query_arguments: QueryArguments,
+    partition_by: &ModelProjection,
+    selected_fields: &ModelProjection,
+    aggregation_selections: &[RelAggregationSelection],
+) -> crate::Result<ManyRecords> {
+    let reversed = query_arguments.needs_reversed_order();
+
+    let (field_names, idents) = field_names_and_idents(selected_fields, aggregation_selections);
+    let meta = column_metadata::create(field_names.as_slice(), idents.as_slice());
+
+    let mut records = ManyRecords::new(field_names.clone());
+
+    if let Some(0) = query_arguments.take {
+        return Ok(records);
+    };
+
+    // Batching splits the parents, never the records of a single parent, so each batch can be paginated on its own.
+    let batches = if query_arguments.should_batch() {
+        query_arguments.batched()
+    } else {
+        vec![query_arguments]
+    };
+
+    for args in batches {
+        let query = read::get_records_per_parent(model, selected_fields, aggregation_selections, partition_by, args);
 
         for item in conn.filter(query.into(), meta.as_slice()).await?.into_iter() {
             records.push(Record::from(item))
         }
+    }
+
+    if reversed {
+        records.reverse();
+    }
+
+    Ok(records)
+}
+
+/// Reads the records of a many-to-many relation with the pagination applied per parent, each record with the id of
+/// its parent, see `read::get_related_m2m_records_per_parent`.
+pub async fn get_related_m2m_records_per_parent(
+    conn: &dyn QueryExt,
+    from_field: &RelationFieldRef,
+    from_record_ids: &[RecordProjection],
+    query_arguments: QueryArguments,
+    selected_fields: &ModelProjection,
+    aggregation_selections: &[RelAggregationSelection],
+) -> crate::Result<ManyRecords> {
+    let reversed = query_arguments.needs_reversed_order();
+    let parent_model_id = from_field.model().primary_identifier();
+
+    let (field_names, mut idents) = field_names_and_idents(selected_fields, aggregation_selections);
+    idents.extend(parent_model_id.type_identifiers_with_arities());
+
+    let mut column_names = field_names.clone();
+    column_names.extend(parent_model_id.db_names());
+
+    let meta = column_metadata::create(column_names.as_slice(), idents.as_slice());
+    let parent_sfs: Vec<_> = parent_model_id.scalar_fields().collect();
+
+    let mut records = ManyRecords::new(field_names.clone());
+
+    if let Some(0) = query_arguments.take {
+        return Ok(records);
+    };
+
+    let ids: Vec<_> = from_record_ids.iter().collect();
+    let queries = read::get_related_m2m_records_per_parent(
+        from_field,
+        &ids,
+        selected_fields,
+        aggregation_selections,
+        query_arguments,
+    );
+
+    for query in queries {
+        for item in conn.filter(query, meta.as_slice()).await?.into_iter() {
+            let mut record = Record::from(item);
+            let parent_values = record.values.split_off(field_names.len());
+
+            let parent_id: RecordProjection = parent_sfs.iter().cloned().zip(parent_values).collect::<Vec<_>>().into();
+
+            record.parent_id = Some(parent_id);
+            records.push(record);
+        }
+    }
+
+    if reversed {
+        records.reverse();
+    }
+
+    Ok(records)
+}
+
+/// Reads the records together with the related records of the relation selections in a single statement, see
+/// `relation_joins::get_records_with_relations`.
+pub async fn get_many_records_with_relations(
+    conn: &dyn QueryExt,
+    family: &SqlFamily,
+    model: &ModelRef,
+    query_arguments: QueryArguments,
+    selected_fields: &ModelProjection,
+    relation_selections: &[RelationSelection],
+) -> crate::Result<Vec<JoinedRecord>> {
+    if let Some(0) = query_arguments.take {
+        return Ok(Vec::new());
     };
 
+    let reversed = query_arguments.needs_reversed_order();
+    let (sql, params) = relation_joins::get_records_with_relations(
+        family,
+        model,
+        query_arguments,
+        selected_fields,
+        relation_selections,
+    )?;
+
+    // The related records of each relation are returned as one JSON column.
+    let (mut field_names, mut idents) = field_names_and_idents(selected_fields, &[]);
+
+    for selection in relation_selections {
+        field_names.push(selection.field.name.clone());
+        idents.push((TypeIdentifier::Json, FieldArity::Required));
+    }
+
+    let meta = column_metadata::create(field_names.as_slice(), idents.as_slice());
+    let scalar_count = selected_fields.scalar_length();
+    let mut records = Vec::new();
+
+    for row in conn.query_raw(&sql, &params).await? {
+        let mut values = row.to_sql_row(&meta)?.values;
+        let related = values.split_off(scalar_count);
+
+        let relations = related
+            .into_iter()
+            .zip(relation_selections)
+            .map(|(value, selection)| {
+                let json = match value {
+                    PrismaValue::Json(json) => serde_json::from_str(&json)?,
+                    _ => serde_json::Value::Null,
+                };
+
+                joined_record::joined_records(json, selection)
+            })
+            .collect::<crate::Result<Vec<_>>>()?;
+
+        records.push(JoinedRecord { values, relations });
+    }
+
     if reversed {
         records.reverse();
     }
@@ -90,6 +352,22 @@ pub async fn get_many_records(
     Ok(records)
 }
 
+/// The names and types of the returned columns: the selected fields, followed by the relation aggregations.
+fn field_names_and_idents(
+    selected_fields: &ModelProjection,
+    aggregation_selections: &[RelAggregationSelection],
+) -> (Vec<String>, Vec<(TypeIdentifier, FieldArity)>) {
+    let mut field_names: Vec<_> = selected_fields.db_names().collect();
+    let mut idents = selected_fields.type_identifiers_with_arities();
+
+    for selection in aggregation_selections {
+        field_names.push(selection.db_alias());
+        idents.push(selection.type_identifier_with_arity());
+    }
+
+    (field_names, idents)
+}
+
 pub async fn get_related_m2m_record_ids(
     conn: &dyn QueryExt,
     from_field: &RelationFieldRef,
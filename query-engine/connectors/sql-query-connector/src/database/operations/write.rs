@@ -1,4 +1,9 @@
-use crate::{error::SqlError, query_builder::write, sql_info::SqlInfo, QueryExt};
+use crate::{
+    error::SqlError,
+    query_builder::write,
+    sql_info::{SqlFamily, SqlInfo},
+    QueryExt,
+};
 use connector_interface::*;
 use itertools::Itertools;
 use prisma_models::*;
@@ -84,6 +89,104 @@ pub async fn create_records(
         return Ok(0);
     }
 
+    let mut count = 0;
+    for batch in partition_into_batches(args, &sql_info) {
+        let stmt = write::create_records(model, batch, skip_duplicates);
+        count += conn.execute(stmt.into()).await?;
+    }
+
+    Ok(count as usize)
+}
+
+/// Create multiple records like `create_records`, resulting in the identifiers of the created records. Requires
+/// `RETURNING` on Postgres and `OUTPUT` on SQL Server.
+pub async fn create_records_returning(
+    conn: &dyn QueryExt,
+    sql_info: SqlInfo,
+    model: &ModelRef,
+    args: Vec<WriteArgs>,
+    skip_duplicates: bool,
+) -> crate::Result<Vec<RecordProjection>> {
+    let mut ids = Vec::with_capacity(args.len());
+
+    for batch in partition_into_batches(args, &sql_info) {
+        let stmt = write::create_records(model, batch, skip_duplicates);
+        let stmt = stmt.returning(model.primary_identifier().as_columns());
+
+        ids.extend(conn.query_ids(stmt.into(), model.primary_identifier()).await?);
+    }
+
+    Ok(ids)
+}
+
+/// Insert the records, or update the given fields of the existing records with the same primary identifier,
+/// resulting in the number of affected rows. Rows setting or updating different sets of fields are upserted in
+/// separate statements.
+///
+/// MySQL updates the existing record on a conflict with any unique key, models with unique criteria besides the
+/// primary identifier are therefore rejected there.
+pub async fn upsert_records(
+    conn: &dyn QueryExt,
+    sql_info: SqlInfo,
+    model: &ModelRef,
+    args: Vec<UpsertArgs>,
+) -> crate::Result<usize> {
+    if let SqlFamily::MySQL = sql_info.family {
+        let id_fields: Vec<ScalarFieldRef> = model.primary_identifier().scalar_fields().collect();
+
+        let mut unique_criteria = model
+            .fields()
+            .scalar()
+            .into_iter()
+            .filter(|field| field.unique())
+            .map(|field| vec![field])
+            .chain(model.unique_indexes().into_iter().map(|index| index.fields()));
+
+        if unique_criteria.any(|fields| fields != id_fields) {
+            return Err(SqlError::QueryError(
+                format!(
+                    "Model `{}` has unique criteria besides its primary identifier and can't be upserted on MySQL.",
+                    model.name
+                )
+                .into(),
+            ));
+        }
+    }
+
+    let groups = args.into_iter().into_group_map_by(|arg| {
+        let mut keys: Vec<_> = arg.args.keys().cloned().collect();
+        keys.sort_by(|a, b| a.0.cmp(&b.0));
+
+        let mut update_fields = arg.update_fields.clone();
+        update_fields.sort_by(|a, b| a.0.cmp(&b.0));
+
+        (keys, update_fields)
+    });
+
+    let mut count = 0;
+
+    for ((_, update_fields), group) in groups {
+        let group = group.into_iter().map(|arg| arg.args).collect();
+
+        for batch in partition_into_batches(group, &sql_info) {
+            let rows = batch.len() as u64;
+            let (sql, params) = write::upsert_records(&sql_info.family, model, batch, &update_fields);
+            let affected = conn.execute_raw(&sql, &params).await?;
+
+            // MySQL counts updated rows twice. Every row is either inserted or updated if there are fields to
+            // update, otherwise the existing records stay unchanged and only the inserted rows are affected.
+            count += match sql_info.family {
+                SqlFamily::MySQL if !update_fields.is_empty() => rows,
+                _ => affected,
+            };
+        }
+    }
+
+    Ok(count as usize)
+}
+
+/// Splits the rows of a bulk insert into batches within the parameter and row limits of the database.
+fn partition_into_batches(args: Vec<WriteArgs>, sql_info: &SqlInfo) -> Vec<Vec<WriteArgs>> {
     let batches = if let Some(max_params) = sql_info.max_bind_values {
         // We need to split inserts if they are above a parameter threshold, as well as split based on number of rows.
         // -> Horizontal partitioning by row number, vertical by number of args.
@@ -154,13 +257,7 @@ pub async fn create_records(
         partitioned_batches.iter().map(|b| b.len()).collect_vec()
     );
 
-    let mut count = 0;
-    for batch in partitioned_batches {
-        let stmt = write::create_records(model, batch, skip_duplicates);
-        count += conn.execute(stmt.into()).await?;
-    }
-
-    Ok(count as usize)
+    partitioned_batches
 }
 
 /// Update multiple records in a database defined in `conn` and the records
@@ -168,10 +265,36 @@ pub async fn create_records(
 /// operation.
 pub async fn update_records(
     conn: &dyn QueryExt,
+    sql_info: SqlInfo,
     model: &ModelRef,
     record_filter: RecordFilter,
     args: WriteArgs,
 ) -> crate::Result<Vec<RecordProjection>> {
+    // Postgres returns the identifiers of the updated records, sparing the read of the affected records.
+    if let SqlFamily::Postgres = sql_info.family {
+        if !args.args.is_empty() && !write::requires_rendered_update(&args) {
+            let model_id = model.primary_identifier();
+
+            return match record_filter.selectors {
+                Some(ids) => {
+                    let ids: Vec<&RecordProjection> = ids.iter().collect();
+                    let mut updated = Vec::with_capacity(ids.len());
+
+                    for (sql, params) in write::update_many_returning_by_ids(&sql_info.family, model, &ids, args)? {
+                        updated.extend(conn.query_raw_ids(&sql, &params, model_id.clone()).await?);
+                    }
+
+                    Ok(updated)
+                }
+                None => {
+                    let (sql, params) =
+                        write::update_many_returning(&sql_info.family, model, record_filter.filter, args)?;
+                    conn.query_raw_ids(&sql, &params, model_id).await
+                }
+            };
+        }
+    }
+
     let ids = conn.filter_selectors(model, record_filter).await?;
     let id_args = pick_args(&model.primary_identifier(), &args);
 
@@ -179,13 +302,16 @@ pub async fn update_records(
         return Ok(vec![]);
     }
 
-    let updates = {
-        let ids: Vec<&RecordProjection> = ids.iter().map(|id| &*id).collect();
-        write::update_many(model, ids.as_slice(), args)?
-    };
+    let ids: Vec<&RecordProjection> = ids.iter().map(|id| &*id).collect();
 
-    for update in updates {
-        conn.query(update).await?;
+    if write::requires_rendered_update(&args) {
+        for (sql, params) in write::render_update_many(&sql_info.family, model, ids.as_slice(), args) {
+            conn.query_raw(&sql, &params).await?;
+        }
+    } else {
+        for update in write::update_many(model, ids.as_slice(), args)? {
+            conn.query(update).await?;
+        }
     }
 
     Ok(merge_write_args(ids, id_args))
@@ -240,6 +366,21 @@ pub async fn disconnect(
     Ok(())
 }
 
+/// Disconnect the parents defined in `parent_ids` from all their children of the many-to-many relation `field`.
+pub async fn delete_relation_records(
+    conn: &dyn QueryExt,
+    field: &RelationFieldRef,
+    parent_ids: &[RecordProjection],
+) -> crate::Result<()> {
+    let parent_ids: Vec<&RecordProjection> = parent_ids.iter().collect();
+
+    for delete in write::delete_relation_table_records_of_parents(field, parent_ids.as_slice()) {
+        conn.query(delete).await?;
+    }
+
+    Ok(())
+}
+
 /// Execute a plain SQL query with the given parameters, returning the number of
 /// affected rows.
 pub async fn execute_raw(conn: &dyn QueryExt, query: String, parameters: Vec<PrismaValue>) -> crate::Result<usize> {
@@ -310,5 +451,7 @@ fn apply_expression(val: PrismaValue, expr: WriteExpression) -> PrismaValue {
         WriteExpression::Substract(rhs) => val - rhs,
         WriteExpression::Multiply(rhs) => val * rhs,
         WriteExpression::Divide(rhs) => val / rhs,
+        WriteExpression::Unset => PrismaValue::Null,
+        expr => unreachable!("Identifiers can't be scalar lists or Json, got {:?}.", expr),
     }
 }
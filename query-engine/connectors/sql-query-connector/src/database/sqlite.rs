@@ -1,5 +1,5 @@
 use super::connection::SqlConnection;
-use crate::{FromSource, SqlError};
+use crate::{sql_info::SqlInfo, FromSource, SqlError};
 use async_trait::async_trait;
 use connector_interface::{
     self as connector,
@@ -71,7 +71,7 @@ impl Connector for Sqlite {
     async fn get_connection<'a>(&'a self) -> connector::Result<Box<dyn Connection + Send + Sync + 'static>> {
         super::catch(&self.connection_info(), async move {
             let conn = self.pool.check_out().await.map_err(SqlError::from)?;
-            let conn = SqlConnection::new(conn, self.connection_info());
+            let conn = SqlConnection::new(conn, self.connection_info(), SqlInfo::from(self.connection_info()));
 
             Ok(Box::new(conn) as Box<dyn Connection + Send + Sync + 'static>)
         })
@@ -2,8 +2,9 @@ use crate::SqlError;
 use crate::{database::operations::*, sql_info::SqlInfo};
 use async_trait::async_trait;
 use connector_interface::{
-    self as connector, filter::Filter, AggregationRow, AggregationSelection, QueryArguments, ReadOperations,
-    RecordFilter, Transaction, WriteArgs, WriteOperations,
+    self as connector, filter::Filter, AggregationRow, AggregationSelection, JoinedRecord, QueryArguments,
+    ReadOperations, RecordFilter, RelAggregationSelection, RelationSelection, Transaction, UpsertArgs, WriteArgs,
+    WriteOperations,
 };
 use prisma_models::prelude::*;
 use prisma_value::PrismaValue;
@@ -12,14 +13,16 @@ use quaint::prelude::ConnectionInfo;
 pub struct SqlConnectorTransaction<'tx> {
     inner: quaint::connector::Transaction<'tx>,
     connection_info: ConnectionInfo,
+    sql_info: SqlInfo,
 }
 
 impl<'tx> SqlConnectorTransaction<'tx> {
-    pub fn new(tx: quaint::connector::Transaction<'tx>, connection_info: &ConnectionInfo) -> Self {
+    pub fn new(tx: quaint::connector::Transaction<'tx>, connection_info: &ConnectionInfo, sql_info: SqlInfo) -> Self {
         let connection_info = connection_info.clone();
         Self {
             inner: tx,
             connection_info,
+            sql_info,
         }
     }
 
@@ -54,9 +57,12 @@ impl<'tx> ReadOperations for SqlConnectorTransaction<'tx> {
         model: &ModelRef,
         filter: &Filter,
         selected_fields: &ModelProjection,
+        aggregation_selections: &[RelAggregationSelection],
     ) -> connector::Result<Option<SingleRecord>> {
-        self.catch(async move { read::get_single_record(&self.inner, model, filter, selected_fields).await })
-            .await
+        self.catch(async move {
+            read::get_single_record(&self.inner, model, filter, selected_fields, aggregation_selections).await
+        })
+        .await
     }
 
     async fn get_many_records(
@@ -64,9 +70,102 @@ impl<'tx> ReadOperations for SqlConnectorTransaction<'tx> {
         model: &ModelRef,
         query_arguments: QueryArguments,
         selected_fields: &ModelProjection,
+        aggregation_selections: &[RelAggregationSelection],
     ) -> connector::Result<ManyRecords> {
-        self.catch(async move { read::get_many_records(&self.inner, model, query_arguments, selected_fields).await })
+        self.catch(async move {
+            read::get_many_records(
+                &self.inner,
+                &self.sql_info,
+                model,
+                query_arguments,
+                selected_fields,
+                aggregation_selections,
+            )
             .await
+        })
+        .await
+    }
+
+    async fn get_many_records_per_parent(
+        &self,
+        model: &ModelRef,
+        query_arguments: QueryArguments,
+        partition_by: &ModelProjection,
+        selected_fields: &ModelProjection,
+        aggregation_selections: &[RelAggregationSelection],
+    ) -> connector::Result<Option<ManyRecords>> {
+        if !self.sql_info.supports_window_functions {
+            return Ok(None);
+        }
+
+        self.catch(async move {
+            read::get_many_records_per_parent(
+                &self.inner,
+                model,
+                query_arguments,
+                partition_by,
+                selected_fields,
+                aggregation_selections,
+            )
+            .await
+            .map(Some)
+        })
+        .await
+    }
+
+    async fn get_many_records_with_relations(
+        &self,
+        model: &ModelRef,
+        query_arguments: QueryArguments,
+        selected_fields: &ModelProjection,
+        relation_selections: &[RelationSelection],
+    ) -> connector::Result<Option<Vec<JoinedRecord>>> {
+        let sql_info = self.sql_info;
+
+        if !sql_info.supports_relation_joins {
+            return Ok(None);
+        }
+
+        self.catch(async move {
+            read::get_many_records_with_relations(
+                &self.inner,
+                &sql_info.family,
+                model,
+                query_arguments,
+                selected_fields,
+                relation_selections,
+            )
+            .await
+            .map(Some)
+        })
+        .await
+    }
+
+    async fn get_related_m2m_records_per_parent(
+        &self,
+        from_field: &RelationFieldRef,
+        from_record_ids: &[RecordProjection],
+        query_arguments: QueryArguments,
+        selected_fields: &ModelProjection,
+        aggregation_selections: &[RelAggregationSelection],
+    ) -> connector::Result<Option<ManyRecords>> {
+        if !self.sql_info.supports_window_functions {
+            return Ok(None);
+        }
+
+        self.catch(async move {
+            read::get_related_m2m_records_per_parent(
+                &self.inner,
+                from_field,
+                from_record_ids,
+                query_arguments,
+                selected_fields,
+                aggregation_selections,
+            )
+            .await
+            .map(Some)
+        })
+        .await
     }
 
     async fn get_related_m2m_record_ids(
@@ -106,26 +205,34 @@ impl<'tx> WriteOperations for SqlConnectorTransaction<'tx> {
         args: Vec<WriteArgs>,
         skip_duplicates: bool,
     ) -> connector::Result<usize> {
-        self.catch(async move {
-            write::create_records(
-                &self.inner,
-                SqlInfo::from(&self.connection_info),
-                model,
-                args,
-                skip_duplicates,
-            )
+        self.catch(async move { write::create_records(&self.inner, self.sql_info, model, args, skip_duplicates).await })
             .await
+    }
+
+    async fn create_records_returning(
+        &self,
+        model: &ModelRef,
+        args: Vec<WriteArgs>,
+        skip_duplicates: bool,
+    ) -> connector::Result<Vec<RecordProjection>> {
+        self.catch(async move {
+            write::create_records_returning(&self.inner, self.sql_info, model, args, skip_duplicates).await
         })
         .await
     }
 
+    async fn upsert_records(&self, model: &ModelRef, args: Vec<UpsertArgs>) -> connector::Result<usize> {
+        self.catch(async move { write::upsert_records(&self.inner, self.sql_info, model, args).await })
+            .await
+    }
+
     async fn update_records(
         &self,
         model: &ModelRef,
         record_filter: RecordFilter,
         args: WriteArgs,
     ) -> connector::Result<Vec<RecordProjection>> {
-        self.catch(async move { write::update_records(&self.inner, model, record_filter, args).await })
+        self.catch(async move { write::update_records(&self.inner, self.sql_info, model, record_filter, args).await })
             .await
     }
 
@@ -154,6 +261,15 @@ impl<'tx> WriteOperations for SqlConnectorTransaction<'tx> {
             .await
     }
 
+    async fn delete_relation_records(
+        &self,
+        field: &RelationFieldRef,
+        parent_ids: &[RecordProjection],
+    ) -> connector::Result<()> {
+        self.catch(async move { write::delete_relation_records(&self.inner, field, parent_ids).await })
+            .await
+    }
+
     async fn execute_raw(&self, query: String, parameters: Vec<PrismaValue>) -> connector::Result<usize> {
         self.catch(async move { write::execute_raw(&self.inner, query, parameters).await })
             .await
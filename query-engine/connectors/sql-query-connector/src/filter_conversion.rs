@@ -137,6 +137,15 @@ impl AliasedCondition for ScalarFilter {
     fn aliased_cond(self, alias: Option<Alias>) -> ConditionTree<'static> {
         match (alias, self.projection) {
             (Some(alias), ScalarProjection::Single(field)) => {
+                if let ScalarCondition::JsonCompare(json_condition) = self.condition {
+                    let column = field.as_column().table(alias.to_string(None));
+                    return convert_json_filter(column.into(), json_condition, &field);
+                }
+
+                if self.condition.is_search() {
+                    return convert_search_filter(field, self.condition, Some(alias));
+                }
+
                 let comparable: Expression = match self.mode {
                     QueryMode::Default => field.as_column().table(alias.to_string(None)).into(),
                     QueryMode::Insensitive => lower(field.as_column().table(alias.to_string(None))).into(),
@@ -154,6 +163,14 @@ impl AliasedCondition for ScalarFilter {
                 convert_scalar_filter(Row::from(columns), self.condition, self.mode, &fields)
             }
             (None, ScalarProjection::Single(field)) => {
+                if let ScalarCondition::JsonCompare(json_condition) = self.condition {
+                    return convert_json_filter(field.as_column().into(), json_condition, &field);
+                }
+
+                if self.condition.is_search() {
+                    return convert_search_filter(field, self.condition, None);
+                }
+
                 let comparable: Expression = match self.mode {
                     QueryMode::Default => field.as_column().into(),
                     QueryMode::Insensitive => lower(field.as_column()).into(),
@@ -379,6 +396,112 @@ where
     }
 }
 
+/// Json filters need the column itself, to extract the value at the filtered path from it.
+fn convert_json_filter(
+    column: Expression<'static>,
+    json_condition: JsonCondition,
+    field: &ScalarFieldRef,
+) -> ConditionTree<'static> {
+    let JsonCondition {
+        condition,
+        path,
+        target_type,
+    } = json_condition;
+
+    let path = path.map(|path| match path {
+        JsonFilterPath::String(path) => JsonPath::string(path),
+        JsonFilterPath::Array(path) => JsonPath::array(path),
+    });
+
+    let condition: Expression = match (target_type, path) {
+        // String filters compare the unquoted string at the path.
+        (Some(JsonTargetType::String), Some(path)) => {
+            let comparable: Expression = json_extract(column, path, true).into();
+
+            match *condition {
+                ScalarCondition::Contains(value) => comparable.like(format!("{}", value)),
+                ScalarCondition::NotContains(value) => comparable.not_like(format!("{}", value)),
+                ScalarCondition::StartsWith(value) => comparable.begins_with(format!("{}", value)),
+                ScalarCondition::NotStartsWith(value) => comparable.not_begins_with(format!("{}", value)),
+                ScalarCondition::EndsWith(value) => comparable.ends_into(format!("{}", value)),
+                ScalarCondition::NotEndsWith(value) => comparable.not_ends_into(format!("{}", value)),
+                cond => unreachable!("Invalid string condition on a Json path: {:?}", cond),
+            }
+            .into()
+        }
+        (Some(JsonTargetType::String), None) => unreachable!("String filters on Json fields require a path."),
+        (Some(JsonTargetType::Array), path) => {
+            let comparable: Expression = match path {
+                Some(path) => json_extract(column, path, false).into(),
+                None => column,
+            };
+
+            match *condition {
+                ScalarCondition::Contains(value) => comparable.json_array_contains(convert_value(field, value)),
+                ScalarCondition::NotContains(value) => comparable.json_array_not_contains(convert_value(field, value)),
+                ScalarCondition::StartsWith(value) => comparable.json_array_begins_with(convert_value(field, value)),
+                ScalarCondition::NotStartsWith(value) => {
+                    comparable.json_array_not_begins_with(convert_value(field, value))
+                }
+                ScalarCondition::EndsWith(value) => comparable.json_array_ends_into(convert_value(field, value)),
+                ScalarCondition::NotEndsWith(value) => comparable.json_array_not_ends_into(convert_value(field, value)),
+                cond => unreachable!("Invalid array condition on a Json path: {:?}", cond),
+            }
+            .into()
+        }
+        // Without target type, the condition compares the Json value at the path.
+        (None, path) => {
+            let comparable: Expression = match path {
+                Some(path) => json_extract(column, path, false).into(),
+                None => column,
+            };
+
+            return default_scalar_filter(comparable, *condition, &[field.clone()]);
+        }
+    };
+
+    ConditionTree::single(condition)
+}
+
+/// Full-text search filters match all fields of the search at once, e.g.
+/// `to_tsvector(concat_ws(' ', title, content)) @@ to_tsquery('cat')` on Postgres or
+/// `MATCH (title, content) AGAINST ('cat' IN BOOLEAN MODE)` on MySQL.
+fn convert_search_filter(
+    field: ScalarFieldRef,
+    condition: ScalarCondition,
+    alias: Option<Alias>,
+) -> ConditionTree<'static> {
+    let (query, projections, negated) = match condition {
+        ScalarCondition::Search(query, projections) => (query, projections, false),
+        ScalarCondition::NotSearch(query, projections) => (query, projections, true),
+        cond => unreachable!("Invalid full-text search condition: {:?}", cond),
+    };
+
+    let fields = std::iter::once(field).chain(
+        projections
+            .into_iter()
+            .flat_map(|projection| projection.scalar_fields().into_iter().cloned().collect::<Vec<_>>()),
+    );
+
+    let columns: Vec<Expression<'static>> = fields
+        .map(|field| match alias {
+            Some(alias) => field.as_column().table(alias.to_string(None)).into(),
+            None => field.as_column().into(),
+        })
+        .collect();
+
+    let comparable: Expression<'static> = text_search(columns.as_slice()).into();
+    let query = format!("{}", query);
+
+    let condition = if negated {
+        comparable.not_matches(query)
+    } else {
+        comparable.matches(query)
+    };
+
+    ConditionTree::single(condition)
+}
+
 fn convert_scalar_filter(
     comparable: impl Comparable<'static>,
     cond: ScalarCondition,
@@ -439,6 +562,10 @@ fn default_scalar_filter(
             }
             _ => comparable.not_in_selection(convert_values(fields, values)),
         },
+        ScalarCondition::JsonCompare(_) => unreachable!("Json filters are converted on their column."),
+        ScalarCondition::Search(..) | ScalarCondition::NotSearch(..) => {
+            unreachable!("Full-text search filters are converted on their columns.")
+        }
     };
 
     ConditionTree::single(condition)
@@ -512,10 +639,15 @@ fn insensitive_scalar_filter(
                     .collect::<Vec<_>>(),
             ),
         },
+        ScalarCondition::JsonCompare(_) => unreachable!("Json filters are converted on their column."),
+        ScalarCondition::Search(..) | ScalarCondition::NotSearch(..) => {
+            unreachable!("Full-text search filters are converted on their columns.")
+        }
     };
 
     ConditionTree::single(condition)
 }
+
 fn convert_first_value<'a>(fields: &[ScalarFieldRef], value: PrismaValue) -> Value<'a> {
     fields.first().unwrap().value(value)
 }
@@ -0,0 +1,160 @@
+//! Decoding of the related records aggregated into JSON by `query_builder::relation_joins`. The databases render
+//! scalars into JSON differently, e.g. Postgres encodes bytes as `\x`-prefixed hex strings, while MySQL encodes them
+//! as `base64:typeNN:` prefixed base64 strings, so the values are decoded by the type of their field.
+
+use crate::{error::SqlError, query_arguments_ext::QueryArgumentsExt, query_builder::relation_joins::ORDINAL_ALIAS};
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+use connector_interface::{JoinedRecord, RelationSelection};
+use datamodel::FieldArity;
+use prisma_models::{PrismaValue, ScalarFieldRef, TypeIdentifier};
+use serde_json::{Map, Value};
+use std::{io, str::FromStr};
+use uuid::Uuid;
+
+/// Decodes the JSON array of the related records of the relation selection, in the order of its query arguments.
+pub fn joined_records(value: Value, selection: &RelationSelection) -> crate::Result<Vec<JoinedRecord>> {
+    let objects = match value {
+        Value::Array(objects) => objects,
+        Value::Null => Vec::new(),
+        other => {
+            return Err(conversion_error(format!(
+                "Expected a JSON array of related records, got {}.",
+                other
+            )))
+        }
+    };
+
+    let mut objects = objects
+        .into_iter()
+        .map(|object| match object {
+            Value::Object(object) => Ok(object),
+            other => Err(conversion_error(format!(
+                "Expected a JSON object for a related record, got {}.",
+                other
+            ))),
+        })
+        .collect::<crate::Result<Vec<_>>>()?;
+
+    objects.sort_by_key(|object| object.get(ORDINAL_ALIAS).and_then(Value::as_i64).unwrap_or(0));
+
+    if selection.args.needs_reversed_order() {
+        objects.reverse();
+    }
+
+    objects
+        .into_iter()
+        .map(|object| joined_record(object, selection))
+        .collect()
+}
+
+fn joined_record(object: Map<String, Value>, selection: &RelationSelection) -> crate::Result<JoinedRecord> {
+    let field_value = |name: &str| object.get(name).cloned().unwrap_or(Value::Null);
+
+    let values = selection
+        .selected_fields
+        .scalar_fields()
+        .map(|sf| scalar_field_value(field_value(&sf.name), &sf))
+        .collect::<crate::Result<Vec<_>>>()?;
+
+    let relations = selection
+        .nested
+        .iter()
+        .map(|nested| joined_records(field_value(&nested.field.name), nested))
+        .collect::<crate::Result<Vec<_>>>()?;
+
+    Ok(JoinedRecord { values, relations })
+}
+
+fn scalar_field_value(value: Value, field: &ScalarFieldRef) -> crate::Result<PrismaValue> {
+    match (value, field.arity) {
+        (Value::Null, FieldArity::List) => Ok(PrismaValue::List(Vec::new())),
+        (Value::Null, _) => Ok(PrismaValue::Null),
+        (Value::Array(values), FieldArity::List) => values
+            .into_iter()
+            .map(|value| scalar_value(value, field))
+            .collect::<crate::Result<Vec<_>>>()
+            .map(PrismaValue::List),
+        (value, _) => scalar_value(value, field),
+    }
+}
+
+fn scalar_value(value: Value, field: &ScalarFieldRef) -> crate::Result<PrismaValue> {
+    let create_error = |value: &Value| {
+        conversion_error(format!(
+            "Could not convert value {} of the field `{}` to type `{:?}`.",
+            value, field.name, field.type_identifier
+        ))
+    };
+
+    Ok(match (&field.type_identifier, value) {
+        (_, Value::Null) => PrismaValue::Null,
+        (TypeIdentifier::Json, value) => PrismaValue::Json(value.to_string()),
+        (TypeIdentifier::String, Value::String(s)) => PrismaValue::String(s),
+        (TypeIdentifier::Enum(_), Value::String(s)) => PrismaValue::Enum(s),
+        (TypeIdentifier::Xml, Value::String(s)) => PrismaValue::Xml(s),
+        (TypeIdentifier::UUID, Value::String(ref s)) => PrismaValue::Uuid(Uuid::parse_str(s)?),
+        (TypeIdentifier::Boolean, Value::Bool(b)) => PrismaValue::Boolean(b),
+        (TypeIdentifier::Boolean, Value::Number(ref n)) => PrismaValue::Boolean(n.as_i64() != Some(0)),
+        (TypeIdentifier::Int, Value::Number(ref n)) | (TypeIdentifier::BigInt, Value::Number(ref n)) => {
+            match n.as_i64() {
+                Some(i) => PrismaValue::Int(i),
+                None => return Err(create_error(&Value::Number(n.clone()))),
+            }
+        }
+        (TypeIdentifier::Float, Value::Number(ref n)) | (TypeIdentifier::Decimal, Value::Number(ref n)) => {
+            let dec = BigDecimal::from_str(&n.to_string()).map_err(|_| create_error(&Value::Number(n.clone())))?;
+            PrismaValue::Float(dec.normalized())
+        }
+        (TypeIdentifier::Float, Value::String(ref s)) | (TypeIdentifier::Decimal, Value::String(ref s)) => {
+            let dec = BigDecimal::from_str(s).map_err(|_| create_error(&Value::String(s.clone())))?;
+            PrismaValue::Float(dec.normalized())
+        }
+        (TypeIdentifier::DateTime, Value::String(ref s)) => match parse_datetime(s) {
+            Some(dt) => PrismaValue::DateTime(dt.into()),
+            None => return Err(create_error(&Value::String(s.clone()))),
+        },
+        (TypeIdentifier::Bytes, Value::String(ref s)) => match decode_bytes(s) {
+            Some(bytes) => PrismaValue::Bytes(bytes),
+            None => return Err(create_error(&Value::String(s.clone()))),
+        },
+        (_, value) => return Err(create_error(&value)),
+    })
+}
+
+/// Postgres renders timestamps with a time zone as RFC 3339, while timestamps without one and all MySQL datetimes
+/// are rendered without an offset and are interpreted as UTC.
+fn parse_datetime(s: &str) -> Option<DateTime<Utc>> {
+    if let Ok(dt) = DateTime::parse_from_rfc3339(s) {
+        return Some(dt.with_timezone(&Utc));
+    }
+
+    let naive = NaiveDateTime::parse_from_str(s, "%Y-%m-%dT%H:%M:%S%.f")
+        .or_else(|_| NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S%.f"))
+        .or_else(|_| NaiveDate::parse_from_str(s, "%Y-%m-%d").map(|d| d.and_hms(0, 0, 0)))
+        .ok()?;
+
+    Some(DateTime::from_utc(naive, Utc))
+}
+
+/// Postgres renders bytes as hex with a `\x` prefix, MySQL as base64 with a `base64:typeNN:` prefix.
+fn decode_bytes(s: &str) -> Option<Vec<u8>> {
+    if let Some(hex) = s.strip_prefix("\\x") {
+        return (0..hex.len())
+            .step_by(2)
+            .map(|i| hex.get(i..i + 2).and_then(|byte| u8::from_str_radix(byte, 16).ok()))
+            .collect();
+    }
+
+    let encoded = match s.strip_prefix("base64:") {
+        Some(rest) => rest.splitn(2, ':').nth(1)?,
+        None => s,
+    };
+
+    prisma_models::decode_bytes(encoded).ok()
+}
+
+fn conversion_error(message: String) -> SqlError {
+    let error = io::Error::new(io::ErrorKind::InvalidData, message);
+    SqlError::ConversionError(error.into())
+}
@@ -5,6 +5,7 @@ mod cursor_condition;
 mod database;
 mod error;
 mod filter_conversion;
+mod joined_record;
 mod ordering;
 mod query_arguments_ext;
 mod query_builder;
@@ -3,6 +3,9 @@ use connector_interface::QueryArguments;
 use prisma_models::*;
 use quaint::ast::*;
 
+static ORDER_JOIN_PREFIX: &str = "orderby_";
+static ORDER_AGGREGATOR_ALIAS: &str = "orderby_aggregator";
+
 /// Builds all expressions for an `ORDER BY` clause based on the query arguments.
 pub fn build(
     query_arguments: &QueryArguments,
@@ -15,22 +18,76 @@ pub fn build(
 
     // The index is used to differentiate potentially separate relations to the same model.
     for (index, order_by) in query_arguments.order_by.iter().enumerate() {
-        // This is the final column identifier to be used for the scalar field to order by.
-        // - If it's on the base model with no hops, it's for example `modelTable.field`.
-        // - If it is with several hops, it's the alias used for the last join, e.g.
-        //   `orderby_{modelname}_{index}.field`
-        let order_by_column = if !order_by.path.is_empty() {
-            let last_rf = order_by.path.last().unwrap();
-
-            Column::from((
-                format!("orderby_{}_{}", &last_rf.related_model().name, index),
-                order_by.field.db_name().to_owned(),
-            ))
-        } else {
-            order_by.field.as_column()
-        };
+        match order_by {
+            OrderBy::Relevance(order_by) => {
+                order_definitions.push(build_order_relevance(order_by, needs_reversed_order));
+            }
+            _ => {
+                let (order_expr, order_joins) = order_expression(order_by, index, base_model);
+
+                joins.extend(order_joins);
+                order_definitions.push((
+                    order_expr,
+                    Some(into_order(order_by.sort_order(), needs_reversed_order)),
+                ));
+            }
+        }
+    }
+
+    (order_definitions, joins)
+}
+
+/// Builds the expression to order by for a scalar or aggregation ordering, together with the joins it requires.
+/// The same index always yields the same join aliases, which allows the cursor condition to compare against the
+/// expressions of the `ORDER BY` clause.
+pub fn order_expression(
+    order_by: &OrderBy,
+    index: usize,
+    base_model: &ModelRef,
+) -> (Expression<'static>, Vec<JoinData<'static>>) {
+    match order_by {
+        OrderBy::Scalar(order_by) => {
+            // This is the final column identifier to be used for the scalar field to order by.
+            // - If it's on the base model with no hops, it's for example `modelTable.field`.
+            // - If it is with several hops, it's the alias used for the last join, e.g.
+            //   `orderby_{modelname}_{index}.field`
+            let order_by_column = match order_by.path.last() {
+                Some(last_rf) => Column::from((
+                    join_alias(&last_rf.related_model(), index),
+                    order_by.field.db_name().to_owned(),
+                )),
+                None => order_by.field.as_column(),
+            };
+
+            (
+                order_by_column.into(),
+                compute_one2one_joins(&order_by.path, index, base_model),
+            )
+        }
+        OrderBy::Aggregation(order_by) => {
+            let (aggregated_rf, hops) = order_by.path.split_last().unwrap();
+            let mut joins = compute_one2one_joins(hops, index, base_model);
+            let aggregation_join = compute_aggregation_join(aggregated_rf, order_by, index, base_model);
+            let aggregator_column = Column::from((aggregation_join_alias(index), ORDER_AGGREGATOR_ALIAS));
+
+            joins.push(aggregation_join);
 
-        for rf in order_by.path.iter() {
+            // Records without related records are not part of the aggregation join, so their count is null.
+            let order_expr: Expression<'static> = match order_by.sort_aggregation {
+                SortAggregation::Count => coalesce(vec![aggregator_column.into(), Value::integer(0).into()]).into(),
+                _ => aggregator_column.into(),
+            };
+
+            (order_expr, joins)
+        }
+        OrderBy::Relevance(order_by) => (build_order_relevance(order_by, false).0, vec![]),
+    }
+}
+
+/// Joins the models along the to-one relation path, aliasing each joined table per ordering index.
+fn compute_one2one_joins(path: &[RelationFieldRef], index: usize, base_model: &ModelRef) -> Vec<JoinData<'static>> {
+    path.iter()
+        .map(|rf| {
             let (left_fields, right_fields) = if rf.is_inlined_on_enclosing_model() {
                 (rf.scalar_fields(), rf.referenced_fields())
             } else {
@@ -40,47 +97,150 @@ pub fn build(
                 )
             };
 
-            // `rf` is always the relation field on the left model in the join (parent).
-            let left_table_alias = if rf.model().name != base_model.name {
-                Some(format!("orderby_{}_{}", &rf.model().name, index))
-            } else {
-                None
-            };
-
-            let right_table_alias = format!("orderby_{}_{}", &rf.related_model().name, index);
-
             let related_model = rf.related_model();
-            let pairs = left_fields.into_iter().zip(right_fields.into_iter());
+            let right_table_alias = join_alias(&related_model, index);
 
-            let on_conditions = pairs
+            let on_conditions = left_fields
+                .into_iter()
+                .zip(right_fields.into_iter())
                 .map(|(a, b)| {
-                    let a_col = if let Some(alias) = left_table_alias.clone() {
-                        Column::from((alias, a.db_name().to_owned()))
-                    } else {
-                        a.as_column()
-                    };
-
+                    let a_col = parent_column(rf, &a, index, base_model);
                     let b_col = Column::from((right_table_alias.clone(), b.db_name().to_owned()));
 
                     a_col.equals(b_col)
                 })
                 .collect::<Vec<_>>();
 
-            joins.push(
-                related_model
-                    .as_table()
-                    .alias(right_table_alias.clone())
-                    .on(ConditionTree::single(on_conditions)),
-            );
-        }
+            related_model
+                .as_table()
+                .alias(right_table_alias)
+                .on(ConditionTree::single(on_conditions))
+        })
+        .collect()
+}
 
-        match (order_by.sort_order, needs_reversed_order) {
-            (SortOrder::Ascending, true) => order_definitions.push(order_by_column.descend()),
-            (SortOrder::Descending, true) => order_definitions.push(order_by_column.ascend()),
-            (SortOrder::Ascending, false) => order_definitions.push(order_by_column.ascend()),
-            (SortOrder::Descending, false) => order_definitions.push(order_by_column.descend()),
-        }
+/// Joins the aggregate of the related records of a to-many relation, grouped by the parent they belong to, e.g.
+/// ```sql
+/// LEFT JOIN (
+///   SELECT `Post`.`authorId`, COUNT(*) AS `orderby_aggregator` FROM `Post` GROUP BY `Post`.`authorId`
+/// ) AS `orderby_aggr_0` ON (`User`.`id` = `orderby_aggr_0`.`authorId`)
+/// ```
+fn compute_aggregation_join(
+    rf: &RelationFieldRef,
+    order_by: &OrderByAggregation,
+    index: usize,
+    base_model: &ModelRef,
+) -> JoinData<'static> {
+    let related_model = rf.related_model();
+
+    // Pairs of (parent field, column of the aggregation subquery referencing it).
+    let (parent_fields, subquery_columns, subquery): (Vec<ScalarFieldRef>, Vec<Column<'static>>, Select<'static>) =
+        if rf.relation().is_many_to_many() {
+            // `m2m_columns` of the related field are the columns of the relation table pointing to the parent.
+            let parent_columns = rf.related_field().m2m_columns();
+            let subquery = Select::from_table(rf.relation().as_table());
+
+            let subquery = match order_by.field {
+                Some(_) => {
+                    let related_ids = related_model.primary_identifier().as_columns().collect::<Vec<_>>();
+                    let join_conditions = rf
+                        .m2m_columns()
+                        .into_iter()
+                        .zip(related_ids)
+                        .map(|(a, b)| a.equals(b))
+                        .collect::<Vec<_>>();
+
+                    subquery.inner_join(related_model.as_table().on(ConditionTree::single(join_conditions)))
+                }
+                None => subquery,
+            };
+
+            (
+                rf.model().primary_identifier().scalar_fields().collect(),
+                parent_columns,
+                subquery,
+            )
+        } else {
+            let child_fields = rf.related_field().scalar_fields();
+
+            (
+                rf.related_field().referenced_fields(),
+                child_fields.iter().map(|f| f.as_column()).collect(),
+                Select::from_table(related_model.as_table()),
+            )
+        };
+
+    let subquery = subquery_columns
+        .iter()
+        .fold(subquery, |acc, col| acc.column(col.clone()).group_by(col.clone()))
+        .value(aggregation_expression(order_by).alias(ORDER_AGGREGATOR_ALIAS));
+
+    let join_alias = aggregation_join_alias(index);
+    let on_conditions = parent_fields
+        .iter()
+        .zip(subquery_columns)
+        .map(|(parent_field, col)| {
+            let a_col = parent_column(rf, parent_field, index, base_model);
+            let b_col = Column::from((join_alias.clone(), col.name.into_owned()));
+
+            a_col.equals(b_col)
+        })
+        .collect::<Vec<_>>();
+
+    Table::from(subquery)
+        .alias(join_alias)
+        .on(ConditionTree::single(on_conditions))
+}
+
+fn aggregation_expression(order_by: &OrderByAggregation) -> Expression<'static> {
+    match (order_by.sort_aggregation, &order_by.field) {
+        (SortAggregation::Count, _) => count(asterisk()).into(),
+        (SortAggregation::Avg, Some(sf)) => avg(sf.as_column()).into(),
+        (SortAggregation::Sum, Some(sf)) => sum(sf.as_column()).into(),
+        (SortAggregation::Min, Some(sf)) => min(sf.as_column()).into(),
+        (SortAggregation::Max, Some(sf)) => max(sf.as_column()).into(),
+        (aggregation, None) => unreachable!("Ordering by {:?} requires a field to aggregate.", aggregation),
     }
+}
 
-    (order_definitions, joins)
+/// `rf` is always the relation field on the left model in the join (parent). The parent is either the base model,
+/// or a model joined previously along the path.
+fn parent_column(
+    rf: &RelationFieldRef,
+    field: &ScalarFieldRef,
+    index: usize,
+    base_model: &ModelRef,
+) -> Column<'static> {
+    if rf.model().name != base_model.name {
+        Column::from((join_alias(&rf.model(), index), field.db_name().to_owned()))
+    } else {
+        field.as_column()
+    }
+}
+
+fn join_alias(model: &ModelRef, index: usize) -> String {
+    format!("{}{}_{}", ORDER_JOIN_PREFIX, &model.name, index)
+}
+
+fn aggregation_join_alias(index: usize) -> String {
+    format!("{}aggr_{}", ORDER_JOIN_PREFIX, index)
+}
+
+/// Orders by the relevance of the given fields for the search query, e.g.
+/// `ts_rank(to_tsvector(concat_ws(' ', title, content)), to_tsquery('cat'))` on Postgres or
+/// `MATCH (title, content) AGAINST ('cat' IN BOOLEAN MODE)` on MySQL.
+fn build_order_relevance(order_by: &OrderByRelevance, needs_reversed_order: bool) -> OrderDefinition<'static> {
+    let columns: Vec<Expression<'static>> = order_by.fields.iter().map(|sf| sf.as_column().into()).collect();
+    let relevance: Expression<'static> = text_search_relevance(&columns, order_by.search.clone()).into();
+
+    (relevance, Some(into_order(order_by.sort_order, needs_reversed_order)))
+}
+
+fn into_order(sort_order: SortOrder, needs_reversed_order: bool) -> Order {
+    match (sort_order, needs_reversed_order) {
+        (SortOrder::Ascending, true) => Order::Desc,
+        (SortOrder::Descending, true) => Order::Asc,
+        (SortOrder::Ascending, false) => Order::Asc,
+        (SortOrder::Descending, false) => Order::Desc,
+    }
 }
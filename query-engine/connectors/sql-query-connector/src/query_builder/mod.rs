@@ -1,4 +1,6 @@
 pub mod read;
+pub mod relation_joins;
+pub mod statement;
 pub mod write;
 
 pub use read::*;
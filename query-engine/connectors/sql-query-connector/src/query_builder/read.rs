@@ -1,5 +1,14 @@
-use crate::{cursor_condition, filter_conversion::AliasedCondition, ordering};
-use connector_interface::{filter::Filter, AggregationSelection, QueryArguments};
+use super::statement::Statement;
+use crate::{
+    cursor_condition,
+    filter_conversion::AliasedCondition,
+    ordering,
+    query_arguments_ext::QueryArgumentsExt,
+    sql_info::{SqlFamily, SqlInfo},
+};
+use connector_interface::{
+    filter::Filter, AggregationSelection, LockMode, QueryArguments, RelAggregationSelection, RowLock,
+};
 use itertools::Itertools;
 use prisma_models::*;
 use quaint::ast::*;
@@ -29,35 +38,12 @@ impl SelectDefinition for Select<'static> {
 
 impl SelectDefinition for QueryArguments {
     fn into_select(self, model: &ModelRef) -> Select<'static> {
-        let (table_opt, cursor_condition) = cursor_condition::build(&self, &model);
-        let (orderings, joins) = ordering::build(&self, &model);
-
         let limit = if self.ignore_take { None } else { self.take_abs() };
         let skip = if self.ignore_skip { 0 } else { self.skip.unwrap_or(0) };
 
-        let filter: ConditionTree = self
-            .filter
-            .map(|f| f.aliased_cond(None))
-            .unwrap_or(ConditionTree::NoCondition);
-
-        let conditions = match (filter, cursor_condition) {
-            (ConditionTree::NoCondition, cursor) => cursor,
-            (filter, ConditionTree::NoCondition) => filter,
-            (filter, cursor) => ConditionTree::and(filter, cursor),
-        };
-
-        let select_ast = Select::from_table(model.as_table())
-            .so_that(conditions)
-            .offset(skip as usize);
-
-        let select_ast = if let Some(table) = table_opt {
-            select_ast.and_from(table)
-        } else {
-            select_ast
-        };
-
+        let (select_ast, orderings) = filtered_select(self, model);
         let select_ast = orderings.into_iter().fold(select_ast, |acc, ord| acc.order_by(ord));
-        let select_ast = joins.into_iter().fold(select_ast, |acc, join| acc.left_join(join));
+        let select_ast = select_ast.offset(skip as usize);
 
         match limit {
             Some(limit) => select_ast.limit(limit as usize),
@@ -66,11 +52,496 @@ impl SelectDefinition for QueryArguments {
     }
 }
 
-pub fn get_records<T>(model: &ModelRef, columns: impl Iterator<Item = Column<'static>>, query: T) -> Select<'static>
+/// Selects from the model with the filter and cursor conditions and the joins required by the orderings, which are
+/// returned for the caller to apply.
+fn filtered_select(args: QueryArguments, model: &ModelRef) -> (Select<'static>, Vec<OrderDefinition<'static>>) {
+    let (table_opt, cursor_condition) = cursor_condition::build(&args, &model);
+    let (orderings, joins) = ordering::build(&args, &model);
+
+    let filter: ConditionTree = args
+        .filter
+        .map(|f| f.aliased_cond(None))
+        .unwrap_or(ConditionTree::NoCondition);
+
+    let conditions = match (filter, cursor_condition) {
+        (ConditionTree::NoCondition, cursor) => cursor,
+        (filter, ConditionTree::NoCondition) => filter,
+        (filter, cursor) => ConditionTree::and(filter, cursor),
+    };
+
+    let select_ast = Select::from_table(model.as_table()).so_that(conditions);
+    let select_ast = joins.into_iter().fold(select_ast, |acc, join| acc.left_join(join));
+
+    // The cursor table is joined instead of being selected from, as the ordering joins can only reference
+    // tables of the same join chain.
+    let select_ast = if let Some(table) = table_opt {
+        select_ast.inner_join(table.on(ConditionTree::NoCondition))
+    } else {
+        select_ast
+    };
+
+    (select_ast, orderings)
+}
+
+pub fn get_records<T>(
+    model: &ModelRef,
+    columns: impl Iterator<Item = Column<'static>>,
+    aggregation_selections: &[RelAggregationSelection],
+    query: T,
+) -> Select<'static>
 where
     T: SelectDefinition,
 {
-    columns.fold(query.into_select(model), |acc, col| acc.column(col))
+    let select = columns.fold(query.into_select(model), |acc, col| acc.column(col));
+
+    aggregation_selections.iter().fold(select, |acc, selection| {
+        acc.value(relation_aggregation(selection).alias(selection.db_alias()))
+    })
+}
+
+static ROW_NUMBER_ALIAS: &str = "row_number";
+static PER_PARENT_TABLE_ALIAS: &str = "per_parent";
+static PARENT_ALIAS_PREFIX: &str = "parent_";
+
+/// Selects the records of a to-many relation with `take` and `skip` applied per parent. The records of each
+/// parent, identified by the `partition_by` fields, are numbered in the order of the query arguments:
+/// ```sql
+/// SELECT `per_parent`.`id`, `per_parent`.`authorId` FROM (
+///     SELECT
+///         ROW_NUMBER() OVER (PARTITION BY `Post`.`authorId` ORDER BY `Post`.`id` ASC) AS `row_number`,
+///         `Post`.`id`,
+///         `Post`.`authorId`
+///     FROM `Post`
+///     WHERE `Post`.`authorId` IN (1, 2)
+/// ) AS `per_parent`
+/// WHERE `per_parent`.`row_number` > 5 AND `per_parent`.`row_number` <= 15
+/// ORDER BY `per_parent`.`authorId` ASC, `per_parent`.`row_number` ASC
+/// ```
+pub fn get_records_per_parent(
+    model: &ModelRef,
+    selected_fields: &ModelProjection,
+    aggregation_selections: &[RelAggregationSelection],
+    partition_by: &ModelProjection,
+    query_arguments: QueryArguments,
+) -> Select<'static> {
+    let numbered = Select::from_table(model.as_table());
+    let partition: Vec<_> = partition_by.as_columns().collect();
+    let parent_names: Vec<_> = partition_by.db_names().collect();
+
+    paginate_per_parent(
+        model,
+        selected_fields,
+        aggregation_selections,
+        numbered,
+        partition,
+        &parent_names,
+        query_arguments,
+    )
+}
+
+/// Selects the records of a many-to-many relation with `take` and `skip` applied per parent, one statement per
+/// chunk of parents. The records are joined with the relation table to number them per parent, the ids of the
+/// parents are selected after the fields and aggregations:
+/// ```sql
+/// SELECT `per_parent`.`id`, `per_parent`.`name`, `per_parent`.`parent_0` FROM (
+///     SELECT
+///         ROW_NUMBER() OVER (PARTITION BY `_CategoryToPost`.`B` ORDER BY `Category`.`id` ASC) AS `row_number`,
+///         `_CategoryToPost`.`B` AS `parent_0`,
+///         `Category`.`id`,
+///         `Category`.`name`
+///     FROM `Category`
+///     INNER JOIN `_CategoryToPost` ON `_CategoryToPost`.`A` = `Category`.`id`
+///     WHERE `_CategoryToPost`.`B` IN (1, 2)
+/// ) AS `per_parent`
+/// WHERE `per_parent`.`row_number` > 5 AND `per_parent`.`row_number` <= 15
+/// ORDER BY `per_parent`.`parent_0` ASC, `per_parent`.`row_number` ASC
+/// ```
+pub fn get_related_m2m_records_per_parent(
+    from_field: &RelationFieldRef,
+    from_record_ids: &[&RecordProjection],
+    selected_fields: &ModelProjection,
+    aggregation_selections: &[RelAggregationSelection],
+    query_arguments: QueryArguments,
+) -> Vec<Query<'static>> {
+    let model = from_field.related_model();
+    let parent_columns = from_field.related_field().m2m_columns();
+    let child_columns = from_field.m2m_columns();
+    let id_columns: Vec<_> = model.primary_identifier().as_columns().collect();
+
+    let parent_names: Vec<_> = (0..parent_columns.len())
+        .map(|index| format!("{}{}", PARENT_ALIAS_PREFIX, index))
+        .collect();
+
+    let join = from_field
+        .relation()
+        .as_table()
+        .on(Row::from(child_columns).equals(Row::from(id_columns)));
+
+    super::chunked_conditions(&parent_columns, from_record_ids, |parents| {
+        let numbered = parent_columns.iter().zip(parent_names.iter()).fold(
+            Select::from_table(model.as_table())
+                .inner_join(join.clone())
+                .so_that(parents),
+            |acc, (column, name)| acc.column(column.clone().alias(name.clone())),
+        );
+
+        let select = paginate_per_parent(
+            &model,
+            selected_fields,
+            aggregation_selections,
+            numbered,
+            parent_columns.clone(),
+            &parent_names,
+            query_arguments.clone(),
+        );
+
+        parent_names.iter().fold(select, |acc, name| {
+            acc.column(Column::from((PER_PARENT_TABLE_ALIAS, name.clone())))
+        })
+    })
+}
+
+/// Numbers the records of the select per parent, identified by the `partition` columns, and keeps the records of
+/// the requested page of each parent. The parents are ordered by the `parent_names` columns of the numbered select.
+fn paginate_per_parent(
+    model: &ModelRef,
+    selected_fields: &ModelProjection,
+    aggregation_selections: &[RelAggregationSelection],
+    numbered: Select<'static>,
+    partition: Vec<Column<'static>>,
+    parent_names: &[String],
+    query_arguments: QueryArguments,
+) -> Select<'static> {
+    let (orderings, joins) = ordering::build(&query_arguments, model);
+    let orderings = numbering_orderings(orderings, model, query_arguments.needs_reversed_order());
+
+    let window = partition
+        .into_iter()
+        .fold(row_number(), |acc, col| acc.partition_by(col));
+    let window = orderings.into_iter().fold(window, |acc, ord| acc.order_by(ord));
+    let window: Expression<'static> = Function::from(window).into();
+
+    let skip = query_arguments.skip.unwrap_or(0);
+    let take = query_arguments.take_abs();
+
+    let filter: ConditionTree = query_arguments
+        .filter
+        .map(|f| f.aliased_cond(None))
+        .unwrap_or(ConditionTree::NoCondition);
+
+    let numbered = numbered.value(window.alias(ROW_NUMBER_ALIAS)).and_where(filter);
+    let numbered = joins.into_iter().fold(numbered, |acc, join| acc.left_join(join));
+    let numbered = get_records(model, selected_fields.as_columns(), aggregation_selections, numbered);
+
+    let row_number = Column::from((PER_PARENT_TABLE_ALIAS, ROW_NUMBER_ALIAS));
+    let mut conditions: Vec<Expression<'static>> = vec![row_number.clone().greater_than(skip).into()];
+
+    if let Some(take) = take {
+        conditions.push(row_number.clone().less_than_or_equals(skip + take).into());
+    }
+
+    let names = selected_fields
+        .db_names()
+        .chain(aggregation_selections.iter().map(|selection| selection.db_alias()));
+
+    let select = names.fold(
+        Select::from_table(Table::from(numbered).alias(PER_PARENT_TABLE_ALIAS)),
+        |acc, name| acc.column(Column::from((PER_PARENT_TABLE_ALIAS, name))),
+    );
+
+    let select = parent_names.iter().fold(select, |acc, name| {
+        acc.order_by(Column::from((PER_PARENT_TABLE_ALIAS, name.clone())).ascend())
+    });
+
+    select
+        .so_that(ConditionTree::And(conditions))
+        .order_by(row_number.ascend())
+}
+
+static DISTINCT_TABLE_ALIAS: &str = "distinct_records";
+static ORDER_ALIAS_PREFIX: &str = "order_";
+
+/// Selects the first record of each set of records sharing the same `distinct` fields, in the order of the query
+/// arguments, before paginating the remaining records:
+/// ```sql
+/// SELECT `distinct_records`.`id`, `distinct_records`.`name` FROM (
+///     SELECT
+///         ROW_NUMBER() OVER (PARTITION BY `User`.`name` ORDER BY `User`.`age` ASC) AS `row_number`,
+///         `User`.`age` AS `order_0`,
+///         `User`.`id`,
+///         `User`.`name`
+///     FROM `User`
+/// ) AS `distinct_records`
+/// WHERE `distinct_records`.`row_number` = 1
+/// ORDER BY `distinct_records`.`order_0` ASC
+/// LIMIT 10 OFFSET 5
+/// ```
+/// Postgres gets `DISTINCT ON` instead, see `get_distinct_on_records`.
+pub fn get_distinct_records(
+    model: &ModelRef,
+    selected_fields: &ModelProjection,
+    aggregation_selections: &[RelAggregationSelection],
+    query_arguments: QueryArguments,
+) -> Select<'static> {
+    let distinct = query_arguments
+        .distinct
+        .clone()
+        .unwrap_or_else(|| model.primary_identifier());
+    let limit = if query_arguments.ignore_take {
+        None
+    } else {
+        query_arguments.take_abs()
+    };
+    let skip = if query_arguments.ignore_skip {
+        0
+    } else {
+        query_arguments.skip.unwrap_or(0)
+    };
+
+    let reversed = query_arguments.needs_reversed_order();
+    let (numbered, orderings) = filtered_select(query_arguments, model);
+    let orderings = numbering_orderings(orderings, model, reversed);
+
+    let window = distinct
+        .as_columns()
+        .fold(row_number(), |acc, col| acc.partition_by(col));
+    let window = orderings.iter().cloned().fold(window, |acc, ord| acc.order_by(ord));
+    let window: Expression<'static> = Function::from(window).into();
+
+    // The order expressions can reference joined tables, so they are selected to be ordered by outside.
+    let numbered = orderings.iter().enumerate().fold(
+        numbered.value(window.alias(ROW_NUMBER_ALIAS)),
+        |acc, (index, (expr, _))| acc.value(expr.clone().alias(format!("{}{}", ORDER_ALIAS_PREFIX, index))),
+    );
+    let numbered = get_records(model, selected_fields.as_columns(), aggregation_selections, numbered);
+
+    let names = selected_fields
+        .db_names()
+        .chain(aggregation_selections.iter().map(|selection| selection.db_alias()));
+
+    let select = names.fold(
+        Select::from_table(Table::from(numbered).alias(DISTINCT_TABLE_ALIAS)),
+        |acc, name| acc.column(Column::from((DISTINCT_TABLE_ALIAS, name))),
+    );
+
+    let select = orderings
+        .into_iter()
+        .enumerate()
+        .fold(select, |acc, (index, (_, order))| {
+            let column = Column::from((DISTINCT_TABLE_ALIAS, format!("{}{}", ORDER_ALIAS_PREFIX, index)));
+            acc.order_by((Expression::from(column), order))
+        });
+
+    let select = select
+        .so_that(Column::from((DISTINCT_TABLE_ALIAS, ROW_NUMBER_ALIAS)).equals(1))
+        .offset(skip as usize);
+
+    match limit {
+        Some(limit) => select.limit(limit as usize),
+        None => select,
+    }
+}
+
+static DISTINCT_ON_TABLE_ALIAS: &str = "distinct_on_records";
+static DISTINCT_ALIAS_PREFIX: &str = "distinct_";
+
+/// Selects the first record of each set of records sharing the same `distinct` fields with the `DISTINCT ON` of
+/// Postgres, which keeps the first record of each set in an ordering led by the `distinct` fields. The records are
+/// ordered by the query arguments again before paginating them:
+/// ```sql
+/// SELECT "distinct_records"."id", "distinct_records"."name" FROM (
+///     SELECT DISTINCT ON ("distinct_on_records"."distinct_0") * FROM (
+///         SELECT "User"."name" AS "distinct_0", "User"."age" AS "order_0", "User"."id", "User"."name"
+///         FROM "User"
+///     ) AS "distinct_on_records"
+///     ORDER BY "distinct_on_records"."distinct_0", "distinct_on_records"."order_0" ASC
+/// ) AS "distinct_records"
+/// ORDER BY "distinct_records"."order_0" ASC
+/// OFFSET $1 LIMIT $2
+/// ```
+/// The query AST has no `DISTINCT ON`, which is why the statement is assembled from fragments.
+pub fn get_distinct_on_records(
+    family: &SqlFamily,
+    model: &ModelRef,
+    selected_fields: &ModelProjection,
+    aggregation_selections: &[RelAggregationSelection],
+    query_arguments: QueryArguments,
+) -> crate::Result<(String, Vec<Value<'static>>)> {
+    let distinct = query_arguments
+        .distinct
+        .clone()
+        .unwrap_or_else(|| model.primary_identifier());
+    let limit = if query_arguments.ignore_take {
+        None
+    } else {
+        query_arguments.take_abs()
+    };
+    let skip = if query_arguments.ignore_skip {
+        0
+    } else {
+        query_arguments.skip.unwrap_or(0)
+    };
+
+    let reversed = query_arguments.needs_reversed_order();
+    let (filtered, orderings) = filtered_select(query_arguments, model);
+    let orderings = numbering_orderings(orderings, model, reversed);
+
+    let distinct_names: Vec<_> = (0..distinct.scalar_fields().count())
+        .map(|index| format!("{}{}", DISTINCT_ALIAS_PREFIX, index))
+        .collect();
+    let order_names: Vec<_> = (0..orderings.len())
+        .map(|index| format!("{}{}", ORDER_ALIAS_PREFIX, index))
+        .collect();
+
+    // The distinct and order expressions can reference joined tables, so they are selected to be used outside.
+    let filtered = distinct
+        .as_columns()
+        .zip(distinct_names.iter())
+        .fold(filtered, |acc, (column, name)| acc.column(column.alias(name.clone())));
+    let filtered = orderings
+        .iter()
+        .zip(order_names.iter())
+        .fold(filtered, |acc, ((expr, _), name)| {
+            acc.value(expr.clone().alias(name.clone()))
+        });
+    let filtered = get_records(model, selected_fields.as_columns(), aggregation_selections, filtered);
+
+    let names = selected_fields
+        .db_names()
+        .chain(aggregation_selections.iter().map(|selection| selection.db_alias()));
+
+    let mut statement = Statement::new(family);
+    statement.push_str("SELECT ");
+
+    for (index, name) in names.enumerate() {
+        if index > 0 {
+            statement.push_str(", ");
+        }
+
+        statement.push_column(DISTINCT_TABLE_ALIAS, &name);
+    }
+
+    statement.push_str(" FROM (SELECT DISTINCT ON (");
+
+    for (index, name) in distinct_names.iter().enumerate() {
+        if index > 0 {
+            statement.push_str(", ");
+        }
+
+        statement.push_column(DISTINCT_ON_TABLE_ALIAS, name);
+    }
+
+    statement.push_str(") * FROM (");
+    statement.push_query(filtered)?;
+    statement.push_str(") AS ");
+    statement.push_ident(DISTINCT_ON_TABLE_ALIAS);
+    statement.push_str(" ORDER BY ");
+
+    for (index, name) in distinct_names.iter().enumerate() {
+        if index > 0 {
+            statement.push_str(", ");
+        }
+
+        statement.push_column(DISTINCT_ON_TABLE_ALIAS, name);
+    }
+
+    for ((_, order), name) in orderings.iter().zip(order_names.iter()) {
+        statement.push_str(", ");
+        statement.push_column(DISTINCT_ON_TABLE_ALIAS, name);
+        push_order(&mut statement, order);
+    }
+
+    statement.push_str(") AS ");
+    statement.push_ident(DISTINCT_TABLE_ALIAS);
+
+    for (index, ((_, order), name)) in orderings.iter().zip(order_names.iter()).enumerate() {
+        statement.push_str(if index == 0 { " ORDER BY " } else { ", " });
+        statement.push_column(DISTINCT_TABLE_ALIAS, name);
+        push_order(&mut statement, order);
+    }
+
+    statement.push_str(" OFFSET ");
+    statement.push_value(Value::integer(skip));
+
+    if let Some(limit) = limit {
+        statement.push_str(" LIMIT ");
+        statement.push_value(Value::integer(limit));
+    }
+
+    Ok(statement.into_parts())
+}
+
+fn push_order(statement: &mut Statement<'_>, order: &Option<Order>) {
+    match order {
+        Some(Order::Desc) => statement.push_str(" DESC"),
+        _ => statement.push_str(" ASC"),
+    }
+}
+
+/// The orderings to number records by with `ROW_NUMBER()`. The records need a deterministic numbering, and some
+/// databases require an ordering for window functions, so the primary identifier is used if there is no ordering.
+pub fn numbering_orderings(
+    orderings: Vec<OrderDefinition<'static>>,
+    model: &ModelRef,
+    reversed: bool,
+) -> Vec<OrderDefinition<'static>> {
+    if !orderings.is_empty() {
+        return orderings;
+    }
+
+    let order = if reversed { Order::Desc } else { Order::Asc };
+
+    model
+        .primary_identifier()
+        .as_columns()
+        .map(|col| (col.into(), Some(order.clone())))
+        .collect()
+}
+
+/// Generates a correlated subquery computing the aggregation for each selected record, e.g.
+/// ```sql
+/// (SELECT COUNT(*) FROM `Post` AS `aggr_Post_posts` WHERE `aggr_Post_posts`.`authorId` = `User`.`id`)
+/// ```
+/// for the count of a one-to-many relation, or for a many-to-many relation:
+/// ```sql
+/// (SELECT COUNT(*) FROM `_CategoryToPost` WHERE `_CategoryToPost`.`B` = `Post`.`id`)
+/// ```
+fn relation_aggregation(selection: &RelAggregationSelection) -> Expression<'static> {
+    match selection {
+        RelAggregationSelection::Count(rf) => {
+            let (table, conditions) = if rf.relation().is_many_to_many() {
+                let parent_columns = rf.model().primary_identifier().as_columns();
+                let conditions: Vec<_> = rf
+                    .related_field()
+                    .m2m_columns()
+                    .into_iter()
+                    .zip(parent_columns)
+                    .map(|(relation_column, parent_column)| relation_column.equals(parent_column))
+                    .collect();
+
+                (rf.relation().as_table(), conditions)
+            } else {
+                // The related model can be the parent model itself, so the related table needs an alias.
+                let related_alias = format!("aggr_{}_{}", rf.related_model().name, rf.name);
+                let related_field = rf.related_field();
+                let conditions: Vec<_> = related_field
+                    .scalar_fields()
+                    .into_iter()
+                    .zip(related_field.referenced_fields())
+                    .map(|(child, parent)| {
+                        Column::from((related_alias.clone(), child.db_name().to_owned())).equals(parent.as_column())
+                    })
+                    .collect();
+
+                (rf.related_model().as_table().alias(related_alias), conditions)
+            };
+
+            Select::from_table(table)
+                .value(count(asterisk()))
+                .so_that(ConditionTree::single(conditions))
+                .into()
+        }
+    }
 }
 
 /// Generates a query of the form:
@@ -101,7 +572,7 @@ where
 /// not absolute - e.g. `SELECT "field" FROM (...)` NOT `SELECT "full"."path"."to"."field" FROM (...)`.
 pub fn aggregate(model: &ModelRef, selections: &[AggregationSelection], args: QueryArguments) -> Select<'static> {
     let columns = extract_columns(model, &selections);
-    let sub_query = get_records(model, columns.into_iter(), args);
+    let sub_query = get_records(model, columns.into_iter(), &[], args);
     let sub_table = Table::from(sub_query).alias("sub");
 
     selections
@@ -212,3 +683,89 @@ fn extract_columns(model: &ModelRef, selections: &[AggregationSelection]) -> Vec
 
     fields.as_columns().collect()
 }
+
+/// Renders the select of the records with a lock on the selected rows of the model, held until the end of the
+/// transaction, e.g. on Postgres:
+/// ```sql
+/// SELECT "public"."Job"."id" FROM "public"."Job" WHERE "public"."Job"."status" = $1
+/// LIMIT $2 FOR UPDATE OF "Job" SKIP LOCKED
+/// ```
+/// Only the table of the model is locked on Postgres and MySQL, not the joined tables of orderings and relation
+/// aggregations. MySQL before 8.0.1 can only lock the rows of all tables, and waits for locked rows.
+/// SQL Server locks with table hints on the table of the model, e.g.
+/// `FROM [dbo].[Job] WITH (UPDLOCK, ROWLOCK, READPAST)`.
+pub fn lock_records(
+    sql_info: &SqlInfo,
+    model: &ModelRef,
+    select: Select<'static>,
+    lock: RowLock,
+) -> crate::Result<(String, Vec<Value<'static>>)> {
+    let family = &sql_info.family;
+    let mut statement = Statement::new(family);
+    statement.push_query(select)?;
+
+    if let SqlFamily::MSSQL = family {
+        let (sql, params) = statement.into_parts();
+
+        let mut hints = vec![match lock.mode {
+            LockMode::Update => "UPDLOCK",
+            LockMode::Share => "HOLDLOCK",
+        }];
+
+        hints.push("ROWLOCK");
+
+        if lock.skip_locked {
+            hints.push("READPAST");
+        } else if lock.no_wait {
+            hints.push("NOWAIT");
+        }
+
+        return Ok((with_table_hints(family, model, &sql, &hints), params));
+    }
+
+    if !sql_info.supports_locking_options {
+        statement.push_str(match lock.mode {
+            LockMode::Update => " FOR UPDATE",
+            LockMode::Share => " LOCK IN SHARE MODE",
+        });
+
+        return Ok(statement.into_parts());
+    }
+
+    statement.push_str(match lock.mode {
+        LockMode::Update => " FOR UPDATE",
+        LockMode::Share => " FOR SHARE",
+    });
+
+    statement.push_str(" OF ");
+    statement.push_ident(model.db_name());
+
+    if lock.skip_locked {
+        statement.push_str(" SKIP LOCKED");
+    } else if lock.no_wait {
+        statement.push_str(" NOWAIT");
+    }
+
+    Ok(statement.into_parts())
+}
+
+/// Adds the SQL Server table hints to the table of the model the rendered select reads from. Relation aggregations,
+/// selected before it, alias the table of the model when reading from it, and everything else of the select follows
+/// it, so it's the first occurrence of the table without an alias.
+fn with_table_hints(family: &SqlFamily, model: &ModelRef, sql: &str, hints: &[&str]) -> String {
+    let mut table = Statement::new(family);
+    table.push_str(" FROM ");
+    table.push_ident(&model.schema_name());
+    table.push_str(".");
+    table.push_ident(model.db_name());
+
+    let (from, _) = table.into_parts();
+
+    let position = sql
+        .match_indices(&from)
+        .map(|(index, _)| index + from.len())
+        .find(|end| !sql[*end..].starts_with(" AS "))
+        .expect("The select reads from the table of the model.");
+
+    format!("{} WITH ({}){}", &sql[..position], hints.join(", "), &sql[position..])
+}
@@ -0,0 +1,239 @@
+//! Reads records together with their relations in a single statement. The related records of each record are
+//! aggregated into a JSON array by a correlated subquery, e.g. on Postgres:
+//! ```sql
+//! SELECT "rel_0"."id", (
+//!     SELECT COALESCE(jsonb_agg(jsonb_build_object(
+//!         '__prisma_ordinal', "rel_1"."__prisma_ordinal",
+//!         'id', "rel_1"."id"
+//!     )), '[]')
+//!     FROM (
+//!         SELECT ROW_NUMBER() OVER (ORDER BY "Post"."id" ASC) AS "__prisma_ordinal", "Post"."id"
+//!         FROM "Post"
+//!         WHERE "Post"."authorId" = "rel_0"."id"
+//!     ) AS "rel_1"
+//! ) AS "posts"
+//! FROM (
+//!     SELECT ROW_NUMBER() OVER (ORDER BY "User"."id" ASC) AS "__prisma_ordinal", "User"."id"
+//!     FROM "User"
+//! ) AS "rel_0"
+//! ORDER BY "rel_0"."__prisma_ordinal" ASC
+//! ```
+//! JSON aggregation doesn't keep the order of the aggregated records, so every related record carries its position
+//! as `__prisma_ordinal`. The query AST has no JSON functions, which is why the statement is assembled from fragments
+//! rendered by the visitor of the database.
+
+use super::{
+    read::{self, SelectDefinition},
+    statement::Statement,
+};
+use crate::{ordering, query_arguments_ext::QueryArgumentsExt, sql_info::SqlFamily};
+use connector_interface::{QueryArguments, RelationSelection};
+use prisma_models::*;
+use quaint::ast::*;
+
+pub static ORDINAL_ALIAS: &str = "__prisma_ordinal";
+static TABLE_ALIAS_PREFIX: &str = "rel_";
+
+/// Postgres functions take at most 100 arguments, so objects with more keys are built in parts.
+const POSTGRES_MAX_OBJECT_KEYS: usize = 50;
+
+/// Builds the statement reading the records of the model, with the JSON array of the related records of each
+/// relation selection as an additional column, in the order of the selections.
+pub fn get_records_with_relations(
+    family: &SqlFamily,
+    model: &ModelRef,
+    query_arguments: QueryArguments,
+    selected_fields: &ModelProjection,
+    relation_selections: &[RelationSelection],
+) -> crate::Result<(String, Vec<Value<'static>>)> {
+    let mut statement = Statement::new(family);
+    let alias = table_alias(0);
+
+    statement.push_str("SELECT ");
+
+    for (index, name) in selected_fields.db_names().enumerate() {
+        if index > 0 {
+            statement.push_str(", ");
+        }
+
+        statement.push_column(&alias, &name);
+    }
+
+    for selection in relation_selections {
+        statement.push_str(", ");
+        push_relation(&mut statement, selection, &alias, 1)?;
+        statement.push_str(" AS ");
+        statement.push_ident(&selection.field.name);
+    }
+
+    statement.push_str(" FROM (");
+    statement.push_query(numbered_select(model, query_arguments, selected_fields, None))?;
+    statement.push_str(") AS ");
+    statement.push_ident(&alias);
+    statement.push_str(" ORDER BY ");
+    statement.push_column(&alias, ORDINAL_ALIAS);
+    statement.push_str(" ASC");
+
+    Ok(statement.into_parts())
+}
+
+/// Renders the subquery aggregating the related records of the selection for the record of the parent alias.
+fn push_relation(
+    statement: &mut Statement<'_>,
+    selection: &RelationSelection,
+    parent_alias: &str,
+    depth: usize,
+) -> crate::Result<()> {
+    let alias = table_alias(depth);
+    let related_model = selection.field.related_model();
+    let conditions = relation_conditions(&selection.field, parent_alias);
+
+    statement.push_str("(SELECT COALESCE(");
+    statement.push_str(statement.json_array_agg());
+    statement.push_str("(");
+    push_object(statement, selection, &alias, depth)?;
+    statement.push_str("), ");
+    statement.push_str(statement.empty_json_array());
+    statement.push_str(") FROM (");
+    statement.push_query(numbered_select(
+        &related_model,
+        selection.args.clone(),
+        &selection.selected_fields,
+        Some(conditions),
+    ))?;
+    statement.push_str(") AS ");
+    statement.push_ident(&alias);
+    statement.push_str(")");
+
+    Ok(())
+}
+
+enum ObjectValue<'a> {
+    Column(String),
+    Relation(&'a RelationSelection),
+}
+
+/// Renders the JSON object of a related record, keyed by field names.
+fn push_object(
+    statement: &mut Statement<'_>,
+    selection: &RelationSelection,
+    alias: &str,
+    depth: usize,
+) -> crate::Result<()> {
+    let mut entries = vec![(ORDINAL_ALIAS.to_owned(), ObjectValue::Column(ORDINAL_ALIAS.to_owned()))];
+
+    entries.extend(
+        selection
+            .selected_fields
+            .scalar_fields()
+            .map(|sf| (sf.name.clone(), ObjectValue::Column(sf.db_name().to_owned()))),
+    );
+
+    entries.extend(
+        selection
+            .nested
+            .iter()
+            .map(|nested| (nested.field.name.clone(), ObjectValue::Relation(nested))),
+    );
+
+    let keys_per_object = match statement.family() {
+        SqlFamily::Postgres => POSTGRES_MAX_OBJECT_KEYS,
+        _ => entries.len(),
+    };
+
+    for (index, chunk) in entries.chunks(keys_per_object).enumerate() {
+        if index > 0 {
+            statement.push_str(" || ");
+        }
+
+        statement.push_str(statement.json_object());
+        statement.push_str("(");
+
+        for (index, (key, value)) in chunk.iter().enumerate() {
+            if index > 0 {
+                statement.push_str(", ");
+            }
+
+            statement.push_literal(key);
+            statement.push_str(", ");
+
+            match value {
+                ObjectValue::Column(name) => statement.push_column(alias, name),
+                ObjectValue::Relation(nested) => push_relation(statement, nested, alias, depth + 1)?,
+            }
+        }
+
+        statement.push_str(")");
+    }
+
+    Ok(())
+}
+
+/// Selects the records with their position in the ordering of the query arguments as an additional column.
+fn numbered_select(
+    model: &ModelRef,
+    query_arguments: QueryArguments,
+    selected_fields: &ModelProjection,
+    conditions: Option<ConditionTree<'static>>,
+) -> Select<'static> {
+    let (orderings, _) = ordering::build(&query_arguments, model);
+    let orderings = read::numbering_orderings(orderings, model, query_arguments.needs_reversed_order());
+
+    let window = orderings.into_iter().fold(row_number(), |acc, ord| acc.order_by(ord));
+    let window: Expression<'static> = Function::from(window).into();
+
+    let select = query_arguments.into_select(model).value(window.alias(ORDINAL_ALIAS));
+
+    let select = match conditions {
+        Some(conditions) => select.and_where(conditions),
+        None => select,
+    };
+
+    read::get_records(model, selected_fields.as_columns(), &[], select)
+}
+
+/// Conditions correlating the related records of the relation field with the parent record of the alias.
+fn relation_conditions(field: &RelationFieldRef, parent_alias: &str) -> ConditionTree<'static> {
+    let parent_column = |sf: ScalarFieldRef| Column::from((parent_alias.to_owned(), sf.db_name().to_owned()));
+
+    if field.relation().is_many_to_many() {
+        let conditions: Vec<Expression<'static>> = field
+            .related_field()
+            .m2m_columns()
+            .into_iter()
+            .zip(field.model().primary_identifier().scalar_fields())
+            .map(|(relation_column, parent_id)| relation_column.equals(parent_column(parent_id)).into())
+            .collect();
+
+        let related_ids = Select::from_table(field.relation().as_table())
+            .columns(field.m2m_columns())
+            .so_that(ConditionTree::And(conditions));
+
+        let id_columns: Vec<Column<'static>> = field.related_model().primary_identifier().as_columns().collect();
+
+        Row::from(id_columns).in_selection(related_ids).into()
+    } else if field.is_inlined_on_enclosing_model() {
+        let conditions: Vec<Expression<'static>> = field
+            .scalar_fields()
+            .into_iter()
+            .zip(field.referenced_fields())
+            .map(|(parent_fk, referenced)| referenced.as_column().equals(parent_column(parent_fk)).into())
+            .collect();
+
+        ConditionTree::And(conditions)
+    } else {
+        let related_field = field.related_field();
+        let conditions: Vec<Expression<'static>> = related_field
+            .scalar_fields()
+            .into_iter()
+            .zip(related_field.referenced_fields())
+            .map(|(child_fk, parent_referenced)| child_fk.as_column().equals(parent_column(parent_referenced)).into())
+            .collect();
+
+        ConditionTree::And(conditions)
+    }
+}
+
+fn table_alias(depth: usize) -> String {
+    format!("{}{}", TABLE_ALIAS_PREFIX, depth)
+}
@@ -0,0 +1,165 @@
+use crate::sql_info::SqlFamily;
+use quaint::{
+    ast::*,
+    visitor::{self, Visitor},
+};
+
+/// A SQL statement assembled from text and fragments rendered by the visitor of the database, collecting the
+/// parameters of all fragments in order. Used for statements requiring SQL the query AST can't express, like JSON
+/// functions.
+pub struct Statement<'a> {
+    family: &'a SqlFamily,
+    sql: String,
+    params: Vec<Value<'static>>,
+}
+
+impl<'a> Statement<'a> {
+    pub fn new(family: &'a SqlFamily) -> Self {
+        Self {
+            family,
+            sql: String::new(),
+            params: Vec::new(),
+        }
+    }
+
+    pub fn family(&self) -> &SqlFamily {
+        self.family
+    }
+
+    pub fn into_parts(self) -> (String, Vec<Value<'static>>) {
+        (self.sql, self.params)
+    }
+
+    pub fn push_str(&mut self, s: &str) {
+        self.sql.push_str(s);
+    }
+
+    pub fn push_ident(&mut self, ident: &str) {
+        let (open, close) = match self.family {
+            SqlFamily::MySQL => ('`', '`'),
+            SqlFamily::MSSQL => ('[', ']'),
+            _ => ('"', '"'),
+        };
+
+        self.sql.push(open);
+        self.sql.push_str(&ident.replace(close, &format!("{}{}", close, close)));
+        self.sql.push(close);
+    }
+
+    pub fn push_column(&mut self, table: &str, column: &str) {
+        self.push_ident(table);
+        self.push_str(".");
+        self.push_ident(column);
+    }
+
+    pub fn push_literal(&mut self, s: &str) {
+        self.sql.push('\'');
+        self.sql.push_str(&s.replace('\'', "''"));
+        self.sql.push('\'');
+    }
+
+    /// Adds the value as a parameter of the statement.
+    pub fn push_value(&mut self, value: Value<'static>) {
+        self.params.push(value);
+
+        match self.family {
+            SqlFamily::Postgres => self.sql.push_str(&format!("${}", self.params.len())),
+            SqlFamily::MSSQL => self.sql.push_str(&format!("@P{}", self.params.len())),
+            _ => self.sql.push('?'),
+        }
+    }
+
+    /// Adds the query rendered by the visitor of the database, e.g. a subselect.
+    pub fn push_query<Q>(&mut self, query: Q) -> crate::Result<()>
+    where
+        Q: Into<Query<'static>>,
+    {
+        let (sql, params) = match self.family {
+            SqlFamily::Postgres => {
+                let (sql, params) = visitor::Postgres::build(query)?;
+                (shift_placeholders(&sql, "$", '"', self.params.len()), params)
+            }
+            SqlFamily::MSSQL => {
+                let (sql, params) = visitor::Mssql::build(query)?;
+                (shift_placeholders(&sql, "@P", ']', self.params.len()), params)
+            }
+            SqlFamily::MySQL => visitor::Mysql::build(query)?,
+            _ => unreachable!("Assembled statements with subqueries are not supported on SQLite."),
+        };
+
+        self.sql.push_str(&sql);
+        self.params.extend(params);
+
+        Ok(())
+    }
+
+    pub fn json_object(&self) -> &'static str {
+        match self.family {
+            SqlFamily::Postgres => "jsonb_build_object",
+            _ => "JSON_OBJECT",
+        }
+    }
+
+    pub fn json_array_agg(&self) -> &'static str {
+        match self.family {
+            SqlFamily::Postgres => "jsonb_agg",
+            _ => "JSON_ARRAYAGG",
+        }
+    }
+
+    pub fn empty_json_array(&self) -> &'static str {
+        match self.family {
+            SqlFamily::Postgres => "'[]'",
+            _ => "JSON_ARRAY()",
+        }
+    }
+
+    pub fn empty_json_object(&self) -> &'static str {
+        match self.family {
+            SqlFamily::Postgres => "'{}'",
+            _ => "JSON_OBJECT()",
+        }
+    }
+}
+
+/// Postgres (`$1`, `$2`, ...) and SQL Server (`@P1`, `@P2`, ...) parameters are numbered per rendered fragment, so
+/// the placeholders of a fragment have to be shifted by the number of parameters preceding it in the statement.
+/// Quoted identifiers, ending with the given quote, are skipped, values are always passed as parameters.
+fn shift_placeholders(sql: &str, marker: &str, quote: char, offset: usize) -> String {
+    if offset == 0 {
+        return sql.to_owned();
+    }
+
+    let mut result = String::with_capacity(sql.len());
+    let mut rest = sql;
+    let mut quoted = false;
+
+    while let Some(c) = rest.chars().next() {
+        if !quoted && rest.starts_with(marker) {
+            result.push_str(marker);
+            rest = &rest[marker.len()..];
+
+            let digits = rest.find(|c: char| !c.is_ascii_digit()).unwrap_or_else(|| rest.len());
+
+            match rest[..digits].parse::<usize>() {
+                Ok(position) => result.push_str(&(position + offset).to_string()),
+                Err(_) => result.push_str(&rest[..digits]),
+            }
+
+            rest = &rest[digits..];
+            continue;
+        }
+
+        match c {
+            '"' if quote == '"' => quoted = !quoted,
+            '[' if quote == ']' => quoted = true,
+            ']' if quote == ']' => quoted = false,
+            _ => (),
+        }
+
+        result.push(c);
+        rest = &rest[c.len_utf8()..];
+    }
+
+    result
+}
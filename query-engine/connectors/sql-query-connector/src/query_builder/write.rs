@@ -1,4 +1,6 @@
-use connector_interface::{DatasourceFieldName, WriteArgs, WriteExpression};
+use super::{statement::Statement, PARAMETER_LIMIT};
+use crate::{filter_conversion::AliasedCondition, sql_info::SqlFamily};
+use connector_interface::{filter::Filter, DatasourceFieldName, WriteArgs, WriteExpression};
 use prisma_models::*;
 use quaint::ast::*;
 use std::convert::TryInto;
@@ -81,10 +83,73 @@ pub fn update_many(model: &ModelRef, ids: &[&RecordProjection], args: WriteArgs)
         return Ok(Vec::new());
     }
 
+    let query = update_set(model, args);
+    let columns: Vec<_> = model.primary_identifier().as_columns().collect();
+    let result: Vec<Query> = super::chunked_conditions(&columns, ids, |conditions| query.clone().so_that(conditions));
+
+    Ok(result)
+}
+
+/// `UPDATE` all records matching the filter in a single statement, returning the primary identifiers of the updated
+/// records. Only supported on Postgres, e.g.:
+/// ```sql
+/// UPDATE "public"."Job" SET "status" = $1 WHERE "public"."Job"."status" = $2 RETURNING "id"
+/// ```
+pub fn update_many_returning(
+    family: &SqlFamily,
+    model: &ModelRef,
+    filter: Filter,
+    args: WriteArgs,
+) -> crate::Result<(String, Vec<Value<'static>>)> {
+    update_returning(family, model, filter.aliased_cond(None), args)
+}
+
+/// Same as `update_many_returning`, for the records with the given identifiers. Renders a statement per chunk of
+/// identifiers.
+pub fn update_many_returning_by_ids(
+    family: &SqlFamily,
+    model: &ModelRef,
+    ids: &[&RecordProjection],
+    args: WriteArgs,
+) -> crate::Result<Vec<(String, Vec<Value<'static>>)>> {
+    let columns: Vec<_> = model.primary_identifier().as_columns().collect();
+
+    ids.chunks(PARAMETER_LIMIT)
+        .map(|chunk| {
+            let conditions = super::conditions(&columns, chunk.iter().copied());
+            update_returning(family, model, conditions, args.clone())
+        })
+        .collect()
+}
+
+fn update_returning(
+    family: &SqlFamily,
+    model: &ModelRef,
+    conditions: ConditionTree<'static>,
+    args: WriteArgs,
+) -> crate::Result<(String, Vec<Value<'static>>)> {
+    let update = update_set(model, args).so_that(conditions);
+    let mut statement = Statement::new(family);
+
+    statement.push_query(update)?;
+    statement.push_str(" RETURNING ");
+
+    for (index, name) in model.primary_identifier().db_names().enumerate() {
+        if index > 0 {
+            statement.push_str(", ");
+        }
+
+        statement.push_ident(&name);
+    }
+
+    Ok(statement.into_parts())
+}
+
+/// The `UPDATE` of the table setting the values of the arguments, without conditions.
+fn update_set(model: &ModelRef, args: WriteArgs) -> Update<'static> {
     let scalar_fields = model.fields().scalar();
 
-    let query = args
-        .args
+    args.args
         .into_iter()
         .fold(Update::table(model.as_table()), |acc, (field_name, val)| {
             let DatasourceFieldName(name) = field_name;
@@ -115,15 +180,408 @@ pub fn update_many(model: &ModelRef, ids: &[&RecordProjection], args: WriteArgs)
                     let e: Expression<'_> = Column::from(name.clone()).into();
                     e / field.value(rhs).into()
                 }
+
+                WriteExpression::Unset => field.value(PrismaValue::Null).into(),
+
+                expr => unreachable!(
+                    "Scalar list and Json operations are rendered by `render_update_many`, got {:?}.",
+                    expr
+                ),
             };
 
             acc.set(name, value)
+        })
+}
+
+/// Whether updating with the arguments requires `render_update_many` instead of `update_many`.
+pub fn requires_rendered_update(args: &WriteArgs) -> bool {
+    args.args.values().any(WriteExpression::is_list_or_json_operation)
+}
+
+/// Renders the `UPDATE` statements of `update_many` directly to SQL. Scalar list and Json operations require database
+/// functions the query AST doesn't support, e.g. on Postgres:
+/// ```sql
+/// UPDATE "public"."Post"
+/// SET "tags" = array_remove("tags", $1), "meta" = jsonb_set(COALESCE("meta", '{}'), $2, CAST($3 AS jsonb))
+/// WHERE ("id") IN (($4), ($5))
+/// ```
+/// Scalar lists are only supported on Postgres, Json operations on Postgres and MySQL.
+pub fn render_update_many(
+    family: &SqlFamily,
+    model: &ModelRef,
+    ids: &[&RecordProjection],
+    args: WriteArgs,
+) -> Vec<(String, Vec<Value<'static>>)> {
+    if args.args.is_empty() || ids.is_empty() {
+        return Vec::new();
+    }
+
+    let scalar_fields = model.fields().scalar();
+    let id_names: Vec<String> = model.primary_identifier().db_names().collect();
+
+    ids.chunks(PARAMETER_LIMIT)
+        .map(|chunk| {
+            let mut statement = Statement::new(family);
+
+            statement.push_str("UPDATE ");
+            push_table(&mut statement, model);
+            statement.push_str(" SET ");
+
+            for (index, (DatasourceFieldName(name), expr)) in args.args.iter().enumerate() {
+                let field = scalar_fields
+                    .iter()
+                    .find(|f| f.db_name() == name.as_str())
+                    .expect("Expected field to be valid");
+
+                if index > 0 {
+                    statement.push_str(", ");
+                }
+
+                statement.push_ident(name);
+                statement.push_str(" = ");
+                push_write_expression(&mut statement, field, expr.clone());
+            }
+
+            statement.push_str(" WHERE ");
+            push_row(&mut statement, id_names.iter(), |statement, name| {
+                statement.push_ident(name)
+            });
+            statement.push_str(" IN (");
+
+            for (index, id) in chunk.iter().enumerate() {
+                if index > 0 {
+                    statement.push_str(", ");
+                }
+
+                push_row(&mut statement, id.db_values().into_iter(), |statement, value| {
+                    statement.push_value(value)
+                });
+            }
+
+            statement.push_str(")");
+            statement.into_parts()
+        })
+        .collect()
+}
+
+/// Renders an upsert of the rows, inserting the rows that don't conflict with an existing record on the primary
+/// identifier and updating the given columns of the conflicting records with the values of the row, e.g. on Postgres:
+/// ```sql
+/// INSERT INTO "public"."User" ("id", "name") VALUES ($1, $2), ($3, $4)
+/// ON CONFLICT ("id") DO UPDATE SET "name" = EXCLUDED."name"
+/// ```
+/// MySQL uses `ON DUPLICATE KEY UPDATE`, SQL Server a `MERGE` statement. All rows must set the same fields, the
+/// `update_fields` among them.
+pub fn upsert_records(
+    family: &SqlFamily,
+    model: &ModelRef,
+    args: Vec<WriteArgs>,
+    update_fields: &[DatasourceFieldName],
+) -> (String, Vec<Value<'static>>) {
+    let fields: Vec<ScalarFieldRef> = model
+        .fields()
+        .scalar()
+        .into_iter()
+        .filter(|field| {
+            args.first()
+                .map(|arg| arg.has_arg_for(field.db_name()))
+                .unwrap_or(false)
+        })
+        .collect();
+
+    let id_fields: Vec<ScalarFieldRef> = model.primary_identifier().scalar_fields().collect();
+    let update_fields: Vec<&ScalarFieldRef> = fields
+        .iter()
+        .filter(|field| !id_fields.contains(field) && update_fields.iter().any(|name| name.0 == field.db_name()))
+        .collect();
+
+    let rows = args.into_iter().map(|mut arg| {
+        fields
+            .iter()
+            .map(|field| {
+                let value: PrismaValue = arg
+                    .take_field_value(field.db_name())
+                    .expect("All upserted rows must set the same fields.")
+                    .try_into()
+                    .expect("Upserts can only use PrismaValue write expressions.");
+
+                field.value(value)
+            })
+            .collect::<Vec<_>>()
+    });
+
+    let mut statement = Statement::new(family);
+    let column_names = || fields.iter().map(|field| field.db_name());
+
+    if let SqlFamily::MSSQL = family {
+        let all_ids_set = id_fields.iter().all(|id| fields.contains(id));
+
+        statement.push_str("MERGE INTO ");
+        push_table(&mut statement, model);
+        statement.push_str(" AS [target] USING (VALUES ");
+        push_values(&mut statement, rows);
+        statement.push_str(") AS [source] ");
+        push_row(&mut statement, column_names(), |statement, name| {
+            statement.push_ident(name)
         });
 
-    let columns: Vec<_> = model.primary_identifier().as_columns().collect();
-    let result: Vec<Query> = super::chunked_conditions(&columns, ids, |conditions| query.clone().so_that(conditions));
+        // Rows without an identifier can't conflict with an existing record.
+        statement.push_str(" ON ");
 
-    Ok(result)
+        if all_ids_set {
+            for (index, id) in id_fields.iter().enumerate() {
+                if index > 0 {
+                    statement.push_str(" AND ");
+                }
+
+                statement.push_column("target", id.db_name());
+                statement.push_str(" = ");
+                statement.push_column("source", id.db_name());
+            }
+        } else {
+            statement.push_str("1 = 0");
+        }
+
+        if !update_fields.is_empty() {
+            statement.push_str(" WHEN MATCHED THEN UPDATE SET ");
+
+            for (index, field) in update_fields.iter().enumerate() {
+                if index > 0 {
+                    statement.push_str(", ");
+                }
+
+                statement.push_column("target", field.db_name());
+                statement.push_str(" = ");
+                statement.push_column("source", field.db_name());
+            }
+        }
+
+        statement.push_str(" WHEN NOT MATCHED THEN INSERT ");
+        push_row(&mut statement, column_names(), |statement, name| {
+            statement.push_ident(name)
+        });
+        statement.push_str(" VALUES ");
+        push_row(&mut statement, column_names(), |statement, name| {
+            statement.push_column("source", name)
+        });
+        statement.push_str(";");
+
+        return statement.into_parts();
+    }
+
+    statement.push_str("INSERT INTO ");
+    push_table(&mut statement, model);
+    statement.push_str(" ");
+    push_row(&mut statement, column_names(), |statement, name| {
+        statement.push_ident(name)
+    });
+    statement.push_str(" VALUES ");
+    push_values(&mut statement, rows);
+
+    match family {
+        SqlFamily::MySQL => {
+            // Without fields to update, setting the first identifier to itself keeps the existing record.
+            let update_fields: Vec<&ScalarFieldRef> = if update_fields.is_empty() {
+                id_fields.iter().take(1).collect()
+            } else {
+                update_fields
+            };
+
+            statement.push_str(" ON DUPLICATE KEY UPDATE ");
+
+            for (index, field) in update_fields.into_iter().enumerate() {
+                if index > 0 {
+                    statement.push_str(", ");
+                }
+
+                statement.push_ident(field.db_name());
+                statement.push_str(" = VALUES(");
+                statement.push_ident(field.db_name());
+                statement.push_str(")");
+            }
+        }
+        _ => {
+            statement.push_str(" ON CONFLICT ");
+            push_row(&mut statement, id_fields.iter(), |statement, field| {
+                statement.push_ident(field.db_name())
+            });
+
+            if update_fields.is_empty() {
+                statement.push_str(" DO NOTHING");
+            } else {
+                statement.push_str(" DO UPDATE SET ");
+
+                for (index, field) in update_fields.into_iter().enumerate() {
+                    if index > 0 {
+                        statement.push_str(", ");
+                    }
+
+                    statement.push_ident(field.db_name());
+                    statement.push_str(" = ");
+                    statement.push_column("excluded", field.db_name());
+                }
+            }
+        }
+    }
+
+    statement.into_parts()
+}
+
+fn push_table(statement: &mut Statement<'_>, model: &ModelRef) {
+    statement.push_ident(&model.schema_name());
+    statement.push_str(".");
+    statement.push_ident(model.db_name());
+}
+
+fn push_values(statement: &mut Statement<'_>, rows: impl Iterator<Item = Vec<Value<'static>>>) {
+    for (index, row) in rows.enumerate() {
+        if index > 0 {
+            statement.push_str(", ");
+        }
+
+        push_row(statement, row.into_iter(), |statement, value| {
+            statement.push_value(value)
+        });
+    }
+}
+
+fn push_row<T>(statement: &mut Statement<'_>, items: impl Iterator<Item = T>, push: impl Fn(&mut Statement<'_>, T)) {
+    statement.push_str("(");
+
+    for (index, item) in items.enumerate() {
+        if index > 0 {
+            statement.push_str(", ");
+        }
+
+        push(statement, item);
+    }
+
+    statement.push_str(")");
+}
+
+fn push_write_expression(statement: &mut Statement<'_>, field: &ScalarFieldRef, expr: WriteExpression) {
+    let column = field.db_name();
+    let is_postgres = matches!(statement.family(), SqlFamily::Postgres);
+
+    let arithmetic = |statement: &mut Statement<'_>, operator: &str, rhs: PrismaValue| {
+        statement.push_str("(");
+        statement.push_ident(column);
+        statement.push_str(operator);
+        statement.push_value(field.value(rhs));
+        statement.push_str(")");
+    };
+
+    match expr {
+        WriteExpression::Field(_) => unimplemented!(),
+        WriteExpression::Value(rhs) => statement.push_value(field.value(rhs)),
+        WriteExpression::Add(rhs) => arithmetic(statement, " + ", rhs),
+        WriteExpression::Substract(rhs) => arithmetic(statement, " - ", rhs),
+        WriteExpression::Multiply(rhs) => arithmetic(statement, " * ", rhs),
+        WriteExpression::Divide(rhs) => arithmetic(statement, " / ", rhs),
+        WriteExpression::Unset => statement.push_str("NULL"),
+
+        // Concatenating with a `NULL` array results in the other array.
+        WriteExpression::Push(values) => {
+            statement.push_ident(column);
+            statement.push_str(" || ");
+            statement.push_value(field.value(values));
+        }
+
+        WriteExpression::Remove(values) => {
+            let values = match values {
+                PrismaValue::List(values) => values,
+                value => vec![value],
+            };
+
+            statement.push_str(&"array_remove(".repeat(values.len()));
+            statement.push_ident(column);
+
+            for value in values {
+                statement.push_str(", ");
+                statement.push_value(field.value(value));
+                statement.push_str(")");
+            }
+        }
+
+        // The top-level keys of the object replace the ones of the field, nested objects are not merged.
+        WriteExpression::JsonMerge(value) => {
+            let empty_object = statement.empty_json_object();
+
+            if is_postgres {
+                statement.push_str("COALESCE(");
+                statement.push_ident(column);
+                statement.push_str(&format!(", {}) || CAST(", empty_object));
+                statement.push_value(field.value(value));
+                statement.push_str(" AS jsonb)");
+            } else {
+                // `JSON_MERGE_PATCH` would merge nested objects and remove keys set to `null`.
+                let object = match value {
+                    PrismaValue::Json(json) => serde_json::from_str::<serde_json::Map<_, _>>(&json)
+                        .expect("Json values are only merged with objects."),
+                    _ => unreachable!("Json values are only merged with objects."),
+                };
+
+                let is_empty = object.is_empty();
+
+                if !is_empty {
+                    statement.push_str("JSON_SET(");
+                }
+
+                statement.push_str("COALESCE(");
+                statement.push_ident(column);
+                statement.push_str(&format!(", {})", empty_object));
+
+                for (key, value) in object {
+                    statement.push_str(", ");
+                    statement.push_value(Value::text(format!("${}", mysql_json_member(&key))));
+                    statement.push_str(", CAST(");
+                    statement.push_value(field.value(PrismaValue::Json(value.to_string())));
+                    statement.push_str(" AS JSON)");
+                }
+
+                if !is_empty {
+                    statement.push_str(")");
+                }
+            }
+        }
+
+        WriteExpression::JsonSet { path, value } => {
+            let empty_object = statement.empty_json_object();
+            let (function, cast, path) = if is_postgres {
+                let path = path.into_iter().map(Value::text).collect();
+                ("jsonb_set", "jsonb", Value::Array(Some(path)))
+            } else {
+                ("JSON_SET", "JSON", Value::text(mysql_json_path(&path)))
+            };
+
+            statement.push_str(function);
+            statement.push_str("(COALESCE(");
+            statement.push_ident(column);
+            statement.push_str(&format!(", {}), ", empty_object));
+            statement.push_value(path);
+            statement.push_str(", CAST(");
+            statement.push_value(field.value(value));
+            statement.push_str(&format!(" AS {}))", cast));
+        }
+    }
+}
+
+/// Renders a path like `["a", "0"]` as the MySQL path `$."a"[0]`, numeric keys index into arrays.
+fn mysql_json_path(path: &[String]) -> String {
+    path.iter().fold(String::from("$"), |mut acc, key| {
+        if !key.is_empty() && key.chars().all(|c| c.is_ascii_digit()) {
+            acc.push_str(&format!("[{}]", key));
+        } else {
+            acc.push_str(&mysql_json_member(key));
+        }
+
+        acc
+    })
+}
+
+/// Renders the object key `a` as the MySQL path leg `."a"`.
+fn mysql_json_member(key: &str) -> String {
+    format!(".\"{}\"", key.replace('\\', "\\\\").replace('"', "\\\""))
 }
 
 pub fn delete_many(model: &ModelRef, ids: &[&RecordProjection]) -> Vec<Query<'static>> {
@@ -177,3 +635,15 @@ pub fn delete_relation_table_records(
 
     Delete::from_table(relation.as_table()).so_that(parent_id_criteria.and(child_id_criteria))
 }
+
+pub fn delete_relation_table_records_of_parents(
+    parent_field: &RelationFieldRef,
+    parent_ids: &[&RecordProjection],
+) -> Vec<Query<'static>> {
+    let relation = parent_field.relation();
+    let parent_columns: Vec<_> = parent_field.related_field().m2m_columns();
+
+    super::chunked_conditions(&parent_columns, parent_ids, |conditions| {
+        Delete::from_table(relation.as_table()).so_that(conditions)
+    })
+}
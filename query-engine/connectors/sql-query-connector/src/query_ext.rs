@@ -112,18 +112,16 @@ pub trait QueryExt: Queryable + Send + Sync {
     }
 
     async fn select_ids(&self, select: Select<'_>, model_id: ModelProjection) -> crate::Result<Vec<RecordProjection>> {
-        let idents: Vec<_> = model_id
-            .fields()
-            .flat_map(|f| match f {
-                Field::Scalar(sf) => vec![sf.type_identifier_with_arity()],
-                Field::Relation(rf) => rf.type_identifiers_with_arities(),
-            })
-            .collect();
-
-        let field_names: Vec<_> = model_id.fields().map(|field| field.name()).collect();
+        self.query_ids(select.into(), model_id).await
+    }
+
+    /// Read the rows of a query only returning the columns of the (primary) identifier, e.g. an `INSERT` with
+    /// `RETURNING`.
+    async fn query_ids(&self, q: Query<'_>, model_id: ModelProjection) -> crate::Result<Vec<RecordProjection>> {
+        let (field_names, idents) = id_metadata(&model_id);
         let meta = column_metadata::create(field_names.as_slice(), &idents);
 
-        let mut rows = self.filter(select.into(), &meta).await?;
+        let mut rows = self.filter(q, &meta).await?;
         let mut result = Vec::new();
 
         for row in rows.drain(0..) {
@@ -135,4 +133,41 @@ pub trait QueryExt: Queryable + Send + Sync {
 
         Ok(result)
     }
+
+    /// Read the rows of a raw SQL statement only returning the columns of the (primary) identifier, e.g. an
+    /// assembled `UPDATE` with `RETURNING`.
+    async fn query_raw_ids(
+        &self,
+        sql: &str,
+        params: &[Value<'_>],
+        model_id: ModelProjection,
+    ) -> crate::Result<Vec<RecordProjection>> {
+        let (field_names, idents) = id_metadata(&model_id);
+        let meta = column_metadata::create(field_names.as_slice(), &idents);
+        let mut result = Vec::new();
+
+        for row in self.query_raw(sql, params).await? {
+            let values = row.to_sql_row(&meta)?.values;
+            let tuples: Vec<_> = model_id.scalar_fields().zip(values.into_iter()).collect();
+
+            result.push(RecordProjection::new(tuples));
+        }
+
+        Ok(result)
+    }
+}
+
+/// The field names and types of the columns of a (primary) identifier.
+fn id_metadata(model_id: &ModelProjection) -> (Vec<&str>, Vec<(TypeIdentifier, FieldArity)>) {
+    let idents: Vec<_> = model_id
+        .fields()
+        .flat_map(|f| match f {
+            Field::Scalar(sf) => vec![sf.type_identifier_with_arity()],
+            Field::Relation(rf) => rf.type_identifiers_with_arities(),
+        })
+        .collect();
+
+    let field_names: Vec<_> = model_id.fields().map(|field| field.name()).collect();
+
+    (field_names, idents)
 }
@@ -1,5 +1,6 @@
 use quaint::prelude::ConnectionInfo;
 
+#[derive(Debug, Clone, Copy)]
 pub enum SqlFamily {
     SQLite,
     Postgres,
@@ -8,6 +9,7 @@ pub enum SqlFamily {
 }
 
 /// Contains meta information about the loaded connector.
+#[derive(Debug, Clone, Copy)]
 pub struct SqlInfo {
     /// SQL family the connector belongs to.
     pub family: SqlFamily,
@@ -19,6 +21,19 @@ pub struct SqlInfo {
     /// Maximum number of bind parameters allowed for a single query.
     /// None is unlimited.
     pub max_bind_values: Option<usize>,
+
+    /// Whether window functions, e.g. `ROW_NUMBER() OVER (PARTITION BY ...)`, can be used.
+    /// MySQL only supports them from version 8 on, and they are never used on MariaDB.
+    pub supports_window_functions: bool,
+
+    /// Whether relations can be read in the same query as their parents by aggregating them into JSON.
+    /// On MySQL, this requires version 8.0.14, the first to allow derived tables referencing the enclosing
+    /// query, and is never the case on MariaDB.
+    pub supports_relation_joins: bool,
+
+    /// Whether locking reads can lock the rows of a single table, and skip or fail on locked rows, e.g.
+    /// `FOR UPDATE OF "Job" SKIP LOCKED`. MySQL only supports this from version 8.0.1 on.
+    pub supports_locking_options: bool,
 }
 
 impl SqlInfo {
@@ -27,6 +42,9 @@ impl SqlInfo {
             family: SqlFamily::SQLite,
             max_rows: Some(999),
             max_bind_values: Some(999),
+            supports_window_functions: true,
+            supports_relation_joins: false,
+            supports_locking_options: false,
         }
     }
 
@@ -35,6 +53,22 @@ impl SqlInfo {
             family: SqlFamily::MySQL,
             max_rows: None,
             max_bind_values: None,
+            supports_window_functions: false,
+            supports_relation_joins: false,
+            supports_locking_options: false,
+        }
+    }
+
+    /// The meta information of a MySQL server reporting the given version, e.g. `8.0.23` or
+    /// `10.5.8-MariaDB`. Features depending on the version are turned off if the version is unknown.
+    pub fn mysql_with_version(version: Option<&str>) -> Self {
+        let version = version.filter(|v| !v.contains("MariaDB")).and_then(mysql_version);
+
+        Self {
+            supports_window_functions: version.map(|v| v >= (8, 0, 0)).unwrap_or(false),
+            supports_relation_joins: version.map(|v| v >= (8, 0, 14)).unwrap_or(false),
+            supports_locking_options: version.map(|v| v >= (8, 0, 1)).unwrap_or(false),
+            ..Self::mysql()
         }
     }
 
@@ -43,6 +77,9 @@ impl SqlInfo {
             family: SqlFamily::Postgres,
             max_rows: None,
             max_bind_values: Some(32767),
+            supports_window_functions: true,
+            supports_relation_joins: true,
+            supports_locking_options: true,
         }
     }
 
@@ -51,6 +88,9 @@ impl SqlInfo {
             family: SqlFamily::MSSQL,
             max_rows: Some(1000),
             max_bind_values: Some(2099),
+            supports_window_functions: true,
+            supports_relation_joins: false,
+            supports_locking_options: false,
         }
     }
 }
@@ -66,3 +106,16 @@ impl From<&ConnectionInfo> for SqlInfo {
         }
     }
 }
+
+/// The numeric `(major, minor, patch)` prefix of a MySQL version string, e.g. `5.7.33-log`.
+fn mysql_version(version: &str) -> Option<(u32, u32, u32)> {
+    let mut parts = version
+        .split(|c: char| !c.is_ascii_digit())
+        .take(3)
+        .map(|part| part.parse::<u32>().ok());
+
+    match (parts.next(), parts.next(), parts.next()) {
+        (Some(Some(major)), Some(Some(minor)), Some(Some(patch))) => Some((major, minor, patch)),
+        _ => None,
+    }
+}
@@ -38,6 +38,9 @@ pub enum CoreError {
 
     #[error("{}", _0)]
     ConfigurationError(String),
+
+    #[error("Transaction API error: {}", _0)]
+    InteractiveTransactionError(String),
 }
 
 impl From<QueryGraphBuilderError> for CoreError {
@@ -152,6 +155,12 @@ impl From<CoreError> for user_facing_errors::Error {
             CoreError::QueryGraphBuilderError(QueryGraphBuilderError::InputError(details)) => {
                 user_facing_errors::KnownError::new(user_facing_errors::query_engine::InputError { details }).into()
             }
+            CoreError::InteractiveTransactionError(error) => {
+                user_facing_errors::KnownError::new(user_facing_errors::query_engine::InteractiveTransactionError {
+                    error,
+                })
+                .into()
+            }
             CoreError::InterpreterError(InterpreterError::InterpretationError(msg, Some(cause))) => {
                 match cause.as_ref() {
                     InterpreterError::QueryGraphBuilderError(QueryGraphBuilderError::RecordNotFound(cause)) => {
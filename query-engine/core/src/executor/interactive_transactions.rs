@@ -0,0 +1,482 @@
+//! Interactive transactions: the client opens a transaction, sends any number of operations to it,
+//! possibly with application logic in between, and finally commits or rolls it back.
+//!
+//! Every open transaction is owned by a dedicated task holding the connection and the
+//! `connector::Transaction`. Requests reach that task through a channel, which keeps the
+//! transaction on a single connection no matter which request handler the operations arrive on.
+use super::pipeline::QueryPipeline;
+use crate::{CoreError, Operation, QueryGraphBuilder, QueryInterpreter, QuerySchemaRef, ResponseData};
+use connector::{Connection, ConnectionLike, Transaction};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    fmt,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+use tokio::sync::{mpsc, oneshot};
+
+/// How many requests for a single transaction can be queued before senders have to wait.
+const TX_CHANNEL_CAPACITY: usize = 16;
+
+/// Identifier of an open interactive transaction, handed out to the client when the transaction starts.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct TxId(String);
+
+impl Default for TxId {
+    fn default() -> Self {
+        Self(uuid::Uuid::new_v4().to_string())
+    }
+}
+
+impl From<String> for TxId {
+    fn from(s: String) -> Self {
+        Self(s)
+    }
+}
+
+impl From<&str> for TxId {
+    fn from(s: &str) -> Self {
+        Self(s.to_owned())
+    }
+}
+
+impl fmt::Display for TxId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+enum TxMessage {
+    Execute {
+        operation: Operation,
+        query_schema: QuerySchemaRef,
+        reply: oneshot::Sender<crate::Result<ResponseData>>,
+    },
+    Commit(oneshot::Sender<crate::Result<()>>),
+    Rollback(oneshot::Sender<crate::Result<()>>),
+}
+
+/// Keeps track of the open interactive transactions of an executor.
+#[derive(Default)]
+pub struct TransactionManager {
+    open_transactions: Arc<Mutex<HashMap<TxId, mpsc::Sender<TxMessage>>>>,
+}
+
+impl TransactionManager {
+    /// Starts a transaction on the given connection. The transaction is rolled back if it is
+    /// neither committed nor rolled back before `timeout` elapses.
+    pub async fn start(&self, conn: Box<dyn Connection + Send + Sync>, timeout: Duration) -> crate::Result<TxId> {
+        let tx_id = TxId::default();
+        let (sender, receiver) = mpsc::channel(TX_CHANNEL_CAPACITY);
+        let (started_sender, started) = oneshot::channel();
+
+        self.open_transactions.lock().unwrap().insert(tx_id.clone(), sender);
+
+        let open_transactions = Arc::clone(&self.open_transactions);
+        let task_tx_id = tx_id.clone();
+
+        tokio::spawn(async move {
+            run_transaction(conn, receiver, timeout, started_sender, &task_tx_id).await;
+            open_transactions.lock().unwrap().remove(&task_tx_id);
+        });
+
+        started.await.map_err(|_| closed_transaction_error(&tx_id))??;
+
+        Ok(tx_id)
+    }
+
+    /// Executes a single operation inside of the given transaction.
+    pub async fn execute(
+        &self,
+        tx_id: &TxId,
+        operation: Operation,
+        query_schema: QuerySchemaRef,
+    ) -> crate::Result<ResponseData> {
+        let (reply, response) = oneshot::channel();
+
+        self.send(
+            tx_id,
+            TxMessage::Execute {
+                operation,
+                query_schema,
+                reply,
+            },
+        )
+        .await?;
+
+        response.await.map_err(|_| closed_transaction_error(tx_id))?
+    }
+
+    pub async fn commit(&self, tx_id: &TxId) -> crate::Result<()> {
+        let (reply, response) = oneshot::channel();
+        self.send(tx_id, TxMessage::Commit(reply)).await?;
+
+        response.await.map_err(|_| closed_transaction_error(tx_id))?
+    }
+
+    pub async fn rollback(&self, tx_id: &TxId) -> crate::Result<()> {
+        let (reply, response) = oneshot::channel();
+        self.send(tx_id, TxMessage::Rollback(reply)).await?;
+
+        response.await.map_err(|_| closed_transaction_error(tx_id))?
+    }
+
+    async fn send(&self, tx_id: &TxId, message: TxMessage) -> crate::Result<()> {
+        let sender = self
+            .open_transactions
+            .lock()
+            .unwrap()
+            .get(tx_id)
+            .cloned()
+            .ok_or_else(|| closed_transaction_error(tx_id))?;
+
+        sender.send(message).await.map_err(|_| closed_transaction_error(tx_id))
+    }
+}
+
+/// The body of the task owning an interactive transaction. Returns once the transaction is
+/// committed, rolled back, or expired.
+async fn run_transaction(
+    conn: Box<dyn Connection + Send + Sync>,
+    mut receiver: mpsc::Receiver<TxMessage>,
+    timeout: Duration,
+    started: oneshot::Sender<crate::Result<()>>,
+    tx_id: &TxId,
+) {
+    let tx = match conn.start_transaction().await {
+        Ok(tx) => {
+            let _ = started.send(Ok(()));
+            tx
+        }
+        Err(err) => {
+            let _ = started.send(Err(err.into()));
+            return;
+        }
+    };
+
+    let expiration = tokio::time::sleep(timeout);
+    tokio::pin!(expiration);
+
+    loop {
+        tokio::select! {
+            _ = &mut expiration => {
+                debug!("Interactive transaction {} expired after {:?}, rolling back.", tx_id, timeout);
+
+                if let Err(err) = tx.rollback().await {
+                    error!("Failed to roll back expired transaction {}: {}", tx_id, err);
+                }
+
+                return;
+            }
+            message = receiver.recv() => match message {
+                Some(TxMessage::Execute { operation, query_schema, reply }) => {
+                    let result = execute_in_transaction(tx.as_ref(), operation, query_schema).await;
+                    let _ = reply.send(result);
+                }
+                Some(TxMessage::Commit(reply)) => {
+                    let _ = reply.send(tx.commit().await.map_err(CoreError::from));
+                    return;
+                }
+                Some(TxMessage::Rollback(reply)) => {
+                    let _ = reply.send(tx.rollback().await.map_err(CoreError::from));
+                    return;
+                }
+                // The manager is gone, nobody can finish the transaction anymore.
+                None => {
+                    let _ = tx.rollback().await;
+                    return;
+                }
+            }
+        }
+    }
+}
+
+async fn execute_in_transaction(
+    tx: &dyn Transaction,
+    operation: Operation,
+    query_schema: QuerySchemaRef,
+) -> crate::Result<ResponseData> {
+    let (query_graph, serializer) = QueryGraphBuilder::new(query_schema).build(operation)?;
+    let interpreter = QueryInterpreter::new(ConnectionLike::Transaction(tx));
+
+    QueryPipeline::new(query_graph, interpreter, serializer).execute().await
+}
+
+fn closed_transaction_error(tx_id: &TxId) -> CoreError {
+    CoreError::InteractiveTransactionError(format!(
+        "Transaction `{}` does not exist or is already closed (committed, rolled back, or expired).",
+        tx_id
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use connector::{
+        AggregationRow, AggregationSelection, Filter, JoinedRecord, QueryArguments, ReadOperations, RecordFilter,
+        RelAggregationSelection, RelationSelection, UpsertArgs, WriteArgs, WriteOperations,
+    };
+    use prisma_models::{
+        ManyRecords, ModelProjection, ModelRef, PrismaValue, RecordProjection, RelationFieldRef, ScalarFieldRef,
+        SingleRecord,
+    };
+
+    /// A connection without a database, recording how its transactions end. Only the transaction
+    /// lifecycle is exercised, the read and write operations are never called.
+    #[derive(Clone, Default)]
+    struct MockConnection {
+        events: Arc<Mutex<Vec<&'static str>>>,
+    }
+
+    impl MockConnection {
+        fn events(&self) -> Vec<&'static str> {
+            self.events.lock().unwrap().clone()
+        }
+    }
+
+    #[async_trait]
+    impl Connection for MockConnection {
+        async fn start_transaction<'a>(&'a self) -> connector::Result<Box<dyn Transaction + 'a>> {
+            self.events.lock().unwrap().push("start");
+            Ok(Box::new(self.clone()))
+        }
+    }
+
+    #[async_trait]
+    impl Transaction for MockConnection {
+        async fn commit(&self) -> connector::Result<()> {
+            self.events.lock().unwrap().push("commit");
+            Ok(())
+        }
+
+        async fn rollback(&self) -> connector::Result<()> {
+            self.events.lock().unwrap().push("rollback");
+            Ok(())
+        }
+    }
+
+    #[async_trait]
+    impl ReadOperations for MockConnection {
+        async fn get_single_record(
+            &self,
+            _: &ModelRef,
+            _: &Filter,
+            _: &ModelProjection,
+            _: &[RelAggregationSelection],
+        ) -> connector::Result<Option<SingleRecord>> {
+            unimplemented!()
+        }
+
+        async fn get_many_records(
+            &self,
+            _: &ModelRef,
+            _: QueryArguments,
+            _: &ModelProjection,
+            _: &[RelAggregationSelection],
+        ) -> connector::Result<ManyRecords> {
+            unimplemented!()
+        }
+
+        async fn get_many_records_per_parent(
+            &self,
+            _: &ModelRef,
+            _: QueryArguments,
+            _: &ModelProjection,
+            _: &ModelProjection,
+            _: &[RelAggregationSelection],
+        ) -> connector::Result<Option<ManyRecords>> {
+            unimplemented!()
+        }
+
+        async fn get_related_m2m_records_per_parent(
+            &self,
+            _: &RelationFieldRef,
+            _: &[RecordProjection],
+            _: QueryArguments,
+            _: &ModelProjection,
+            _: &[RelAggregationSelection],
+        ) -> connector::Result<Option<ManyRecords>> {
+            unimplemented!()
+        }
+
+        async fn get_many_records_with_relations(
+            &self,
+            _: &ModelRef,
+            _: QueryArguments,
+            _: &ModelProjection,
+            _: &[RelationSelection],
+        ) -> connector::Result<Option<Vec<JoinedRecord>>> {
+            unimplemented!()
+        }
+
+        async fn get_related_m2m_record_ids(
+            &self,
+            _: &RelationFieldRef,
+            _: &[RecordProjection],
+        ) -> connector::Result<Vec<(RecordProjection, RecordProjection)>> {
+            unimplemented!()
+        }
+
+        async fn aggregate_records(
+            &self,
+            _: &ModelRef,
+            _: QueryArguments,
+            _: Vec<AggregationSelection>,
+            _: Vec<ScalarFieldRef>,
+            _: Option<Filter>,
+        ) -> connector::Result<Vec<AggregationRow>> {
+            unimplemented!()
+        }
+    }
+
+    #[async_trait]
+    impl WriteOperations for MockConnection {
+        async fn create_record(&self, _: &ModelRef, _: WriteArgs) -> connector::Result<RecordProjection> {
+            unimplemented!()
+        }
+
+        async fn create_records(&self, _: &ModelRef, _: Vec<WriteArgs>, _: bool) -> connector::Result<usize> {
+            unimplemented!()
+        }
+
+        async fn create_records_returning(
+            &self,
+            _: &ModelRef,
+            _: Vec<WriteArgs>,
+            _: bool,
+        ) -> connector::Result<Vec<RecordProjection>> {
+            unimplemented!()
+        }
+
+        async fn upsert_records(&self, _: &ModelRef, _: Vec<UpsertArgs>) -> connector::Result<usize> {
+            unimplemented!()
+        }
+
+        async fn update_records(
+            &self,
+            _: &ModelRef,
+            _: RecordFilter,
+            _: WriteArgs,
+        ) -> connector::Result<Vec<RecordProjection>> {
+            unimplemented!()
+        }
+
+        async fn delete_records(&self, _: &ModelRef, _: RecordFilter) -> connector::Result<usize> {
+            unimplemented!()
+        }
+
+        async fn connect(
+            &self,
+            _: &RelationFieldRef,
+            _: &RecordProjection,
+            _: &[RecordProjection],
+        ) -> connector::Result<()> {
+            unimplemented!()
+        }
+
+        async fn disconnect(
+            &self,
+            _: &RelationFieldRef,
+            _: &RecordProjection,
+            _: &[RecordProjection],
+        ) -> connector::Result<()> {
+            unimplemented!()
+        }
+
+        async fn delete_relation_records(&self, _: &RelationFieldRef, _: &[RecordProjection]) -> connector::Result<()> {
+            unimplemented!()
+        }
+
+        async fn execute_raw(&self, _: String, _: Vec<PrismaValue>) -> connector::Result<usize> {
+            unimplemented!()
+        }
+
+        async fn query_raw(&self, _: String, _: Vec<PrismaValue>) -> connector::Result<serde_json::Value> {
+            unimplemented!()
+        }
+    }
+
+    const TIMEOUT: Duration = Duration::from_secs(5);
+
+    fn assert_closed(result: crate::Result<()>, tx_id: &TxId) {
+        match result {
+            Err(CoreError::InteractiveTransactionError(msg)) => {
+                assert!(msg.contains(&tx_id.to_string()), "{}", msg);
+                assert!(msg.contains("does not exist or is already closed"), "{}", msg);
+            }
+            other => panic!(
+                "Expected a closed transaction error, got {:?}",
+                other.map_err(|e| e.to_string())
+            ),
+        }
+    }
+
+    #[tokio::test]
+    async fn committing_a_transaction_commits_and_closes_it() {
+        let manager = TransactionManager::default();
+        let conn = MockConnection::default();
+
+        let tx_id = manager.start(Box::new(conn.clone()), TIMEOUT).await.unwrap();
+        manager.commit(&tx_id).await.unwrap();
+
+        assert_eq!(conn.events(), vec!["start", "commit"]);
+        assert_closed(manager.commit(&tx_id).await, &tx_id);
+        assert_closed(manager.rollback(&tx_id).await, &tx_id);
+    }
+
+    #[tokio::test]
+    async fn rolling_back_a_transaction_rolls_back_and_closes_it() {
+        let manager = TransactionManager::default();
+        let conn = MockConnection::default();
+
+        let tx_id = manager.start(Box::new(conn.clone()), TIMEOUT).await.unwrap();
+        manager.rollback(&tx_id).await.unwrap();
+
+        assert_eq!(conn.events(), vec!["start", "rollback"]);
+        assert_closed(manager.commit(&tx_id).await, &tx_id);
+    }
+
+    #[tokio::test]
+    async fn transactions_are_rolled_back_when_they_expire() {
+        let manager = TransactionManager::default();
+        let conn = MockConnection::default();
+
+        let tx_id = manager
+            .start(Box::new(conn.clone()), Duration::from_millis(10))
+            .await
+            .unwrap();
+
+        tokio::time::sleep(Duration::from_millis(200)).await;
+
+        assert_eq!(conn.events(), vec!["start", "rollback"]);
+        assert_closed(manager.commit(&tx_id).await, &tx_id);
+    }
+
+    #[tokio::test]
+    async fn transactions_are_independent_of_each_other() {
+        let manager = TransactionManager::default();
+        let first = MockConnection::default();
+        let second = MockConnection::default();
+
+        let first_id = manager.start(Box::new(first.clone()), TIMEOUT).await.unwrap();
+        let second_id = manager.start(Box::new(second.clone()), TIMEOUT).await.unwrap();
+        assert_ne!(first_id, second_id);
+
+        manager.rollback(&second_id).await.unwrap();
+        manager.commit(&first_id).await.unwrap();
+
+        assert_eq!(first.events(), vec!["start", "commit"]);
+        assert_eq!(second.events(), vec!["start", "rollback"]);
+    }
+
+    #[tokio::test]
+    async fn unknown_transaction_ids_are_rejected() {
+        let manager = TransactionManager::default();
+        let tx_id = TxId::from("does-not-exist");
+
+        assert_closed(manager.commit(&tx_id).await, &tx_id);
+        assert_closed(manager.rollback(&tx_id).await, &tx_id);
+    }
+}
@@ -46,6 +46,8 @@ impl ExpressionResult {
                     }
                 },
 
+                QueryResult::Ids(ids) if ids.iter().all(|id| model_projection.matches(id)) => Some(ids.clone()),
+
                 // We always select IDs, the unwraps are safe.
                 QueryResult::RecordSelection(rs) => Some(
                     rs.scalars
@@ -30,6 +30,18 @@ impl InMemoryRecordProcessor {
         processor
     }
 
+    /// The original query arguments if the only processing left to do is pagination without a cursor,
+    /// which connectors can apply per parent on the database level instead.
+    pub fn per_parent_pagination_args(&self) -> Option<QueryArguments> {
+        let paginates = self.take.or(self.skip).is_some();
+
+        if paginates && self.cursor.is_none() && self.distinct.is_none() {
+            Some(self.args.clone())
+        } else {
+            None
+        }
+    }
+
     fn take_abs(&self) -> Option<i64> {
         self.take.clone().map(|t| if t < 0 { -t } else { t })
     }
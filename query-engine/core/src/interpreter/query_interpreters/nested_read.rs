@@ -1,6 +1,8 @@
 use super::inmemory_record_processor::InMemoryRecordProcessor;
 use crate::{interpreter::InterpretationResult, query_ast::*};
-use connector::{self, filter::Filter, ConnectionLike, QueryArguments, ReadOperations, ScalarCompare};
+use connector::{
+    self, filter::Filter, ConnectionLike, QueryArguments, ReadOperations, RelAggregationSelection, ScalarCompare,
+};
 use prisma_models::{ManyRecords, ModelProjection, Record, RecordProjection, RelationFieldRef};
 use prisma_value::PrismaValue;
 use std::collections::HashMap;
@@ -27,6 +29,24 @@ pub async fn m2m<'a, 'b>(
     if parent_ids.is_empty() {
         return Ok(ManyRecords::empty(&query.selected_fields));
     }
+
+    // The related records can be paginated per parent by the connector, joined with the relation table.
+    if let Some(args) = processor.per_parent_pagination_args() {
+        let paginated_scalars = tx
+            .get_related_m2m_records_per_parent(
+                parent_field,
+                &parent_ids,
+                args,
+                &query.selected_fields,
+                &query.aggregation_selections,
+            )
+            .await?;
+
+        if let Some(scalars) = paginated_scalars {
+            return Ok(scalars);
+        }
+    }
+
     let ids = tx.get_related_m2m_record_ids(&query.parent_field, &parent_ids).await?;
 
     if ids.is_empty() {
@@ -44,7 +64,11 @@ pub async fn m2m<'a, 'b>(
         .collect::<std::result::Result<Vec<_>, _>>()?;
 
     // a roundtrip can be avoided if: there is no additional filter AND the selection set is the child_link_id
-    let mut scalars = if query.args.do_nothing() && child_link_id == query.selected_fields {
+    // AND no relation aggregations are selected
+    let avoid_roundtrip =
+        query.args.do_nothing() && child_link_id == query.selected_fields && query.aggregation_selections.is_empty();
+
+    let mut scalars = if avoid_roundtrip {
         ManyRecords::from_projection(child_ids, &query.selected_fields).with_unique_records()
     } else {
         let mut args = query.args.clone();
@@ -54,8 +78,13 @@ pub async fn m2m<'a, 'b>(
             Some(existing_filter) => Some(Filter::and(vec![existing_filter, filter])),
             None => Some(filter),
         };
-        tx.get_many_records(&query.parent_field.related_model(), args, &query.selected_fields)
-            .await?
+        tx.get_many_records(
+            &query.parent_field.related_model(),
+            args,
+            &query.selected_fields,
+            &query.aggregation_selections,
+        )
+        .await?
     };
 
     // Child id to parent ids
@@ -114,6 +143,7 @@ pub async fn one2m<'a, 'b>(
     parent_result: Option<&'a ManyRecords>,
     query_args: QueryArguments,
     selected_fields: &ModelProjection,
+    aggregation_selections: &[RelAggregationSelection],
     processor: InMemoryRecordProcessor,
 ) -> InterpretationResult<ManyRecords> {
     let parent_model_id = parent_field.model().primary_identifier();
@@ -165,18 +195,45 @@ pub async fn one2m<'a, 'b>(
     }
 
     // a roundtrip can be avoided if: there is no additional filter AND the selection set is the child_link_id
-    let mut scalars = if query_args.do_nothing() && &child_link_id == selected_fields {
-        ManyRecords::from_projection(uniq_projections, selected_fields).with_unique_records()
+    // AND no relation aggregations are selected
+    let avoid_roundtrip =
+        query_args.do_nothing() && &child_link_id == selected_fields && aggregation_selections.is_empty();
+
+    let (mut scalars, is_paginated) = if avoid_roundtrip {
+        let scalars = ManyRecords::from_projection(uniq_projections, selected_fields).with_unique_records();
+        (scalars, false)
     } else {
         let filter = child_link_id.is_in(uniq_projections);
-        let mut args = query_args;
-
-        args.filter = match args.filter {
-            Some(existing_filter) => Some(Filter::and(vec![existing_filter, filter])),
-            None => Some(filter),
+        let related_model = parent_field.related_model();
+
+        // Child records holding the link to their parent can be paginated per parent by the connector.
+        let paginated_scalars = match processor.per_parent_pagination_args() {
+            Some(args) if parent_field.related_field().is_inlined_on_enclosing_model() => {
+                let args = with_filter(args, filter.clone());
+
+                tx.get_many_records_per_parent(
+                    &related_model,
+                    args,
+                    &child_link_id,
+                    selected_fields,
+                    aggregation_selections,
+                )
+                .await?
+            }
+            _ => None,
         };
-        tx.get_many_records(&parent_field.related_model(), args, selected_fields)
-            .await?
+
+        match paginated_scalars {
+            Some(scalars) => (scalars, true),
+            None => {
+                let args = with_filter(query_args, filter);
+                let scalars = tx
+                    .get_many_records(&related_model, args, selected_fields, aggregation_selections)
+                    .await?;
+
+                (scalars, false)
+            }
+        }
     };
 
     // Inlining is done on the parent, this means that we need to write the primary parent ID
@@ -225,5 +282,18 @@ pub async fn one2m<'a, 'b>(
         ));
     }
 
-    Ok(processor.apply(scalars))
+    if is_paginated {
+        Ok(scalars)
+    } else {
+        Ok(processor.apply(scalars))
+    }
+}
+
+fn with_filter(mut args: QueryArguments, filter: Filter) -> QueryArguments {
+    args.filter = match args.filter {
+        Some(existing_filter) => Some(Filter::and(vec![existing_filter, filter])),
+        None => Some(filter),
+    };
+
+    args
 }
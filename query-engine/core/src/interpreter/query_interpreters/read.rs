@@ -1,9 +1,15 @@
 use super::*;
-use crate::{interpreter::InterpretationResult, query_ast::*, result_ast::*};
-use connector::{self, ConnectionLike, QueryArguments, ReadOperations};
+use crate::{
+    interpreter::{InterpretationResult, InterpreterError},
+    query_ast::*,
+    result_ast::*,
+};
+use connector::{
+    self, ConnectionLike, QueryArguments, ReadOperations, RelAggregationRow, RelAggregationSelection, RelationSelection,
+};
 use futures::future::{BoxFuture, FutureExt};
 use inmemory_record_processor::InMemoryRecordProcessor;
-use prisma_models::ManyRecords;
+use prisma_models::{ManyRecords, Record};
 
 pub fn execute<'a, 'b>(
     tx: &'a ConnectionLike<'a, 'b>,
@@ -31,20 +37,27 @@ fn read_one<'conn, 'tx>(
         let model = query.model;
         let model_id = model.primary_identifier();
         let filter = query.filter.expect("Expected filter to be set for ReadOne query.");
-        let scalars = tx.get_single_record(&model, &filter, &query.selected_fields).await?;
+        let scalars = tx
+            .get_single_record(&model, &filter, &query.selected_fields, &query.aggregation_selections)
+            .await?;
 
         match scalars {
             Some(record) => {
                 let records: ManyRecords = record.into();
+                let (records, aggregation_rows) =
+                    extract_aggregation_rows_from_scalars(records, query.aggregation_selections);
                 let nested: Vec<QueryResult> = process_nested(tx, query.nested, Some(&records)).await?;
 
                 Ok(RecordSelection {
                     name: query.name,
                     fields: query.selection_order,
+                    composite_selections: query.composite_selections,
                     scalars: records,
                     nested,
                     model_id,
                     query_arguments: QueryArguments::new(model),
+                    aggregation_rows,
+                    joined_relations: None,
                 }
                 .into())
             }
@@ -52,10 +65,13 @@ fn read_one<'conn, 'tx>(
             None => Ok(QueryResult::RecordSelection(Box::new(RecordSelection {
                 name: query.name,
                 fields: query.selection_order,
+                composite_selections: query.composite_selections,
                 model_id,
                 scalars: ManyRecords::default(),
                 nested: vec![],
                 query_arguments: QueryArguments::new(model),
+                aggregation_rows: None,
+                joined_relations: None,
             }))),
         }
     };
@@ -64,38 +80,68 @@ fn read_one<'conn, 'tx>(
 }
 
 /// Queries a set of records.
-/// If the query specifies distinct, we need to lift up pagination (and distinct) processing to the core with in-memory record processing.
-/// -> Distinct can't be processed in the DB with our current query API model.
-///    We need to select IDs / uniques alongside the distincts, which doesn't work in SQL, as all records
-///    are distinct by definition if a unique is in the selection set.
+/// If the query specifies distinct and the connector can't apply it natively, we need to lift up pagination (and distinct)
+/// processing to the core with in-memory record processing.
+/// -> A plain `SELECT DISTINCT` doesn't work, as we need to select IDs / uniques alongside the distincts, and all
+///    records are distinct by definition if a unique is in the selection set.
 /// -> Unstable cursors can't reliably be fetched by the underlying datasource, so we need to process part of it in-memory.
+/// If the query asks for joined relations, the nested reads are resolved together with the records where possible.
 fn read_many<'a, 'b>(
     tx: &'a ConnectionLike<'a, 'b>,
     mut query: ManyRecordsQuery,
 ) -> BoxFuture<'a, InterpretationResult<QueryResult>> {
     let fut = async move {
+        // Row locks are released right away outside of transactions.
+        if query.args.lock.is_some() && matches!(tx, ConnectionLike::Connection(_)) {
+            return Err(InterpreterError::InterpretationError(
+                "Locking the read records with `lock` is only valid inside transactions.".to_owned(),
+                None,
+            ));
+        }
+
+        // Locks are rendered on the reads of the records only, so relations are read with separate queries.
+        if query.relation_load_strategy == RelationLoadStrategy::Join && query.args.lock.is_none() {
+            if let Some(result) = read_many_with_relations(tx, &query).await? {
+                return Ok(result);
+            }
+        }
+
         let scalars = if query.args.requires_inmemory_processing() {
             let processor = InMemoryRecordProcessor::new_from_query_args(&mut query.args);
             let scalars = tx
-                .get_many_records(&query.model, query.args.clone(), &query.selected_fields)
+                .get_many_records(
+                    &query.model,
+                    query.args.clone(),
+                    &query.selected_fields,
+                    &query.aggregation_selections,
+                )
                 .await?;
 
             processor.apply(scalars)
         } else {
-            tx.get_many_records(&query.model, query.args.clone(), &query.selected_fields)
-                .await?
+            tx.get_many_records(
+                &query.model,
+                query.args.clone(),
+                &query.selected_fields,
+                &query.aggregation_selections,
+            )
+            .await?
         };
 
+        let (scalars, aggregation_rows) = extract_aggregation_rows_from_scalars(scalars, query.aggregation_selections);
         let model_id = query.model.primary_identifier();
         let nested: Vec<QueryResult> = process_nested(tx, query.nested, Some(&scalars)).await?;
 
         Ok(RecordSelection {
             name: query.name,
             fields: query.selection_order,
+            composite_selections: query.composite_selections,
             query_arguments: query.args,
             model_id,
             scalars,
             nested,
+            aggregation_rows,
+            joined_relations: None,
         }
         .into())
     };
@@ -103,6 +149,90 @@ fn read_many<'a, 'b>(
     fut.boxed()
 }
 
+/// Queries a set of records together with the related records of all nested reads in a single connector call.
+/// Returns `None` if the connector can't join relations or if any read requires processing the connector doesn't
+/// do, in which case the records are read with separate queries.
+async fn read_many_with_relations<'a, 'b>(
+    tx: &'a ConnectionLike<'a, 'b>,
+    query: &ManyRecordsQuery,
+) -> InterpretationResult<Option<QueryResult>> {
+    if query.args.distinct.is_some()
+        || query.args.requires_inmemory_processing()
+        || !query.aggregation_selections.is_empty()
+        || query.nested.is_empty()
+    {
+        return Ok(None);
+    }
+
+    let relation_selections = match query.nested.iter().map(relation_selection).collect::<Option<Vec<_>>>() {
+        Some(selections) => selections,
+        None => return Ok(None),
+    };
+
+    let records = tx
+        .get_many_records_with_relations(
+            &query.model,
+            query.args.clone(),
+            &query.selected_fields,
+            &relation_selections,
+        )
+        .await?;
+
+    let records = match records {
+        Some(records) => records,
+        None => return Ok(None),
+    };
+
+    let mut scalars = ManyRecords::new(query.selected_fields.db_names().collect());
+    let mut rows = Vec::with_capacity(records.len());
+
+    for record in records {
+        scalars.push(Record::new(record.values));
+        rows.push(record.relations);
+    }
+
+    Ok(Some(
+        RecordSelection {
+            name: query.name.clone(),
+            fields: query.selection_order.clone(),
+            composite_selections: query.composite_selections.clone(),
+            query_arguments: query.args.clone(),
+            model_id: query.model.primary_identifier(),
+            scalars,
+            nested: vec![],
+            aggregation_rows: None,
+            joined_relations: Some(JoinedRelations {
+                queries: query.nested.clone(),
+                rows,
+            }),
+        }
+        .into(),
+    ))
+}
+
+/// The relation selection resolving a nested read, if the connector is able to join it without further processing.
+/// Joined reads don't apply `distinct`, not even natively, so distinct reads are not joined.
+fn relation_selection(query: &ReadQuery) -> Option<RelationSelection> {
+    match query {
+        ReadQuery::RelatedRecordsQuery(q)
+            if q.parent_projections.is_none()
+                && q.aggregation_selections.is_empty()
+                && q.args.distinct.is_none()
+                && !q.args.requires_inmemory_processing() =>
+        {
+            let nested = q.nested.iter().map(relation_selection).collect::<Option<Vec<_>>>()?;
+
+            Some(RelationSelection {
+                field: q.parent_field.clone(),
+                args: q.args.clone(),
+                selected_fields: q.selected_fields.clone(),
+                nested,
+            })
+        }
+        _ => None,
+    }
+}
+
 /// Queries related records for a set of parent IDs.
 fn read_related<'a, 'b>(
     tx: &'a ConnectionLike<'a, 'b>,
@@ -124,11 +254,13 @@ fn read_related<'a, 'b>(
                 parent_result,
                 query.args.clone(),
                 &query.selected_fields,
+                &query.aggregation_selections,
                 processor,
             )
             .await?
         };
 
+        let (scalars, aggregation_rows) = extract_aggregation_rows_from_scalars(scalars, query.aggregation_selections);
         let model = query.parent_field.related_model();
         let model_id = model.primary_identifier();
         let nested: Vec<QueryResult> = process_nested(tx, query.nested, Some(&scalars)).await?;
@@ -136,10 +268,13 @@ fn read_related<'a, 'b>(
         Ok(RecordSelection {
             name: query.name,
             fields: query.selection_order,
+            composite_selections: query.composite_selections,
             query_arguments: query.args,
             model_id,
             scalars,
             nested,
+            aggregation_rows,
+            joined_relations: None,
         }
         .into())
     };
@@ -188,3 +323,44 @@ fn process_nested<'a, 'b>(
 
     fut.boxed()
 }
+
+/// Splits off the relation aggregation values that the connector returns after the selected fields
+/// of each record, so that only the selected fields remain in the records.
+fn extract_aggregation_rows_from_scalars(
+    mut scalars: ManyRecords,
+    aggregation_selections: Vec<RelAggregationSelection>,
+) -> (ManyRecords, Option<Vec<RelAggregationRow>>) {
+    if aggregation_selections.is_empty() {
+        return (scalars, None);
+    }
+
+    let aggregation_names: Vec<String> = aggregation_selections.iter().map(|sel| sel.db_alias()).collect();
+    let first_aggregation_idx = match scalars
+        .field_names
+        .iter()
+        .position(|name| aggregation_names.contains(name))
+    {
+        Some(idx) => idx,
+        // Short-circuited reads don't reach the connector and never contain records.
+        None => return (scalars, Some(Vec::new())),
+    };
+
+    let aggregation_rows = scalars
+        .records
+        .iter_mut()
+        .map(|record| {
+            let aggregation_values = record.values.split_off(first_aggregation_idx);
+
+            aggregation_selections
+                .iter()
+                .cloned()
+                .zip(aggregation_values)
+                .map(|(selection, value)| selection.into_result(value))
+                .collect()
+        })
+        .collect();
+
+    scalars.field_names.truncate(first_aggregation_idx);
+
+    (scalars, Some(aggregation_rows))
+}
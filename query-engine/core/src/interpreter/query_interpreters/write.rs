@@ -13,12 +13,14 @@ pub async fn execute<'a, 'b>(
     match write_query {
         WriteQuery::CreateRecord(q) => create_one(tx, q).await,
         WriteQuery::CreateManyRecords(q) => create_many(tx, q).await,
+        WriteQuery::UpsertManyRecords(q) => upsert_many(tx, q).await,
         WriteQuery::UpdateRecord(q) => update_one(tx, q).await,
         WriteQuery::DeleteRecord(q) => delete_one(tx, q).await,
         WriteQuery::UpdateManyRecords(q) => update_many(tx, q).await,
         WriteQuery::DeleteManyRecords(q) => delete_many(tx, q).await,
         WriteQuery::ConnectRecords(q) => connect(tx, q).await,
         WriteQuery::DisconnectRecords(q) => disconnect(tx, q).await,
+        WriteQuery::DeleteRelationRecords(q) => delete_relation_records(tx, q).await,
         WriteQuery::ExecuteRaw(rq) => execute_raw(tx, rq.query, rq.parameters).await,
         WriteQuery::QueryRaw(rq) => query_raw(tx, rq.query, rq.parameters).await,
     }
@@ -54,11 +56,26 @@ async fn create_many<'a, 'b>(
     tx: &'a ConnectionLike<'a, 'b>,
     q: CreateManyRecords,
 ) -> InterpretationResult<QueryResult> {
+    if q.returning {
+        let ids = tx.create_records_returning(&q.model, q.args, q.skip_duplicates).await?;
+
+        return Ok(QueryResult::Ids(ids));
+    }
+
     let affected_records = tx.create_records(&q.model, q.args, q.skip_duplicates).await?;
 
     Ok(QueryResult::Count(affected_records))
 }
 
+async fn upsert_many<'a, 'b>(
+    tx: &'a ConnectionLike<'a, 'b>,
+    q: UpsertManyRecords,
+) -> InterpretationResult<QueryResult> {
+    let affected_records = tx.upsert_records(&q.model, q.args).await?;
+
+    Ok(QueryResult::Count(affected_records))
+}
+
 async fn update_one<'a, 'b>(tx: &'a ConnectionLike<'a, 'b>, q: UpdateRecord) -> InterpretationResult<QueryResult> {
     let mut res = tx.update_records(&q.model, q.record_filter, q.args).await?;
 
@@ -86,7 +103,11 @@ async fn update_many<'a, 'b>(
 ) -> InterpretationResult<QueryResult> {
     let res = tx.update_records(&q.model, q.record_filter, q.args).await?;
 
-    Ok(QueryResult::Count(res.len()))
+    if q.returning {
+        Ok(QueryResult::Ids(res))
+    } else {
+        Ok(QueryResult::Count(res.len()))
+    }
 }
 
 async fn delete_many<'a, 'b>(
@@ -119,3 +140,12 @@ async fn disconnect<'a, 'b>(tx: &'a ConnectionLike<'a, 'b>, q: DisconnectRecords
 
     Ok(QueryResult::Unit)
 }
+
+async fn delete_relation_records<'a, 'b>(
+    tx: &'a ConnectionLike<'a, 'b>,
+    q: DeleteRelationRecords,
+) -> InterpretationResult<QueryResult> {
+    tx.delete_relation_records(&q.relation_field, &q.parent_ids).await?;
+
+    Ok(QueryResult::Unit)
+}
@@ -1,6 +1,6 @@
 //! Prisma read query AST
 use super::FilteredQuery;
-use connector::{filter::Filter, AggregationSelection, QueryArguments};
+use connector::{filter::Filter, AggregationSelection, QueryArguments, RelAggregationSelection};
 use prisma_models::prelude::*;
 use std::fmt::Display;
 
@@ -101,6 +101,8 @@ pub struct RecordQuery {
     pub selected_fields: ModelProjection,
     pub nested: Vec<ReadQuery>,
     pub selection_order: Vec<String>,
+    pub composite_selections: Vec<CompositeSelection>,
+    pub aggregation_selections: Vec<RelAggregationSelection>,
 }
 
 #[derive(Debug, Clone)]
@@ -112,6 +114,25 @@ pub struct ManyRecordsQuery {
     pub selected_fields: ModelProjection,
     pub nested: Vec<ReadQuery>,
     pub selection_order: Vec<String>,
+    pub composite_selections: Vec<CompositeSelection>,
+    pub aggregation_selections: Vec<RelAggregationSelection>,
+    pub relation_load_strategy: RelationLoadStrategy,
+}
+
+/// How the relations selected by a query are read.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RelationLoadStrategy {
+    /// Together with the records in a single query, if the connector is able to.
+    Join,
+
+    /// With a separate query per relation.
+    Query,
+}
+
+impl Default for RelationLoadStrategy {
+    fn default() -> Self {
+        Self::Query
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -123,12 +144,22 @@ pub struct RelatedRecordsQuery {
     pub selected_fields: ModelProjection,
     pub nested: Vec<ReadQuery>,
     pub selection_order: Vec<String>,
+    pub composite_selections: Vec<CompositeSelection>,
+    pub aggregation_selections: Vec<RelAggregationSelection>,
 
     /// Fields and values of the parent to satisfy the relation query without
     /// relying on the parent result passed by the interpreter.
     pub parent_projections: Option<Vec<RecordProjection>>,
 }
 
+/// A field selected from a composite value, with the fields selected from its own value if it embeds a composite
+/// type itself. Composite values are stored as a whole, the selection is applied when serializing them.
+#[derive(Debug, Clone)]
+pub struct CompositeSelection {
+    pub field: String,
+    pub nested: Vec<CompositeSelection>,
+}
+
 #[derive(Debug, Clone)]
 pub struct AggregateRecordsQuery {
     pub name: String,
@@ -1,6 +1,6 @@
 //! Write query AST
 use super::FilteredQuery;
-use connector::{filter::Filter, DatasourceFieldName, RecordFilter, WriteArgs};
+use connector::{filter::Filter, DatasourceFieldName, RecordFilter, UpsertArgs, WriteArgs};
 use prisma_models::prelude::*;
 use std::sync::Arc;
 
@@ -8,12 +8,14 @@ use std::sync::Arc;
 pub enum WriteQuery {
     CreateRecord(CreateRecord),
     CreateManyRecords(CreateManyRecords),
+    UpsertManyRecords(UpsertManyRecords),
     UpdateRecord(UpdateRecord),
     DeleteRecord(DeleteRecord),
     UpdateManyRecords(UpdateManyRecords),
     DeleteManyRecords(DeleteManyRecords),
     ConnectRecords(ConnectRecords),
     DisconnectRecords(DisconnectRecords),
+    DeleteRelationRecords(DeleteRelationRecords),
     ExecuteRaw(RawQuery),
     QueryRaw(RawQuery),
 }
@@ -47,13 +49,15 @@ impl WriteQuery {
         // DeleteMany, Connect and Disconnect do not return anything.
         match self {
             Self::CreateRecord(_) => returns_id,
-            Self::CreateManyRecords(_) => false,
+            Self::CreateManyRecords(q) => q.returning && returns_id,
+            Self::UpsertManyRecords(_) => false,
             Self::UpdateRecord(_) => returns_id,
             Self::DeleteRecord(_) => returns_id,
             Self::UpdateManyRecords(_) => returns_id,
             Self::DeleteManyRecords(_) => false,
             Self::ConnectRecords(_) => false,
             Self::DisconnectRecords(_) => false,
+            Self::DeleteRelationRecords(_) => false,
             Self::ExecuteRaw(_) => false,
             Self::QueryRaw(_) => false,
         }
@@ -63,12 +67,14 @@ impl WriteQuery {
         match self {
             Self::CreateRecord(q) => Arc::clone(&q.model),
             Self::CreateManyRecords(q) => Arc::clone(&q.model),
+            Self::UpsertManyRecords(q) => Arc::clone(&q.model),
             Self::UpdateRecord(q) => Arc::clone(&q.model),
             Self::DeleteRecord(q) => Arc::clone(&q.model),
             Self::UpdateManyRecords(q) => Arc::clone(&q.model),
             Self::DeleteManyRecords(q) => Arc::clone(&q.model),
             Self::ConnectRecords(q) => q.relation_field.model(),
             Self::DisconnectRecords(q) => q.relation_field.model(),
+            Self::DeleteRelationRecords(q) => q.relation_field.model(),
             Self::ExecuteRaw(_) => unimplemented!(),
             Self::QueryRaw(_) => unimplemented!(),
         }
@@ -102,6 +108,7 @@ impl std::fmt::Display for WriteQuery {
         match self {
             Self::CreateRecord(q) => write!(f, "CreateRecord(model: {}, args: {:?})", q.model.name, q.args),
             Self::CreateManyRecords(q) => write!(f, "CreateManyRecord(model: {})", q.model.name),
+            Self::UpsertManyRecords(q) => write!(f, "UpsertManyRecords(model: {})", q.model.name),
             Self::UpdateRecord(q) => write!(
                 f,
                 "UpdateRecord(model: {}, filter: {:?}, args: {:?})",
@@ -112,6 +119,7 @@ impl std::fmt::Display for WriteQuery {
             Self::DeleteManyRecords(q) => write!(f, "DeleteManyRecords: {}", q.model.name),
             Self::ConnectRecords(_) => write!(f, "ConnectRecords"),
             Self::DisconnectRecords(_) => write!(f, "DisconnectRecords"),
+            Self::DeleteRelationRecords(q) => write!(f, "DeleteRelationRecords: {}", q.relation_field.relation().name),
             Self::ExecuteRaw(r) => write!(f, "ExecuteRaw: {} ({:?})", r.query, r.parameters),
             Self::QueryRaw(r) => write!(f, "QueryRaw: {} ({:?})", r.query, r.parameters),
         }
@@ -129,6 +137,9 @@ pub struct CreateManyRecords {
     pub model: ModelRef,
     pub args: Vec<WriteArgs>,
     pub skip_duplicates: bool,
+
+    /// Whether the identifiers of the created records are returned.
+    pub returning: bool,
 }
 
 impl CreateManyRecords {
@@ -146,6 +157,12 @@ impl CreateManyRecords {
     }
 }
 
+#[derive(Debug, Clone)]
+pub struct UpsertManyRecords {
+    pub model: ModelRef,
+    pub args: Vec<UpsertArgs>,
+}
+
 #[derive(Debug, Clone)]
 pub struct UpdateRecord {
     pub model: ModelRef,
@@ -158,6 +175,8 @@ pub struct UpdateManyRecords {
     pub model: ModelRef,
     pub record_filter: RecordFilter,
     pub args: WriteArgs,
+    /// Whether the identifiers of the updated records are returned instead of their count.
+    pub returning: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -186,6 +205,14 @@ pub struct DisconnectRecords {
     pub relation_field: RelationFieldRef,
 }
 
+/// Deletes the rows of a many-to-many relation table connecting the parents to any records, e.g. to emulate the
+/// foreign keys of the relation table.
+#[derive(Debug, Clone)]
+pub struct DeleteRelationRecords {
+    pub parent_ids: Vec<RecordProjection>,
+    pub relation_field: RelationFieldRef,
+}
+
 #[derive(Debug, Clone)]
 pub struct RawQuery {
     pub query: String,
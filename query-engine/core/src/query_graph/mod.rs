@@ -9,6 +9,7 @@ pub use transformers::*;
 
 use crate::{
     interpreter::ExpressionResult, FilteredQuery, ManyRecordsQuery, Query, QueryGraphBuilderResult, ReadQuery,
+    RelationLoadStrategy,
 };
 use connector::{IdFilter, QueryArguments};
 use guard::*;
@@ -735,6 +736,9 @@ impl QueryGraph {
                 selected_fields: ModelProjection::union(identifiers),
                 nested: vec![],
                 selection_order: vec![],
+                composite_selections: vec![],
+                aggregation_selections: vec![],
+                relation_load_strategy: RelationLoadStrategy::Query,
             });
 
             let query = Query::Read(read_query);
@@ -1,5 +1,7 @@
 use super::*;
-use crate::{constants::inputs::args, query_document::*, query_graph::*, schema::*, IrSerializer};
+use crate::{constants::inputs::args, query_document::*, query_graph::*, schema::*, IrSerializer, ReadQuery};
+use connector::{LockMode, RowLock};
+use datamodel_connector::ConnectorCapability;
 use prisma_value::PrismaValue;
 
 // TODO: Think about if this is really necessary here, or if the whole code should move into
@@ -80,17 +82,39 @@ impl QueryGraphBuilder {
 
         let mut graph = match (&query_info.tag, query_info.model.clone()) {
             (QueryTag::FindUnique, Some(m)) => read::find_unique(parsed_field, m).map(Into::into),
-            (QueryTag::FindFirst, Some(m)) => read::find_first(parsed_field, m).map(Into::into),
-            (QueryTag::FindMany, Some(m)) => read::find_many(parsed_field, m).map(Into::into),
+            (QueryTag::FindFirst, Some(m)) => read::find_first(parsed_field, m)
+                .map(|query| self.with_native_distinct(query))
+                .map(Into::into),
+            (QueryTag::FindMany, Some(m)) => read::find_many(parsed_field, m)
+                .map(|query| self.with_native_distinct(query))
+                .map(Into::into),
             (QueryTag::Aggregate, Some(m)) => read::aggregate(parsed_field, m).map(Into::into),
             (QueryTag::GroupBy, Some(m)) => read::group_by(parsed_field, m).map(Into::into),
             (QueryTag::CreateOne, Some(m)) => QueryGraph::root(|g| write::create_record(g, m, parsed_field)),
             (QueryTag::CreateMany, Some(m)) => QueryGraph::root(|g| write::create_many_records(g, m, parsed_field)),
+            (QueryTag::CreateManyAndReturn, Some(m)) => {
+                QueryGraph::root(|g| write::create_many_records_and_return(g, m, parsed_field))
+            }
             (QueryTag::UpdateOne, Some(m)) => QueryGraph::root(|g| write::update_record(g, m, parsed_field)),
-            (QueryTag::UpdateMany, Some(m)) => QueryGraph::root(|g| write::update_many_records(g, m, parsed_field)),
+            (QueryTag::UpdateMany, Some(m)) => {
+                let lock = self.write_selection_lock();
+                QueryGraph::root(|g| write::update_many_records(g, m, parsed_field, lock))
+            }
+            (QueryTag::UpdateManyAndReturn, Some(m)) => {
+                let lock = self.write_selection_lock();
+                QueryGraph::root(|g| write::update_many_records_and_return(g, m, parsed_field, lock))
+            }
             (QueryTag::UpsertOne, Some(m)) => QueryGraph::root(|g| write::upsert_record(g, m, parsed_field)),
+            (QueryTag::UpsertMany, Some(m)) => QueryGraph::root(|g| write::upsert_many_records(g, m, parsed_field)),
             (QueryTag::DeleteOne, Some(m)) => QueryGraph::root(|g| write::delete_record(g, m, parsed_field)),
-            (QueryTag::DeleteMany, Some(m)) => QueryGraph::root(|g| write::delete_many_records(g, m, parsed_field)),
+            (QueryTag::DeleteMany, Some(m)) => {
+                let lock = self.write_selection_lock();
+                QueryGraph::root(|g| write::delete_many_records(g, m, parsed_field, lock))
+            }
+            (QueryTag::DeleteManyAndReturn, Some(m)) => {
+                let lock = self.write_selection_lock();
+                QueryGraph::root(|g| write::delete_many_records_and_return(g, m, parsed_field, lock))
+            }
             (QueryTag::ExecuteRaw, _) => QueryGraph::root(|g| write::execute_raw(g, parsed_field)),
             (QueryTag::QueryRaw, _) => QueryGraph::root(|g| write::query_raw(g, parsed_field)),
             _ => unreachable!("Query builder dispatching failed."),
@@ -103,6 +127,30 @@ impl QueryGraphBuilder {
         Ok(graph)
     }
 
+    /// Leaves `distinct` of top-level reads to connectors able to apply it in the database.
+    fn with_native_distinct(&self, mut query: ReadQuery) -> ReadQuery {
+        if let ReadQuery::ManyRecordsQuery(ref mut m) = query {
+            m.args.native_distinct = self.query_schema.has_capability(ConnectorCapability::NativeDistinct);
+        }
+
+        query
+    }
+
+    /// The lock on the records read ahead of a many records write to select them, e.g. the first `take` records of an
+    /// ordering. Concurrent writes can't change or delete the selected records before the write, and skip them
+    /// instead of waiting for them. `None` if the connector can't lock rows.
+    fn write_selection_lock(&self) -> Option<RowLock> {
+        if self.query_schema.has_capability(ConnectorCapability::RowLocking) {
+            Some(RowLock {
+                mode: LockMode::Update,
+                skip_locked: true,
+                no_wait: false,
+            })
+        } else {
+            None
+        }
+    }
+
     fn derive_serializer(selection: &Selection, field: &OutputFieldRef) -> IrSerializer {
         IrSerializer {
             key: selection
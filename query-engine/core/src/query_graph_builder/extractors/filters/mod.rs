@@ -4,10 +4,13 @@ mod scalar;
 
 use super::utils;
 use crate::{
+    constants::inputs::filters,
     query_document::{ParsedInputMap, ParsedInputValue},
     QueryGraphBuilderError, QueryGraphBuilderResult,
 };
-use connector::{filter::Filter, QueryMode, RelationCompare, ScalarCompare};
+use connector::{
+    filter::Filter, JsonFilterPath, QueryMode, RelationCompare, ScalarCompare, ScalarCondition, ScalarFilter,
+};
 use filter_grouping::*;
 use prisma_models::{Field, ModelRef, PrismaValue, RelationFieldRef, ScalarFieldRef};
 use std::{convert::TryInto, str::FromStr};
@@ -135,6 +138,8 @@ pub fn extract_filter(value_map: ParsedInputMap, model: &ModelRef) -> QueryGraph
             .filter(|filter| !matches!(filter, Ok(Filter::Empty)))
             .collect::<QueryGraphBuilderResult<Vec<Filter>>>()?;
 
+        let filters = merge_search_filters(filters);
+
         match filters.len() {
             0 => Ok(Filter::empty()),
             1 => Ok(filters.into_iter().next().unwrap()),
@@ -144,6 +149,42 @@ pub fn extract_filter(value_map: ParsedInputMap, model: &ModelRef) -> QueryGraph
     extract_filter(value_map, model, 0)
 }
 
+/// Search filters with the same query on several fields of the model are merged into a single full-text
+/// search over all of those fields. MySQL can only search a set of columns covered by a single fulltext index.
+fn merge_search_filters(filters: Vec<Filter>) -> Vec<Filter> {
+    let mut merged: Vec<Filter> = Vec::with_capacity(filters.len());
+
+    for filter in filters {
+        let search = match &filter {
+            Filter::Scalar(ScalarFilter {
+                projection,
+                condition: ScalarCondition::Search(query, _),
+                ..
+            }) => Some((query.clone(), projection.clone())),
+            _ => None,
+        };
+
+        if let Some((query, projection)) = search {
+            let existing_search = merged.iter_mut().find_map(|existing| match existing {
+                Filter::Scalar(ScalarFilter {
+                    condition: ScalarCondition::Search(existing_query, projections),
+                    ..
+                }) if *existing_query == query => Some(projections),
+                _ => None,
+            });
+
+            if let Some(projections) = existing_search {
+                projections.push(projection);
+                continue;
+            }
+        }
+
+        merged.push(filter);
+    }
+
+    merged
+}
+
 /// Field is the field the filter is refering to and `value` is the passed filter. E.g. `where: { <field>: <value> }.
 /// `value` can be either a flat scalar (for shorthand filter notation) or an object (full filter syntax).
 fn extract_scalar_filters(field: &ScalarFieldRef, value: ParsedInputValue) -> QueryGraphBuilderResult<Vec<Filter>> {
@@ -155,6 +196,30 @@ fn extract_scalar_filters(field: &ScalarFieldRef, value: ParsedInputValue) -> Qu
                 None => QueryMode::Default,
             };
 
+            let json_path = match filter_map.remove(filters::PATH) {
+                Some(path) => Some(parse_json_path(path)?),
+                None => None,
+            };
+
+            if json_path.is_none() {
+                let string_filter = [
+                    filters::STRING_CONTAINS,
+                    filters::STRING_STARTS_WITH,
+                    filters::STRING_ENDS_WITH,
+                ]
+                .iter()
+                .find(|key| filter_map.contains_key(**key));
+
+                if let Some(key) = string_filter {
+                    return Err(QueryGraphBuilderError::InputError(format!(
+                        "The `{}` filter on the Json field `{}` requires a `{}`.",
+                        key,
+                        field.name,
+                        filters::PATH
+                    )));
+                }
+            }
+
             let mut filters: Vec<Filter> = filter_map
                 .into_iter()
                 .map(|(k, v)| scalar::parse(&k, field, v, false))
@@ -164,6 +229,11 @@ fn extract_scalar_filters(field: &ScalarFieldRef, value: ParsedInputValue) -> Qu
                 .collect();
 
             filters.iter_mut().for_each(|f| f.set_mode(mode.clone()));
+
+            if let Some(path) = json_path {
+                filters.iter_mut().for_each(|f| f.set_json_path(path.clone()));
+            }
+
             Ok(filters)
         }
         x => Err(QueryGraphBuilderError::InputError(format!(
@@ -202,6 +272,25 @@ fn extract_relation_filters(field: &RelationFieldRef, value: ParsedInputValue) -
     }
 }
 
+/// A string is a JSON path (MySQL), a list holds the keys and indices leading to the value (Postgres).
+fn parse_json_path(input: ParsedInputValue) -> QueryGraphBuilderResult<JsonFilterPath> {
+    let value: PrismaValue = input.try_into()?;
+
+    match value {
+        PrismaValue::String(path) => Ok(JsonFilterPath::String(path)),
+        PrismaValue::List(keys) => keys
+            .into_iter()
+            .map(|key| key.into_string())
+            .collect::<Option<Vec<_>>>()
+            .map(JsonFilterPath::Array)
+            .ok_or_else(|| QueryGraphBuilderError::InputError("Json filter paths must only contain strings.".into())),
+        x => Err(QueryGraphBuilderError::InputError(format!(
+            "Invalid Json filter path: {:?}",
+            x
+        ))),
+    }
+}
+
 fn parse_query_mode(input: ParsedInputValue) -> QueryGraphBuilderResult<QueryMode> {
     let value: PrismaValue = input.try_into()?;
     let s = match value {
@@ -1,7 +1,10 @@
 use crate::{
     constants::inputs::filters, ParsedInputMap, ParsedInputValue, QueryGraphBuilderError, QueryGraphBuilderResult,
 };
-use connector::{Filter, ScalarCompare, ScalarListCompare};
+use connector::{
+    Filter, JsonCondition, JsonTargetType, QueryMode, ScalarCompare, ScalarCondition, ScalarFilter, ScalarListCompare,
+    ScalarProjection,
+};
 use prisma_models::{PrismaValue, ScalarFieldRef};
 use std::convert::TryInto;
 
@@ -89,6 +92,79 @@ pub fn parse(
         filters::HAS_SOME => vec![field.contains_some_element(as_prisma_value_list(input)?)],
         filters::IS_EMPTY => vec![field.is_empty_list(input.try_into()?)],
 
+        // Json-specific filters
+        filters::STRING_CONTAINS if reverse => vec![json_filter(
+            field,
+            ScalarCondition::NotContains(as_prisma_value(input)?),
+            JsonTargetType::String,
+        )],
+        filters::STRING_STARTS_WITH if reverse => vec![json_filter(
+            field,
+            ScalarCondition::NotStartsWith(as_prisma_value(input)?),
+            JsonTargetType::String,
+        )],
+        filters::STRING_ENDS_WITH if reverse => vec![json_filter(
+            field,
+            ScalarCondition::NotEndsWith(as_prisma_value(input)?),
+            JsonTargetType::String,
+        )],
+        filters::ARRAY_CONTAINS if reverse => vec![json_filter(
+            field,
+            ScalarCondition::NotContains(as_prisma_value(input)?),
+            JsonTargetType::Array,
+        )],
+        filters::ARRAY_STARTS_WITH if reverse => vec![json_filter(
+            field,
+            ScalarCondition::NotStartsWith(as_prisma_value(input)?),
+            JsonTargetType::Array,
+        )],
+        filters::ARRAY_ENDS_WITH if reverse => vec![json_filter(
+            field,
+            ScalarCondition::NotEndsWith(as_prisma_value(input)?),
+            JsonTargetType::Array,
+        )],
+
+        filters::STRING_CONTAINS => vec![json_filter(
+            field,
+            ScalarCondition::Contains(as_prisma_value(input)?),
+            JsonTargetType::String,
+        )],
+        filters::STRING_STARTS_WITH => vec![json_filter(
+            field,
+            ScalarCondition::StartsWith(as_prisma_value(input)?),
+            JsonTargetType::String,
+        )],
+        filters::STRING_ENDS_WITH => vec![json_filter(
+            field,
+            ScalarCondition::EndsWith(as_prisma_value(input)?),
+            JsonTargetType::String,
+        )],
+        filters::ARRAY_CONTAINS => vec![json_filter(
+            field,
+            ScalarCondition::Contains(as_prisma_value(input)?),
+            JsonTargetType::Array,
+        )],
+        filters::ARRAY_STARTS_WITH => vec![json_filter(
+            field,
+            ScalarCondition::StartsWith(as_prisma_value(input)?),
+            JsonTargetType::Array,
+        )],
+        filters::ARRAY_ENDS_WITH => vec![json_filter(
+            field,
+            ScalarCondition::EndsWith(as_prisma_value(input)?),
+            JsonTargetType::Array,
+        )],
+
+        // Full-text search filters
+        filters::SEARCH if reverse => vec![search_filter(
+            field,
+            ScalarCondition::NotSearch(as_prisma_value(input)?, vec![]),
+        )],
+        filters::SEARCH => vec![search_filter(
+            field,
+            ScalarCondition::Search(as_prisma_value(input)?, vec![]),
+        )],
+
         // Aggregation filters
         filters::COUNT => aggregation_filter(field, input, reverse, Filter::count)?,
         filters::AVG => aggregation_filter(field, input, reverse, Filter::average)?,
@@ -107,6 +183,27 @@ pub fn parse(
     Ok(filter)
 }
 
+/// The path of Json filters is set afterwards, as it is a sibling of the filter in the input.
+fn json_filter(field: &ScalarFieldRef, condition: ScalarCondition, target_type: JsonTargetType) -> Filter {
+    Filter::from(ScalarFilter {
+        projection: ScalarProjection::Single(field.clone()),
+        condition: ScalarCondition::JsonCompare(JsonCondition {
+            condition: Box::new(condition),
+            path: None,
+            target_type: Some(target_type),
+        }),
+        mode: QueryMode::Default,
+    })
+}
+
+fn search_filter(field: &ScalarFieldRef, condition: ScalarCondition) -> Filter {
+    Filter::from(ScalarFilter {
+        projection: ScalarProjection::Single(field.clone()),
+        condition,
+        mode: QueryMode::Default,
+    })
+}
+
 fn as_prisma_value(input: ParsedInputValue) -> QueryGraphBuilderResult<PrismaValue> {
     Ok(input.try_into()?)
 }
@@ -1,14 +1,15 @@
 use super::*;
 use crate::{
     constants::inputs::args,
+    constants::inputs::lock,
     constants::inputs::ordering,
     query_document::{ParsedArgument, ParsedInputMap},
     QueryGraphBuilderError, QueryGraphBuilderResult,
 };
-use connector::QueryArguments;
+use connector::{LockMode, QueryArguments, RowLock};
 use prisma_models::{
     Field, ModelProjection, ModelRef, OrderBy, PrismaValue, RecordProjection, RelationFieldRef, ScalarFieldRef,
-    SortOrder,
+    SortAggregation, SortOrder,
 };
 use std::convert::{identity, TryInto};
 
@@ -46,6 +47,11 @@ pub fn extract_query_args(arguments: Vec<ParsedArgument>, model: &ModelRef) -> Q
                         ..res
                     }),
 
+                    args::LOCK => Ok(QueryArguments {
+                        lock: extract_lock(arg.value)?,
+                        ..res
+                    }),
+
                     args::WHERE => {
                         let val: Option<ParsedInputMap> = arg.value.try_into()?;
                         match val {
@@ -65,6 +71,15 @@ pub fn extract_query_args(arguments: Vec<ParsedArgument>, model: &ModelRef) -> Q
         },
     )?;
 
+    // Distinct reads wrap the select of the records, which the lock can't be applied to.
+    if query_args.lock.is_some() && query_args.distinct.is_some() {
+        return Err(QueryGraphBuilderError::InputError(format!(
+            "The `{}` and `{}` arguments can't be combined.",
+            args::LOCK,
+            args::DISTINCT
+        )));
+    }
+
     Ok(finalize_arguments(query_args, model))
 }
 
@@ -96,9 +111,26 @@ fn process_order_object(
 ) -> QueryGraphBuilderResult<Option<OrderBy>> {
     match object.into_iter().next() {
         None => Ok(None),
+        Some((field_name, field_value)) if field_name == ordering::RELEVANCE => {
+            if !path.is_empty() {
+                return Err(QueryGraphBuilderError::InputError(format!(
+                    "The `{}` ordering can only be used on the model of the query, not through relations.",
+                    ordering::RELEVANCE
+                )));
+            }
+
+            let object: ParsedInputMap = field_value.try_into()?;
+            Ok(Some(extract_order_by_relevance(object)?))
+        }
         Some((field_name, field_value)) => {
             let field = model.fields().find_from_all(&field_name)?;
             match field {
+                Field::Relation(rf) if rf.is_list => {
+                    path.push(rf.clone());
+
+                    let object: ParsedInputMap = field_value.try_into()?;
+                    extract_order_by_aggregation(&rf.related_model(), object, path)
+                }
                 Field::Relation(rf) => {
                     path.push(rf.clone());
 
@@ -106,20 +138,90 @@ fn process_order_object(
                     process_order_object(&rf.related_model(), object, path)
                 }
                 Field::Scalar(sf) => {
-                    let value: PrismaValue = field_value.try_into()?;
-                    let sort_order = match value.into_string().unwrap().to_lowercase().as_str() {
-                        ordering::ASC => SortOrder::Ascending,
-                        ordering::DESC => SortOrder::Descending,
-                        _ => unreachable!(),
-                    };
-
-                    Ok(Some(OrderBy::new(sf.clone(), path, sort_order)))
+                    let sort_order = extract_sort_order(field_value)?;
+
+                    Ok(Some(OrderBy::scalar(sf.clone(), path, sort_order)))
                 }
             }
         }
     }
 }
 
+/// Extracts an ordering by an aggregate of the related records of the last (to-many) relation in the path,
+/// e.g. `{ _count: desc }` or `{ _max: { score: asc } }`.
+fn extract_order_by_aggregation(
+    related_model: &ModelRef,
+    object: ParsedInputMap,
+    path: Vec<RelationFieldRef>,
+) -> QueryGraphBuilderResult<Option<OrderBy>> {
+    let (aggregation_name, aggregation_value) = match object.into_iter().next() {
+        Some(pair) => pair,
+        None => return Ok(None),
+    };
+
+    let sort_aggregation = match aggregation_name.as_str() {
+        ordering::UNDERSCORE_COUNT => {
+            let sort_order = extract_sort_order(aggregation_value)?;
+            return Ok(Some(OrderBy::aggregation(
+                None,
+                path,
+                sort_order,
+                SortAggregation::Count,
+            )));
+        }
+        ordering::UNDERSCORE_AVG => SortAggregation::Avg,
+        ordering::UNDERSCORE_SUM => SortAggregation::Sum,
+        ordering::UNDERSCORE_MIN => SortAggregation::Min,
+        ordering::UNDERSCORE_MAX => SortAggregation::Max,
+        _ => unreachable!(),
+    };
+
+    let object: ParsedInputMap = aggregation_value.try_into()?;
+    let (field_name, field_value) = object.into_iter().next().unwrap();
+    let field = related_model.fields().find_from_scalar(&field_name)?;
+    let sort_order = extract_sort_order(field_value)?;
+
+    Ok(Some(OrderBy::aggregation(
+        Some(field),
+        path,
+        sort_order,
+        sort_aggregation,
+    )))
+}
+
+fn extract_order_by_relevance(mut object: ParsedInputMap) -> QueryGraphBuilderResult<OrderBy> {
+    let fields: Vec<ScalarFieldRef> = match object.remove(ordering::FIELDS).unwrap() {
+        ParsedInputValue::List(list) => list
+            .into_iter()
+            .map(|element| Ok(element.try_into()?))
+            .collect::<QueryGraphBuilderResult<Vec<_>>>()?,
+        single => vec![single.try_into()?],
+    };
+
+    if fields.is_empty() {
+        return Err(QueryGraphBuilderError::InputError(format!(
+            "The `{}` ordering requires at least one field in `{}`.",
+            ordering::RELEVANCE,
+            ordering::FIELDS
+        )));
+    }
+
+    let search: PrismaValue = object.remove(ordering::SEARCH).unwrap().try_into()?;
+    let sort_order = extract_sort_order(object.remove(ordering::SORT).unwrap())?;
+
+    Ok(OrderBy::relevance(fields, search.into_string().unwrap(), sort_order))
+}
+
+fn extract_sort_order(value: ParsedInputValue) -> QueryGraphBuilderResult<SortOrder> {
+    let value: PrismaValue = value.try_into()?;
+
+    Ok(match value.into_string().unwrap().to_lowercase().as_str() {
+        ordering::ASC => SortOrder::Ascending,
+        ordering::DESC => SortOrder::Descending,
+        _ => unreachable!(),
+    })
+}
+
 fn extract_distinct(value: ParsedInputValue) -> QueryGraphBuilderResult<ModelProjection> {
     let fields: Vec<Field> = match value {
         ParsedInputValue::List(list) => list
@@ -135,6 +237,40 @@ fn extract_distinct(value: ParsedInputValue) -> QueryGraphBuilderResult<ModelPro
     Ok(ModelProjection::new(fields))
 }
 
+fn extract_lock(value: ParsedInputValue) -> QueryGraphBuilderResult<Option<RowLock>> {
+    let val: Option<ParsedInputMap> = value.try_into()?;
+    let mut map = match val {
+        Some(map) => map,
+        None => return Ok(None),
+    };
+
+    let mode: Option<String> = map.remove(lock::MODE).unwrap().try_into()?;
+    let mode = match mode.unwrap().as_str() {
+        lock::UPDATE => LockMode::Update,
+        lock::SHARE => LockMode::Share,
+        _ => unreachable!(),
+    };
+
+    let skip_locked: Option<bool> = map.remove(lock::SKIP_LOCKED).map(|v| v.try_into()).transpose()?;
+    let no_wait: Option<bool> = map.remove(lock::NO_WAIT).map(|v| v.try_into()).transpose()?;
+    let skip_locked = skip_locked.unwrap_or(false);
+    let no_wait = no_wait.unwrap_or(false);
+
+    if skip_locked && no_wait {
+        return Err(QueryGraphBuilderError::InputError(format!(
+            "The `{}` and `{}` lock options can't be combined.",
+            lock::SKIP_LOCKED,
+            lock::NO_WAIT
+        )));
+    }
+
+    Ok(Some(RowLock {
+        mode,
+        skip_locked,
+        no_wait,
+    }))
+}
+
 fn extract_skip(value: ParsedInputValue) -> QueryGraphBuilderResult<Option<i64>> {
     let val: Option<i64> = value.try_into()?;
 
@@ -75,8 +75,21 @@ fn verify_orderings(orderings: &[OrderBy], group_by: &[ScalarFieldRef]) -> Query
     let mut missing_fields = vec![];
 
     for ordering in orderings {
-        if !group_by.contains(&ordering.field) {
-            missing_fields.push(ordering.field.name.clone());
+        match ordering {
+            OrderBy::Scalar(ordering) if !group_by.contains(&ordering.field) => {
+                missing_fields.push(ordering.field.name.clone());
+            }
+            OrderBy::Relevance(_) => {
+                return Err(QueryGraphBuilderError::InputError(
+                    "Ordering by relevance is not supported in groupBy queries.".to_owned(),
+                ))
+            }
+            OrderBy::Aggregation(_) => {
+                return Err(QueryGraphBuilderError::InputError(
+                    "Ordering by relation aggregates is not supported in groupBy queries.".to_owned(),
+                ))
+            }
+            _ => (),
         }
     }
 
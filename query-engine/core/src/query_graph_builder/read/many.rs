@@ -1,14 +1,26 @@
 use super::*;
-use crate::{query_document::ParsedField, ManyRecordsQuery, ReadQuery};
-use prisma_models::ModelRef;
+use crate::{
+    constants::inputs::{args, relation_load_strategy},
+    query_document::ParsedField,
+    ArgumentListLookup, ManyRecordsQuery, ReadQuery, RelationLoadStrategy,
+};
+use prisma_models::{ModelRef, PrismaValue};
+use std::convert::TryInto;
+
+pub fn find_many(mut field: ParsedField, model: ModelRef) -> QueryGraphBuilderResult<ReadQuery> {
+    let relation_load_strategy = match field.arguments.lookup(args::RELATION_LOAD_STRATEGY) {
+        Some(arg) => extract_relation_load_strategy(arg.value.try_into()?),
+        None => RelationLoadStrategy::default(),
+    };
 
-pub fn find_many(field: ParsedField, model: ModelRef) -> QueryGraphBuilderResult<ReadQuery> {
     let args = extractors::extract_query_args(field.arguments, &model)?;
     let name = field.name;
     let alias = field.alias;
     let nested_fields = field.nested_fields.unwrap().fields;
     let selection_order: Vec<String> = utils::collect_selection_order(&nested_fields);
     let selected_fields = utils::collect_selected_fields(&nested_fields, &model);
+    let composite_selections = utils::collect_composite_selections(&nested_fields, &model);
+    let aggregation_selections = utils::collect_relation_aggr_selections(&nested_fields, &model);
     let nested = utils::collect_nested_queries(nested_fields, &model)?;
     let model = model;
 
@@ -23,5 +35,16 @@ pub fn find_many(field: ParsedField, model: ModelRef) -> QueryGraphBuilderResult
         selected_fields,
         nested,
         selection_order,
+        composite_selections,
+        aggregation_selections,
+        relation_load_strategy,
     }))
 }
+
+fn extract_relation_load_strategy(value: PrismaValue) -> RelationLoadStrategy {
+    match value.into_string().unwrap().as_str() {
+        relation_load_strategy::JOIN => RelationLoadStrategy::Join,
+        relation_load_strategy::QUERY => RelationLoadStrategy::Query,
+        _ => unreachable!(),
+    }
+}
@@ -19,6 +19,8 @@ pub fn find_unique(mut field: ParsedField, model: ModelRef) -> QueryGraphBuilder
     let nested_fields = field.nested_fields.unwrap().fields;
     let selection_order: Vec<String> = utils::collect_selection_order(&nested_fields);
     let selected_fields = utils::collect_selected_fields(&nested_fields, &model);
+    let composite_selections = utils::collect_composite_selections(&nested_fields, &model);
+    let aggregation_selections = utils::collect_relation_aggr_selections(&nested_fields, &model);
     let nested = utils::collect_nested_queries(nested_fields, &model)?;
     let selected_fields = utils::merge_relation_selections(selected_fields, None, &nested);
 
@@ -30,5 +32,7 @@ pub fn find_unique(mut field: ParsedField, model: ModelRef) -> QueryGraphBuilder
         selected_fields,
         nested,
         selection_order,
+        composite_selections,
+        aggregation_selections,
     }))
 }
@@ -13,6 +13,8 @@ pub fn find_related(
     let sub_selections = field.nested_fields.unwrap().fields;
     let selection_order: Vec<String> = utils::collect_selection_order(&sub_selections);
     let selected_fields = utils::collect_selected_fields(&sub_selections, &model);
+    let composite_selections = utils::collect_composite_selections(&sub_selections, &model);
+    let aggregation_selections = utils::collect_relation_aggr_selections(&sub_selections, &model);
     let nested = utils::collect_nested_queries(sub_selections, &model)?;
     let parent_field = parent;
 
@@ -27,6 +29,8 @@ pub fn find_related(
         selected_fields,
         nested,
         selection_order,
+        composite_selections,
+        aggregation_selections,
         parent_projections: None,
     }))
 }
@@ -1,5 +1,6 @@
 use super::*;
-use crate::{FieldPair, ReadQuery};
+use crate::{constants::outputs::fields::UNDERSCORE_COUNT, CompositeSelection, FieldPair, ReadQuery};
+use connector::RelAggregationSelection;
 use prisma_models::{Field, ModelProjection, ModelRef, RecordProjection, RelationFieldRef};
 use std::sync::Arc;
 
@@ -35,8 +36,36 @@ pub fn collect_selected_fields(from: &[FieldPair], model: &ModelRef) -> ModelPro
     model_id.merge(selected_projection)
 }
 
+/// Collects the fields selected from the values of the selected composite fields.
+/// Unwraps are safe due to query validation.
+pub fn collect_composite_selections(from: &[FieldPair], model: &ModelRef) -> Vec<CompositeSelection> {
+    from.iter()
+        .filter(|pair| {
+            model
+                .fields()
+                .find_from_scalar(&pair.parsed_field.name)
+                .map(|sf| sf.is_composite())
+                .unwrap_or(false)
+        })
+        .map(composite_selection)
+        .collect()
+}
+
+fn composite_selection(pair: &FieldPair) -> CompositeSelection {
+    let nested = match &pair.parsed_field.nested_fields {
+        Some(nested_object) => nested_object.fields.iter().map(composite_selection).collect(),
+        None => Vec::new(),
+    };
+
+    CompositeSelection {
+        field: pair.parsed_field.name.clone(),
+        nested,
+    }
+}
+
 pub fn collect_nested_queries(from: Vec<FieldPair>, model: &ModelRef) -> QueryGraphBuilderResult<Vec<ReadQuery>> {
     from.into_iter()
+        .filter(|pair| pair.parsed_field.name != UNDERSCORE_COUNT)
         .filter_map(|pair| {
             let model_field = model.fields().find_from_all(&pair.parsed_field.name).unwrap();
             match model_field {
@@ -52,6 +81,31 @@ pub fn collect_nested_queries(from: Vec<FieldPair>, model: &ModelRef) -> QueryGr
         .collect::<QueryGraphBuilderResult<Vec<ReadQuery>>>()
 }
 
+/// Collects the relation aggregations requested with the `_count` selection, in selection order.
+/// Unwraps are safe due to query validation.
+pub fn collect_relation_aggr_selections(from: &[FieldPair], model: &ModelRef) -> Vec<RelAggregationSelection> {
+    from.iter()
+        .filter(|pair| pair.parsed_field.name == UNDERSCORE_COUNT)
+        .flat_map(|pair| {
+            pair.parsed_field
+                .nested_fields
+                .as_ref()
+                .unwrap()
+                .fields
+                .iter()
+                .map(|count_pair| {
+                    let rf = model
+                        .fields()
+                        .find_from_relation_fields(&count_pair.parsed_field.name)
+                        .unwrap();
+
+                    RelAggregationSelection::Count(rf)
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
 /// Performs a lookahead based on the nested queries and merges fields required
 /// to resolve the nested queries.
 /// A lookback on the parent is also performed to ensure that fields required for
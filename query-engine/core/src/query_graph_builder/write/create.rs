@@ -5,7 +5,7 @@ use crate::{
     query_graph::{Node, NodeRef, QueryGraph, QueryGraphDependency},
     ArgumentListLookup, ParsedField, ParsedInputList, ParsedInputMap,
 };
-use connector::IdFilter;
+use connector::{DatasourceFieldName, IdFilter, UpsertArgs, WriteArgs};
 use prisma_models::ModelRef;
 use std::{convert::TryInto, sync::Arc};
 use write_args_parser::*;
@@ -59,6 +59,121 @@ pub fn create_many_records(
 ) -> QueryGraphBuilderResult<()> {
     graph.flag_transactional();
 
+    let (args, skip_duplicates) = create_many_args(&model, &mut field)?;
+    let query = CreateManyRecords {
+        model,
+        args,
+        skip_duplicates,
+        returning: false,
+    };
+
+    graph.create_node(Query::Write(WriteQuery::CreateManyRecords(query)));
+    Ok(())
+}
+
+/// Creates a create many query returning the ids of the created records and adds it to the query graph,
+/// together with a companion read query for the created records.
+pub fn create_many_records_and_return(
+    graph: &mut QueryGraph,
+    model: ModelRef,
+    mut field: ParsedField,
+) -> QueryGraphBuilderResult<()> {
+    graph.flag_transactional();
+
+    let (args, skip_duplicates) = create_many_args(&model, &mut field)?;
+    let query = CreateManyRecords {
+        model: Arc::clone(&model),
+        args,
+        skip_duplicates,
+        returning: true,
+    };
+
+    let create_node = graph.create_node(Query::Write(WriteQuery::CreateManyRecords(query)));
+
+    // Follow-up read query on the write. Skipped duplicates can result in no created records at all.
+    utils::insert_returned_records_read(graph, &model, field, &create_node)?;
+
+    Ok(())
+}
+
+/// Creates an upsert many query and adds it to the query graph.
+pub fn upsert_many_records(
+    graph: &mut QueryGraph,
+    model: ModelRef,
+    mut field: ParsedField,
+) -> QueryGraphBuilderResult<()> {
+    graph.flag_transactional();
+
+    let args = upsert_many_args(&model, &mut field)?;
+
+    // The update of conflicting rows happens inside the database, there is no way to emulate `onUpdate` for it.
+    if model.internal_data_model().emulates_referential_actions() {
+        let primary_identifier = model.primary_identifier();
+        let internal_model = model.internal_data_model();
+
+        for rf in internal_model.fields_referencing_model(&model) {
+            for field in rf.referenced_fields() {
+                if !primary_identifier.contains(Arc::clone(&field))
+                    && args
+                        .iter()
+                        .any(|a| a.update_fields.iter().any(|name| name.0 == field.db_name()))
+                {
+                    return Err(QueryGraphBuilderError::InputError(format!(
+                        "Field `{}` on model `{}` is referenced by the relation `{}` and can't be written by an upsertMany, because the referential actions of the relation are emulated.",
+                        field.name,
+                        model.name,
+                        rf.relation().name
+                    )));
+                }
+            }
+        }
+    }
+
+    let query = UpsertManyRecords { model, args };
+
+    graph.create_node(Query::Write(WriteQuery::UpsertManyRecords(query)));
+    Ok(())
+}
+
+/// Parses the rows of an upsert many. Only the fields set in the query, and the `@updatedAt` field, are updated on
+/// existing records. Defaults only apply to inserted records.
+fn upsert_many_args(model: &ModelRef, field: &mut ParsedField) -> QueryGraphBuilderResult<Vec<UpsertArgs>> {
+    let data_list: ParsedInputList = match field.arguments.lookup(args::DATA) {
+        Some(data) => data.value.try_into()?,
+        None => vec![],
+    };
+
+    data_list
+        .into_iter()
+        .map(|data_value| {
+            let data_map = data_value.try_into()?;
+            let mut args = WriteArgsParser::from(model, data_map)?.args;
+            let mut update_fields: Vec<DatasourceFieldName> = args.keys().cloned().collect();
+
+            if let Some(updated_at) = model.fields().updated_at() {
+                if !args.has_arg_for(updated_at.db_name()) {
+                    update_fields.push(updated_at.into());
+                }
+            }
+
+            for sf in model.fields().scalar() {
+                if args.has_arg_for(sf.db_name()) {
+                    continue;
+                }
+
+                if let Some(value) = sf.default_value.as_ref().and_then(|default| default.get()) {
+                    args.insert(&sf, value);
+                }
+            }
+
+            args.add_datetimes(model);
+
+            Ok(UpsertArgs { args, update_fields })
+        })
+        .collect()
+}
+
+fn create_many_args(model: &ModelRef, field: &mut ParsedField) -> QueryGraphBuilderResult<(Vec<WriteArgs>, bool)> {
     let data_list: ParsedInputList = match field.arguments.lookup(args::DATA) {
         Some(data) => data.value.try_into()?,
         None => vec![],
@@ -73,21 +188,14 @@ pub fn create_many_records(
         .into_iter()
         .map(|data_value| {
             let data_map = data_value.try_into()?;
-            let mut args = WriteArgsParser::from(&model, data_map)?.args;
+            let mut args = WriteArgsParser::from(model, data_map)?.args;
 
-            args.add_datetimes(&model);
+            args.add_datetimes(model);
             Ok(args)
         })
         .collect::<QueryGraphBuilderResult<Vec<_>>>()?;
 
-    let query = CreateManyRecords {
-        model,
-        args,
-        skip_duplicates,
-    };
-
-    graph.create_node(Query::Write(WriteQuery::CreateManyRecords(query)));
-    Ok(())
+    Ok((args, skip_duplicates))
 }
 
 pub fn create_record_node(
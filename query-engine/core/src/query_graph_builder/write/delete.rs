@@ -2,10 +2,10 @@ use super::*;
 use crate::{
     constants::inputs::args,
     query_ast::*,
-    query_graph::{QueryGraph, QueryGraphDependency},
+    query_graph::{Node, NodeRef, QueryGraph, QueryGraphDependency},
     ArgumentListLookup, FilteredQuery, ParsedField,
 };
-use connector::filter::Filter;
+use connector::{filter::Filter, RowLock};
 use prisma_models::ModelRef;
 use std::{convert::TryInto, sync::Arc};
 
@@ -55,31 +55,89 @@ pub fn delete_many_records(
     graph: &mut QueryGraph,
     model: ModelRef,
     mut field: ParsedField,
+    lock: Option<RowLock>,
 ) -> QueryGraphBuilderResult<()> {
     graph.flag_transactional();
 
-    let filter = match field.arguments.lookup(args::WHERE) {
-        Some(where_arg) => extract_filter(where_arg.value.try_into()?, &model)?,
-        None => Filter::empty(),
-    };
+    let mut selection = utils::extract_write_many_selection(&model, &mut field)?;
+    let filter = selection.filter.clone().unwrap_or_else(Filter::empty);
+    let limited = utils::is_limited_selection(&selection);
+
+    // The records selected by "orderBy" and "take" are deleted by their identifiers.
+    if limited {
+        selection.lock = lock;
+    }
 
     let model_id = model.primary_identifier();
-    let read_query = utils::read_ids_infallible(model.clone(), model_id, filter.clone());
-    let record_filter = filter.into();
+    let read_query = utils::read_ids_with_args(model.clone(), model_id, selection);
+    let read_query_node = graph.create_node(read_query);
+
+    delete_many_records_node(graph, &model, &read_query_node, filter, limited)?;
+
+    Ok(())
+}
+
+/// Creates a top level delete many records query and adds it to the query graph, together with a prefetch of the
+/// deleted records as the result. The prefetched records are locked with `lock`, so that they can't be deleted by a
+/// concurrent write before they are deleted and returned.
+pub fn delete_many_records_and_return(
+    graph: &mut QueryGraph,
+    model: ModelRef,
+    field: ParsedField,
+    lock: Option<RowLock>,
+) -> QueryGraphBuilderResult<()> {
+    graph.flag_transactional();
+
+    // Prefetch read query for the delete, selecting the records by "where", "orderBy" and "take".
+    let mut read_query = read::find_many(field, Arc::clone(&model))?;
+
+    if let ReadQuery::ManyRecordsQuery(ref mut query) = read_query {
+        query.args.lock = lock;
+    }
+
+    let read_node = graph.create_node(Query::Read(read_query));
+
+    delete_many_records_node(graph, &model, &read_node, Filter::empty(), true)?;
+    graph.add_result_node(&read_node);
+
+    Ok(())
+}
+
+/// Creates a delete many records query node preceded by the read of the records to delete. The records are either
+/// selected by the filter, or by the identifiers returned by the read if the read selects more than a filter.
+fn delete_many_records_node(
+    graph: &mut QueryGraph,
+    model: &ModelRef,
+    read_node: &NodeRef,
+    filter: Filter,
+    delete_read_ids: bool,
+) -> QueryGraphBuilderResult<NodeRef> {
     let delete_many = WriteQuery::DeleteManyRecords(DeleteManyRecords {
         model: model.clone(),
-        record_filter,
+        record_filter: filter.into(),
     });
 
-    let read_query_node = graph.create_node(read_query);
     let delete_many_node = graph.create_node(Query::Write(delete_many));
+    utils::insert_deletion_checks(graph, model, read_node, &delete_many_node)?;
 
-    utils::insert_deletion_checks(graph, &model, &read_query_node, &delete_many_node)?;
-    graph.create_edge(
-        &read_query_node,
-        &delete_many_node,
-        QueryGraphDependency::ExecutionOrder,
-    )?;
+    if delete_read_ids {
+        graph.create_edge(
+            read_node,
+            &delete_many_node,
+            QueryGraphDependency::ParentProjection(
+                model.primary_identifier(),
+                Box::new(|mut delete_many_node, parent_ids| {
+                    if let Node::Query(Query::Write(WriteQuery::DeleteManyRecords(ref mut dmr))) = delete_many_node {
+                        dmr.record_filter = parent_ids.into();
+                    }
 
-    Ok(())
+                    Ok(delete_many_node)
+                }),
+            ),
+        )?;
+    } else {
+        graph.create_edge(read_node, &delete_many_node, QueryGraphDependency::ExecutionOrder)?;
+    }
+
+    Ok(delete_many_node)
 }
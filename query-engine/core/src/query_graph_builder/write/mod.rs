@@ -13,8 +13,8 @@ pub mod utils;
 use super::*;
 
 // Expose top level write operation builder functions.
-pub use create::{create_many_records, create_record};
-pub use delete::{delete_many_records, delete_record};
+pub use create::{create_many_records, create_many_records_and_return, create_record, upsert_many_records};
+pub use delete::{delete_many_records, delete_many_records_and_return, delete_record};
 pub use raw::{execute_raw, query_raw};
-pub use update::{update_many_records, update_record};
+pub use update::{update_many_records, update_many_records_and_return, update_record};
 pub use upsert::upsert_record;
@@ -445,6 +445,7 @@ pub fn nested_create_many(
         model: Arc::clone(child_model),
         args,
         skip_duplicates,
+        returning: false,
     };
 
     let create_node = graph.create_node(Query::Write(WriteQuery::CreateManyRecords(query)));
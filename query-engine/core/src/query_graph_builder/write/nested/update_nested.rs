@@ -79,6 +79,10 @@ pub fn nested_update(
                 }),
             ),
         )?;
+
+        if child_model.internal_data_model().emulates_referential_actions() {
+            utils::insert_emulated_on_update_with_parent(graph, child_model, &find_child_records_node, &update_node)?;
+        }
     }
 
     Ok(())
@@ -109,6 +113,7 @@ pub fn nested_update_many(
             model: Arc::clone(&child_model),
             record_filter: RecordFilter::empty(),
             args: update_args.args,
+            returning: false,
         });
 
         let update_many_node = graph.create_node(Query::Write(update_many));
@@ -128,6 +133,15 @@ pub fn nested_update_many(
                 }),
             ),
         )?;
+
+        if child_model.internal_data_model().emulates_referential_actions() {
+            utils::insert_emulated_on_update_with_parent(
+                graph,
+                child_model,
+                &find_child_records_node,
+                &update_many_node,
+            )?;
+        }
     }
 
     Ok(())
@@ -165,6 +165,10 @@ pub fn nested_upsert(
             ),
         )?;
 
+        if child_model.internal_data_model().emulates_referential_actions() {
+            utils::insert_emulated_on_update_with_parent(graph, &child_model, &read_children_node, &update_node)?;
+        }
+
         graph.create_edge(&if_node, &update_node, QueryGraphDependency::Then)?;
         graph.create_edge(&if_node, &create_node, QueryGraphDependency::Else)?;
 
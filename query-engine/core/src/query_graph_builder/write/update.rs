@@ -5,7 +5,7 @@ use crate::{
     query_graph::{Node, NodeRef, QueryGraph, QueryGraphDependency},
     ArgumentListLookup, ParsedField, ParsedInputMap,
 };
-use connector::{Filter, IdFilter};
+use connector::{Filter, IdFilter, RowLock};
 use prisma_models::ModelRef;
 use std::{convert::TryInto, sync::Arc};
 
@@ -19,7 +19,11 @@ pub fn update_record(graph: &mut QueryGraph, model: ModelRef, mut field: ParsedF
     let data_argument = field.arguments.lookup(args::DATA).unwrap();
     let data_map: ParsedInputMap = data_argument.value.try_into()?;
 
-    let update_node = update_record_node(graph, filter, Arc::clone(&model), data_map)?;
+    let update_node = update_record_node(graph, filter.clone(), Arc::clone(&model), data_map)?;
+
+    if model.internal_data_model().emulates_referential_actions() {
+        utils::insert_emulated_on_update(graph, &model, filter, &update_node)?;
+    }
 
     let read_query = read::find_unique(field, model.clone())?;
     let read_node = graph.create_node(Query::Read(read_query));
@@ -55,30 +59,84 @@ pub fn update_many_records(
     graph: &mut QueryGraph,
     model: ModelRef,
     mut field: ParsedField,
+    lock: Option<RowLock>,
 ) -> QueryGraphBuilderResult<()> {
     graph.flag_transactional();
+    update_many_records_node(graph, &model, &mut field, false, lock)?;
 
-    let filter = match field.arguments.lookup(args::WHERE) {
-        Some(where_arg) => extract_filter(where_arg.value.try_into()?, &model)?,
-        None => Filter::empty(),
-    };
+    Ok(())
+}
+
+/// Creates an update many record query and adds it to the query graph, together with a read of the updated records.
+pub fn update_many_records_and_return(
+    graph: &mut QueryGraph,
+    model: ModelRef,
+    mut field: ParsedField,
+    lock: Option<RowLock>,
+) -> QueryGraphBuilderResult<()> {
+    graph.flag_transactional();
+
+    let update_many_node = update_many_records_node(graph, &model, &mut field, true, lock)?;
+    utils::insert_returned_records_read(graph, &model, field, &update_many_node)?;
+
+    Ok(())
+}
+
+/// Creates an update many record query node and adds it to the query graph. If the update is limited to the first
+/// `take` records of an ordering, the records are selected by a read preceding the update, locking them with `lock`.
+fn update_many_records_node(
+    graph: &mut QueryGraph,
+    model: &ModelRef,
+    field: &mut ParsedField,
+    returning: bool,
+    lock: Option<RowLock>,
+) -> QueryGraphBuilderResult<NodeRef> {
+    let mut selection = utils::extract_write_many_selection(model, field)?;
+    let filter = selection.filter.clone().unwrap_or_else(Filter::empty);
 
     let data_argument = field.arguments.lookup(args::DATA).unwrap();
     let data_map: ParsedInputMap = data_argument.value.try_into()?;
-    let update_args = WriteArgsParser::from(&model, data_map)?;
+    let update_args = WriteArgsParser::from(model, data_map)?;
 
     let mut args = update_args.args;
-    args.update_datetimes(Arc::clone(&model));
+    args.update_datetimes(Arc::clone(model));
 
-    let record_filter = filter.into();
     let update_many = WriteQuery::UpdateManyRecords(UpdateManyRecords {
-        model,
-        record_filter,
+        model: Arc::clone(model),
+        record_filter: filter.into(),
         args,
+        returning,
     });
-    graph.create_node(Query::Write(update_many));
+    let update_many_node = graph.create_node(Query::Write(update_many));
+
+    if utils::is_limited_selection(&selection) {
+        selection.lock = lock;
+
+        let read_ids_query =
+            utils::read_ids_with_args(Arc::clone(model), model.primary_identifier(), selection.clone());
+        let read_ids_node = graph.create_node(read_ids_query);
+
+        graph.create_edge(
+            &read_ids_node,
+            &update_many_node,
+            QueryGraphDependency::ParentProjection(
+                model.primary_identifier(),
+                Box::new(|mut update_many_node, parent_ids| {
+                    if let Node::Query(Query::Write(WriteQuery::UpdateManyRecords(ref mut ur))) = update_many_node {
+                        ur.record_filter = parent_ids.into();
+                    }
+
+                    Ok(update_many_node)
+                }),
+            ),
+        )?;
+    }
 
-    Ok(())
+    if model.internal_data_model().emulates_referential_actions() {
+        utils::insert_emulated_on_update_with_args(graph, model, selection, &update_many_node)?;
+    }
+
+    Ok(update_many_node)
 }
 
 /// Creates an update record query node and adds it to the query graph.
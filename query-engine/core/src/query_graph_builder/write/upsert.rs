@@ -24,7 +24,16 @@ pub fn upsert_record(graph: &mut QueryGraph, model: ModelRef, mut field: ParsedF
     let read_parent_records_node = graph.create_node(read_parent_records);
 
     let create_node = create::create_record_node(graph, Arc::clone(&model), create_argument.value.try_into()?)?;
-    let update_node = update::update_record_node(graph, filter, Arc::clone(&model), update_argument.value.try_into()?)?;
+    let update_node = update::update_record_node(
+        graph,
+        filter.clone(),
+        Arc::clone(&model),
+        update_argument.value.try_into()?,
+    )?;
+
+    if model.internal_data_model().emulates_referential_actions() {
+        utils::insert_emulated_on_update(graph, &model, filter, &update_node)?;
+    }
 
     let read_query = read::find_unique(field, Arc::clone(&model))?;
     let read_node_create = graph.create_node(Query::Read(read_query.clone()));
@@ -1,11 +1,14 @@
 use crate::{
+    constants::inputs::args,
     query_ast::*,
     query_graph::{Flow, Node, NodeRef, QueryGraph, QueryGraphDependency},
-    ParsedInputValue, QueryGraphBuilderError, QueryGraphBuilderResult,
+    query_graph_builder::extractors,
+    ArgumentListLookup, ParsedArgument, ParsedField, ParsedInputValue, QueryGraphBuilderError, QueryGraphBuilderResult,
 };
-use connector::{Filter, WriteArgs};
+use connector::{DatasourceFieldName, Filter, QueryArguments, WriteArgs, WriteExpression};
+use datamodel::ReferentialAction;
 use itertools::Itertools;
-use prisma_models::{ModelProjection, ModelRef, RelationFieldRef};
+use prisma_models::{ModelProjection, ModelRef, PrismaValue, RelationFieldRef, ScalarFieldRef};
 use std::sync::Arc;
 
 /// Coerces single values (`ParsedInputValue::Single` and `ParsedInputValue::Map`) into a vector.
@@ -30,17 +33,28 @@ pub fn read_ids_infallible<T>(model: ModelRef, projection: ModelProjection, filt
 where
     T: Into<Filter>,
 {
-    let selected_fields = get_selected_fields(&model, projection);
     let filter: Filter = filter.into();
+    let args = (Arc::clone(&model), filter).into();
+
+    read_ids_with_args(model, projection, args)
+}
+
+/// Produces a non-failing read query that fetches the requested projection of the records selected by the arguments,
+/// e.g. the first `take` records of an ordering.
+pub fn read_ids_with_args(model: ModelRef, projection: ModelProjection, args: QueryArguments) -> Query {
+    let selected_fields = get_selected_fields(&model, projection);
 
     let read_query = ReadQuery::ManyRecordsQuery(ManyRecordsQuery {
         name: "read_ids_infallible".into(), // this name only eases debugging
         alias: None,
-        model: model.clone(),
-        args: (model, filter).into(),
+        model,
+        args,
         selected_fields,
         nested: vec![],
         selection_order: vec![],
+        composite_selections: vec![],
+        aggregation_selections: vec![],
+        relation_load_strategy: RelationLoadStrategy::Query,
     });
 
     Query::Read(read_query)
@@ -111,6 +125,8 @@ where
         selected_fields,
         nested: vec![],
         selection_order: vec![],
+        composite_selections: vec![],
+        aggregation_selections: vec![],
     })));
 
     graph.create_edge(
@@ -148,6 +164,7 @@ where
         model,
         record_filter,
         args,
+        returning: false,
     };
 
     graph.create_node(Query::Write(WriteQuery::UpdateManyRecords(ur)))
@@ -264,6 +281,8 @@ pub fn insert_existing_1to1_related_model_checks(
 /// Inserts checks into the graph that check all required, non-list relations pointing to
 /// the given `model`. Those checks fail at runtime (edges to the `Empty` node) if one or more
 /// records are found. Checks are inserted between `parent_node` and `child_node`.
+/// Relations with an explicit `onDelete: Cascade`, `SetNull` or `SetDefault` are not checked, the
+/// foreign keys of the database take care of the dependent records.
 ///
 /// This function is usually part of a delete (`deleteOne` or `deleteMany`).
 /// Expects `parent_node` to return one or more IDs (for records of `model`) to be checked.
@@ -301,6 +320,9 @@ pub fn insert_existing_1to1_related_model_checks(
 /// └─▶│       Delete       │
 ///    └────────────────────┘
 /// ```
+///
+/// If the referential actions of relations are emulated, the emulated `onDelete` actions are inserted
+/// instead (see `insert_emulated_on_delete`).
 pub fn insert_deletion_checks(
     graph: &mut QueryGraph,
     model: &ModelRef,
@@ -308,7 +330,23 @@ pub fn insert_deletion_checks(
     child_node: &NodeRef,
 ) -> QueryGraphBuilderResult<()> {
     let internal_model = model.internal_data_model();
-    let relation_fields = internal_model.fields_requiring_model(model);
+
+    if internal_model.emulates_referential_actions() {
+        return insert_emulated_on_delete(graph, model, parent_node, child_node, &mut vec![model.name.clone()]);
+    }
+
+    let relation_fields: Vec<_> = internal_model
+        .fields_requiring_model(model)
+        .into_iter()
+        .filter(|rf| {
+            !matches!(
+                rf.relation_info.on_delete,
+                Some(ReferentialAction::Cascade)
+                    | Some(ReferentialAction::SetNull)
+                    | Some(ReferentialAction::SetDefault)
+            )
+        })
+        .collect();
     let mut check_nodes = vec![];
 
     if !relation_fields.is_empty() {
@@ -354,3 +392,552 @@ pub fn insert_deletion_checks(
 
     Ok(())
 }
+
+/// Maximum number of cascading relations followed on a path that contains a cycle, e.g. for self-relations.
+/// The query graph is built ahead of execution, so cycles of cascading relations are unrolled up to this depth.
+const MAX_EMULATED_CASCADE_DEPTH: usize = 8;
+
+/// Inserts the emulated `onDelete` referential actions of all relations holding a foreign key to `model`
+/// into the graph. Used instead of database foreign keys when the datasource is configured with
+/// `referentialIntegrity = "prisma"`.
+///
+/// Expects `parent_node` to return one or more IDs (for records of `model`) that are going to be deleted
+/// by `child_node`. For every relation pointing to `model`, the dependent records are read, and depending
+/// on the referential action of the relation:
+/// - `Restrict` / `NoAction`: the delete fails if dependent records exist.
+/// - `Cascade`: the dependent records are deleted, emulating their own `onDelete` actions recursively.
+/// - `SetNull` / `SetDefault`: the foreign key fields of the dependent records are updated.
+///
+/// The rows of implicit many-to-many relation tables connecting the deleted records are deleted as well, as the
+/// relation tables have no foreign keys either.
+///
+/// ```text
+///    ┌────────────────────┐
+///    │ Find Record IDs to │
+/// ┌──│       Delete       │
+/// │  └────────────────────┘
+/// │             │
+/// │             ▼
+/// │  ┌────────────────────┐
+/// ├─▶│ Find Dependents A  │──┐
+/// │  └────────────────────┘  │
+/// │             │            ▼
+/// │             │  ┌───────────────────┐
+/// │             │  │ Delete / Update / │
+/// │             │  │   Fail if > 0     │
+/// │             │  └───────────────────┘
+/// │             ▼            │
+/// │  ┌────────────────────┐  │
+/// └─▶│       Delete       │◀─┘
+///    └────────────────────┘
+/// ```
+///
+/// `path` holds the models of the cascading relations leading to `model`, starting with the model of the deleted
+/// records and ending with `model`. A relation to a model that is already on the path is a cycle, e.g. `A -> A -> A`
+/// for a self-relation. Paths with cycles are unrolled up to a total depth of `MAX_EMULATED_CASCADE_DEPTH` relations,
+/// no matter how many cycles interlock on them, so the graph stays bounded. Deeper chains of dependent records fail
+/// the delete instead of leaving dangling foreign keys behind.
+fn insert_emulated_on_delete(
+    graph: &mut QueryGraph,
+    model: &ModelRef,
+    parent_node: &NodeRef,
+    child_node: &NodeRef,
+    path: &mut Vec<String>,
+) -> QueryGraphBuilderResult<()> {
+    let internal_model = model.internal_data_model();
+    let dependent_fields = internal_model.fields_referencing_model(model);
+
+    for rf in dependent_fields {
+        let parent_relation_field = rf.related_field();
+        let dependent_model = rf.model();
+        let dependent_model_identifier = dependent_model.primary_identifier();
+        let read_node =
+            insert_find_children_by_parent_node(graph, parent_node, &parent_relation_field, Filter::empty())?;
+
+        match rf.on_delete_action() {
+            ReferentialAction::Restrict | ReferentialAction::NoAction => {
+                insert_dependents_check(graph, &read_node, child_node, &parent_relation_field)?;
+            }
+            ReferentialAction::Cascade if exceeds_cascade_depth(path, &rf) => {
+                insert_cascade_depth_check(graph, &read_node, child_node, &rf)?;
+            }
+            ReferentialAction::Cascade => {
+                let delete_many_node =
+                    graph.create_node(Query::Write(WriteQuery::DeleteManyRecords(DeleteManyRecords {
+                        model: Arc::clone(&dependent_model),
+                        record_filter: Filter::empty().into(),
+                    })));
+
+                graph.create_edge(
+                    &read_node,
+                    &delete_many_node,
+                    QueryGraphDependency::ParentProjection(
+                        dependent_model_identifier,
+                        Box::new(|mut delete_many_node, dependent_ids| {
+                            if let Node::Query(Query::Write(WriteQuery::DeleteManyRecords(ref mut dmr))) =
+                                delete_many_node
+                            {
+                                dmr.record_filter = dependent_ids.into();
+                            }
+
+                            Ok(delete_many_node)
+                        }),
+                    ),
+                )?;
+
+                path.push(path_entry(&rf));
+                insert_emulated_on_delete(graph, &dependent_model, &read_node, &delete_many_node, path)?;
+                path.pop();
+
+                graph.create_edge(&delete_many_node, child_node, QueryGraphDependency::ExecutionOrder)?;
+            }
+            ReferentialAction::SetNull => {
+                let args = set_foreign_key_args(&rf, |_| PrismaValue::Null);
+                insert_update_dependents(graph, &read_node, child_node, &rf, args, path)?;
+            }
+            ReferentialAction::SetDefault => {
+                let args = set_foreign_key_args(&rf, |field| {
+                    field
+                        .default_value
+                        .as_ref()
+                        .and_then(|default| default.get())
+                        .unwrap_or(PrismaValue::Null)
+                });
+                insert_update_dependents(graph, &read_node, child_node, &rf, args, path)?;
+            }
+        }
+    }
+
+    let m2m_fields = model
+        .fields()
+        .relation()
+        .into_iter()
+        .filter(|rf| rf.relation().is_many_to_many());
+
+    for rf in m2m_fields {
+        let delete_relation_node =
+            graph.create_node(Query::Write(WriteQuery::DeleteRelationRecords(DeleteRelationRecords {
+                parent_ids: vec![],
+                relation_field: rf,
+            })));
+
+        graph.create_edge(
+            parent_node,
+            &delete_relation_node,
+            QueryGraphDependency::ParentProjection(
+                model.primary_identifier(),
+                Box::new(|mut delete_relation_node, parent_ids| {
+                    if let Node::Query(Query::Write(WriteQuery::DeleteRelationRecords(ref mut drr))) =
+                        delete_relation_node
+                    {
+                        drr.parent_ids = parent_ids;
+                    }
+
+                    Ok(delete_relation_node)
+                }),
+            ),
+        )?;
+
+        graph.create_edge(&delete_relation_node, child_node, QueryGraphDependency::ExecutionOrder)?;
+    }
+
+    Ok(())
+}
+
+/// Inserts the emulated `onUpdate` referential actions of all relations holding a foreign key to `model`
+/// into the graph, for an update of `model` records matching `filter`. Relations are only considered if the
+/// update changes at least one of the fields they reference.
+///
+/// The records to update are read before `update_node` runs, so that the dependent records can still be found
+/// through their current foreign key values:
+/// - `Restrict` / `NoAction`: the update fails if dependent records exist.
+/// - `Cascade`: the foreign key fields of the dependent records receive the same write as the referenced fields.
+/// - `SetNull` / `SetDefault`: the foreign key fields of the dependent records are updated.
+///
+/// Updates of dependent records are emulated recursively if they change referenced fields themselves.
+pub fn insert_emulated_on_update<T>(
+    graph: &mut QueryGraph,
+    model: &ModelRef,
+    filter: T,
+    update_node: &NodeRef,
+) -> QueryGraphBuilderResult<()>
+where
+    T: Into<Filter>,
+{
+    let selection = (Arc::clone(model), filter.into()).into();
+    insert_emulated_on_update_with_args(graph, model, selection, update_node)
+}
+
+/// Same as `insert_emulated_on_update`, for updates of the records selected by the query arguments, e.g. the first
+/// `take` records of an ordering.
+pub fn insert_emulated_on_update_with_args(
+    graph: &mut QueryGraph,
+    model: &ModelRef,
+    selection: QueryArguments,
+    update_node: &NodeRef,
+) -> QueryGraphBuilderResult<()> {
+    let (args, dependent_fields) = match emulated_on_update_dependents(graph, model, update_node) {
+        Some(dependents) => dependents,
+        None => return Ok(()),
+    };
+
+    let projection = emulated_on_update_projection(model, &dependent_fields);
+    let read_parents_node = graph.create_node(read_ids_with_args(Arc::clone(model), projection, selection));
+
+    insert_emulated_on_update_actions(
+        graph,
+        model,
+        &args,
+        dependent_fields,
+        &read_parents_node,
+        update_node,
+        &mut vec![model.name.clone()],
+    )
+}
+
+/// Same as `insert_emulated_on_update`, for updates of the records whose IDs are returned by `parent_node`, e.g. the
+/// children found by a nested update.
+pub fn insert_emulated_on_update_with_parent(
+    graph: &mut QueryGraph,
+    model: &ModelRef,
+    parent_node: &NodeRef,
+    update_node: &NodeRef,
+) -> QueryGraphBuilderResult<()> {
+    insert_emulated_on_update_with_path(graph, model, parent_node, update_node, &mut vec![model.name.clone()])
+}
+
+fn insert_emulated_on_update_with_path(
+    graph: &mut QueryGraph,
+    model: &ModelRef,
+    parent_node: &NodeRef,
+    update_node: &NodeRef,
+    path: &mut Vec<String>,
+) -> QueryGraphBuilderResult<()> {
+    let (args, dependent_fields) = match emulated_on_update_dependents(graph, model, update_node) {
+        Some(dependents) => dependents,
+        None => return Ok(()),
+    };
+
+    let projection = emulated_on_update_projection(model, &dependent_fields);
+    let read_parents_node = graph.create_node(read_ids_infallible(Arc::clone(model), projection, Filter::empty()));
+
+    graph.create_edge(
+        parent_node,
+        &read_parents_node,
+        QueryGraphDependency::ParentProjection(
+            model.primary_identifier(),
+            Box::new(|mut read_parents_node, parent_ids| {
+                if let Node::Query(Query::Read(ReadQuery::ManyRecordsQuery(ref mut q))) = read_parents_node {
+                    // The update can affect no records at all.
+                    if parent_ids.is_empty() {
+                        q.args.take = Some(0);
+                    } else {
+                        q.add_filter(parent_ids.filter());
+                    }
+                };
+
+                Ok(read_parents_node)
+            }),
+        ),
+    )?;
+
+    insert_emulated_on_update_actions(
+        graph,
+        model,
+        &args,
+        dependent_fields,
+        &read_parents_node,
+        update_node,
+        path,
+    )
+}
+
+/// Returns the write arguments of `update_node` and the relations holding a foreign key to one of the fields they
+/// change, or `None` if there is nothing to emulate.
+fn emulated_on_update_dependents(
+    graph: &QueryGraph,
+    model: &ModelRef,
+    update_node: &NodeRef,
+) -> Option<(WriteArgs, Vec<RelationFieldRef>)> {
+    let args = match graph.node_content(update_node).unwrap() {
+        Node::Query(Query::Write(WriteQuery::UpdateRecord(ur))) => ur.args.clone(),
+        Node::Query(Query::Write(WriteQuery::UpdateManyRecords(ur))) => ur.args.clone(),
+        _ => return None,
+    };
+
+    let internal_model = model.internal_data_model();
+    let dependent_fields: Vec<_> = internal_model
+        .fields_referencing_model(model)
+        .into_iter()
+        .filter(|rf| {
+            rf.referenced_fields()
+                .iter()
+                .any(|field| args.has_arg_for(field.db_name()))
+        })
+        .collect();
+
+    if dependent_fields.is_empty() {
+        None
+    } else {
+        Some((args, dependent_fields))
+    }
+}
+
+fn emulated_on_update_projection(model: &ModelRef, dependent_fields: &[RelationFieldRef]) -> ModelProjection {
+    dependent_fields.iter().fold(model.primary_identifier(), |acc, rf| {
+        acc.merge(rf.related_field().linking_fields())
+    })
+}
+
+/// Inserts the actions of `dependent_fields` for the update of the records returned by `read_parents_node`.
+fn insert_emulated_on_update_actions(
+    graph: &mut QueryGraph,
+    model: &ModelRef,
+    args: &WriteArgs,
+    dependent_fields: Vec<RelationFieldRef>,
+    read_parents_node: &NodeRef,
+    update_node: &NodeRef,
+    path: &mut Vec<String>,
+) -> QueryGraphBuilderResult<()> {
+    for rf in dependent_fields {
+        let parent_relation_field = rf.related_field();
+        let read_node =
+            insert_find_children_by_parent_node(graph, read_parents_node, &parent_relation_field, Filter::empty())?;
+
+        match rf.on_update_action() {
+            ReferentialAction::Restrict | ReferentialAction::NoAction => {
+                insert_dependents_check(graph, &read_node, update_node, &parent_relation_field)?;
+            }
+            ReferentialAction::Cascade => {
+                let mut dependent_args = WriteArgs::new();
+
+                for (fk_field, referenced_field) in rf.scalar_fields().iter().zip(rf.referenced_fields()) {
+                    match args.get_field_value(referenced_field.db_name()) {
+                        Some(WriteExpression::Field(_)) => {
+                            return Err(QueryGraphBuilderError::InputError(format!(
+                                "The update of field `{}` on model `{}` can't be cascaded to the relation `{}`, because it references another field.",
+                                referenced_field.name,
+                                model.name,
+                                rf.relation().name
+                            )))
+                        }
+                        Some(expr) => dependent_args.insert(DatasourceFieldName::from(fk_field), expr.clone()),
+                        None => (),
+                    }
+                }
+
+                insert_update_dependents(graph, &read_node, update_node, &rf, dependent_args, path)?;
+            }
+            ReferentialAction::SetNull => {
+                let args = set_foreign_key_args(&rf, |_| PrismaValue::Null);
+                insert_update_dependents(graph, &read_node, update_node, &rf, args, path)?;
+            }
+            ReferentialAction::SetDefault => {
+                let args = set_foreign_key_args(&rf, |field| {
+                    field
+                        .default_value
+                        .as_ref()
+                        .and_then(|default| default.get())
+                        .unwrap_or(PrismaValue::Null)
+                });
+                insert_update_dependents(graph, &read_node, update_node, &rf, args, path)?;
+            }
+        }
+    }
+
+    graph.create_edge(read_parents_node, update_node, QueryGraphDependency::ExecutionOrder)?;
+
+    Ok(())
+}
+
+/// Identifies a cascading relation on the path of an emulated referential action by its dependent model.
+fn path_entry(rf: &RelationFieldRef) -> String {
+    rf.model().name.clone()
+}
+
+/// Whether following the cascading relation `rf` closes a cycle of models on a `path` that already is
+/// `MAX_EMULATED_CASCADE_DEPTH` relations deep.
+fn exceeds_cascade_depth(path: &[String], rf: &RelationFieldRef) -> bool {
+    // The first model on the path is the one the action started from, not a followed relation.
+    path.contains(&path_entry(rf)) && path.len() > MAX_EMULATED_CASCADE_DEPTH
+}
+
+/// Fails before `child_node` runs if `read_node` returns any dependent records, because the cycle of cascading
+/// relations through `rf` is deeper than `MAX_EMULATED_CASCADE_DEPTH`.
+fn insert_cascade_depth_check(
+    graph: &mut QueryGraph,
+    read_node: &NodeRef,
+    child_node: &NodeRef,
+    rf: &RelationFieldRef,
+) -> QueryGraphBuilderResult<()> {
+    let relation_name = rf.relation().name.clone();
+
+    graph.create_edge(
+        read_node,
+        child_node,
+        QueryGraphDependency::ParentProjection(
+            rf.model().primary_identifier(),
+            Box::new(move |child_node, dependent_ids| {
+                if !dependent_ids.is_empty() {
+                    return Err(QueryGraphBuilderError::InputError(format!(
+                        "The change can't be cascaded through the relation `{}`, because its chain of dependent records is deeper than {} levels.",
+                        relation_name, MAX_EMULATED_CASCADE_DEPTH
+                    )));
+                }
+
+                Ok(child_node)
+            }),
+        ),
+    )?;
+
+    Ok(())
+}
+
+/// Fails before `child_node` runs if `read_node` returns any dependent records.
+fn insert_dependents_check(
+    graph: &mut QueryGraph,
+    read_node: &NodeRef,
+    child_node: &NodeRef,
+    parent_relation_field: &RelationFieldRef,
+) -> QueryGraphBuilderResult<()> {
+    let dependent_model_identifier = parent_relation_field.related_model().primary_identifier();
+    let relation_field = Arc::clone(parent_relation_field);
+
+    graph.create_edge(
+        read_node,
+        child_node,
+        QueryGraphDependency::ParentProjection(
+            dependent_model_identifier,
+            Box::new(move |child_node, dependent_ids| {
+                if !dependent_ids.is_empty() {
+                    return Err(QueryGraphBuilderError::RelationViolation(relation_field.into()));
+                }
+
+                Ok(child_node)
+            }),
+        ),
+    )?;
+
+    Ok(())
+}
+
+/// Updates the dependent records of `rf` returned by `read_node` with `args`, before `child_node` runs. The update is
+/// emulated recursively if it changes fields referenced by other relations (see `insert_emulated_on_update`).
+fn insert_update_dependents(
+    graph: &mut QueryGraph,
+    read_node: &NodeRef,
+    child_node: &NodeRef,
+    rf: &RelationFieldRef,
+    args: WriteArgs,
+    path: &mut Vec<String>,
+) -> QueryGraphBuilderResult<()> {
+    let dependent_model = rf.model();
+    let update_many_node = graph.create_node(Query::Write(WriteQuery::UpdateManyRecords(UpdateManyRecords {
+        model: Arc::clone(&dependent_model),
+        record_filter: Filter::empty().into(),
+        args,
+        returning: false,
+    })));
+
+    graph.create_edge(
+        read_node,
+        &update_many_node,
+        QueryGraphDependency::ParentProjection(
+            dependent_model.primary_identifier(),
+            Box::new(|mut update_many_node, dependent_ids| {
+                if let Node::Query(Query::Write(WriteQuery::UpdateManyRecords(ref mut ur))) = update_many_node {
+                    ur.record_filter = dependent_ids.into();
+                }
+
+                Ok(update_many_node)
+            }),
+        ),
+    )?;
+
+    if emulated_on_update_dependents(graph, &dependent_model, &update_many_node).is_some() {
+        if exceeds_cascade_depth(path, rf) {
+            insert_cascade_depth_check(graph, read_node, child_node, rf)?;
+        } else {
+            path.push(path_entry(rf));
+            insert_emulated_on_update_with_path(graph, &dependent_model, read_node, &update_many_node, path)?;
+            path.pop();
+        }
+    }
+
+    graph.create_edge(&update_many_node, child_node, QueryGraphDependency::ExecutionOrder)?;
+
+    Ok(())
+}
+
+/// Write arguments setting all foreign key fields of `rf` to the value returned by `value_fn`.
+fn set_foreign_key_args<F>(rf: &RelationFieldRef, value_fn: F) -> WriteArgs
+where
+    F: Fn(&ScalarFieldRef) -> PrismaValue,
+{
+    let mut args = WriteArgs::new();
+
+    for field in rf.scalar_fields() {
+        args.insert(DatasourceFieldName::from(&field), value_fn(&field));
+    }
+
+    args
+}
+
+/// Extracts the "where", "orderBy" and "take" arguments selecting the records of a many records write. The "orderBy"
+/// argument is left on the field, so that a read of the affected records keeps the ordering.
+pub fn extract_write_many_selection(
+    model: &ModelRef,
+    field: &mut ParsedField,
+) -> QueryGraphBuilderResult<QueryArguments> {
+    let mut selection: Vec<ParsedArgument> = field
+        .arguments
+        .iter()
+        .filter(|arg| arg.name == args::ORDER_BY)
+        .cloned()
+        .collect();
+
+    selection.extend(field.arguments.lookup(args::WHERE));
+    selection.extend(field.arguments.lookup(args::TAKE));
+
+    extractors::extract_query_args(selection, model)
+}
+
+/// Whether the selection of a many records write is limited beyond its filter, requiring the affected records to be
+/// read before the write.
+pub fn is_limited_selection(selection: &QueryArguments) -> bool {
+    selection.take.is_some() || !selection.order_by.is_empty()
+}
+
+/// Adds a read of the records returned by a many records write node to the graph, as the result of the query.
+pub fn insert_returned_records_read(
+    graph: &mut QueryGraph,
+    model: &ModelRef,
+    field: ParsedField,
+    write_node: &NodeRef,
+) -> QueryGraphBuilderResult<NodeRef> {
+    let read_query = crate::query_graph_builder::read::find_many(field, Arc::clone(model))?;
+    let read_node = graph.create_node(Query::Read(read_query));
+
+    graph.add_result_node(&read_node);
+    graph.create_edge(
+        write_node,
+        &read_node,
+        QueryGraphDependency::ParentProjection(
+            model.primary_identifier(),
+            Box::new(move |mut read_node, parent_ids| {
+                if let Node::Query(Query::Read(ReadQuery::ManyRecordsQuery(ref mut q))) = read_node {
+                    // The write can affect no records at all.
+                    if parent_ids.is_empty() {
+                        q.args.take = Some(0);
+                    } else {
+                        q.add_filter(parent_ids.filter());
+                    }
+                };
+
+                Ok(read_node)
+            }),
+        ),
+    )?;
+
+    Ok(read_node)
+}
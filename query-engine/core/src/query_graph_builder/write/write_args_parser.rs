@@ -23,14 +23,39 @@ impl WriteArgsParser {
                 let field = model.fields().find_from_all(&k).unwrap();
 
                 match field {
+                    // Composite values are always written as a whole, serialized into a single Json value.
+                    Field::Scalar(sf) if sf.is_composite() => {
+                        let value = match composite_value(v)? {
+                            serde_json::Value::Null => PrismaValue::Null,
+                            json => PrismaValue::Json(json.to_string()),
+                        };
+
+                        args.args.insert(sf, WriteExpression::Value(value))
+                    }
+
                     Field::Scalar(sf) if sf.is_list => {
-                        let set_value: PrismaValue = match v {
-                            ParsedInputValue::List(_) => v.try_into()?,
-                            ParsedInputValue::Map(mut map) => map.remove(operations::SET).unwrap().try_into()?,
+                        let expr: WriteExpression = match v {
+                            ParsedInputValue::List(_) => {
+                                let set_value: PrismaValue = v.try_into()?;
+                                set_value.into()
+                            }
+                            ParsedInputValue::Map(map) => {
+                                let (operation, value) = map.into_iter().next().unwrap();
+                                let value: PrismaValue = value.try_into()?;
+
+                                match operation.as_str() {
+                                    operations::SET => WriteExpression::Value(value),
+                                    operations::PUSH => WriteExpression::Push(list_value(value)),
+                                    operations::REMOVE => WriteExpression::Remove(list_value(value)),
+                                    operations::UNSET if value == PrismaValue::Boolean(true) => WriteExpression::Unset,
+                                    operations::UNSET => return Ok(args),
+                                    _ => unreachable!("Invalid update operation"),
+                                }
+                            }
                             _ => unreachable!(),
                         };
 
-                        args.args.insert(sf, set_value)
+                        args.args.insert(sf, expr)
                     }
 
                     Field::Scalar(sf) => {
@@ -38,10 +63,25 @@ impl WriteArgsParser {
                             ParsedInputValue::Single(v) => v.into(),
                             ParsedInputValue::Map(map) => {
                                 let (operation, value) = map.into_iter().next().unwrap();
+
+                                if operation == operations::SET_AT_PATH {
+                                    let mut set_at_path: ParsedInputMap = value.try_into()?;
+                                    let path: Vec<PrismaValue> =
+                                        set_at_path.remove(operations::PATH).unwrap().try_into()?;
+                                    let value: PrismaValue =
+                                        set_at_path.remove(operations::VALUE).unwrap().try_into()?;
+
+                                    let path = path.into_iter().map(|segment| segment.into_string().unwrap()).collect();
+
+                                    args.args.insert(sf, WriteExpression::JsonSet { path, value });
+                                    return Ok(args);
+                                }
+
                                 let value: PrismaValue = value.try_into()?;
 
                                 match operation.as_str() {
                                     operations::SET => WriteExpression::Value(value),
+                                    operations::MERGE => WriteExpression::JsonMerge(json_object(value)?),
                                     operations::INCREMENT => WriteExpression::Add(value),
                                     operations::DECREMENT => WriteExpression::Substract(value),
                                     operations::MULTIPLY => WriteExpression::Multiply(value),
@@ -66,3 +106,45 @@ impl WriteArgsParser {
         )
     }
 }
+
+/// List operations accept a single element or a list of elements.
+fn list_value(value: PrismaValue) -> PrismaValue {
+    match value {
+        PrismaValue::List(_) => value,
+        value => PrismaValue::List(vec![value]),
+    }
+}
+
+/// Json values are only merged with objects, their top-level keys replace the ones of the field.
+fn json_object(value: PrismaValue) -> QueryGraphBuilderResult<PrismaValue> {
+    match &value {
+        PrismaValue::Json(json) if matches!(serde_json::from_str(json), Ok(serde_json::Value::Object(_))) => Ok(value),
+        _ => Err(QueryGraphBuilderError::InputError(format!(
+            "The `{}` operation requires a Json object.",
+            operations::MERGE
+        ))),
+    }
+}
+
+/// Converts the parsed input of a composite field into the Json document it is stored as.
+fn composite_value(value: ParsedInputValue) -> QueryGraphBuilderResult<serde_json::Value> {
+    match value {
+        ParsedInputValue::Map(map) => map
+            .into_iter()
+            .map(|(k, v)| Ok((k, composite_value(v)?)))
+            .collect::<QueryGraphBuilderResult<serde_json::Map<_, _>>>()
+            .map(serde_json::Value::Object),
+        ParsedInputValue::List(list) => list
+            .into_iter()
+            .map(composite_value)
+            .collect::<QueryGraphBuilderResult<Vec<_>>>()
+            .map(serde_json::Value::Array),
+        ParsedInputValue::Single(PrismaValue::Json(json)) => serde_json::from_str(&json)
+            .map_err(|err| QueryGraphBuilderError::InputError(format!("Invalid Json in composite value: {}", err))),
+        ParsedInputValue::Single(pv) => serde_json::to_value(pv)
+            .map_err(|err| QueryGraphBuilderError::InputError(format!("Invalid composite value: {}", err))),
+        _ => Err(QueryGraphBuilderError::InputError(
+            "Composite values have to be objects, lists of objects or null.".to_owned(),
+        )),
+    }
+}
@@ -2,10 +2,11 @@ use super::*;
 use crate::{
     constants::outputs::fields,
     schema::{IntoArc, ObjectTypeStrongRef, OutputType, OutputTypeRef, ScalarType},
-    CoreError, DatabaseEnumType, EnumType, OutputFieldRef, QueryResult, RecordAggregations, RecordSelection,
+    CompositeSelection, CoreError, DatabaseEnumType, EnumType, OutputFieldRef, QueryResult, ReadQuery,
+    RecordAggregations, RecordSelection, RelatedRecordsQuery,
 };
 use bigdecimal::ToPrimitive;
-use connector::AggregationResult;
+use connector::{AggregationResult, JoinedRecord, RelAggregationResult, RelAggregationRow};
 use indexmap::IndexMap;
 use prisma_models::{PrismaValue, RecordProjection};
 use std::{borrow::Borrow, collections::HashMap};
@@ -56,6 +57,7 @@ pub fn serialize_internal(
 
         QueryResult::Json(_) => unimplemented!(),
         QueryResult::Id(_) => unimplemented!(),
+        QueryResult::Ids(_) => unimplemented!(),
         QueryResult::Unit => unimplemented!(),
     }
 }
@@ -282,6 +284,13 @@ fn serialize_objects(
         .filter_map(|f| model.map_scalar_db_field_name(f).map(|x| x.name.clone()))
         .collect();
 
+    let mut aggregation_rows = result.aggregation_rows.map(|rows| rows.into_iter());
+
+    let (joined_queries, mut joined_rows) = match result.joined_relations {
+        Some(joined) => (joined.queries, Some(joined.rows.into_iter())),
+        None => (Vec::new(), None),
+    };
+
     // Write all fields, nested and list fields unordered into a map, afterwards order all into the final order.
     // If nothing is written to the object, write null instead.
     for record in result.scalars.records.into_iter() {
@@ -291,14 +300,20 @@ fn serialize_objects(
             object_mapping.insert(record.parent_id.clone(), Vec::new());
         }
 
-        // Write scalars, but skip objects and lists, which while they are in the selection, are handled separately.
+        // Write scalars (composite fields included, which are stored as Json). Relations are handled separately.
         let values = record.values;
         let mut object = HashMap::with_capacity(values.len());
 
         for (val, scalar_field_name) in values.into_iter().zip(field_names.iter()) {
             let field = typ.find_field(scalar_field_name).unwrap();
 
-            if !field.field_type.is_object() {
+            if is_composite(&field) {
+                let selection = composite_selection(&result.composite_selections, scalar_field_name);
+                object.insert(
+                    scalar_field_name.to_owned(),
+                    serialize_composite(&field, val, selection)?,
+                );
+            } else {
                 object.insert(scalar_field_name.to_owned(), serialize_scalar(&field, val)?);
             }
         }
@@ -306,6 +321,16 @@ fn serialize_objects(
         // Write nested results
         write_nested_items(&record_id, &mut nested_mapping, &mut object, &typ);
 
+        // Write relation aggregations
+        if let Some(row) = aggregation_rows.as_mut().and_then(|rows| rows.next()) {
+            write_rel_aggregation_row(row, &mut object);
+        }
+
+        // Write relations read together with the records
+        if let Some(row) = joined_rows.as_mut().and_then(|rows| rows.next()) {
+            write_joined_relations(row, &joined_queries, &mut object, &typ)?;
+        }
+
         let map = result
             .fields
             .iter()
@@ -329,6 +354,90 @@ fn serialize_objects(
     Ok(object_mapping)
 }
 
+/// Writes the relation aggregations of a record into their selection objects, e.g. `_count: { posts: 2 }`.
+fn write_rel_aggregation_row(row: RelAggregationRow, into: &mut HashMap<String, Item>) {
+    let mut counts = Map::with_capacity(row.len());
+
+    for result in row {
+        match result {
+            RelAggregationResult::Count(rf, count) => {
+                counts.insert(rf.name.clone(), Item::Value(count));
+            }
+        }
+    }
+
+    into.insert(fields::UNDERSCORE_COUNT.to_owned(), Item::Map(counts));
+}
+
+/// Writes the related records read together with a record, one list of records per nested query.
+fn write_joined_relations(
+    row: Vec<Vec<JoinedRecord>>,
+    queries: &[ReadQuery],
+    into: &mut HashMap<String, Item>,
+    enclosing_type: &ObjectTypeStrongRef,
+) -> crate::Result<()> {
+    for (records, query) in row.into_iter().zip(queries) {
+        let query = match query {
+            ReadQuery::RelatedRecordsQuery(query) => query,
+            _ => unreachable!("Only related records queries are joined."),
+        };
+
+        let field = enclosing_type.find_field(&query.name).unwrap();
+        let typ = field.field_type.as_object_type().unwrap();
+
+        let mut items = records
+            .into_iter()
+            .map(|record| serialize_joined_record(record, query, &typ))
+            .collect::<crate::Result<Vec<_>>>()?;
+
+        let item = if field.field_type.is_list() {
+            Item::list(items)
+        } else if items.is_empty() {
+            Item::Value(PrismaValue::Null)
+        } else {
+            items.swap_remove(0)
+        };
+
+        into.insert(query.name.clone(), Item::Ref(ItemRef::new(item)));
+    }
+
+    Ok(())
+}
+
+fn serialize_joined_record(
+    record: JoinedRecord,
+    query: &RelatedRecordsQuery,
+    typ: &ObjectTypeStrongRef,
+) -> crate::Result<Item> {
+    let mut object = HashMap::with_capacity(query.selection_order.len());
+
+    for (value, scalar_field) in record.values.into_iter().zip(query.selected_fields.scalar_fields()) {
+        let field = typ.find_field(&scalar_field.name).unwrap();
+
+        if is_composite(&field) {
+            let selection = composite_selection(&query.composite_selections, &scalar_field.name);
+            object.insert(
+                scalar_field.name.clone(),
+                serialize_composite(&field, value, selection)?,
+            );
+        } else {
+            object.insert(scalar_field.name.clone(), serialize_scalar(&field, value)?);
+        }
+    }
+
+    write_joined_relations(record.relations, &query.nested, &mut object, typ)?;
+
+    let map = query.selection_order.iter().fold(
+        Map::with_capacity(query.selection_order.len()),
+        |mut acc, field_name| {
+            acc.insert(field_name.to_owned(), object.remove(field_name).unwrap());
+            acc
+        },
+    );
+
+    Ok(Item::Map(map))
+}
+
 /// Unwraps are safe due to query validation.
 fn write_nested_items(
     record_id: &Option<RecordProjection>,
@@ -426,6 +535,93 @@ fn serialize_scalar(field: &OutputFieldRef, value: PrismaValue) -> crate::Result
     }
 }
 
+/// Scalar fields with an object output type (or a list of them) embed a composite type.
+fn is_composite(field: &OutputFieldRef) -> bool {
+    match field.field_type.as_ref() {
+        OutputType::List(inner) => inner.is_object(),
+        typ => typ.is_object(),
+    }
+}
+
+/// The fields selected from the values of the composite field.
+fn composite_selection<'a>(selections: &'a [CompositeSelection], field_name: &str) -> &'a [CompositeSelection] {
+    selections
+        .iter()
+        .find(|selection| selection.field == field_name)
+        .map(|selection| selection.nested.as_slice())
+        .unwrap_or(&[])
+}
+
+/// Composite fields are object-typed scalar fields, stored as a Json document (or a Json array for lists).
+/// The stored document is projected onto the selected fields; an unset list is returned as an empty list.
+fn serialize_composite(
+    field: &OutputFieldRef,
+    value: PrismaValue,
+    selection: &[CompositeSelection],
+) -> crate::Result<Item> {
+    let json = match value {
+        PrismaValue::Null if field.field_type.is_list() => serde_json::Value::Array(Vec::new()),
+        PrismaValue::Null if field.is_nullable => return Ok(Item::Value(PrismaValue::Null)),
+        PrismaValue::Json(s) | PrismaValue::String(s) => serde_json::from_str(&s).map_err(|err| {
+            CoreError::SerializationError(format!(
+                "Unable to deserialize composite value of field '{}': {}",
+                field.name, err
+            ))
+        })?,
+        pv => {
+            return Err(CoreError::SerializationError(format!(
+                "Attempted to serialize composite '{}' for field '{}', expected a Json value",
+                pv, field.name
+            )))
+        }
+    };
+
+    let typ = field.field_type.as_object_type().unwrap();
+
+    Ok(Item::Json(project_composite(json, &typ, selection)))
+}
+
+/// Keeps the selected fields of a composite value (or of each value of a list), in selection order, recursing into
+/// embedded composite values. Fields missing in the stored document are returned as null, or as an empty list.
+fn project_composite(
+    value: serde_json::Value,
+    typ: &ObjectTypeStrongRef,
+    selection: &[CompositeSelection],
+) -> serde_json::Value {
+    match value {
+        serde_json::Value::Array(values) => serde_json::Value::Array(
+            values
+                .into_iter()
+                .map(|value| project_composite(value, typ, selection))
+                .collect(),
+        ),
+        serde_json::Value::Object(mut object) => {
+            let projected = selection
+                .iter()
+                .map(|selected| {
+                    let field = typ.find_field(&selected.field).unwrap();
+
+                    let value = match object.remove(&selected.field) {
+                        None | Some(serde_json::Value::Null) if field.field_type.is_list() => {
+                            serde_json::Value::Array(Vec::new())
+                        }
+                        None => serde_json::Value::Null,
+                        Some(value) => match field.field_type.as_object_type() {
+                            Some(embedded) => project_composite(value, &embedded, &selected.nested),
+                            None => value,
+                        },
+                    };
+
+                    (selected.field.clone(), value)
+                })
+                .collect();
+
+            serde_json::Value::Object(projected)
+        }
+        value => value,
+    }
+}
+
 fn convert_prisma_value(value: PrismaValue, st: &ScalarType) -> Result<PrismaValue, CoreError> {
     let item_value = match (st, value) {
         (ScalarType::String, PrismaValue::String(s)) => PrismaValue::String(s),
@@ -1,9 +1,11 @@
-use connector::{AggregationRow, QueryArguments};
+use crate::{CompositeSelection, ReadQuery};
+use connector::{AggregationRow, JoinedRecord, QueryArguments, RelAggregationRow};
 use prisma_models::{ManyRecords, ModelProjection, RecordProjection};
 
 #[derive(Debug, Clone)]
 pub enum QueryResult {
     Id(Option<RecordProjection>),
+    Ids(Vec<RecordProjection>),
     Count(usize),
     RecordSelection(Box<RecordSelection>),
     Json(serde_json::Value),
@@ -20,6 +22,9 @@ pub struct RecordSelection {
     /// Holds an ordered list of selected field names for each contained record.
     pub fields: Vec<String>,
 
+    /// The fields selected from the values of composite fields.
+    pub composite_selections: Vec<CompositeSelection>,
+
     /// Scalar field results
     pub scalars: ManyRecords,
 
@@ -32,6 +37,21 @@ pub struct RecordSelection {
 
     /// Model projection that can be used to retrieve the IDs of the contained records.
     pub model_id: ModelProjection,
+
+    /// Relation aggregation results, one row per contained record, if any were selected.
+    pub aggregation_rows: Option<Vec<RelAggregationRow>>,
+
+    /// Relations read together with the records instead of by the nested queries, if they were joined.
+    pub joined_relations: Option<JoinedRelations>,
+}
+
+#[derive(Debug, Clone)]
+pub struct JoinedRelations {
+    /// The nested queries the relations were read for.
+    pub queries: Vec<ReadQuery>,
+
+    /// The related records of each nested query, one row per contained record.
+    pub rows: Vec<Vec<Vec<JoinedRecord>>>,
 }
 
 impl From<RecordSelection> for QueryResult {
@@ -1,4 +1,5 @@
 use super::*;
+use datamodel_connector::{ConnectorCapabilities, ConnectorCapability};
 use fmt::Debug;
 use prisma_models::{InternalDataModelRef, ModelRef};
 use std::{borrow::Borrow, fmt};
@@ -28,6 +29,9 @@ pub struct QuerySchema {
     output_object_types: Vec<ObjectTypeStrongRef>,
 
     pub internal_data_model: InternalDataModelRef,
+
+    /// The capabilities of the connector the schema was built for.
+    capabilities: ConnectorCapabilities,
 }
 
 impl QuerySchema {
@@ -37,6 +41,7 @@ impl QuerySchema {
         input_object_types: Vec<InputObjectTypeStrongRef>,
         output_object_types: Vec<ObjectTypeStrongRef>,
         internal_data_model: InternalDataModelRef,
+        capabilities: ConnectorCapabilities,
     ) -> Self {
         QuerySchema {
             query,
@@ -44,9 +49,14 @@ impl QuerySchema {
             input_object_types,
             output_object_types,
             internal_data_model,
+            capabilities,
         }
     }
 
+    pub fn has_capability(&self, capability: ConnectorCapability) -> bool {
+        self.capabilities.contains(capability)
+    }
+
     pub fn find_mutation_field<T>(&self, name: T) -> Option<OutputFieldRef>
     where
         T: Into<String>,
@@ -93,11 +103,15 @@ pub enum QueryTag {
     FindMany,
     CreateOne,
     CreateMany,
+    CreateManyAndReturn,
     UpdateOne,
     UpdateMany,
+    UpdateManyAndReturn,
     DeleteOne,
     DeleteMany,
+    DeleteManyAndReturn,
     UpsertOne,
+    UpsertMany,
     Aggregate,
     GroupBy,
     ExecuteRaw,
@@ -112,11 +126,15 @@ impl fmt::Display for QueryTag {
             Self::FindMany => "findMany",
             Self::CreateOne => "createOne",
             Self::CreateMany => "createMany",
+            Self::CreateManyAndReturn => "createManyAndReturn",
             Self::UpdateOne => "updateOne",
             Self::UpdateMany => "updateMany",
+            Self::UpdateManyAndReturn => "updateManyAndReturn",
             Self::DeleteOne => "deleteOne",
             Self::DeleteMany => "deleteMany",
+            Self::DeleteManyAndReturn => "deleteManyAndReturn",
             Self::UpsertOne => "upsertOne",
+            Self::UpsertMany => "upsertMany",
             Self::Aggregate => "aggregate",
             Self::GroupBy => "groupBy",
             Self::ExecuteRaw => "executeRaw",
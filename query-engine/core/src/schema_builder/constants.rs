@@ -27,6 +27,28 @@ pub mod inputs {
 
         // createMany-specific args
         pub const SKIP_DUPLICATES: &str = "skipDuplicates";
+
+        // relation loading args
+        pub const RELATION_LOAD_STRATEGY: &str = "relationLoadStrategy";
+
+        // row locking args
+        pub const LOCK: &str = "lock";
+    }
+
+    pub mod lock {
+        pub const LOCK_INPUT: &str = "LockInput";
+        pub const LOCK_MODE: &str = "LockMode";
+        pub const MODE: &str = "mode";
+        pub const SKIP_LOCKED: &str = "skipLocked";
+        pub const NO_WAIT: &str = "noWait";
+        pub const UPDATE: &str = "update";
+        pub const SHARE: &str = "share";
+    }
+
+    pub mod relation_load_strategy {
+        pub const RELATION_LOAD_STRATEGY: &str = "RelationLoadStrategy";
+        pub const JOIN: &str = "join";
+        pub const QUERY: &str = "query";
     }
 
     pub mod operations {
@@ -44,6 +66,15 @@ pub mod inputs {
 
         // scalar lists
         pub const SET: &str = "set";
+        pub const PUSH: &str = "push";
+        pub const REMOVE: &str = "remove";
+        pub const UNSET: &str = "unset";
+
+        // json
+        pub const MERGE: &str = "merge";
+        pub const SET_AT_PATH: &str = "setAtPath";
+        pub const PATH: &str = "path";
+        pub const VALUE: &str = "value";
 
         // numbers
         pub const INCREMENT: &str = "increment";
@@ -87,6 +118,18 @@ pub mod inputs {
         pub const HAS_EVERY: &str = "hasEvery";
         pub const IS_EMPTY: &str = "isEmpty";
 
+        // Json filters
+        pub const PATH: &str = "path";
+        pub const STRING_CONTAINS: &str = "string_contains";
+        pub const STRING_STARTS_WITH: &str = "string_starts_with";
+        pub const STRING_ENDS_WITH: &str = "string_ends_with";
+        pub const ARRAY_CONTAINS: &str = "array_contains";
+        pub const ARRAY_STARTS_WITH: &str = "array_starts_with";
+        pub const ARRAY_ENDS_WITH: &str = "array_ends_with";
+
+        // full-text search filters
+        pub const SEARCH: &str = "search";
+
         // m2m filters
         pub const EVERY: &str = "every";
         pub const SOME: &str = "some";
@@ -108,6 +151,19 @@ pub mod inputs {
         pub const SORT_ORDER: &str = "SortOrder";
         pub const ASC: &str = "asc";
         pub const DESC: &str = "desc";
+
+        // full-text search relevance ordering
+        pub const RELEVANCE: &str = "_relevance";
+        pub const FIELDS: &str = "fields";
+        pub const SEARCH: &str = "search";
+        pub const SORT: &str = "sort";
+
+        // to-many relation aggregate ordering
+        pub const UNDERSCORE_COUNT: &str = "_count";
+        pub const UNDERSCORE_AVG: &str = "_avg";
+        pub const UNDERSCORE_SUM: &str = "_sum";
+        pub const UNDERSCORE_MIN: &str = "_min";
+        pub const UNDERSCORE_MAX: &str = "_max";
     }
 }
 
@@ -119,5 +175,8 @@ pub mod outputs {
         pub const MIN: &str = "min";
         pub const MAX: &str = "max";
         pub const SUM: &str = "sum";
+
+        // relation aggregation fields
+        pub const UNDERSCORE_COUNT: &str = "_count";
     }
 }
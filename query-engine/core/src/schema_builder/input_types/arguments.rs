@@ -1,6 +1,7 @@
 use super::*;
-use constants::inputs::args;
+use constants::inputs::{args, lock, relation_load_strategy};
 use datamodel_connector::ConnectorCapability;
+use prisma_models::{dml::DefaultValue, PrismaValue};
 
 /// Builds "where" argument.
 pub(crate) fn where_argument(ctx: &mut BuilderContext, model: &ModelRef) -> InputField {
@@ -80,19 +81,34 @@ pub(crate) fn create_many_arguments(ctx: &mut BuilderContext, model: &ModelRef)
     }
 }
 
-/// Builds "where" and "data" arguments intended for the update many field.
+/// Builds the "data" argument intended for the upsert many field.
+pub(crate) fn upsert_many_arguments(ctx: &mut BuilderContext, model: &ModelRef) -> Vec<InputField> {
+    let upsert_many_type = InputType::object(create_many_objects::upsert_many_object_type(ctx, model));
+
+    vec![input_field(args::DATA, InputType::list(upsert_many_type), None)]
+}
+
+/// Builds "data", "where", "orderBy" and "take" arguments intended for the update many field.
 pub(crate) fn update_many_arguments(ctx: &mut BuilderContext, model: &ModelRef) -> Vec<InputField> {
     let update_many_types = update_many_objects::update_many_input_types(ctx, model, None);
-    let where_arg = where_argument(ctx, model);
+    let mut args = vec![input_field(args::DATA, update_many_types, None)];
 
-    vec![input_field(args::DATA, update_many_types, None), where_arg]
+    args.extend(write_many_selection_arguments(ctx, model));
+    args
 }
 
-/// Builds "where" argument intended for the delete many field.
+/// Builds "where", "orderBy" and "take" arguments intended for the delete many field.
 pub(crate) fn delete_many_arguments(ctx: &mut BuilderContext, model: &ModelRef) -> Vec<InputField> {
-    let where_arg = where_argument(ctx, model);
+    write_many_selection_arguments(ctx, model)
+}
 
-    vec![where_arg]
+/// Arguments selecting the records affected by a many-records write, e.g. the first `take` records of the ordering.
+fn write_many_selection_arguments(ctx: &mut BuilderContext, model: &ModelRef) -> Vec<InputField> {
+    vec![
+        where_argument(ctx, model),
+        order_by_argument(ctx, model, false),
+        input_field(args::TAKE, InputType::int(), None).optional(),
+    ]
 }
 
 /// Builds "many records where" arguments based on the given model and field.
@@ -138,6 +154,66 @@ pub(crate) fn many_records_arguments(
     args
 }
 
+/// Builds the "relationLoadStrategy" argument, choosing between reading the selected relations with one query per
+/// relation or together with the records in a single query. Only built for connectors able to join relations.
+pub(crate) fn relation_load_strategy_argument(ctx: &BuilderContext) -> Option<InputField> {
+    if !ctx.capabilities.contains(ConnectorCapability::RelationJoins) {
+        return None;
+    }
+
+    let enum_type = Arc::new(string_enum_type(
+        relation_load_strategy::RELATION_LOAD_STRATEGY,
+        vec![
+            relation_load_strategy::JOIN.to_owned(),
+            relation_load_strategy::QUERY.to_owned(),
+        ],
+    ));
+
+    let field = input_field(
+        args::RELATION_LOAD_STRATEGY,
+        InputType::enum_type(enum_type),
+        Some(DefaultValue::Single(PrismaValue::Enum(
+            relation_load_strategy::QUERY.to_owned(),
+        ))),
+    )
+    .optional();
+
+    Some(field)
+}
+
+/// Builds the "lock" argument, locking the read records for the remainder of the transaction, e.g. with
+/// `FOR UPDATE SKIP LOCKED`. Only built for connectors able to lock rows.
+pub(crate) fn lock_argument(ctx: &mut BuilderContext) -> Option<InputField> {
+    if !ctx.capabilities.contains(ConnectorCapability::RowLocking) {
+        return None;
+    }
+
+    let input_object = lock_object_type(ctx);
+    Some(input_field(args::LOCK, InputType::object(input_object), None).optional())
+}
+
+fn lock_object_type(ctx: &mut BuilderContext) -> InputObjectTypeWeakRef {
+    let ident = Identifier::new(lock::LOCK_INPUT.to_owned(), PRISMA_NAMESPACE);
+    return_cached_input!(ctx, &ident);
+
+    let input_object = Arc::new(init_input_object_type(ident.clone()));
+    ctx.cache_input_type(ident, input_object.clone());
+
+    let mode_enum = Arc::new(string_enum_type(
+        lock::LOCK_MODE,
+        vec![lock::UPDATE.to_owned(), lock::SHARE.to_owned()],
+    ));
+
+    let fields = vec![
+        input_field(lock::MODE, InputType::enum_type(mode_enum), None),
+        input_field(lock::SKIP_LOCKED, InputType::boolean(), None).optional(),
+        input_field(lock::NO_WAIT, InputType::boolean(), None).optional(),
+    ];
+
+    input_object.set_fields(fields);
+    Arc::downgrade(&input_object)
+}
+
 // Builds "orderBy" argument.
 pub(crate) fn order_by_argument(ctx: &mut BuilderContext, model: &ModelRef, include_relations: bool) -> InputField {
     let order_object_type = InputType::object(order_by_objects::order_by_object_type(
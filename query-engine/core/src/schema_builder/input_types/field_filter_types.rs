@@ -142,7 +142,19 @@ fn full_scalar_filter_type(
     let mapped_scalar_type = map_scalar_input_type(ctx, typ, list);
 
     let mut fields: Vec<_> = match typ {
-        TypeIdentifier::String | TypeIdentifier::UUID => equality_filters(mapped_scalar_type.clone(), nullable)
+        TypeIdentifier::String => equality_filters(mapped_scalar_type.clone(), nullable)
+            .chain(inclusion_filters(mapped_scalar_type.clone(), nullable))
+            .chain(alphanumeric_filters(mapped_scalar_type.clone()))
+            .chain(string_filters(mapped_scalar_type.clone()))
+            .chain(search_filter(
+                ctx,
+                mapped_scalar_type.clone(),
+                nested || include_aggregates,
+            ))
+            .chain(query_mode_field(ctx, nested))
+            .collect(),
+
+        TypeIdentifier::UUID => equality_filters(mapped_scalar_type.clone(), nullable)
             .chain(inclusion_filters(mapped_scalar_type.clone(), nullable))
             .chain(alphanumeric_filters(mapped_scalar_type.clone()))
             .chain(string_filters(mapped_scalar_type.clone()))
@@ -158,7 +170,11 @@ fn full_scalar_filter_type(
             .chain(alphanumeric_filters(mapped_scalar_type.clone()))
             .collect(),
 
-        TypeIdentifier::Boolean | TypeIdentifier::Json | TypeIdentifier::Xml | TypeIdentifier::Bytes => {
+        TypeIdentifier::Json => equality_filters(mapped_scalar_type.clone(), nullable)
+            .chain(json_filters(ctx, mapped_scalar_type.clone(), nested))
+            .collect(),
+
+        TypeIdentifier::Boolean | TypeIdentifier::Xml | TypeIdentifier::Bytes => {
             equality_filters(mapped_scalar_type.clone(), nullable).collect()
         }
 
@@ -251,6 +267,55 @@ fn string_filters(mapped_type: InputType) -> impl Iterator<Item = InputField> {
     .into_iter()
 }
 
+/// Full-text search filter, only available on the topmost filter level of connectors that can run full-text queries.
+fn search_filter(ctx: &BuilderContext, mapped_type: InputType, nested: bool) -> impl Iterator<Item = InputField> {
+    let fields = if !nested && feature_flags::get().fullTextSearch && ctx.supports_full_text_search() {
+        vec![input_field(filters::SEARCH, mapped_type, None).optional()]
+    } else {
+        vec![]
+    };
+
+    fields.into_iter()
+}
+
+/// Filters on the values inside of Json documents. The format of `path` depends on the connector.
+fn json_filters(ctx: &BuilderContext, mapped_type: InputType, nested: bool) -> impl Iterator<Item = InputField> {
+    let path_type = if ctx.capabilities.contains(ConnectorCapability::JsonFilteringArrayPath) {
+        Some(InputType::list(InputType::string()))
+    } else if ctx.capabilities.contains(ConnectorCapability::JsonFilteringJsonPath) {
+        Some(InputType::string())
+    } else {
+        None
+    };
+
+    // Json filters are only available on the topmost filter level, not in aggregation filters.
+    let fields = match path_type {
+        Some(path_type) if !nested => {
+            let mut fields = vec![
+                input_field(filters::PATH, path_type, None).optional(),
+                input_field(filters::STRING_CONTAINS, InputType::string(), None).optional(),
+                input_field(filters::STRING_STARTS_WITH, InputType::string(), None).optional(),
+                input_field(filters::STRING_ENDS_WITH, InputType::string(), None).optional(),
+                input_field(filters::ARRAY_CONTAINS, mapped_type.clone(), None)
+                    .optional()
+                    .nullable(),
+                input_field(filters::ARRAY_STARTS_WITH, mapped_type.clone(), None)
+                    .optional()
+                    .nullable(),
+                input_field(filters::ARRAY_ENDS_WITH, mapped_type.clone(), None)
+                    .optional()
+                    .nullable(),
+            ];
+
+            fields.extend(alphanumeric_filters(mapped_type));
+            fields
+        }
+        _ => vec![],
+    };
+
+    fields.into_iter()
+}
+
 fn query_mode_field(ctx: &BuilderContext, nested: bool) -> impl Iterator<Item = InputField> {
     // Limit query mode field to the topmost filter level.
     // Only build mode field for connectors with insensitive filter support.
@@ -243,9 +243,26 @@ where
             let input_object = match ctx.get_input_type(&set_object_ident) {
                 Some(t) => t,
                 None => {
-                    let set_fields = vec![input_field(operations::SET, list_input_type.clone(), None)];
-                    let input_object = Arc::new(input_object_type(set_object_ident.clone(), set_fields));
-
+                    let input_object = if input_object_name == "Update" {
+                        let element_type = map_scalar_input_type(ctx, &f.type_identifier, false);
+                        let element_or_list = vec![element_type, list_input_type.clone()];
+
+                        let fields = vec![
+                            input_field(operations::SET, list_input_type.clone(), None).optional(),
+                            input_field(operations::PUSH, element_or_list.clone(), None).optional(),
+                            input_field(operations::REMOVE, element_or_list, None).optional(),
+                            input_field(operations::UNSET, InputType::boolean(), None).optional(),
+                        ];
+
+                        let mut input_object = input_object_type(set_object_ident.clone(), fields);
+                        input_object.require_exactly_one_field();
+                        input_object
+                    } else {
+                        let set_fields = vec![input_field(operations::SET, list_input_type.clone(), None)];
+                        input_object_type(set_object_ident.clone(), set_fields)
+                    };
+
+                    let input_object = Arc::new(input_object);
                     ctx.cache_input_type(set_object_ident, input_object.clone());
                     Arc::downgrade(&input_object)
                 }
@@ -10,7 +10,20 @@ use objects::*;
 use prisma_models::{RelationFieldRef, ScalarFieldRef};
 
 fn map_scalar_input_type_for_field(ctx: &mut BuilderContext, field: &ScalarFieldRef) -> InputType {
-    map_scalar_input_type(ctx, &field.type_identifier, field.is_list)
+    match &field.composite_type {
+        Some(composite_type) => map_composite_input_type(ctx, &composite_type.name, composite_type.is_list),
+        None => map_scalar_input_type(ctx, &field.type_identifier, field.is_list),
+    }
+}
+
+fn map_composite_input_type(ctx: &mut BuilderContext, composite_type: &str, list: bool) -> InputType {
+    let typ = InputType::object(composite_objects::composite_create_input_type(ctx, composite_type));
+
+    if list {
+        InputType::list(typ)
+    } else {
+        typ
+    }
 }
 
 fn map_scalar_input_type(ctx: &mut BuilderContext, typ: &TypeIdentifier, list: bool) -> InputType {
@@ -0,0 +1,34 @@
+use super::*;
+
+/// Builds the "<x>CreateInput" input object type of a composite type.
+/// Composite values are always written as a whole, so the type is also used to update fields of composite types.
+pub(crate) fn composite_create_input_type(ctx: &mut BuilderContext, composite_type: &str) -> InputObjectTypeWeakRef {
+    let ident = Identifier::new(format!("{}CreateInput", composite_type), PRISMA_NAMESPACE);
+    return_cached_input!(ctx, &ident);
+
+    let composite_type = ctx
+        .internal_data_model
+        .find_composite_type(composite_type)
+        .expect("Composite type references must always be valid.");
+
+    let input_object = Arc::new(init_input_object_type(ident.clone()));
+    ctx.cache_input_type(ident, input_object.clone());
+
+    let fields = composite_type
+        .fields
+        .iter()
+        .map(|field| {
+            let typ = match &field.composite_type {
+                Some(embedded) => map_composite_input_type(ctx, embedded, field.is_list),
+                None => map_scalar_input_type(ctx, &field.type_identifier, field.is_list),
+            };
+
+            input_field(field.name.clone(), typ, None)
+                .optional_if(!field.is_required)
+                .nullable_if(!field.is_required && !field.is_list)
+        })
+        .collect();
+
+    input_object.set_fields(fields);
+    Arc::downgrade(&input_object)
+}
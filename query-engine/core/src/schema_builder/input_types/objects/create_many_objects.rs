@@ -22,7 +22,7 @@ pub(crate) fn create_many_object_type(
     let input_object = Arc::new(init_input_object_type(ident.clone()));
     ctx.cache_input_type(ident, input_object.clone());
 
-    let linking_fields = if let Some(parent_field) = parent_field {
+    let linking_fields: Vec<ScalarFieldRef> = if let Some(parent_field) = parent_field {
         let child_field = parent_field.related_field();
         if child_field.is_inlined_on_enclosing_model() {
             child_field.linking_fields().scalar_fields().collect()
@@ -33,6 +33,34 @@ pub(crate) fn create_many_object_type(
         vec![]
     };
 
+    let fields = create_many_fields(ctx, model, &linking_fields, true);
+
+    input_object.set_fields(fields);
+    Arc::downgrade(&input_object)
+}
+
+/// Upsert many data input type.
+/// Input type allows to write the same fields as the create many input type, but has no defaults:
+/// the fields set in the query are updated on existing records, defaults only apply to inserted ones.
+pub(crate) fn upsert_many_object_type(ctx: &mut BuilderContext, model: &ModelRef) -> InputObjectTypeWeakRef {
+    let ident = Identifier::new(format!("{}UpsertManyInput", model.name), PRISMA_NAMESPACE);
+    return_cached_input!(ctx, &ident);
+
+    let input_object = Arc::new(init_input_object_type(ident.clone()));
+    ctx.cache_input_type(ident, input_object.clone());
+
+    let fields = create_many_fields(ctx, model, &[], false);
+
+    input_object.set_fields(fields);
+    Arc::downgrade(&input_object)
+}
+
+fn create_many_fields(
+    ctx: &mut BuilderContext,
+    model: &ModelRef,
+    linking_fields: &[ScalarFieldRef],
+    with_defaults: bool,
+) -> Vec<InputField> {
     // 1) Filter out parent links.
     // 2) Only allow writing autoincrement fields if the connector supports it.
     let scalar_fields: Vec<ScalarFieldRef> = model
@@ -50,7 +78,7 @@ pub(crate) fn create_many_object_type(
         })
         .collect();
 
-    let fields = input_fields::scalar_input_fields(
+    input_fields::scalar_input_fields(
         ctx,
         model.name.clone(),
         "CreateMany",
@@ -62,9 +90,6 @@ pub(crate) fn create_many_object_type(
                 .optional_if(!f.is_required || f.default_value.is_some() || f.is_created_at() || f.is_updated_at())
                 .nullable_if(!f.is_required)
         },
-        true,
-    );
-
-    input_object.set_fields(fields);
-    Arc::downgrade(&input_object)
+        with_defaults,
+    )
 }
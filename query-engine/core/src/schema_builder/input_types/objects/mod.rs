@@ -1,3 +1,4 @@
+pub(super) mod composite_objects;
 pub(super) mod connect_or_create_objects;
 pub(super) mod create_many_objects;
 pub(super) mod create_one_objects;
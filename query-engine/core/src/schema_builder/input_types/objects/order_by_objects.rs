@@ -7,10 +7,7 @@ pub(crate) fn order_by_object_type(
     include_relations: bool,
     seen_relations: &mut Vec<String>,
 ) -> InputObjectTypeWeakRef {
-    let enum_type = Arc::new(string_enum_type(
-        ordering::SORT_ORDER,
-        vec![ordering::ASC.to_owned(), ordering::DESC.to_owned()],
-    ));
+    let enum_type = sort_order_enum();
 
     let ident = Identifier::new(format!("{}OrderByInput", model.name), PRISMA_NAMESPACE);
     return_cached_input!(ctx, &ident);
@@ -21,12 +18,12 @@ pub(crate) fn order_by_object_type(
     let input_object = Arc::new(input_object);
     ctx.cache_input_type(ident, input_object.clone());
 
-    let fields = model
+    let mut fields: Vec<InputField> = model
         .fields()
         .all
         .iter()
         .filter_map(|field| match field {
-            // Only allow to-one relation order-bys.
+            // To-one relations order by a field of the related record.
             ModelField::Relation(rf) if !rf.is_list && !seen_relations.contains(&rf.relation().name) => {
                 seen_relations.push(rf.relation().name.clone());
 
@@ -35,6 +32,14 @@ pub(crate) fn order_by_object_type(
 
                 Some(input_field(rf.name.clone(), InputType::object(related_object_type), None).optional())
             }
+            // To-many relations order by an aggregate of the related records. The type is shared by all
+            // operations, so this is gated on the feature flag rather than on `include_relations`.
+            ModelField::Relation(rf) if rf.is_list && feature_flags::get().orderByRelation => {
+                let related_model = rf.related_model();
+                let aggregate_object_type = order_by_relation_aggregate_object_type(ctx, &related_model);
+
+                Some(input_field(rf.name.clone(), InputType::object(aggregate_object_type), None).optional())
+            }
             ModelField::Scalar(sf) => {
                 Some(input_field(sf.name.clone(), InputType::Enum(enum_type.clone()), None).optional())
             }
@@ -42,6 +47,150 @@ pub(crate) fn order_by_object_type(
         })
         .collect();
 
+    if feature_flags::get().fullTextSearch && ctx.supports_full_text_search() {
+        if let Some(relevance_object_type) = order_by_relevance_object_type(ctx, model, enum_type) {
+            fields.push(input_field(ordering::RELEVANCE, InputType::object(relevance_object_type), None).optional());
+        }
+    }
+
+    input_object.set_fields(fields);
+    Arc::downgrade(&input_object)
+}
+
+fn sort_order_enum() -> EnumTypeRef {
+    Arc::new(string_enum_type(
+        ordering::SORT_ORDER,
+        vec![ordering::ASC.to_owned(), ordering::DESC.to_owned()],
+    ))
+}
+
+/// Builds the "<Model>OrderByRelationAggregateInput" object type, ordering by the count of the related records
+/// or by an aggregate of one of their fields, e.g. `{ _count: desc }` or `{ _avg: { score: asc } }`.
+fn order_by_relation_aggregate_object_type(ctx: &mut BuilderContext, model: &ModelRef) -> InputObjectTypeWeakRef {
+    let ident = Identifier::new(format!("{}OrderByRelationAggregateInput", model.name), PRISMA_NAMESPACE);
+    return_cached_input!(ctx, &ident);
+
+    let mut input_object = init_input_object_type(ident.clone());
+    input_object.allow_at_most_one_field();
+
+    let input_object = Arc::new(input_object);
+    ctx.cache_input_type(ident, input_object.clone());
+
+    let numeric_fields: Vec<_> = model
+        .fields()
+        .scalar()
+        .into_iter()
+        .filter(|sf| !sf.is_list && sf.is_numeric())
+        .collect();
+
+    let comparable_fields: Vec<_> = model
+        .fields()
+        .scalar()
+        .into_iter()
+        .filter(|sf| !sf.is_list && sf.type_identifier != TypeIdentifier::Json)
+        .collect();
+
+    let mut fields = vec![input_field(ordering::UNDERSCORE_COUNT, InputType::Enum(sort_order_enum()), None).optional()];
+
+    let aggregates = vec![
+        (ordering::UNDERSCORE_AVG, "Avg", &numeric_fields),
+        (ordering::UNDERSCORE_SUM, "Sum", &numeric_fields),
+        (ordering::UNDERSCORE_MIN, "Min", &comparable_fields),
+        (ordering::UNDERSCORE_MAX, "Max", &comparable_fields),
+    ];
+
+    for (name, suffix, aggregate_fields) in aggregates {
+        if let Some(object_type) = order_by_aggregate_fields_object_type(ctx, model, suffix, aggregate_fields) {
+            fields.push(input_field(name, InputType::object(object_type), None).optional());
+        }
+    }
+
     input_object.set_fields(fields);
     Arc::downgrade(&input_object)
 }
+
+/// Builds the "<Model><Aggregate>OrderByRelationAggregateInput" object type, selecting the single field to aggregate.
+/// Returns `None` if there are no fields to aggregate.
+fn order_by_aggregate_fields_object_type(
+    ctx: &mut BuilderContext,
+    model: &ModelRef,
+    suffix: &str,
+    aggregate_fields: &[ScalarFieldRef],
+) -> Option<InputObjectTypeWeakRef> {
+    if aggregate_fields.is_empty() {
+        return None;
+    }
+
+    let ident = Identifier::new(
+        format!("{}{}OrderByRelationAggregateInput", model.name, suffix),
+        PRISMA_NAMESPACE,
+    );
+
+    if let Some(existing_type) = ctx.get_input_type(&ident) {
+        return Some(existing_type);
+    }
+
+    let mut input_object = init_input_object_type(ident.clone());
+    input_object.require_exactly_one_field();
+
+    let input_object = Arc::new(input_object);
+    ctx.cache_input_type(ident, input_object.clone());
+
+    let enum_type = sort_order_enum();
+    let fields = aggregate_fields
+        .iter()
+        .map(|sf| input_field(sf.name.clone(), InputType::Enum(enum_type.clone()), None).optional())
+        .collect();
+
+    input_object.set_fields(fields);
+    Some(Arc::downgrade(&input_object))
+}
+
+/// Builds the "<Model>OrderByRelevanceInput" object type, ordering by the full-text search relevance
+/// of the String fields of the model. Returns `None` if the model has no String fields.
+fn order_by_relevance_object_type(
+    ctx: &mut BuilderContext,
+    model: &ModelRef,
+    sort_order_enum: EnumTypeRef,
+) -> Option<InputObjectTypeWeakRef> {
+    let string_fields: Vec<_> = model
+        .fields()
+        .scalar()
+        .into_iter()
+        .filter(|sf| sf.type_identifier == TypeIdentifier::String && !sf.is_list)
+        .collect();
+
+    if string_fields.is_empty() {
+        return None;
+    }
+
+    let ident = Identifier::new(format!("{}OrderByRelevanceInput", model.name), PRISMA_NAMESPACE);
+
+    if let Some(existing_type) = ctx.get_input_type(&ident) {
+        return Some(existing_type);
+    }
+
+    let input_object = Arc::new(init_input_object_type(ident.clone()));
+    ctx.cache_input_type(ident, input_object.clone());
+
+    let fields_enum_type = InputType::Enum(Arc::new(EnumType::FieldRef(FieldRefEnumType {
+        name: format!("{}OrderByRelevanceFieldEnum", capitalize(&model.name)),
+        values: string_fields
+            .into_iter()
+            .map(|field| (field.name.clone(), field))
+            .collect(),
+    })));
+
+    let fields = vec![
+        input_field(
+            ordering::FIELDS,
+            vec![InputType::list(fields_enum_type.clone()), fields_enum_type],
+            None,
+        ),
+        input_field(ordering::SORT, InputType::Enum(sort_order_enum), None),
+        input_field(ordering::SEARCH, InputType::string(), None),
+    ];
+
+    input_object.set_fields(fields);
+    Some(Arc::downgrade(&input_object))
+}
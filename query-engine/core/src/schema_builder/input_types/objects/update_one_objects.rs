@@ -131,6 +131,13 @@ fn non_list_scalar_update_field_mapper(
     field: &ScalarFieldRef,
     default: Option<DefaultValue>,
 ) -> InputField {
+    // Composite values can only be replaced as a whole.
+    if field.is_composite() {
+        return input_field(field.name.clone(), map_scalar_input_type_for_field(ctx, field), default)
+            .optional()
+            .nullable_if(!field.is_required);
+    }
+
     let base_update_type = match &field.type_identifier {
         TypeIdentifier::Float => InputType::object(operations_object_type(ctx, "Float", field, true)),
         TypeIdentifier::Decimal => InputType::object(operations_object_type(ctx, "Decimal", field, true)),
@@ -139,7 +146,7 @@ fn non_list_scalar_update_field_mapper(
         TypeIdentifier::String => InputType::object(operations_object_type(ctx, "String", field, false)),
         TypeIdentifier::Boolean => InputType::object(operations_object_type(ctx, "Bool", field, false)),
         TypeIdentifier::Enum(e) => InputType::object(operations_object_type(ctx, &format!("Enum{}", e), field, false)),
-        TypeIdentifier::Json => map_scalar_input_type_for_field(ctx, field),
+        TypeIdentifier::Json => InputType::object(json_operations_object_type(ctx, field)),
         TypeIdentifier::DateTime => InputType::object(operations_object_type(ctx, "DateTime", field, false)),
         TypeIdentifier::UUID => InputType::object(operations_object_type(ctx, "Uuid", field, false)),
         TypeIdentifier::Xml => InputType::object(operations_object_type(ctx, "Xml", field, false)),
@@ -147,14 +154,11 @@ fn non_list_scalar_update_field_mapper(
         TypeIdentifier::Unsupported => unreachable!("No unsupported field should reach that path"),
     };
 
-    let input_field = if field.type_identifier != TypeIdentifier::Json {
-        let types = vec![map_scalar_input_type_for_field(ctx, field), base_update_type];
-        input_field(field.name.clone(), types, default)
-    } else {
-        input_field(field.name.clone(), base_update_type, default)
-    };
+    let types = vec![map_scalar_input_type_for_field(ctx, field), base_update_type];
 
-    input_field.optional().nullable_if(!field.is_required)
+    input_field(field.name.clone(), types, default)
+        .optional()
+        .nullable_if(!field.is_required)
 }
 
 fn operations_object_type(
@@ -195,6 +199,49 @@ fn operations_object_type(
     Arc::downgrade(&obj)
 }
 
+/// Builds "<Nullable>JsonFieldUpdateOperationsInput" input object type.
+fn json_operations_object_type(ctx: &mut BuilderContext, field: &ScalarFieldRef) -> InputObjectTypeWeakRef {
+    let nullable = if field.is_required { "" } else { "Nullable" };
+    let ident = Identifier::new(format!("{}JsonFieldUpdateOperationsInput", nullable), PRISMA_NAMESPACE);
+    return_cached_input!(ctx, &ident);
+
+    let mut obj = init_input_object_type(ident.clone());
+    obj.require_exactly_one_field();
+
+    let obj = Arc::new(obj);
+    ctx.cache_input_type(ident, obj.clone());
+
+    let typ = map_scalar_input_type_for_field(ctx, field);
+    let set_at_path = InputType::object(json_set_at_path_object_type(ctx));
+
+    let fields = vec![
+        input_field(operations::SET, typ.clone(), None)
+            .optional()
+            .nullable_if(!field.is_required),
+        input_field(operations::MERGE, typ, None).optional(),
+        input_field(operations::SET_AT_PATH, set_at_path, None).optional(),
+    ];
+
+    obj.set_fields(fields);
+    Arc::downgrade(&obj)
+}
+
+/// Builds "JsonSetAtPathInput" input object type.
+fn json_set_at_path_object_type(ctx: &mut BuilderContext) -> InputObjectTypeWeakRef {
+    let ident = Identifier::new("JsonSetAtPathInput".to_owned(), PRISMA_NAMESPACE);
+    return_cached_input!(ctx, &ident);
+
+    let fields = vec![
+        input_field(operations::PATH, InputType::list(InputType::string()), None),
+        input_field(operations::VALUE, InputType::json(), None),
+    ];
+
+    let obj = Arc::new(input_object_type(ident.clone(), fields));
+    ctx.cache_input_type(ident, obj.clone());
+
+    Arc::downgrade(&obj)
+}
+
 /// For update input types only. Compute input fields for checked relational fields.
 fn relation_input_fields_for_checked_update_one(
     ctx: &mut BuilderContext,
@@ -39,7 +39,7 @@ mod utils;
 
 use crate::schema::*;
 use cache::TypeRefCache;
-use datamodel_connector::ConnectorCapabilities;
+use datamodel_connector::{ConnectorCapabilities, ConnectorCapability};
 use prisma_models::{Field as ModelField, Index, InternalDataModelRef, ModelRef, RelationFieldRef, TypeIdentifier};
 use std::sync::Arc;
 
@@ -112,6 +112,14 @@ impl BuilderContext {
     pub fn cache_output_type(&mut self, ident: Identifier, typ: ObjectTypeStrongRef) {
         self.cache.output_types.insert(ident, typ);
     }
+
+    /// Whether the connector can run full-text search queries, with or without a fulltext index.
+    pub fn supports_full_text_search(&self) -> bool {
+        self.capabilities.contains(ConnectorCapability::FullTextSearchWithIndex)
+            || self
+                .capabilities
+                .contains(ConnectorCapability::FullTextSearchWithoutIndex)
+    }
 }
 
 #[derive(Debug)]
@@ -166,6 +174,7 @@ pub fn build(
         input_objects,
         output_objects,
         ctx.internal_data_model,
+        ctx.capabilities,
     )
 }
 
@@ -93,10 +93,12 @@ pub(crate) fn group_by_output_object_type(ctx: &mut BuilderContext, model: &Mode
 fn scalar_fields(ctx: &mut BuilderContext, model: &ModelRef) -> Vec<OutputField> {
     let fields = model.fields().scalar();
 
+    // Grouped values are returned as stored, composite values as plain Json.
     fields
         .into_iter()
         .map(|f| {
-            field(f.name.clone(), vec![], map_scalar_output_type_for_field(ctx, &f), None).nullable_if(!f.is_required)
+            let typ = map_scalar_output_type(ctx, &f.type_identifier, f.is_list);
+            field(f.name.clone(), vec![], typ, None).nullable_if(!f.is_required)
         })
         .collect()
 }
@@ -1,4 +1,4 @@
-use super::output_objects::map_scalar_output_type_for_field;
+use super::output_objects::{map_scalar_output_type, map_scalar_output_type_for_field};
 use super::*;
 use prisma_models::ScalarFieldRef;
 
@@ -10,6 +10,7 @@ pub(crate) fn build(ctx: &mut BuilderContext) -> (OutputType, ObjectTypeStrongRe
     let non_embedded_models = ctx.internal_data_model.non_embedded_models();
     let mut fields: Vec<OutputField> = non_embedded_models
         .into_iter()
+        .filter(|model| !model.is_view)
         .map(|model| {
             let mut vec = vec![];
 
@@ -19,6 +20,8 @@ pub(crate) fn build(ctx: &mut BuilderContext) -> (OutputType, ObjectTypeStrongRe
                 append_opt(&mut vec, upsert_item_field(ctx, &model));
                 if feature_flags::get().createMany {
                     append_opt(&mut vec, create_many_field(ctx, &model));
+                    append_opt(&mut vec, create_many_and_return_field(ctx, &model));
+                    append_opt(&mut vec, upsert_many_field(ctx, &model));
                 }
             }
 
@@ -26,7 +29,9 @@ pub(crate) fn build(ctx: &mut BuilderContext) -> (OutputType, ObjectTypeStrongRe
             append_opt(&mut vec, update_item_field(ctx, &model));
 
             vec.push(update_many_field(ctx, &model));
+            vec.push(update_many_and_return_field(ctx, &model));
             vec.push(delete_many_field(ctx, &model));
+            vec.push(delete_many_and_return_field(ctx, &model));
 
             vec
         })
@@ -195,6 +200,22 @@ fn delete_many_field(ctx: &mut BuilderContext, model: &ModelRef) -> OutputField
     )
 }
 
+/// Builds a delete many mutation field returning the deleted records (e.g. deleteManyUserAndReturn) for given model.
+fn delete_many_and_return_field(ctx: &mut BuilderContext, model: &ModelRef) -> OutputField {
+    let arguments = arguments::delete_many_arguments(ctx, model);
+    let field_name = format!("deleteMany{}AndReturn", model.name);
+
+    field(
+        field_name,
+        arguments,
+        OutputType::list(OutputType::object(output_objects::map_model_object_type(ctx, &model))),
+        Some(QueryInfo {
+            model: Some(Arc::clone(&model)),
+            tag: QueryTag::DeleteManyAndReturn,
+        }),
+    )
+}
+
 /// Builds an update mutation field (e.g. updateUser) for given model.
 fn update_item_field(ctx: &mut BuilderContext, model: &ModelRef) -> Option<OutputField> {
     arguments::update_one_arguments(ctx, model).map(|args| {
@@ -233,6 +254,46 @@ fn create_many_field(ctx: &mut BuilderContext, model: &ModelRef) -> Option<Outpu
     }
 }
 
+/// Builds a create many mutation field returning the created records (e.g. createManyUserAndReturn) for given model.
+fn create_many_and_return_field(ctx: &mut BuilderContext, model: &ModelRef) -> Option<OutputField> {
+    if ctx.capabilities.contains(ConnectorCapability::CreateManyReturning) {
+        let arguments = arguments::create_many_arguments(ctx, model);
+        let field_name = format!("createMany{}AndReturn", model.name);
+
+        Some(field(
+            field_name,
+            arguments,
+            OutputType::list(OutputType::object(output_objects::map_model_object_type(ctx, &model))),
+            Some(QueryInfo {
+                model: Some(Arc::clone(&model)),
+                tag: QueryTag::CreateManyAndReturn,
+            }),
+        ))
+    } else {
+        None
+    }
+}
+
+/// Builds an upsert many mutation field (e.g. upsertManyUser) for given model.
+fn upsert_many_field(ctx: &mut BuilderContext, model: &ModelRef) -> Option<OutputField> {
+    if ctx.capabilities.contains(ConnectorCapability::UpsertMany) {
+        let arguments = arguments::upsert_many_arguments(ctx, model);
+        let field_name = format!("upsertMany{}", model.name);
+
+        Some(field(
+            field_name,
+            arguments,
+            OutputType::object(output_objects::affected_records_object_type(ctx)),
+            Some(QueryInfo {
+                model: Some(Arc::clone(&model)),
+                tag: QueryTag::UpsertMany,
+            }),
+        ))
+    } else {
+        None
+    }
+}
+
 /// Builds an update many mutation field (e.g. updateManyUsers) for given model.
 fn update_many_field(ctx: &mut BuilderContext, model: &ModelRef) -> OutputField {
     let arguments = arguments::update_many_arguments(ctx, model);
@@ -252,6 +313,22 @@ fn update_many_field(ctx: &mut BuilderContext, model: &ModelRef) -> OutputField
     )
 }
 
+/// Builds an update many mutation field returning the updated records (e.g. updateManyUserAndReturn) for given model.
+fn update_many_and_return_field(ctx: &mut BuilderContext, model: &ModelRef) -> OutputField {
+    let arguments = arguments::update_many_arguments(ctx, model);
+    let field_name = format!("updateMany{}AndReturn", model.name);
+
+    field(
+        field_name,
+        arguments,
+        OutputType::list(OutputType::object(output_objects::map_model_object_type(ctx, &model))),
+        Some(QueryInfo {
+            model: Some(Arc::clone(&model)),
+            tag: QueryTag::UpdateManyAndReturn,
+        }),
+    )
+}
+
 /// Builds an upsert mutation field (e.g. upsertUser) for given model.
 fn upsert_item_field(ctx: &mut BuilderContext, model: &ModelRef) -> Option<OutputField> {
     arguments::upsert_arguments(ctx, model).map(|args| {
@@ -35,12 +35,51 @@ pub(crate) fn initialize_model_object_type_cache(ctx: &mut BuilderContext) {
 /// Computes model output type fields.
 /// Important: This requires that the cache has already been initialized.
 fn compute_model_object_type_fields(ctx: &mut BuilderContext, model: &ModelRef) -> Vec<OutputField> {
-    model
+    let mut fields: Vec<OutputField> = model
         .fields()
         .all
         .iter()
         .map(|f| output_objects::map_field(ctx, f))
-        .collect()
+        .collect();
+
+    if let Some(count_field) = relation_count_field(ctx, model) {
+        fields.push(count_field);
+    }
+
+    fields
+}
+
+/// Returns the `_count` field counting the related records of each to-many relation of the model,
+/// if the model has any.
+fn relation_count_field(ctx: &mut BuilderContext, model: &ModelRef) -> Option<OutputField> {
+    let relation_fields: Vec<_> = model.fields().relation().into_iter().filter(|rf| rf.is_list).collect();
+
+    if relation_fields.is_empty() {
+        None
+    } else {
+        let object_type = OutputType::object(map_relation_count_object_type(ctx, model, &relation_fields));
+
+        Some(field(fields::UNDERSCORE_COUNT, vec![], object_type, None))
+    }
+}
+
+fn map_relation_count_object_type(
+    ctx: &mut BuilderContext,
+    model: &ModelRef,
+    relation_fields: &[RelationFieldRef],
+) -> ObjectTypeWeakRef {
+    let ident = Identifier::new(format!("{}CountOutputType", capitalize(&model.name)), PRISMA_NAMESPACE);
+    return_cached_output!(ctx, &ident);
+
+    let fields = relation_fields
+        .iter()
+        .map(|rf| field(rf.name.clone(), vec![], OutputType::int(), None))
+        .collect();
+
+    let object = Arc::new(object_type(ident.clone(), fields, None));
+    ctx.cache_output_type(ident, object.clone());
+
+    Arc::downgrade(&object)
 }
 
 /// Returns an output object type for the given model.
@@ -52,13 +91,19 @@ pub(crate) fn map_model_object_type(ctx: &mut BuilderContext, model: &ModelRef)
 }
 
 pub(crate) fn map_field(ctx: &mut BuilderContext, model_field: &ModelField) -> OutputField {
+    // Lists of composite values are stored as nullable Json, but null is returned as an empty list.
+    let is_composite_list = match model_field {
+        ModelField::Scalar(sf) => sf.composite_type.as_ref().map(|ct| ct.is_list).unwrap_or(false),
+        ModelField::Relation(_) => false,
+    };
+
     field(
         model_field.name(),
         arguments::many_records_field_arguments(ctx, &model_field),
         map_output_type(ctx, &model_field),
         None,
     )
-    .nullable_if(!model_field.is_required())
+    .nullable_if(!model_field.is_required() && !is_composite_list)
 }
 
 pub(crate) fn map_output_type(ctx: &mut BuilderContext, model_field: &ModelField) -> OutputType {
@@ -69,7 +114,50 @@ pub(crate) fn map_output_type(ctx: &mut BuilderContext, model_field: &ModelField
 }
 
 pub(crate) fn map_scalar_output_type_for_field(ctx: &mut BuilderContext, field: &ScalarFieldRef) -> OutputType {
-    map_scalar_output_type(ctx, &field.type_identifier, field.is_list)
+    match &field.composite_type {
+        Some(composite_type) => map_composite_output_type(ctx, &composite_type.name, composite_type.is_list),
+        None => map_scalar_output_type(ctx, &field.type_identifier, field.is_list),
+    }
+}
+
+fn map_composite_output_type(ctx: &mut BuilderContext, composite_type: &str, list: bool) -> OutputType {
+    let output_type = OutputType::object(map_composite_object_type(ctx, composite_type));
+
+    if list {
+        OutputType::list(output_type)
+    } else {
+        output_type
+    }
+}
+
+/// Returns the output object type of a composite type. Composite values are always returned as a whole.
+pub(crate) fn map_composite_object_type(ctx: &mut BuilderContext, composite_type: &str) -> ObjectTypeWeakRef {
+    let ident = Identifier::new(composite_type.to_owned(), MODEL_NAMESPACE);
+    return_cached_output!(ctx, &ident);
+
+    let composite_type = ctx
+        .internal_data_model
+        .find_composite_type(composite_type)
+        .expect("Composite type references must always be valid.");
+
+    let object = Arc::new(ObjectType::new(ident.clone(), None));
+    ctx.cache_output_type(ident, object.clone());
+
+    let fields = composite_type
+        .fields
+        .iter()
+        .map(|f| {
+            let typ = match &f.composite_type {
+                Some(embedded) => map_composite_output_type(ctx, embedded, f.is_list),
+                None => map_scalar_output_type(ctx, &f.type_identifier, f.is_list),
+            };
+
+            field(f.name.clone(), vec![], typ, None).nullable_if(!f.is_required && !f.is_list)
+        })
+        .collect();
+
+    object.set_fields(fields);
+    Arc::downgrade(&object)
 }
 
 pub(crate) fn map_scalar_output_type(ctx: &mut BuilderContext, typ: &TypeIdentifier, list: bool) -> OutputType {
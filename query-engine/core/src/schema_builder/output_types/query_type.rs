@@ -49,7 +49,10 @@ fn find_unique_field(ctx: &mut BuilderContext, model: &ModelRef) -> Option<Outpu
 
 /// Builds a find first item field for given model.
 fn find_first_field(ctx: &mut BuilderContext, model: &ModelRef) -> OutputField {
-    let args = arguments::many_records_arguments(ctx, &model, true);
+    let mut args = arguments::many_records_arguments(ctx, &model, true);
+    append_opt(&mut args, arguments::relation_load_strategy_argument(ctx));
+    append_opt(&mut args, arguments::lock_argument(ctx));
+
     let field_name = format!("findFirst{}", model.name);
 
     field(
@@ -66,7 +69,10 @@ fn find_first_field(ctx: &mut BuilderContext, model: &ModelRef) -> OutputField {
 
 /// Builds a "multiple" query arity items field (e.g. "users", "posts", ...) for given model.
 fn all_items_field(ctx: &mut BuilderContext, model: &ModelRef) -> OutputField {
-    let args = arguments::many_records_arguments(ctx, &model, true);
+    let mut args = arguments::many_records_arguments(ctx, &model, true);
+    append_opt(&mut args, arguments::relation_load_strategy_argument(ctx));
+    append_opt(&mut args, arguments::lock_argument(ctx));
+
     let field_name = ctx.pluralize_internal(camel_case(pluralize(&model.name)), format!("findMany{}", model.name));
 
     field(
@@ -2,13 +2,13 @@ use crate::{error::ApiError, logger::ChannelLogger};
 use datamodel::{diagnostics::ValidatedConfiguration, Datamodel};
 use napi::threadsafe_function::ThreadsafeFunction;
 use prisma_models::DatamodelConverter;
-use query_core::{exec_loader, schema_builder, BuildMode, QueryExecutor, QuerySchema, QuerySchemaRenderer};
+use query_core::{exec_loader, schema_builder, BuildMode, QueryExecutor, QuerySchema, QuerySchemaRenderer, TxId};
 use request_handlers::{
     dmmf::{self, DataModelMetaFormat},
     GraphQLSchemaRenderer, GraphQlBody, GraphQlHandler, PrismaResponse,
 };
 use serde::{Deserialize, Deserializer, Serialize};
-use std::{collections::BTreeMap, str::FromStr, sync::Arc};
+use std::{collections::BTreeMap, str::FromStr, sync::Arc, time::Duration};
 use tokio::sync::RwLock;
 use tracing::metadata::LevelFilter;
 
@@ -73,6 +73,23 @@ impl ConnectedEngine {
     }
 }
 
+/// Time after which an interactive transaction is rolled back, if the client did not set one.
+const DEFAULT_TRANSACTION_TIMEOUT: Duration = Duration::from_millis(5000);
+
+/// Returned from the `startTransaction` method in javascript.
+#[derive(Debug, Serialize)]
+pub struct StartedTransaction {
+    id: TxId,
+}
+
+/// Parameters of the `startTransaction` method in javascript.
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionOptions {
+    /// Time in milliseconds after which the transaction is rolled back, if still open.
+    timeout: Option<u64>,
+}
+
 /// Parameters defining the construction of an engine.
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -148,7 +165,7 @@ impl QueryEngine {
                     .logger
                     .clone()
                     .with_logging(|| async move {
-                        let template = DatamodelConverter::convert(&builder.datamodel.ast);
+                        let mut template = DatamodelConverter::convert(&builder.datamodel.ast);
 
                         // We only support one data source at the moment, so take the first one (default not exposed yet).
                         let data_source = builder
@@ -158,6 +175,8 @@ impl QueryEngine {
                             .first()
                             .ok_or_else(|| ApiError::configuration("No valid data source found"))?;
 
+                        template.referential_integrity = data_source.referential_integrity;
+
                         let (db_name, executor) = exec_loader::load(&data_source).await?;
                         let connector = executor.primary_connector();
                         connector.get_connection().await?;
@@ -218,14 +237,15 @@ impl QueryEngine {
         }
     }
 
-    /// If connected, sends a query to the core and returns the response.
-    pub async fn query(&self, query: GraphQlBody) -> crate::Result<PrismaResponse> {
+    /// If connected, sends a query to the core and returns the response. With a transaction id,
+    /// the query runs inside of that open interactive transaction.
+    pub async fn query(&self, query: GraphQlBody, tx_id: Option<TxId>) -> crate::Result<PrismaResponse> {
         match *self.inner.read().await {
             Inner::Connected(ref engine) => {
                 engine
                     .logger
                     .with_logging(|| async move {
-                        let handler = GraphQlHandler::new(engine.executor(), engine.query_schema());
+                        let handler = GraphQlHandler::new(engine.executor(), engine.query_schema()).with_tx_id(tx_id);
                         Ok(handler.handle(query).await)
                     })
                     .await
@@ -234,6 +254,39 @@ impl QueryEngine {
         }
     }
 
+    /// If connected, opens an interactive transaction and returns its id.
+    pub async fn start_transaction(&self, options: TransactionOptions) -> crate::Result<StartedTransaction> {
+        match *self.inner.read().await {
+            Inner::Connected(ref engine) => {
+                let timeout = options
+                    .timeout
+                    .map(Duration::from_millis)
+                    .unwrap_or(DEFAULT_TRANSACTION_TIMEOUT);
+
+                let id = engine.executor().start_tx(timeout).await?;
+
+                Ok(StartedTransaction { id })
+            }
+            Inner::Builder(_) => Err(ApiError::NotConnected),
+        }
+    }
+
+    /// If connected, commits and closes an interactive transaction.
+    pub async fn commit_transaction(&self, tx_id: TxId) -> crate::Result<()> {
+        match *self.inner.read().await {
+            Inner::Connected(ref engine) => Ok(engine.executor().commit_tx(&tx_id).await?),
+            Inner::Builder(_) => Err(ApiError::NotConnected),
+        }
+    }
+
+    /// If connected, rolls back and closes an interactive transaction.
+    pub async fn rollback_transaction(&self, tx_id: TxId) -> crate::Result<()> {
+        match *self.inner.read().await {
+            Inner::Connected(ref engine) => Ok(engine.executor().rollback_tx(&tx_id).await?),
+            Inner::Builder(_) => Err(ApiError::NotConnected),
+        }
+    }
+
     /// Loads the query schema. Only available when connected.
     pub async fn sdl_schema(&self) -> crate::Result<String> {
         match *self.inner.read().await {
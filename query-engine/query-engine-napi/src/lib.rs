@@ -1,10 +1,10 @@
-use engine::{ConstructorOptions, QueryEngine};
+use engine::{ConstructorOptions, QueryEngine, TransactionOptions};
 use napi::{
-    threadsafe_function::ThreadSafeCallContext, CallContext, Env, JsFunction, JsObject, JsUndefined, JsUnknown,
-    Property,
+    threadsafe_function::ThreadSafeCallContext, CallContext, Env, JsFunction, JsObject, JsString, JsUndefined,
+    JsUnknown, Property, ValueType,
 };
 use napi_derive::{js_function, module_exports};
-use query_core::QueryExecutor;
+use query_core::{QueryExecutor, TxId};
 
 mod engine;
 mod error;
@@ -61,7 +61,7 @@ fn disconnect(ctx: CallContext) -> napi::Result<JsObject> {
         })
 }
 
-#[js_function(1)]
+#[js_function(2)]
 fn query(ctx: CallContext) -> napi::Result<JsObject> {
     let this: JsObject = ctx.this_unchecked();
     let engine: &QueryEngine = ctx.env.unwrap(&this)?;
@@ -70,10 +70,68 @@ fn query(ctx: CallContext) -> napi::Result<JsObject> {
     let query = ctx.get::<JsObject>(0)?;
     let body = ctx.env.from_js_value(query)?;
 
-    ctx.env
-        .execute_tokio_future(async move { Ok(engine.query(body).await?) }, |&mut env, response| {
-            env.create_string(&serde_json::to_string(&response).unwrap())
-        })
+    // The transaction id is optional.
+    let tx_id = ctx.get::<JsUnknown>(1)?;
+    let tx_id = match tx_id.get_type()? {
+        ValueType::String => Some(tx_id_from_js(ctx.get::<JsString>(1)?)?),
+        _ => None,
+    };
+
+    ctx.env.execute_tokio_future(
+        async move { Ok(engine.query(body, tx_id).await?) },
+        |&mut env, response| env.create_string(&serde_json::to_string(&response).unwrap()),
+    )
+}
+
+#[js_function(1)]
+fn start_transaction(ctx: CallContext) -> napi::Result<JsObject> {
+    let this: JsObject = ctx.this_unchecked();
+    let engine: &QueryEngine = ctx.env.unwrap(&this)?;
+    let engine: QueryEngine = engine.clone();
+
+    // The options are optional.
+    let options = ctx.get::<JsUnknown>(0)?;
+    let options: TransactionOptions = match options.get_type()? {
+        ValueType::Object => ctx.env.from_js_value(options)?,
+        _ => TransactionOptions::default(),
+    };
+
+    ctx.env.execute_tokio_future(
+        async move { Ok(engine.start_transaction(options).await?) },
+        |&mut env, started| env.create_string(&serde_json::to_string(&started).unwrap()),
+    )
+}
+
+#[js_function(1)]
+fn commit_transaction(ctx: CallContext) -> napi::Result<JsObject> {
+    let this: JsObject = ctx.this_unchecked();
+    let engine: &QueryEngine = ctx.env.unwrap(&this)?;
+    let engine: QueryEngine = engine.clone();
+
+    let tx_id = tx_id_from_js(ctx.get::<JsString>(0)?)?;
+
+    ctx.env.execute_tokio_future(
+        async move { Ok(engine.commit_transaction(tx_id).await?) },
+        |&mut env, ()| env.get_undefined(),
+    )
+}
+
+#[js_function(1)]
+fn rollback_transaction(ctx: CallContext) -> napi::Result<JsObject> {
+    let this: JsObject = ctx.this_unchecked();
+    let engine: &QueryEngine = ctx.env.unwrap(&this)?;
+    let engine: QueryEngine = engine.clone();
+
+    let tx_id = tx_id_from_js(ctx.get::<JsString>(0)?)?;
+
+    ctx.env.execute_tokio_future(
+        async move { Ok(engine.rollback_transaction(tx_id).await?) },
+        |&mut env, ()| env.get_undefined(),
+    )
+}
+
+fn tx_id_from_js(tx_id: JsString) -> napi::Result<TxId> {
+    Ok(TxId::from(tx_id.into_utf8()?.into_owned()?))
 }
 
 #[js_function(0)]
@@ -133,6 +191,9 @@ pub fn init(mut exports: JsObject, env: Env) -> napi::Result<()> {
             Property::new(&env, "connect")?.with_method(connect),
             Property::new(&env, "disconnect")?.with_method(disconnect),
             Property::new(&env, "query")?.with_method(query),
+            Property::new(&env, "startTransaction")?.with_method(start_transaction),
+            Property::new(&env, "commitTransaction")?.with_method(commit_transaction),
+            Property::new(&env, "rollbackTransaction")?.with_method(rollback_transaction),
             Property::new(&env, "sdlSchema")?.with_method(sdl_schema),
             Property::new(&env, "dmmf")?.with_method(dmmf),
             Property::new(&env, "getConfig")?.with_method(get_config),
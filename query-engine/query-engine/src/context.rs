@@ -47,7 +47,7 @@ impl ContextBuilder {
 impl PrismaContext {
     /// Initializes a new Prisma context.
     async fn new(config: Configuration, dm: Datamodel, legacy: bool, enable_raw_queries: bool) -> PrismaResult<Self> {
-        let template = DatamodelConverter::convert(&dm);
+        let mut template = DatamodelConverter::convert(&dm);
 
         // We only support one data source at the moment, so take the first one (default not exposed yet).
         let data_source = config
@@ -55,6 +55,8 @@ impl PrismaContext {
             .first()
             .ok_or_else(|| PrismaError::ConfigurationError("No valid data source found".into()))?;
 
+        template.referential_integrity = data_source.referential_integrity;
+
         // Load executor
 
         let (db_name, executor) = exec_loader::load(&data_source).await?;
@@ -82,6 +82,11 @@ pub struct PrismaOpt {
     #[structopt(long = "debug", short = "d")]
     pub enable_debug_mode: bool,
 
+    /// Time in milliseconds after which an interactive transaction that was neither committed
+    /// nor rolled back is rolled back.
+    #[structopt(long, env = "PRISMA_INTERACTIVE_TRANSACTION_TIMEOUT", default_value = "5000")]
+    pub interactive_transaction_timeout: u64,
+
     /// Set the log format.
     #[structopt(long = "log-format", env = "RUST_LOG_FORMAT")]
     pub log_format: Option<String>,
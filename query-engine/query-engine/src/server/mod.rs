@@ -5,31 +5,41 @@ use crate::opt::PrismaOpt;
 use crate::PrismaResult;
 
 use elapsed_middleware::ElapsedMiddleware;
-use query_core::schema::QuerySchemaRenderer;
+use query_core::{schema::QuerySchemaRenderer, TxId};
 use request_handlers::{dmmf, GraphQLSchemaRenderer, GraphQlBody, GraphQlHandler};
 use serde_json::json;
 use tide::http::{mime, StatusCode};
 use tide::{prelude::*, Body, Request, Response};
 use tide_server_timing::TimingMiddleware;
 
-use std::sync::Arc;
+use std::{sync::Arc, time::Duration};
 
 mod elapsed_middleware;
 
+/// Header routing a query into an open interactive transaction.
+static TRANSACTION_ID_HEADER: &str = "x-transaction-id";
+
 //// Shared application state.
 pub(crate) struct State {
     cx: Arc<PrismaContext>,
     enable_playground: bool,
     enable_debug_mode: bool,
+    interactive_transaction_timeout: Duration,
 }
 
 impl State {
     /// Create a new instance of `State`.
-    fn new(cx: PrismaContext, enable_playground: bool, enable_debug_mode: bool) -> Self {
+    fn new(
+        cx: PrismaContext,
+        enable_playground: bool,
+        enable_debug_mode: bool,
+        interactive_transaction_timeout: Duration,
+    ) -> Self {
         Self {
             cx: Arc::new(cx),
             enable_playground,
             enable_debug_mode,
+            interactive_transaction_timeout,
         }
     }
 }
@@ -40,6 +50,7 @@ impl Clone for State {
             cx: self.cx.clone(),
             enable_playground: self.enable_playground,
             enable_debug_mode: self.enable_debug_mode,
+            interactive_transaction_timeout: self.interactive_transaction_timeout,
         }
     }
 }
@@ -58,7 +69,12 @@ pub async fn listen(opts: PrismaOpt) -> PrismaResult<()> {
         .build()
         .await?;
 
-    let mut app = tide::with_state(State::new(cx, opts.enable_playground, opts.enable_debug_mode));
+    let mut app = tide::with_state(State::new(
+        cx,
+        opts.enable_playground,
+        opts.enable_debug_mode,
+        Duration::from_millis(opts.interactive_transaction_timeout),
+    ));
     app.with(ElapsedMiddleware::new());
 
     if opts.enable_playground {
@@ -67,6 +83,9 @@ pub async fn listen(opts: PrismaOpt) -> PrismaResult<()> {
 
     app.at("/").post(graphql_handler);
     app.at("/").get(playground_handler);
+    app.at("/transaction/start").post(start_transaction_handler);
+    app.at("/transaction/:id/commit").post(commit_transaction_handler);
+    app.at("/transaction/:id/rollback").post(rollback_transaction_handler);
     app.at("/sdl").get(sdl_handler);
     app.at("/dmmf").get(dmmf_handler);
     app.at("/server_info").get(server_info_handler);
@@ -93,10 +112,13 @@ async fn graphql_handler(mut req: Request<State>) -> tide::Result {
         }
     }
 
+    let tx_id = req
+        .header(TRANSACTION_ID_HEADER)
+        .map(|values| TxId::from(values.as_str()));
     let body: GraphQlBody = req.body_json().await?;
     let cx = req.state().cx.clone();
 
-    let handler = GraphQlHandler::new(&*cx.executor, cx.query_schema());
+    let handler = GraphQlHandler::new(&*cx.executor, cx.query_schema()).with_tx_id(tx_id);
     let result = handler.handle(body).await;
 
     let mut res = Response::new(StatusCode::Ok);
@@ -105,6 +127,63 @@ async fn graphql_handler(mut req: Request<State>) -> tide::Result {
     Ok(res)
 }
 
+/// Options of the `/transaction/start` endpoint. The body is optional.
+#[derive(Debug, Default, Deserialize)]
+struct StartTransactionInput {
+    /// Overrides the server-wide timeout of the transaction, in milliseconds.
+    timeout: Option<u64>,
+}
+
+/// Opens an interactive transaction and responds with its id. Queries sent to `/` with the id in
+/// the `x-transaction-id` header run inside of that transaction.
+async fn start_transaction_handler(mut req: Request<State>) -> tide::Result {
+    let body = req.body_string().await?;
+
+    let input: StartTransactionInput = if body.trim().is_empty() {
+        StartTransactionInput::default()
+    } else {
+        serde_json::from_str(&body)?
+    };
+
+    let timeout = input
+        .timeout
+        .map(Duration::from_millis)
+        .unwrap_or(req.state().interactive_transaction_timeout);
+
+    match req.state().cx.executor.start_tx(timeout).await {
+        Ok(tx_id) => Ok(json!({ "id": tx_id }).into()),
+        Err(err) => transaction_error_response(err),
+    }
+}
+
+/// Commits and closes an interactive transaction.
+async fn commit_transaction_handler(req: Request<State>) -> tide::Result {
+    let tx_id = TxId::from(req.param("id")?);
+
+    match req.state().cx.executor.commit_tx(&tx_id).await {
+        Ok(()) => Ok(json!({}).into()),
+        Err(err) => transaction_error_response(err),
+    }
+}
+
+/// Rolls back and closes an interactive transaction.
+async fn rollback_transaction_handler(req: Request<State>) -> tide::Result {
+    let tx_id = TxId::from(req.param("id")?);
+
+    match req.state().cx.executor.rollback_tx(&tx_id).await {
+        Ok(()) => Ok(json!({}).into()),
+        Err(err) => transaction_error_response(err),
+    }
+}
+
+fn transaction_error_response(err: query_core::CoreError) -> tide::Result {
+    let err: user_facing_errors::Error = err.into();
+    let mut res = Response::new(StatusCode::BadRequest);
+    res.set_body(Body::from_json(&json!({ "errors": [err] }))?);
+
+    Ok(res)
+}
+
 /// Expose the GraphQL playground if enabled.
 ///
 /// # Security
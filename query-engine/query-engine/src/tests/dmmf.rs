@@ -103,6 +103,7 @@ fn test_dmmf_cli_command(schema: &str) -> PrismaResult<()> {
         enable_debug_mode: false,
         enable_raw_queries: false,
         enable_playground: false,
+        interactive_transaction_timeout: 5000,
         legacy: false,
         log_format: None,
         overwrite_datasources: None,
@@ -17,7 +17,8 @@ pub enum GraphQlBody {
 pub struct SingleQuery {
     query: String,
     operation_name: Option<String>,
-    variables: HashMap<String, String>,
+    #[serde(default)]
+    variables: HashMap<String, serde_json::Value>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -49,7 +50,7 @@ impl GraphQlBody {
         match self {
             GraphQlBody::Single(body) => {
                 let gql_doc = gql::parse_query(&body.query)?;
-                let operation = GraphQLProtocolAdapter::convert(gql_doc, body.operation_name)?;
+                let operation = GraphQLProtocolAdapter::convert(gql_doc, body.operation_name, body.variables)?;
 
                 Ok(QueryDocument::Single(operation))
             }
@@ -59,7 +60,7 @@ impl GraphQlBody {
                     .into_iter()
                     .map(|body| {
                         let gql_doc = gql::parse_query(&body.query)?;
-                        GraphQLProtocolAdapter::convert(gql_doc, body.operation_name)
+                        GraphQLProtocolAdapter::convert(gql_doc, body.operation_name, body.variables)
                     })
                     .collect();
 
@@ -4,18 +4,29 @@ use futures::FutureExt;
 use indexmap::IndexMap;
 use query_core::{
     BatchDocument, CompactedDocument, Item, Operation, QueryDocument, QueryExecutor, QuerySchemaRef, QueryValue,
-    ResponseData,
+    ResponseData, TxId,
 };
 use std::panic::AssertUnwindSafe;
 
 pub struct GraphQlHandler<'a> {
     executor: &'a (dyn QueryExecutor + Send + Sync + 'a),
     query_schema: &'a QuerySchemaRef,
+    tx_id: Option<TxId>,
 }
 
 impl<'a> GraphQlHandler<'a> {
     pub fn new(executor: &'a (dyn QueryExecutor + Send + Sync + 'a), query_schema: &'a QuerySchemaRef) -> Self {
-        Self { executor, query_schema }
+        Self {
+            executor,
+            query_schema,
+            tx_id: None,
+        }
+    }
+
+    /// Routes all operations into the given open interactive transaction.
+    pub fn with_tx_id(mut self, tx_id: Option<TxId>) -> Self {
+        self.tx_id = tx_id;
+        self
     }
 
     pub async fn handle(&self, body: GraphQlBody) -> PrismaResponse {
@@ -50,12 +61,9 @@ impl<'a> GraphQlHandler<'a> {
     async fn handle_batch(&self, queries: Vec<Operation>, transactional: bool) -> PrismaResponse {
         use user_facing_errors::Error;
 
-        match AssertUnwindSafe(
-            self.executor
-                .execute_batch(queries, transactional, self.query_schema.clone()),
-        )
-        .catch_unwind()
-        .await
+        match AssertUnwindSafe(self.execute_batch(queries, transactional))
+            .catch_unwind()
+            .await
         {
             Ok(Ok(responses)) => {
                 let gql_responses: Vec<GQLResponse> = responses
@@ -147,6 +155,43 @@ impl<'a> GraphQlHandler<'a> {
     }
 
     async fn handle_graphql(&self, query_doc: Operation) -> query_core::Result<ResponseData> {
-        self.executor.execute(query_doc, self.query_schema.clone()).await
+        match &self.tx_id {
+            Some(tx_id) => {
+                self.executor
+                    .execute_in_tx(tx_id, query_doc, self.query_schema.clone())
+                    .await
+            }
+            None => self.executor.execute(query_doc, self.query_schema.clone()).await,
+        }
+    }
+
+    /// Inside of an interactive transaction, the operations of a batch run one after the other in
+    /// that transaction, and the `transactional` flag has no effect.
+    async fn execute_batch(
+        &self,
+        queries: Vec<Operation>,
+        transactional: bool,
+    ) -> query_core::Result<Vec<query_core::Result<ResponseData>>> {
+        match &self.tx_id {
+            Some(tx_id) => {
+                let mut results = Vec::with_capacity(queries.len());
+
+                for query in queries {
+                    let result = self
+                        .executor
+                        .execute_in_tx(tx_id, query, self.query_schema.clone())
+                        .await;
+
+                    results.push(result);
+                }
+
+                Ok(results)
+            }
+            None => {
+                self.executor
+                    .execute_batch(queries, transactional, self.query_schema.clone())
+                    .await
+            }
+        }
     }
 }
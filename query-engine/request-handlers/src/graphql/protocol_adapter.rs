@@ -1,10 +1,12 @@
 use crate::HandlerError;
 use bigdecimal::{BigDecimal, FromPrimitive};
 use graphql_parser::query::{
-    Definition, Document, OperationDefinition, Selection as GqlSelection, SelectionSet, Value,
+    Definition, Document, Field, FragmentDefinition, OperationDefinition, Selection as GqlSelection, SelectionSet,
+    Value, VariableDefinition,
 };
 use indexmap::IndexMap;
 use query_core::query_document::*;
+use std::collections::HashMap;
 
 /// Protocol adapter for GraphQL -> Query Document.
 ///
@@ -14,28 +16,94 @@ use query_core::query_document::*;
 /// - If the JSON payload specifies an operation name, only that specific operation is picked and the rest ignored.
 /// - Fields on the queries are mapped to `Field`s, including arguments.
 /// - Concrete values (e.g. in arguments) are mapped to `QueryValue`s.
+/// - Variables are substituted with the values of the JSON payload, or the default values of their definitions.
+///   Arguments and object fields referencing a variable without value are omitted.
+/// - Fragment spreads and inline fragments are expanded in place. Type conditions are not checked,
+///   the expanded fields are validated against the query schema like any other field.
 ///
 /// Currently unsupported features:
-/// - Fragments in any form.
-/// - Variables.
 /// - Subscription queries.
+/// - Directives are ignored.
 /// - Query names are ignored
 pub struct GraphQLProtocolAdapter;
 
+/// The fragments of the document and the variable values of the operation being converted.
+struct ConversionContext<'a> {
+    fragments: &'a HashMap<String, FragmentDefinition<String>>,
+    variables: HashMap<String, QueryValue>,
+}
+
+impl<'a> ConversionContext<'a> {
+    fn new(
+        fragments: &'a HashMap<String, FragmentDefinition<String>>,
+        variables: &HashMap<String, QueryValue>,
+        variable_definitions: Vec<VariableDefinition<String>>,
+    ) -> crate::Result<Self> {
+        let mut variables = variables.clone();
+
+        // Default values are constants and can't reference other variables.
+        let defaults_ctx = ConversionContext {
+            fragments,
+            variables: HashMap::new(),
+        };
+
+        for definition in variable_definitions {
+            if let Some(default_value) = definition.default_value {
+                if !variables.contains_key(&definition.name) {
+                    let value = GraphQLProtocolAdapter::convert_value(default_value, &defaults_ctx)?;
+                    variables.insert(definition.name, value);
+                }
+            }
+        }
+
+        Ok(Self { fragments, variables })
+    }
+
+    /// Variables without value are treated as not provided.
+    fn is_provided(&self, value: &Value<String>) -> bool {
+        match value {
+            Value::Variable(name) => self.variables.contains_key(name),
+            _ => true,
+        }
+    }
+}
+
 impl GraphQLProtocolAdapter {
-    pub fn convert(gql_doc: Document<String>, operation: Option<String>) -> crate::Result<Operation> {
+    pub fn convert(
+        gql_doc: Document<String>,
+        operation: Option<String>,
+        variables: HashMap<String, serde_json::Value>,
+    ) -> crate::Result<Operation> {
+        let variables = variables
+            .into_iter()
+            .map(|(name, value)| Ok((name, Self::convert_json_value(value)?)))
+            .collect::<crate::Result<HashMap<_, _>>>()?;
+
+        let (fragments, definitions): (Vec<_>, Vec<_>) = gql_doc
+            .definitions
+            .into_iter()
+            .partition(|def| matches!(def, Definition::Fragment(_)));
+
+        let fragments: HashMap<String, FragmentDefinition<String>> = fragments
+            .into_iter()
+            .filter_map(|def| match def {
+                Definition::Fragment(f) => Some((f.name.clone(), f)),
+                _ => None,
+            })
+            .collect();
+
+        let convert_definition = |def| Self::convert_definition(def, &fragments, &variables);
+
         let mut operations: Vec<Operation> = match operation {
-            Some(ref op) => gql_doc
-                .definitions
+            Some(ref op) => definitions
                 .into_iter()
                 .find(|def| Self::matches_operation(def, op))
                 .ok_or_else(|| HandlerError::query_conversion(format!("Operation '{}' does not match any query.", op)))
-                .and_then(Self::convert_definition),
+                .and_then(convert_definition),
 
-            None => gql_doc
-                .definitions
+            None => definitions
                 .into_iter()
-                .map(Self::convert_definition)
+                .map(convert_definition)
                 .collect::<crate::Result<Vec<Vec<Operation>>>>()
                 .map(|r| r.into_iter().flatten().collect::<Vec<Operation>>()),
         }?;
@@ -48,66 +116,111 @@ impl GraphQLProtocolAdapter {
         Ok(operation)
     }
 
-    fn convert_definition(def: Definition<String>) -> crate::Result<Vec<Operation>> {
+    fn convert_definition(
+        def: Definition<String>,
+        fragments: &HashMap<String, FragmentDefinition<String>>,
+        variables: &HashMap<String, QueryValue>,
+    ) -> crate::Result<Vec<Operation>> {
         match def {
-            Definition::Fragment(f) => Err(HandlerError::unsupported_feature(
-                "Fragment definition",
-                format!("Fragment '{}', at position {}.", f.name, f.position),
-            )),
+            // Fragments are expanded where they are spread.
+            Definition::Fragment(_) => Ok(vec![]),
             Definition::Operation(op) => match op {
                 OperationDefinition::Subscription(s) => Err(HandlerError::unsupported_feature(
                     "Subscription query",
                     format!("At position {}.", s.position),
                 )),
-                OperationDefinition::SelectionSet(s) => Self::convert_query(s),
-                OperationDefinition::Query(q) => Self::convert_query(q.selection_set),
-                OperationDefinition::Mutation(m) => Self::convert_mutation(m.selection_set),
+                OperationDefinition::SelectionSet(s) => {
+                    let ctx = ConversionContext::new(fragments, variables, vec![])?;
+                    Self::convert_query(s, &ctx)
+                }
+                OperationDefinition::Query(q) => {
+                    let ctx = ConversionContext::new(fragments, variables, q.variable_definitions)?;
+                    Self::convert_query(q.selection_set, &ctx)
+                }
+                OperationDefinition::Mutation(m) => {
+                    let ctx = ConversionContext::new(fragments, variables, m.variable_definitions)?;
+                    Self::convert_mutation(m.selection_set, &ctx)
+                }
             },
         }
     }
 
-    fn convert_query(selection_set: SelectionSet<String>) -> crate::Result<Vec<Operation>> {
-        Self::convert_selection_set(selection_set).map(|fields| fields.into_iter().map(Operation::Read).collect())
+    fn convert_query(selection_set: SelectionSet<String>, ctx: &ConversionContext) -> crate::Result<Vec<Operation>> {
+        Self::convert_selection_set(selection_set, ctx, &mut vec![])
+            .map(|fields| fields.into_iter().map(Operation::Read).collect())
     }
 
-    fn convert_mutation(selection_set: SelectionSet<String>) -> crate::Result<Vec<Operation>> {
-        Self::convert_selection_set(selection_set).map(|fields| fields.into_iter().map(Operation::Write).collect())
+    fn convert_mutation(selection_set: SelectionSet<String>, ctx: &ConversionContext) -> crate::Result<Vec<Operation>> {
+        Self::convert_selection_set(selection_set, ctx, &mut vec![])
+            .map(|fields| fields.into_iter().map(Operation::Write).collect())
     }
 
-    fn convert_selection_set(selection_set: SelectionSet<String>) -> crate::Result<Vec<Selection>> {
-        selection_set
-            .items
-            .into_iter()
-            .map(|item| match item {
-                GqlSelection::Field(f) => {
-                    let arguments: Vec<(String, QueryValue)> = f
-                        .arguments
-                        .into_iter()
-                        .map(|(k, v)| Ok((k, Self::convert_value(v)?)))
-                        .collect::<crate::Result<Vec<_>>>()?;
-
-                    let mut builder = Selection::builder(f.name);
-                    builder.set_arguments(arguments);
-                    builder.nested_selections(Self::convert_selection_set(f.selection_set)?);
-
-                    if let Some(alias) = f.alias {
-                        builder.alias(alias);
-                    };
-
-                    Ok(builder.build())
+    /// `fragment_path` contains the names of the fragments currently being expanded, to reject fragment cycles.
+    fn convert_selection_set(
+        selection_set: SelectionSet<String>,
+        ctx: &ConversionContext,
+        fragment_path: &mut Vec<String>,
+    ) -> crate::Result<Vec<Selection>> {
+        let mut selections = Vec::with_capacity(selection_set.items.len());
+
+        for item in selection_set.items {
+            match item {
+                GqlSelection::Field(f) => selections.push(Self::convert_field(f, ctx, fragment_path)?),
+
+                GqlSelection::FragmentSpread(fs) => {
+                    if fragment_path.contains(&fs.fragment_name) {
+                        return Err(HandlerError::query_conversion(format!(
+                            "Fragment '{}' is spread within itself, at position {}.",
+                            fs.fragment_name, fs.position
+                        )));
+                    }
+
+                    let fragment = ctx.fragments.get(&fs.fragment_name).ok_or_else(|| {
+                        HandlerError::query_conversion(format!(
+                            "Unknown fragment '{}', at position {}.",
+                            fs.fragment_name, fs.position
+                        ))
+                    })?;
+
+                    fragment_path.push(fs.fragment_name);
+                    selections.extend(Self::convert_selection_set(
+                        fragment.selection_set.clone(),
+                        ctx,
+                        fragment_path,
+                    )?);
+                    fragment_path.pop();
                 }
 
-                GqlSelection::FragmentSpread(fs) => Err(HandlerError::unsupported_feature(
-                    "Fragment spread",
-                    format!("Fragment '{}', at position {}.", fs.fragment_name, fs.position),
-                )),
+                GqlSelection::InlineFragment(i) => {
+                    selections.extend(Self::convert_selection_set(i.selection_set, ctx, fragment_path)?)
+                }
+            }
+        }
 
-                GqlSelection::InlineFragment(i) => Err(HandlerError::unsupported_feature(
-                    "Inline fragment",
-                    format!("At position {}.", i.position),
-                )),
-            })
-            .collect()
+        Ok(selections)
+    }
+
+    fn convert_field(
+        field: Field<String>,
+        ctx: &ConversionContext,
+        fragment_path: &mut Vec<String>,
+    ) -> crate::Result<Selection> {
+        let arguments: Vec<(String, QueryValue)> = field
+            .arguments
+            .into_iter()
+            .filter(|(_, v)| ctx.is_provided(v))
+            .map(|(k, v)| Ok((k, Self::convert_value(v, ctx)?)))
+            .collect::<crate::Result<Vec<_>>>()?;
+
+        let mut builder = Selection::builder(field.name);
+        builder.set_arguments(arguments);
+        builder.nested_selections(Self::convert_selection_set(field.selection_set, ctx, fragment_path)?);
+
+        if let Some(alias) = field.alias {
+            builder.alias(alias);
+        };
+
+        Ok(builder.build())
     }
 
     /// Checks if the given GraphQL definition matches the operation name that should be executed.
@@ -124,12 +237,13 @@ impl GraphQLProtocolAdapter {
         }
     }
 
-    fn convert_value(value: Value<String>) -> crate::Result<QueryValue> {
+    fn convert_value(value: Value<String>, ctx: &ConversionContext) -> crate::Result<QueryValue> {
         match value {
-            Value::Variable(name) => Err(HandlerError::unsupported_feature(
-                "Variable usage",
-                format!("Variable '{}'.", name),
-            )),
+            Value::Variable(name) => ctx
+                .variables
+                .get(&name)
+                .cloned()
+                .ok_or_else(|| HandlerError::query_conversion(format!("Missing value for variable '{}'.", name))),
             Value::Int(i) => match i.as_i64() {
                 Some(i) => Ok(QueryValue::Int(i)),
                 None => Err(HandlerError::query_conversion(format!(
@@ -148,7 +262,7 @@ impl GraphQLProtocolAdapter {
             Value::List(values) => {
                 let values: Vec<QueryValue> = values
                     .into_iter()
-                    .map(Self::convert_value)
+                    .map(|v| Self::convert_value(v, ctx))
                     .collect::<crate::Result<Vec<QueryValue>>>()?;
 
                 Ok(QueryValue::List(values))
@@ -156,7 +270,40 @@ impl GraphQLProtocolAdapter {
             Value::Object(map) => {
                 let values = map
                     .into_iter()
-                    .map(|(k, v)| Self::convert_value(v).map(|v| (k, v)))
+                    .filter(|(_, v)| ctx.is_provided(v))
+                    .map(|(k, v)| Self::convert_value(v, ctx).map(|v| (k, v)))
+                    .collect::<crate::Result<IndexMap<String, QueryValue>>>()?;
+
+                Ok(QueryValue::Object(values))
+            }
+        }
+    }
+
+    /// Converts the JSON value of a variable. JSON has no notion of enums, enum values are passed as strings.
+    fn convert_json_value(value: serde_json::Value) -> crate::Result<QueryValue> {
+        match value {
+            serde_json::Value::Null => Ok(QueryValue::Null),
+            serde_json::Value::Bool(b) => Ok(QueryValue::Boolean(b)),
+            serde_json::Value::Number(n) => match n.as_i64() {
+                Some(i) => Ok(QueryValue::Int(i)),
+                None => match n.as_f64().and_then(BigDecimal::from_f64) {
+                    Some(dec) => Ok(QueryValue::Float(dec)),
+                    None => Err(HandlerError::query_conversion(format!("Invalid number: {}", n))),
+                },
+            },
+            serde_json::Value::String(s) => Ok(QueryValue::String(s)),
+            serde_json::Value::Array(values) => {
+                let values: Vec<QueryValue> = values
+                    .into_iter()
+                    .map(Self::convert_json_value)
+                    .collect::<crate::Result<Vec<QueryValue>>>()?;
+
+                Ok(QueryValue::List(values))
+            }
+            serde_json::Value::Object(map) => {
+                let values = map
+                    .into_iter()
+                    .map(|(k, v)| Self::convert_json_value(v).map(|v| (k, v)))
                     .collect::<crate::Result<IndexMap<String, QueryValue>>>()?;
 
                 Ok(QueryValue::Object(values))
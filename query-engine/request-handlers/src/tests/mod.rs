@@ -1 +1,2 @@
 mod dmmf;
+mod protocol_adapter;
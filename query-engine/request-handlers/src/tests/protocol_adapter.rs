@@ -0,0 +1,104 @@
+use crate::GraphQLProtocolAdapter;
+use graphql_parser::parse_query;
+use query_core::query_document::{Operation, QueryValue, Selection};
+use serde_json::json;
+use std::collections::HashMap;
+
+fn convert(query: &str, variables: serde_json::Value) -> crate::Result<Selection> {
+    let variables: HashMap<String, serde_json::Value> = serde_json::from_value(variables).unwrap();
+    let operation = GraphQLProtocolAdapter::convert(parse_query(query).unwrap(), None, variables)?;
+
+    match operation {
+        Operation::Read(selection) => Ok(selection),
+        Operation::Write(selection) => Ok(selection),
+    }
+}
+
+fn nested_names(selection: &Selection) -> Vec<&str> {
+    selection.nested_selections().iter().map(|s| s.name()).collect()
+}
+
+#[test]
+fn variables_are_substituted_in_arguments() {
+    let query = r#"
+        query ($id: Int!, $name: String) {
+            findManyUser(where: { id: $id, name: $name }, take: $take) { id }
+        }
+    "#;
+
+    let selection = convert(query, json!({ "id": 1, "name": "Bob" })).unwrap();
+    let (name, value) = &selection.arguments()[0];
+
+    assert_eq!(selection.arguments().len(), 1);
+    assert_eq!(name, "where");
+
+    let mut expected = indexmap::IndexMap::new();
+    expected.insert("id".to_owned(), QueryValue::Int(1));
+    expected.insert("name".to_owned(), QueryValue::String("Bob".to_owned()));
+
+    assert_eq!(value, &QueryValue::Object(expected));
+}
+
+#[test]
+fn variables_fall_back_to_their_default_values() {
+    let query = r#"
+        query ($take: Int = 10, $skip: Int) {
+            findManyUser(take: $take, skip: $skip) { id }
+        }
+    "#;
+
+    let selection = convert(query, json!({})).unwrap();
+
+    assert_eq!(selection.arguments(), &[("take".to_owned(), QueryValue::Int(10))]);
+}
+
+#[test]
+fn variables_inside_lists_must_be_provided() {
+    let query = r#"query ($id: Int) { findManyUser(where: { id: { in: [$id] } }) { id } }"#;
+
+    assert!(convert(query, json!({})).is_err());
+}
+
+#[test]
+fn fragments_are_expanded() {
+    let query = r#"
+        query {
+            findManyUser {
+                id
+                ...UserFields
+                ... on User {
+                    email
+                }
+            }
+        }
+
+        fragment UserFields on User {
+            name
+            posts { ...PostFields }
+        }
+
+        fragment PostFields on Post {
+            title
+        }
+    "#;
+
+    let selection = convert(query, json!({})).unwrap();
+
+    assert_eq!(nested_names(&selection), vec!["id", "name", "posts", "email"]);
+    assert_eq!(nested_names(&selection.nested_selections()[2]), vec!["title"]);
+}
+
+#[test]
+fn cyclic_fragments_are_rejected() {
+    let query = r#"
+        query {
+            findManyUser { ...UserFields }
+        }
+
+        fragment UserFields on User {
+            posts { author { ...UserFields } }
+        }
+    "#;
+
+    assert!(convert(query, json!({})).is_err());
+}